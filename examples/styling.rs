@@ -168,7 +168,7 @@ fn demonstrate_button_views(ctx: &RenderContext) {
         .with_text(|text| text.color(Color::rgb(0.2, 0.2, 0.2)).font_size(16.0));
 
     let tertiary_button = Button::new("Tertiary Action")
-        .background_color(Color::rgba(0.0, 0.0, 0.0, 0.0)) // Transparent
+        .background_color(Color::TRANSPARENT)
         .with_text(|text| text.color(Color::rgb(0.2, 0.5, 0.9)).font_size(16.0));
 
     // Semantic button colors - conveying meaning through color
@@ -195,9 +195,9 @@ fn demonstrate_button_views(ctx: &RenderContext) {
 
     // State demonstrations
     let disabled_button = Button::new("Disabled")
-        .background_color(Color::rgb(0.7, 0.7, 0.7))
-        .with_text(|text| text.color(Color::rgb(0.5, 0.5, 0.5)))
-        .disable();
+        .background_color(Color::rgb(0.2, 0.5, 0.9))
+        .with_text(|text| text.color(Color::WHITE))
+        .as_disabled_style();
 
     // Advanced styling - subtle colors and transparency
     let glass_button = Button::new("Glass Effect")
@@ -308,13 +308,12 @@ fn demonstrate_view_extraction(ctx: &RenderContext) {
     println!("    Text: {:?}", complex_button.text.content);
     println!("    Enabled: {}", complex_button.is_enabled());
     println!("    Pressed: {}", complex_button.is_pressed());
-    println!(
-        "    Background: RGBA({}, {}, {}, {})",
-        complex_button.background_color.r,
-        complex_button.background_color.g,
-        complex_button.background_color.b,
-        complex_button.background_color.a
-    );
+    if let Some(background) = complex_button.background_color {
+        println!(
+            "    Background: RGBA({}, {}, {}, {})",
+            background.r, background.g, background.b, background.a
+        );
+    }
 
     println!();
     println!("  After MockBackend Extraction:");
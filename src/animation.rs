@@ -0,0 +1,167 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Time-based transitions between values
+//!
+//! An Animation describes a transition from one value to another over a
+//! duration. It holds no clock: callers ask for the value at a point in time
+//! measured from a shared origin, such as the time since the app started.
+//! This keeps models pure and makes animations trivial to test.
+//!
+//! Animations respect the render context's accessibility settings. When the
+//! user asked for reduced motion, every animation jumps straight to its end
+//! value.
+
+use std::time::Duration;
+
+use crate::{extraction::RenderContext, style::Color};
+
+/// Values that can be interpolated by an animation.
+pub trait Animatable: Clone {
+    /// Get the value a fraction `t` of the way from `self` to `other`.
+    ///
+    /// `t` is between 0.0 and 1.0, and implementations must return `self`
+    /// and `other` exactly at those ends.
+    fn interpolate(&self, other: &Self, t: f32) -> Self;
+}
+
+impl Animatable for f32 {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        self * (1.0 - t) + other * t
+    }
+}
+
+impl Animatable for Color {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        self.lerp(other, t)
+    }
+}
+
+/// A linear transition from one value to another.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use ironwood::{prelude::*, animation::Animation};
+///
+/// let fade = Animation::new(0.0, 1.0, Duration::from_millis(200));
+/// let ctx = RenderContext::new();
+/// assert_eq!(fade.value_at(Duration::from_millis(100), &ctx), 0.5);
+///
+/// // With reduced motion the transition is skipped
+/// let ctx = RenderContext::new().with_reduce_motion(true);
+/// assert_eq!(fade.value_at(Duration::ZERO, &ctx), 1.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Animation<T> {
+    /// The value at the start of the transition
+    pub from: T,
+    /// The value at the end of the transition
+    pub to: T,
+    /// When the transition starts, relative to the caller's time origin
+    pub start: Duration,
+    /// How long the transition takes
+    pub duration: Duration,
+}
+
+impl<T: Animatable> Animation<T> {
+    /// Create an animation that starts at time zero.
+    pub fn new(from: T, to: T, duration: Duration) -> Self {
+        Self {
+            from,
+            to,
+            start: Duration::ZERO,
+            duration,
+        }
+    }
+
+    /// Start the animation at a later time.
+    pub fn starting_at(mut self, start: Duration) -> Self {
+        self.start = start;
+        self
+    }
+
+    /// Get how far along the transition is at `time`, from 0.0 to 1.0.
+    ///
+    /// Under reduced motion the transition is always complete.
+    pub fn progress_at(&self, time: Duration, ctx: &RenderContext) -> f32 {
+        if ctx.reduce_motion() {
+            return 1.0;
+        }
+        if time < self.start {
+            return 0.0;
+        }
+        if self.duration.is_zero() {
+            return 1.0;
+        }
+        let elapsed = time.saturating_sub(self.start);
+        (elapsed.as_secs_f32() / self.duration.as_secs_f32()).clamp(0.0, 1.0)
+    }
+
+    /// Get the animated value at `time`.
+    ///
+    /// Before the start this is `from` and after the end it is `to`. Under
+    /// reduced motion it is always `to`.
+    pub fn value_at(&self, time: Duration, ctx: &RenderContext) -> T {
+        match self.progress_at(time, ctx) {
+            0.0 => self.from.clone(),
+            1.0 => self.to.clone(),
+            t => self.from.interpolate(&self.to, t),
+        }
+    }
+
+    /// Check whether the transition has finished at `time`.
+    pub fn is_finished(&self, time: Duration, ctx: &RenderContext) -> bool {
+        self.progress_at(time, ctx) >= 1.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(ms: u64) -> Duration {
+        Duration::from_millis(ms)
+    }
+
+    #[test]
+    fn value_follows_time() {
+        let ctx = RenderContext::new();
+        let slide = Animation::new(10.0, 20.0, millis(100)).starting_at(millis(50));
+
+        assert_eq!(slide.value_at(millis(0), &ctx), 10.0);
+        assert_eq!(slide.value_at(millis(50), &ctx), 10.0);
+        assert_eq!(slide.value_at(millis(75), &ctx), 12.5);
+        assert_eq!(slide.value_at(millis(150), &ctx), 20.0);
+        assert_eq!(slide.value_at(millis(500), &ctx), 20.0);
+        assert!(!slide.is_finished(millis(149), &ctx));
+        assert!(slide.is_finished(millis(150), &ctx));
+    }
+
+    #[test]
+    fn reduced_motion_jumps_to_end() {
+        let ctx = RenderContext::new().with_reduce_motion(true);
+        let slide = Animation::new(10.0, 20.0, millis(100)).starting_at(millis(50));
+        assert_eq!(slide.value_at(millis(50), &ctx), 20.0);
+        assert_eq!(slide.value_at(millis(0), &ctx), 20.0);
+        assert!(slide.is_finished(millis(0), &ctx));
+
+        let fade = Animation::new(Color::BLACK, Color::WHITE, millis(300));
+        assert_eq!(fade.value_at(millis(0), &ctx), Color::WHITE);
+    }
+
+    #[test]
+    fn colors_and_instant_transitions() {
+        let ctx = RenderContext::new();
+        let fade = Animation::new(Color::BLACK, Color::WHITE, millis(200));
+        assert_eq!(fade.value_at(millis(100), &ctx), Color::rgb(0.5, 0.5, 0.5));
+
+        let instant = Animation::new(0.0, 1.0, Duration::ZERO).starting_at(millis(10));
+        assert_eq!(instant.value_at(millis(9), &ctx), 0.0);
+        assert_eq!(instant.value_at(millis(10), &ctx), 1.0);
+    }
+}
+
+// End of File
@@ -0,0 +1,269 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Backend that routes each view type to a different backend
+//!
+//! A composite backend lets an application render most views with one
+//! backend while handing particular types to another, such as a custom view
+//! that only a specialised backend knows how to draw. Each route pairs a view
+//! type with the backend that extracts it and a conversion into the output
+//! type `C` shared by every route.
+//!
+//! Dispatch is by the view's concrete type, exactly like [`ViewRegistry`]:
+//! there is no fallback chain, so a view with no route fails with
+//! [`ExtractionError::UnregisteredType`] instead of silently picking a
+//! backend.
+
+use std::{
+    any::{TypeId, type_name},
+    collections::HashMap,
+    fmt::{Debug, Formatter, Result as FormatterResult},
+    marker::PhantomData,
+};
+
+use crate::{
+    extraction::{
+        ExtractionError, ExtractionResult, RegistryResult, RenderContext, ViewExtractor,
+        ViewRegistry,
+    },
+    view::View,
+};
+
+/// A backend that delegates extraction to a different backend per view type.
+///
+/// Routes are registered with [`route`](Self::route), naming the view type,
+/// the backend that extracts it, and how that backend's output becomes a `C`.
+/// Extraction then looks up the view's concrete type and runs its route.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{
+///     backends::{
+///         CompositeBackend,
+///         terminal::{Grid, TerminalBackend},
+///         MockBackend, MockSpacer,
+///     },
+///     prelude::*,
+/// };
+///
+/// #[derive(Debug)]
+/// enum Drawn {
+///     Cells(Grid),
+///     Gap(MockSpacer),
+/// }
+///
+/// let mut backend = CompositeBackend::<Drawn>::new();
+/// backend.route::<Text, TerminalBackend, _>(Drawn::Cells)?;
+/// backend.route::<Spacer, MockBackend, _>(Drawn::Gap)?;
+///
+/// let ctx = RenderContext::new();
+/// assert!(matches!(backend.extract(&Text::new("hi"), &ctx)?, Drawn::Cells(_)));
+/// assert!(matches!(backend.extract(&Spacer::new(), &ctx)?, Drawn::Gap(_)));
+/// assert!(backend.extract(&Button::new("Go").view(), &ctx).is_err());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct CompositeBackend<C> {
+    /// Extractors and converters for every routed view type
+    registry: ViewRegistry,
+    /// Name of the backend each routed view type is handed to
+    backends: HashMap<TypeId, &'static str>,
+    _output: PhantomData<fn() -> C>,
+}
+
+impl<C: 'static> CompositeBackend<C> {
+    /// Create a composite backend with no routes.
+    pub fn new() -> Self {
+        Self {
+            registry: ViewRegistry::new(),
+            backends: HashMap::new(),
+            _output: PhantomData,
+        }
+    }
+
+    /// Route a view type to a backend.
+    ///
+    /// Views of type `V` are extracted by `B`, and the result is passed
+    /// through `convert` to produce the composite output.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RegistryError::DuplicateRegistration` if `V` already has a
+    /// route. The existing route is left in place.
+    pub fn route<V, B, F>(&mut self, convert: F) -> RegistryResult<()>
+    where
+        V: View + 'static,
+        B: ViewExtractor<V> + 'static,
+        B::Output: 'static,
+        F: Fn(B::Output) -> C + Send + Sync + 'static,
+    {
+        self.registry.register_full::<V, B, C, F>(convert)?;
+        self.backends.insert(TypeId::of::<V>(), type_name::<B>());
+        Ok(())
+    }
+
+    /// Extract a view with the backend routed for its concrete type.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ExtractionError::UnregisteredType` if the view's type has no
+    /// route, and otherwise any error from the routed backend.
+    pub fn extract(&self, view: &dyn View, ctx: &RenderContext) -> ExtractionResult<C> {
+        let converted = self.registry.extract_and_convert::<Self>(view, ctx)?;
+        converted
+            .downcast::<C>()
+            .map(|output| *output)
+            .map_err(|_| ExtractionError::OutputDowncastFailed {
+                expected_type: type_name::<C>(),
+                path: Vec::new(),
+            })
+    }
+
+    /// Get the name of the backend a view would be routed to.
+    ///
+    /// Names are the full paths reported by `std::any::type_name`. Returns
+    /// `None` if the view's type has no route.
+    pub fn backend_for(&self, view: &dyn View) -> Option<&'static str> {
+        self.backends.get(&view.as_any().type_id()).copied()
+    }
+
+    /// Check if a view type has a route.
+    pub fn is_routed<V: View + 'static>(&self) -> bool {
+        self.registry.is_registered::<V>()
+    }
+
+    /// Get the number of routed view types.
+    pub fn len(&self) -> usize {
+        self.registry.len()
+    }
+
+    /// Check if no view types have been routed.
+    pub fn is_empty(&self) -> bool {
+        self.registry.is_empty()
+    }
+}
+
+impl<C: 'static> Default for CompositeBackend<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C> Debug for CompositeBackend<C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FormatterResult {
+        let mut routes: Vec<&'static str> = self.backends.values().copied().collect();
+        routes.sort_unstable();
+        f.debug_struct("CompositeBackend")
+            .field("routes", &self.backends.len())
+            .field("backends", &routes)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::any::Any;
+
+    use super::*;
+    use crate::{
+        backends::mock::{MockBackend, MockDynamicChild},
+        elements::{Spacer, Text},
+        extraction::RegistryError,
+    };
+
+    /// A custom view only the badge backend understands.
+    #[derive(Debug)]
+    struct Badge(u32);
+
+    impl View for Badge {
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+    }
+
+    struct BadgeBackend;
+
+    impl ViewExtractor<Badge> for BadgeBackend {
+        type Output = String;
+
+        fn extract(view: &Badge, _ctx: &RenderContext) -> ExtractionResult<Self::Output> {
+            Ok(format!("({})", view.0))
+        }
+    }
+
+    #[derive(Debug)]
+    enum Rendered {
+        Mock(MockDynamicChild),
+        Badge(String),
+    }
+
+    fn composite() -> CompositeBackend<Rendered> {
+        let mut backend = CompositeBackend::new();
+        backend
+            .route::<Text, MockBackend, _>(|text| Rendered::Mock(MockDynamicChild::Text(text)))
+            .unwrap();
+        backend
+            .route::<Spacer, MockBackend, _>(|spacer| {
+                Rendered::Mock(MockDynamicChild::Spacer(spacer))
+            })
+            .unwrap();
+        backend
+            .route::<Badge, BadgeBackend, _>(Rendered::Badge)
+            .unwrap();
+        backend
+    }
+
+    #[test]
+    fn views_route_to_their_backend() {
+        let backend = composite();
+        let ctx = RenderContext::new();
+        assert_eq!(backend.len(), 3);
+
+        match backend.extract(&Text::new("Inbox"), &ctx).unwrap() {
+            Rendered::Mock(MockDynamicChild::Text(text)) => assert_eq!(text.content, "Inbox"),
+            other => panic!("expected mock text, got {:?}", other),
+        }
+        match backend.extract(&Badge(3), &ctx).unwrap() {
+            Rendered::Badge(badge) => assert_eq!(badge, "(3)"),
+            other => panic!("expected badge, got {:?}", other),
+        }
+        assert!(matches!(
+            backend.extract(&Spacer::new(), &ctx).unwrap(),
+            Rendered::Mock(MockDynamicChild::Spacer(_))
+        ));
+
+        assert_eq!(
+            backend.backend_for(&Badge(1)),
+            Some(type_name::<BadgeBackend>())
+        );
+        assert_eq!(
+            backend.backend_for(&Text::new("")),
+            Some(type_name::<MockBackend>())
+        );
+    }
+
+    #[test]
+    fn unrouted_types_error_clearly() {
+        let backend = composite();
+        let view = crate::elements::Padding::new(Text::new("Boxed"), Default::default());
+        assert_eq!(backend.backend_for(&view), None);
+
+        let error = backend.extract(&view, &RenderContext::new()).unwrap_err();
+        assert!(matches!(error, ExtractionError::UnregisteredType { .. }));
+        assert!(error.to_string().contains("Padding"));
+    }
+
+    #[test]
+    fn routes_cannot_be_replaced() {
+        let mut backend = composite();
+        let result = backend.route::<Badge, BadgeBackend, _>(|_| Rendered::Badge(String::new()));
+        assert!(matches!(
+            result,
+            Err(RegistryError::DuplicateRegistration { .. })
+        ));
+        assert_eq!(backend.len(), 3);
+    }
+}
+
+// End of File
@@ -0,0 +1,382 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! JSON backend for snapshot testing
+//!
+//! The JSON backend extracts a view tree with the mock backend and turns the
+//! result into nested JSON objects. Every node has a `"type"` field naming
+//! the view next to the view's own fields, and container nodes hold their
+//! children as nested nodes under `"content"`. Comparing the JSON of a whole
+//! screen against a stored snapshot is usually much shorter than asserting
+//! on each extracted node by hand.
+//!
+//! Available with the `json` feature.
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::{
+    backends::mock::{
+        MockBackend, MockBadge, MockBorder, MockCard, MockDynamicChild, MockFlowLayout, MockGrid,
+        MockHStack, MockIdentified, MockOpacity, MockPadding, MockScrollView, MockTagged,
+        MockTooltip, MockVStack, MockZStack,
+    },
+    extraction::{ExtractionResult, RenderContext},
+    view::View,
+};
+
+/// Backend that extracts views into tagged JSON.
+///
+/// Any view registered with [`MockBackend`] can be extracted, including
+/// dynamic containers, which become nested JSON objects.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{backends::json::JsonBackend, prelude::*};
+///
+/// let view = VStack::dynamic().child(Box::new(Text::new("Hello")));
+/// let json = JsonBackend::new()
+///     .extract_to_value(&view, &RenderContext::new())
+///     .unwrap();
+///
+/// assert_eq!(json["type"], "VStack");
+/// assert_eq!(json["content"][0]["type"], "Text");
+/// assert_eq!(json["content"][0]["content"], "Hello");
+/// ```
+pub struct JsonBackend {
+    /// Backend that performs the underlying extraction
+    mock: MockBackend,
+}
+
+impl JsonBackend {
+    /// Create a JSON backend that can extract every view the mock backend supports.
+    pub fn new() -> Self {
+        Self {
+            mock: MockBackend::new(),
+        }
+    }
+
+    /// Extract a view into a JSON value.
+    pub fn extract_to_value(
+        &self,
+        view: &dyn View,
+        context: &RenderContext,
+    ) -> ExtractionResult<Value> {
+        Ok(Self::to_value(&self.mock.extract_dynamic(view, context)?))
+    }
+
+    /// Extract a view into pretty-printed JSON, ready to compare against a snapshot.
+    pub fn extract_to_string(
+        &self,
+        view: &dyn View,
+        context: &RenderContext,
+    ) -> ExtractionResult<String> {
+        let value = self.extract_to_value(view, context)?;
+        Ok(serde_json::to_string_pretty(&value).expect("JSON values always serialize"))
+    }
+
+    /// Convert an already extracted tree into tagged JSON.
+    pub fn to_value(node: &MockDynamicChild) -> Value {
+        use MockDynamicChild as Node;
+
+        let (view_type, value) = match node {
+            Node::Text(text) => ("Text", fields(text)),
+            Node::RichText(text) => ("RichText", fields(text)),
+            Node::Image(image) => ("Image", fields(image)),
+            Node::Gauge(gauge) => ("Gauge", fields(gauge)),
+            Node::Sparkline(sparkline) => ("Sparkline", fields(sparkline)),
+            Node::Label(label) => ("Label", fields(label.as_ref())),
+            Node::Button(button) => ("Button", fields(button)),
+            Node::Checkbox(checkbox) => ("Checkbox", fields(checkbox)),
+            Node::Picker(picker) => ("Picker", fields(picker)),
+            Node::Slider(slider) => ("Slider", fields(slider)),
+            Node::Spinner(spinner) => ("Spinner", fields(spinner)),
+            Node::ActivityIndicator(indicator) => ("ActivityIndicator", fields(indicator)),
+            Node::Stepper(stepper) => ("Stepper", fields(stepper)),
+            Node::Table(table) => ("Table", fields(table)),
+            Node::Toggle(toggle) => ("Toggle", fields(toggle)),
+            Node::Spacer(spacer) => ("Spacer", fields(spacer)),
+            // Containers serialize a copy of their own fields with the children left
+            // out, then attach the children converted one by one
+            Node::VStack(stack) => (
+                "VStack",
+                container(
+                    &MockVStack {
+                        content: (),
+                        alignment: stack.alignment,
+                        horizontal_edge: stack.horizontal_edge,
+                        spacing: stack.spacing,
+                    },
+                    "content",
+                    list(&stack.content),
+                ),
+            ),
+            Node::HStack(stack) => (
+                "HStack",
+                container(
+                    &MockHStack {
+                        content: (),
+                        alignment: stack.alignment,
+                        leading_edge: stack.leading_edge,
+                        spacing: stack.spacing,
+                    },
+                    "content",
+                    list(&stack.content),
+                ),
+            ),
+            Node::ZStack(stack) => (
+                "ZStack",
+                container(
+                    &MockZStack {
+                        content: (),
+                        alignment: stack.alignment,
+                    },
+                    "content",
+                    list(&stack.content),
+                ),
+            ),
+            Node::Grid(grid) => (
+                "Grid",
+                container(
+                    &MockGrid {
+                        columns: grid.columns,
+                        row_spacing: grid.row_spacing,
+                        column_spacing: grid.column_spacing,
+                        content: Vec::new(),
+                    },
+                    "content",
+                    list(&grid.content),
+                ),
+            ),
+            Node::FlowLayout(flow) => (
+                "FlowLayout",
+                container(
+                    &MockFlowLayout {
+                        max_width: flow.max_width,
+                        line_spacing: flow.line_spacing,
+                        item_spacing: flow.item_spacing,
+                        content: Vec::new(),
+                    },
+                    "content",
+                    list(&flow.content),
+                ),
+            ),
+            Node::Padding(padding) => (
+                "Padding",
+                container(
+                    &MockPadding {
+                        insets: padding.insets,
+                        content: (),
+                    },
+                    "content",
+                    Self::to_value(&padding.content),
+                ),
+            ),
+            Node::Border(border) => (
+                "Border",
+                container(
+                    &MockBorder {
+                        style: border.style,
+                        content: (),
+                    },
+                    "content",
+                    Self::to_value(&border.content),
+                ),
+            ),
+            Node::Opacity(opacity) => (
+                "Opacity",
+                container(
+                    &MockOpacity {
+                        opacity: opacity.opacity,
+                        content: (),
+                    },
+                    "content",
+                    Self::to_value(&opacity.content),
+                ),
+            ),
+            // The badge's own text is its content, so the child is tagged under "child"
+            Node::Badge(badge) => (
+                "Badge",
+                container(
+                    &MockBadge {
+                        content: badge.content.clone(),
+                        background: badge.background,
+                        alignment: badge.alignment,
+                        child: (),
+                    },
+                    "child",
+                    Self::to_value(&badge.child),
+                ),
+            ),
+            Node::Tooltip(tooltip) => (
+                "Tooltip",
+                container(
+                    &MockTooltip {
+                        text: tooltip.text.clone(),
+                        placement: tooltip.placement,
+                        child: (),
+                    },
+                    "child",
+                    Self::to_value(&tooltip.child),
+                ),
+            ),
+            Node::Card(card) => (
+                "Card",
+                container(
+                    &MockCard {
+                        background: card.background,
+                        corner_radius: card.corner_radius,
+                        insets: card.insets,
+                        shadow: card.shadow,
+                        content: (),
+                    },
+                    "content",
+                    Self::to_value(&card.content),
+                ),
+            ),
+            Node::ScrollView(scroll) => (
+                "ScrollView",
+                container(
+                    &MockScrollView {
+                        axis: scroll.axis,
+                        shows_indicators: scroll.shows_indicators,
+                        content: (),
+                    },
+                    "content",
+                    list(&scroll.content),
+                ),
+            ),
+            Node::Identified(identified) => (
+                "Identified",
+                container(
+                    &MockIdentified {
+                        id: identified.id.clone(),
+                        content: (),
+                    },
+                    "content",
+                    Self::to_value(&identified.content),
+                ),
+            ),
+            Node::Tagged(tagged) => (
+                "Tagged",
+                container(
+                    &MockTagged {
+                        tags: tagged.tags.clone(),
+                        content: (),
+                    },
+                    "content",
+                    Self::to_value(&tagged.content),
+                ),
+            ),
+        };
+
+        let mut tagged = Map::new();
+        tagged.insert("type".to_string(), Value::from(view_type));
+        match value {
+            Value::Object(fields) => tagged.extend(fields),
+            other => {
+                tagged.insert("value".to_string(), other);
+            }
+        }
+        Value::Object(tagged)
+    }
+}
+
+impl Default for JsonBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Serialize an extracted node's fields.
+fn fields<T: Serialize>(node: &T) -> Value {
+    // Mock nodes are plain structs of strings, numbers, and enums, which JSON can always represent
+    serde_json::to_value(node).expect("extracted mock nodes always serialize to JSON")
+}
+
+/// Convert a list of children to tagged nodes.
+fn list(children: &[MockDynamicChild]) -> Value {
+    Value::Array(children.iter().map(JsonBackend::to_value).collect())
+}
+
+/// Serialize a container's own fields and attach its already converted children.
+///
+/// `shell` is a copy of the container without its children, so the subtree
+/// below it is only converted once, by the caller.
+fn container<T: Serialize>(shell: &T, key: &str, children: Value) -> Value {
+    let mut value = fields(shell);
+    if let Value::Object(map) = &mut value {
+        map.insert(key.to_string(), children);
+    }
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        elements::{HStack, Padding, Spacer, Text, VStack},
+        model::Model,
+        style::Color,
+        widgets::Button,
+    };
+
+    #[test]
+    fn dynamic_tree_becomes_nested_json() {
+        let toolbar = HStack::dynamic()
+            .child(Box::new(Button::new("Save").view()))
+            .child(Box::new(Spacer::new()));
+        let screen = VStack::dynamic()
+            .child(Box::new(Text::new("Editor").color(Color::BLUE)))
+            .child(Box::new(toolbar))
+            .spacing(8.0);
+
+        let json = JsonBackend::new()
+            .extract_to_value(&screen, &RenderContext::new())
+            .unwrap();
+
+        assert_eq!(json["type"], "VStack");
+        assert_eq!(json["spacing"], 8.0);
+        assert_eq!(json["content"][0]["type"], "Text");
+        assert_eq!(json["content"][0]["content"], "Editor");
+        assert_eq!(json["content"][0]["color"]["b"], 1.0);
+        assert_eq!(json["content"][1]["type"], "HStack");
+        assert_eq!(json["content"][1]["content"][0]["type"], "Button");
+        assert_eq!(json["content"][1]["content"][0]["text"], "Save");
+        assert_eq!(json["content"][1]["content"][1]["type"], "Spacer");
+    }
+
+    #[test]
+    fn single_child_containers_nest_one_object() {
+        let total: Box<dyn View> = Box::new(Text::new("Total").id("total").boxed());
+        let view = Padding::all(total, 4.0);
+        let json = JsonBackend::new()
+            .extract_to_value(&view, &RenderContext::new())
+            .unwrap();
+
+        assert_eq!(json["type"], "Padding");
+        assert_eq!(json["content"]["type"], "Identified");
+        assert_eq!(json["content"]["id"], "total");
+        assert_eq!(json["content"]["content"]["type"], "Text");
+    }
+
+    #[test]
+    fn output_string_is_stable() {
+        let backend = JsonBackend::new();
+        let view = VStack::dynamic().child(Box::new(Text::new("Same")));
+        let first = backend
+            .extract_to_string(&view, &RenderContext::new())
+            .unwrap();
+        let second = backend
+            .extract_to_string(&view, &RenderContext::new())
+            .unwrap();
+
+        assert_eq!(first, second);
+        assert!(first.contains("\"type\": \"VStack\""));
+        assert!(serde_json::from_str::<Value>(&first).is_ok());
+    }
+}
+
+// End of File
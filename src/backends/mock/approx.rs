@@ -0,0 +1,471 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Approximate comparison of extracted views
+
+use std::collections::BTreeMap;
+
+use super::{
+    MockActivityIndicator, MockBadge, MockBorder, MockButton, MockCard, MockCheckbox,
+    MockDynamicChild, MockFlowLayout, MockGauge, MockGrid, MockHStack, MockIdentified, MockImage,
+    MockLabel, MockOpacity, MockPadding, MockPicker, MockRichText, MockScrollView, MockSlider,
+    MockSpacer, MockSparkline, MockSpinner, MockStepper, MockTable, MockTagged, MockText,
+    MockTextRef, MockToggle, MockTooltip, MockVStack, MockZStack,
+};
+use crate::{
+    elements::{
+        Alignment, Alignment2D, BorderStyle, ContentMode, EdgeInsets, GaugeStyle, HorizontalEdge,
+        ImageSource, ScrollAxis, Shadow, SpinnerStyle, TextSpan, TooltipPlacement,
+    },
+    interaction::InteractionState,
+    style::{
+        Color, FontFamily, FontStyle, FontWeight, PressFeedback, TextAlignment, TextStyle,
+        TruncationMode,
+    },
+    widgets::{CellEdit, CheckState, SortState},
+};
+
+/// Comparison of extracted trees that tolerates small floating point differences.
+///
+/// Sizes, spacings and font sizes computed by a layout pass or by scaling
+/// often differ from the expected value by a rounding error, which makes the
+/// derived `PartialEq` too strict for assertions. `approx_eq` treats two
+/// `f32` fields as equal when they are within `epsilon` of each other, and
+/// compares every other field, such as text, counts and enums, exactly.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{backends::mock::{ApproxEq, MockBackend}, prelude::*};
+///
+/// let column = |spacing: f32| {
+///     let view = VStack::new((Text::new("A"), Text::new("B"))).spacing(spacing);
+///     MockBackend::extract(&view, &RenderContext::new()).unwrap()
+/// };
+/// let exact = column(0.3);
+/// let computed = column(0.1 + 0.2 + 1e-6);
+///
+/// assert_ne!(exact, computed);
+/// assert!(exact.approx_eq(&computed, 1e-4));
+/// ```
+pub trait ApproxEq {
+    /// Check equality with floating point values compared within `epsilon`.
+    fn approx_eq(&self, other: &Self, epsilon: f32) -> bool;
+}
+
+impl ApproxEq for f32 {
+    /// Equal values, including infinities, always match; NaN never does.
+    fn approx_eq(&self, other: &Self, epsilon: f32) -> bool {
+        self == other || (self - other).abs() <= epsilon
+    }
+}
+
+/// Implement approximate comparison as exact equality for types without floats.
+macro_rules! approx_eq_exact {
+    ($($exact:ty),* $(,)?) => {
+        $(impl ApproxEq for $exact {
+            fn approx_eq(&self, other: &Self, _epsilon: f32) -> bool {
+                self == other
+            }
+        })*
+    };
+}
+
+approx_eq_exact!(
+    bool,
+    i32,
+    usize,
+    String,
+    &str,
+    BTreeMap<String, String>,
+    Alignment,
+    Alignment2D,
+    CellEdit,
+    CheckState,
+    ContentMode,
+    FontFamily,
+    FontStyle,
+    FontWeight,
+    GaugeStyle,
+    HorizontalEdge,
+    ImageSource,
+    InteractionState,
+    ScrollAxis,
+    SortState,
+    SpinnerStyle,
+    TextAlignment,
+    TextSpan,
+    TooltipPlacement,
+    TruncationMode,
+);
+
+impl<T: ApproxEq> ApproxEq for Option<T> {
+    fn approx_eq(&self, other: &Self, epsilon: f32) -> bool {
+        match (self, other) {
+            (Some(left), Some(right)) => left.approx_eq(right, epsilon),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+impl<T: ApproxEq> ApproxEq for Vec<T> {
+    fn approx_eq(&self, other: &Self, epsilon: f32) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other)
+                .all(|(left, right)| left.approx_eq(right, epsilon))
+    }
+}
+
+impl<T: ApproxEq + ?Sized> ApproxEq for Box<T> {
+    fn approx_eq(&self, other: &Self, epsilon: f32) -> bool {
+        (**self).approx_eq(other, epsilon)
+    }
+}
+
+/// Implement approximate comparison for one tuple arity.
+macro_rules! tuple_approx_eq {
+    ($($name:ident . $index:tt),+) => {
+        impl<$($name: ApproxEq),+> ApproxEq for ($($name,)+) {
+            fn approx_eq(&self, other: &Self, epsilon: f32) -> bool {
+                $(self.$index.approx_eq(&other.$index, epsilon))&&+
+            }
+        }
+    };
+}
+
+tuple_approx_eq!(T1.0, T2.1);
+tuple_approx_eq!(T1.0, T2.1, T3.2);
+tuple_approx_eq!(T1.0, T2.1, T3.2, T4.3);
+tuple_approx_eq!(T1.0, T2.1, T3.2, T4.3, T5.4);
+tuple_approx_eq!(T1.0, T2.1, T3.2, T4.3, T5.4, T6.5);
+tuple_approx_eq!(T1.0, T2.1, T3.2, T4.3, T5.4, T6.5, T7.6);
+tuple_approx_eq!(T1.0, T2.1, T3.2, T4.3, T5.4, T6.5, T7.6, T8.7);
+tuple_approx_eq!(T1.0, T2.1, T3.2, T4.3, T5.4, T6.5, T7.6, T8.7, T9.8);
+tuple_approx_eq!(T1.0, T2.1, T3.2, T4.3, T5.4, T6.5, T7.6, T8.7, T9.8, T10.9);
+
+/// Implement approximate comparison field by field.
+///
+/// The struct is destructured with every listed field, so adding a field to
+/// the struct without listing it here fails to compile.
+macro_rules! approx_eq_fields {
+    (<$generic:ident> $compared:ty { $($field:ident),+ $(,)? }) => {
+        impl<$generic: ApproxEq> ApproxEq for $compared {
+            fn approx_eq(&self, other: &Self, epsilon: f32) -> bool {
+                let Self { $($field),+ } = self;
+                $($field.approx_eq(&other.$field, epsilon))&&+
+            }
+        }
+    };
+    ($compared:ty { $($field:ident),+ $(,)? }) => {
+        impl ApproxEq for $compared {
+            fn approx_eq(&self, other: &Self, epsilon: f32) -> bool {
+                let Self { $($field),+ } = self;
+                $($field.approx_eq(&other.$field, epsilon))&&+
+            }
+        }
+    };
+}
+
+approx_eq_fields!(Color { r, g, b, a });
+approx_eq_fields!(EdgeInsets {
+    top,
+    leading,
+    bottom,
+    trailing
+});
+approx_eq_fields!(BorderStyle {
+    width,
+    color,
+    corner_radius
+});
+approx_eq_fields!(Shadow {
+    color,
+    radius,
+    offset_x,
+    offset_y
+});
+approx_eq_fields!(PressFeedback { scale, offset });
+
+impl ApproxEq for TextStyle {
+    fn approx_eq(&self, other: &Self, epsilon: f32) -> bool {
+        self.font_size.approx_eq(&other.font_size, epsilon)
+            && self.color.approx_eq(&other.color, epsilon)
+            && self.font_weight == other.font_weight
+            && self.font_style == other.font_style
+            && self.font_family == other.font_family
+            && self.alignment == other.alignment
+            && self.line_spacing.approx_eq(&other.line_spacing, epsilon)
+            && self.line_limit == other.line_limit
+            && self.truncation == other.truncation
+    }
+}
+
+approx_eq_fields!(MockText {
+    content,
+    font_size,
+    color,
+    font_weight,
+    font_style,
+    font_family,
+    alignment,
+    line_spacing,
+    line_limit,
+    truncation,
+    effective_style,
+});
+approx_eq_fields!(MockRichText {
+    spans,
+    font_size,
+    color
+});
+approx_eq_fields!(MockTextRef<'_> {
+    content,
+    font_size,
+    color
+});
+approx_eq_fields!(MockImage {
+    source,
+    size,
+    content_mode
+});
+approx_eq_fields!(MockGauge {
+    fraction,
+    zone_color,
+    style
+});
+approx_eq_fields!(MockSparkline {
+    normalized_points,
+    color
+});
+approx_eq_fields!(MockLabel {
+    icon,
+    title,
+    spacing
+});
+approx_eq_fields!(MockButton {
+    text,
+    background_color,
+    text_style,
+    interaction_state,
+    opacity,
+    focus_ring,
+    press_feedback,
+});
+approx_eq_fields!(MockCheckbox {
+    state,
+    label,
+    label_style,
+    interaction_state,
+    opacity,
+});
+approx_eq_fields!(MockPicker {
+    selected_label,
+    options,
+    is_open,
+    label_style,
+    interaction_state,
+    opacity,
+});
+approx_eq_fields!(MockSlider {
+    value,
+    min,
+    max,
+    label_style,
+    interaction_state,
+    opacity,
+});
+approx_eq_fields!(MockSpinner { style, size, color });
+approx_eq_fields!(MockActivityIndicator {
+    spinner,
+    phase,
+    segments
+});
+approx_eq_fields!(MockStepper {
+    value,
+    min,
+    max,
+    increment_enabled,
+    decrement_enabled,
+    interaction_state,
+    opacity,
+});
+approx_eq_fields!(MockTable {
+    headers,
+    alignments,
+    rows,
+    selected,
+    selected_column,
+    editable,
+    editing,
+    sort,
+    interaction_state,
+    opacity,
+});
+approx_eq_fields!(MockToggle {
+    is_on,
+    on_color,
+    off_color,
+    interaction_state,
+    opacity,
+});
+approx_eq_fields!(MockSpacer {
+    min_size,
+    max_size,
+    layout_priority
+});
+approx_eq_fields!(<T> MockVStack<T> {
+    content,
+    alignment,
+    horizontal_edge,
+    spacing
+});
+approx_eq_fields!(<T> MockHStack<T> {
+    content,
+    alignment,
+    leading_edge,
+    spacing
+});
+approx_eq_fields!(<T> MockZStack<T> { content, alignment });
+approx_eq_fields!(MockGrid {
+    columns,
+    row_spacing,
+    column_spacing,
+    content
+});
+approx_eq_fields!(MockFlowLayout {
+    max_width,
+    line_spacing,
+    item_spacing,
+    content
+});
+approx_eq_fields!(<T> MockPadding<T> { insets, content });
+approx_eq_fields!(<T> MockBorder<T> { style, content });
+approx_eq_fields!(<T> MockOpacity<T> { opacity, content });
+approx_eq_fields!(<T> MockBadge<T> {
+    content,
+    background,
+    alignment,
+    child
+});
+approx_eq_fields!(<T> MockCard<T> {
+    background,
+    corner_radius,
+    insets,
+    shadow,
+    content
+});
+approx_eq_fields!(<T> MockTooltip<T> {
+    text,
+    placement,
+    child
+});
+approx_eq_fields!(<T> MockScrollView<T> {
+    axis,
+    shows_indicators,
+    content
+});
+approx_eq_fields!(<T> MockIdentified<T> { id, content });
+approx_eq_fields!(<T> MockTagged<T> { tags, content });
+
+impl ApproxEq for MockDynamicChild {
+    fn approx_eq(&self, other: &Self, epsilon: f32) -> bool {
+        // Matching on `self` alone keeps the list of variants exhaustive
+        macro_rules! compare {
+            ($($variant:ident),+ $(,)?) => {
+                match self {
+                    $(Self::$variant(left) => matches!(
+                        other,
+                        Self::$variant(right) if left.approx_eq(right, epsilon)
+                    ),)+
+                }
+            };
+        }
+
+        compare!(
+            Text,
+            RichText,
+            Image,
+            Gauge,
+            Sparkline,
+            Label,
+            Button,
+            Checkbox,
+            Picker,
+            Slider,
+            Spinner,
+            ActivityIndicator,
+            Stepper,
+            Table,
+            Toggle,
+            Spacer,
+            VStack,
+            HStack,
+            ZStack,
+            Grid,
+            FlowLayout,
+            Padding,
+            Border,
+            Opacity,
+            Badge,
+            Card,
+            Tooltip,
+            ScrollView,
+            Identified,
+            Tagged,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        backends::mock::MockBackend,
+        elements::{HStack, Text, VStack},
+        extraction::RenderContext,
+        model::Model,
+        widgets::{ActivityIndicator, ActivityIndicatorMessage},
+    };
+
+    #[test]
+    fn approx_eq_tolerates_rounding_in_floats_only() {
+        let ctx = RenderContext::new();
+        let screen = |spacing: f32, phase_ticks: usize, title: &str| {
+            let indicator = (0..phase_ticks).fold(ActivityIndicator::new(), |indicator, _| {
+                indicator.update(ActivityIndicatorMessage::Tick)
+            });
+            let view = VStack::dynamic()
+                .spacing(spacing)
+                .child(Box::new(
+                    Text::new(title.to_string()).font_size(16.0 * spacing),
+                ))
+                .child(Box::new(
+                    HStack::dynamic()
+                        .spacing(spacing / 3.0)
+                        .child(Box::new(indicator.view())),
+                ));
+            MockBackend::new().extract_dynamic(&view, &ctx).unwrap()
+        };
+
+        let laid_out = screen(0.1 + 0.2, 0, "Step 1");
+        let rounded = screen(0.3 + 1e-6, 0, "Step 1");
+        assert_ne!(laid_out, rounded);
+        assert!(laid_out.approx_eq(&rounded, 1e-4));
+        assert!(!laid_out.approx_eq(&rounded, 1e-9));
+
+        // Integers and text are never rounded, whatever the tolerance
+        assert!(!laid_out.approx_eq(&screen(0.3, 1, "Step 1"), 10.0));
+        assert!(!laid_out.approx_eq(&screen(0.3, 0, "Step 2"), 10.0));
+        assert!(!laid_out.approx_eq(&screen(0.3, 0, "Step 1.0"), 10.0));
+        assert!(!screen(0.3, 0, "1.0").approx_eq(&screen(0.3, 0, "1.00001"), 10.0));
+
+        // NaN is never close to anything, including itself
+        assert!(!screen(f32::NAN, 0, "Step 1").approx_eq(&screen(f32::NAN, 0, "Step 1"), 10.0));
+    }
+}
+
+// End of File
@@ -0,0 +1,676 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Mock extraction of optional views, collections and tuples
+
+use super::MockBackend;
+use crate::{
+    extraction::{ExtractionResult, RenderContext, ViewExtractor},
+    view::View,
+};
+
+// Unit views have no content, so they extract to nothing
+impl ViewExtractor<()> for MockBackend {
+    type Output = ();
+
+    fn extract(_view: &(), _context: &RenderContext) -> ExtractionResult<Self::Output> {
+        Ok(())
+    }
+}
+
+// Optional view extraction - returns Some(extracted) or None
+impl<V> ViewExtractor<Option<V>> for MockBackend
+where
+    V: View,
+    Self: ViewExtractor<V>,
+{
+    type Output = Option<<Self as ViewExtractor<V>>::Output>;
+
+    fn extract(view: &Option<V>, context: &RenderContext) -> ExtractionResult<Self::Output> {
+        view.as_ref()
+            .map(|inner| Self::extract(inner, context))
+            .transpose()
+    }
+}
+
+/// Homogeneous collection extraction, preserving the order of the elements
+impl<V> ViewExtractor<Vec<V>> for MockBackend
+where
+    V: View,
+    Self: ViewExtractor<V>,
+{
+    type Output = Vec<<Self as ViewExtractor<V>>::Output>;
+
+    fn extract(view: &Vec<V>, context: &RenderContext) -> ExtractionResult<Self::Output> {
+        view.iter()
+            .map(|item| Self::extract(item, context))
+            .collect()
+    }
+}
+
+/// Fixed-size array extraction, preserving the order of the elements
+impl<V, const N: usize> ViewExtractor<[V; N]> for MockBackend
+where
+    V: View,
+    Self: ViewExtractor<V>,
+{
+    type Output = [<Self as ViewExtractor<V>>::Output; N];
+
+    fn extract(view: &[V; N], context: &RenderContext) -> ExtractionResult<Self::Output> {
+        let mut outputs = Vec::with_capacity(N);
+        for item in view {
+            outputs.push(Self::extract(item, context)?);
+        }
+        match outputs.try_into() {
+            Ok(outputs) => Ok(outputs),
+            Err(_) => unreachable!("extracted exactly one output per array element"),
+        }
+    }
+}
+
+// Tuple extraction implementations - return tuples of extracted outputs
+// For simplicity and to avoid type recursion issues, we'll implement a few key arities
+impl<V1, V2> ViewExtractor<(V1, V2)> for MockBackend
+where
+    V1: View,
+    V2: View,
+    Self: ViewExtractor<V1> + ViewExtractor<V2>,
+{
+    type Output = (
+        <Self as ViewExtractor<V1>>::Output,
+        <Self as ViewExtractor<V2>>::Output,
+    );
+
+    fn extract(view: &(V1, V2), context: &RenderContext) -> ExtractionResult<Self::Output> {
+        Ok((
+            Self::extract(&view.0, context)?,
+            Self::extract(&view.1, context)?,
+        ))
+    }
+}
+
+impl<V1, V2, V3> ViewExtractor<(V1, V2, V3)> for MockBackend
+where
+    V1: View,
+    V2: View,
+    V3: View,
+    Self: ViewExtractor<V1> + ViewExtractor<V2> + ViewExtractor<V3>,
+{
+    type Output = (
+        <Self as ViewExtractor<V1>>::Output,
+        <Self as ViewExtractor<V2>>::Output,
+        <Self as ViewExtractor<V3>>::Output,
+    );
+
+    fn extract(view: &(V1, V2, V3), context: &RenderContext) -> ExtractionResult<Self::Output> {
+        Ok((
+            Self::extract(&view.0, context)?,
+            Self::extract(&view.1, context)?,
+            Self::extract(&view.2, context)?,
+        ))
+    }
+}
+
+impl<V1, V2, V3, V4> ViewExtractor<(V1, V2, V3, V4)> for MockBackend
+where
+    V1: View,
+    V2: View,
+    V3: View,
+    V4: View,
+    Self: ViewExtractor<V1> + ViewExtractor<V2> + ViewExtractor<V3> + ViewExtractor<V4>,
+{
+    type Output = (
+        <Self as ViewExtractor<V1>>::Output,
+        <Self as ViewExtractor<V2>>::Output,
+        <Self as ViewExtractor<V3>>::Output,
+        <Self as ViewExtractor<V4>>::Output,
+    );
+
+    fn extract(view: &(V1, V2, V3, V4), context: &RenderContext) -> ExtractionResult<Self::Output> {
+        Ok((
+            Self::extract(&view.0, context)?,
+            Self::extract(&view.1, context)?,
+            Self::extract(&view.2, context)?,
+            Self::extract(&view.3, context)?,
+        ))
+    }
+}
+
+impl<V1, V2, V3, V4, V5> ViewExtractor<(V1, V2, V3, V4, V5)> for MockBackend
+where
+    V1: View,
+    V2: View,
+    V3: View,
+    V4: View,
+    V5: View,
+    Self: ViewExtractor<V1>
+        + ViewExtractor<V2>
+        + ViewExtractor<V3>
+        + ViewExtractor<V4>
+        + ViewExtractor<V5>,
+{
+    type Output = (
+        <Self as ViewExtractor<V1>>::Output,
+        <Self as ViewExtractor<V2>>::Output,
+        <Self as ViewExtractor<V3>>::Output,
+        <Self as ViewExtractor<V4>>::Output,
+        <Self as ViewExtractor<V5>>::Output,
+    );
+
+    fn extract(
+        view: &(V1, V2, V3, V4, V5),
+        context: &RenderContext,
+    ) -> ExtractionResult<Self::Output> {
+        Ok((
+            Self::extract(&view.0, context)?,
+            Self::extract(&view.1, context)?,
+            Self::extract(&view.2, context)?,
+            Self::extract(&view.3, context)?,
+            Self::extract(&view.4, context)?,
+        ))
+    }
+}
+
+impl<V1, V2, V3, V4, V5, V6> ViewExtractor<(V1, V2, V3, V4, V5, V6)> for MockBackend
+where
+    V1: View,
+    V2: View,
+    V3: View,
+    V4: View,
+    V5: View,
+    V6: View,
+    Self: ViewExtractor<V1>
+        + ViewExtractor<V2>
+        + ViewExtractor<V3>
+        + ViewExtractor<V4>
+        + ViewExtractor<V5>
+        + ViewExtractor<V6>,
+{
+    type Output = (
+        <Self as ViewExtractor<V1>>::Output,
+        <Self as ViewExtractor<V2>>::Output,
+        <Self as ViewExtractor<V3>>::Output,
+        <Self as ViewExtractor<V4>>::Output,
+        <Self as ViewExtractor<V5>>::Output,
+        <Self as ViewExtractor<V6>>::Output,
+    );
+
+    fn extract(
+        view: &(V1, V2, V3, V4, V5, V6),
+        context: &RenderContext,
+    ) -> ExtractionResult<Self::Output> {
+        Ok((
+            Self::extract(&view.0, context)?,
+            Self::extract(&view.1, context)?,
+            Self::extract(&view.2, context)?,
+            Self::extract(&view.3, context)?,
+            Self::extract(&view.4, context)?,
+            Self::extract(&view.5, context)?,
+        ))
+    }
+}
+
+impl<V1, V2, V3, V4, V5, V6, V7> ViewExtractor<(V1, V2, V3, V4, V5, V6, V7)> for MockBackend
+where
+    V1: View,
+    V2: View,
+    V3: View,
+    V4: View,
+    V5: View,
+    V6: View,
+    V7: View,
+    Self: ViewExtractor<V1>
+        + ViewExtractor<V2>
+        + ViewExtractor<V3>
+        + ViewExtractor<V4>
+        + ViewExtractor<V5>
+        + ViewExtractor<V6>
+        + ViewExtractor<V7>,
+{
+    type Output = (
+        <Self as ViewExtractor<V1>>::Output,
+        <Self as ViewExtractor<V2>>::Output,
+        <Self as ViewExtractor<V3>>::Output,
+        <Self as ViewExtractor<V4>>::Output,
+        <Self as ViewExtractor<V5>>::Output,
+        <Self as ViewExtractor<V6>>::Output,
+        <Self as ViewExtractor<V7>>::Output,
+    );
+
+    fn extract(
+        view: &(V1, V2, V3, V4, V5, V6, V7),
+        context: &RenderContext,
+    ) -> ExtractionResult<Self::Output> {
+        Ok((
+            Self::extract(&view.0, context)?,
+            Self::extract(&view.1, context)?,
+            Self::extract(&view.2, context)?,
+            Self::extract(&view.3, context)?,
+            Self::extract(&view.4, context)?,
+            Self::extract(&view.5, context)?,
+            Self::extract(&view.6, context)?,
+        ))
+    }
+}
+
+impl<V1, V2, V3, V4, V5, V6, V7, V8> ViewExtractor<(V1, V2, V3, V4, V5, V6, V7, V8)> for MockBackend
+where
+    V1: View,
+    V2: View,
+    V3: View,
+    V4: View,
+    V5: View,
+    V6: View,
+    V7: View,
+    V8: View,
+    Self: ViewExtractor<V1>
+        + ViewExtractor<V2>
+        + ViewExtractor<V3>
+        + ViewExtractor<V4>
+        + ViewExtractor<V5>
+        + ViewExtractor<V6>
+        + ViewExtractor<V7>
+        + ViewExtractor<V8>,
+{
+    type Output = (
+        <Self as ViewExtractor<V1>>::Output,
+        <Self as ViewExtractor<V2>>::Output,
+        <Self as ViewExtractor<V3>>::Output,
+        <Self as ViewExtractor<V4>>::Output,
+        <Self as ViewExtractor<V5>>::Output,
+        <Self as ViewExtractor<V6>>::Output,
+        <Self as ViewExtractor<V7>>::Output,
+        <Self as ViewExtractor<V8>>::Output,
+    );
+
+    fn extract(
+        view: &(V1, V2, V3, V4, V5, V6, V7, V8),
+        context: &RenderContext,
+    ) -> ExtractionResult<Self::Output> {
+        Ok((
+            Self::extract(&view.0, context)?,
+            Self::extract(&view.1, context)?,
+            Self::extract(&view.2, context)?,
+            Self::extract(&view.3, context)?,
+            Self::extract(&view.4, context)?,
+            Self::extract(&view.5, context)?,
+            Self::extract(&view.6, context)?,
+            Self::extract(&view.7, context)?,
+        ))
+    }
+}
+
+impl<V1, V2, V3, V4, V5, V6, V7, V8, V9> ViewExtractor<(V1, V2, V3, V4, V5, V6, V7, V8, V9)>
+    for MockBackend
+where
+    V1: View,
+    V2: View,
+    V3: View,
+    V4: View,
+    V5: View,
+    V6: View,
+    V7: View,
+    V8: View,
+    V9: View,
+    Self: ViewExtractor<V1>
+        + ViewExtractor<V2>
+        + ViewExtractor<V3>
+        + ViewExtractor<V4>
+        + ViewExtractor<V5>
+        + ViewExtractor<V6>
+        + ViewExtractor<V7>
+        + ViewExtractor<V8>
+        + ViewExtractor<V9>,
+{
+    type Output = (
+        <Self as ViewExtractor<V1>>::Output,
+        <Self as ViewExtractor<V2>>::Output,
+        <Self as ViewExtractor<V3>>::Output,
+        <Self as ViewExtractor<V4>>::Output,
+        <Self as ViewExtractor<V5>>::Output,
+        <Self as ViewExtractor<V6>>::Output,
+        <Self as ViewExtractor<V7>>::Output,
+        <Self as ViewExtractor<V8>>::Output,
+        <Self as ViewExtractor<V9>>::Output,
+    );
+
+    fn extract(
+        view: &(V1, V2, V3, V4, V5, V6, V7, V8, V9),
+        context: &RenderContext,
+    ) -> ExtractionResult<Self::Output> {
+        Ok((
+            Self::extract(&view.0, context)?,
+            Self::extract(&view.1, context)?,
+            Self::extract(&view.2, context)?,
+            Self::extract(&view.3, context)?,
+            Self::extract(&view.4, context)?,
+            Self::extract(&view.5, context)?,
+            Self::extract(&view.6, context)?,
+            Self::extract(&view.7, context)?,
+            Self::extract(&view.8, context)?,
+        ))
+    }
+}
+
+impl<V1, V2, V3, V4, V5, V6, V7, V8, V9, V10>
+    ViewExtractor<(V1, V2, V3, V4, V5, V6, V7, V8, V9, V10)> for MockBackend
+where
+    V1: View,
+    V2: View,
+    V3: View,
+    V4: View,
+    V5: View,
+    V6: View,
+    V7: View,
+    V8: View,
+    V9: View,
+    V10: View,
+    Self: ViewExtractor<V1>
+        + ViewExtractor<V2>
+        + ViewExtractor<V3>
+        + ViewExtractor<V4>
+        + ViewExtractor<V5>
+        + ViewExtractor<V6>
+        + ViewExtractor<V7>
+        + ViewExtractor<V8>
+        + ViewExtractor<V9>
+        + ViewExtractor<V10>,
+{
+    type Output = (
+        <Self as ViewExtractor<V1>>::Output,
+        <Self as ViewExtractor<V2>>::Output,
+        <Self as ViewExtractor<V3>>::Output,
+        <Self as ViewExtractor<V4>>::Output,
+        <Self as ViewExtractor<V5>>::Output,
+        <Self as ViewExtractor<V6>>::Output,
+        <Self as ViewExtractor<V7>>::Output,
+        <Self as ViewExtractor<V8>>::Output,
+        <Self as ViewExtractor<V9>>::Output,
+        <Self as ViewExtractor<V10>>::Output,
+    );
+
+    fn extract(
+        view: &(V1, V2, V3, V4, V5, V6, V7, V8, V9, V10),
+        context: &RenderContext,
+    ) -> ExtractionResult<Self::Output> {
+        Ok((
+            Self::extract(&view.0, context)?,
+            Self::extract(&view.1, context)?,
+            Self::extract(&view.2, context)?,
+            Self::extract(&view.3, context)?,
+            Self::extract(&view.4, context)?,
+            Self::extract(&view.5, context)?,
+            Self::extract(&view.6, context)?,
+            Self::extract(&view.7, context)?,
+            Self::extract(&view.8, context)?,
+            Self::extract(&view.9, context)?,
+        ))
+    }
+}
+
+impl<V1, V2, V3, V4, V5, V6, V7, V8, V9, V10, V11>
+    ViewExtractor<(V1, V2, V3, V4, V5, V6, V7, V8, V9, V10, V11)> for MockBackend
+where
+    V1: View,
+    V2: View,
+    V3: View,
+    V4: View,
+    V5: View,
+    V6: View,
+    V7: View,
+    V8: View,
+    V9: View,
+    V10: View,
+    V11: View,
+    Self: ViewExtractor<V1>
+        + ViewExtractor<V2>
+        + ViewExtractor<V3>
+        + ViewExtractor<V4>
+        + ViewExtractor<V5>
+        + ViewExtractor<V6>
+        + ViewExtractor<V7>
+        + ViewExtractor<V8>
+        + ViewExtractor<V9>
+        + ViewExtractor<V10>
+        + ViewExtractor<V11>,
+{
+    type Output = (
+        <Self as ViewExtractor<V1>>::Output,
+        <Self as ViewExtractor<V2>>::Output,
+        <Self as ViewExtractor<V3>>::Output,
+        <Self as ViewExtractor<V4>>::Output,
+        <Self as ViewExtractor<V5>>::Output,
+        <Self as ViewExtractor<V6>>::Output,
+        <Self as ViewExtractor<V7>>::Output,
+        <Self as ViewExtractor<V8>>::Output,
+        <Self as ViewExtractor<V9>>::Output,
+        <Self as ViewExtractor<V10>>::Output,
+        <Self as ViewExtractor<V11>>::Output,
+    );
+
+    fn extract(
+        view: &(V1, V2, V3, V4, V5, V6, V7, V8, V9, V10, V11),
+        context: &RenderContext,
+    ) -> ExtractionResult<Self::Output> {
+        Ok((
+            Self::extract(&view.0, context)?,
+            Self::extract(&view.1, context)?,
+            Self::extract(&view.2, context)?,
+            Self::extract(&view.3, context)?,
+            Self::extract(&view.4, context)?,
+            Self::extract(&view.5, context)?,
+            Self::extract(&view.6, context)?,
+            Self::extract(&view.7, context)?,
+            Self::extract(&view.8, context)?,
+            Self::extract(&view.9, context)?,
+            Self::extract(&view.10, context)?,
+        ))
+    }
+}
+
+impl<V1, V2, V3, V4, V5, V6, V7, V8, V9, V10, V11, V12>
+    ViewExtractor<(V1, V2, V3, V4, V5, V6, V7, V8, V9, V10, V11, V12)> for MockBackend
+where
+    V1: View,
+    V2: View,
+    V3: View,
+    V4: View,
+    V5: View,
+    V6: View,
+    V7: View,
+    V8: View,
+    V9: View,
+    V10: View,
+    V11: View,
+    V12: View,
+    Self: ViewExtractor<V1>
+        + ViewExtractor<V2>
+        + ViewExtractor<V3>
+        + ViewExtractor<V4>
+        + ViewExtractor<V5>
+        + ViewExtractor<V6>
+        + ViewExtractor<V7>
+        + ViewExtractor<V8>
+        + ViewExtractor<V9>
+        + ViewExtractor<V10>
+        + ViewExtractor<V11>
+        + ViewExtractor<V12>,
+{
+    type Output = (
+        <Self as ViewExtractor<V1>>::Output,
+        <Self as ViewExtractor<V2>>::Output,
+        <Self as ViewExtractor<V3>>::Output,
+        <Self as ViewExtractor<V4>>::Output,
+        <Self as ViewExtractor<V5>>::Output,
+        <Self as ViewExtractor<V6>>::Output,
+        <Self as ViewExtractor<V7>>::Output,
+        <Self as ViewExtractor<V8>>::Output,
+        <Self as ViewExtractor<V9>>::Output,
+        <Self as ViewExtractor<V10>>::Output,
+        <Self as ViewExtractor<V11>>::Output,
+        <Self as ViewExtractor<V12>>::Output,
+    );
+
+    fn extract(
+        view: &(V1, V2, V3, V4, V5, V6, V7, V8, V9, V10, V11, V12),
+        context: &RenderContext,
+    ) -> ExtractionResult<Self::Output> {
+        Ok((
+            Self::extract(&view.0, context)?,
+            Self::extract(&view.1, context)?,
+            Self::extract(&view.2, context)?,
+            Self::extract(&view.3, context)?,
+            Self::extract(&view.4, context)?,
+            Self::extract(&view.5, context)?,
+            Self::extract(&view.6, context)?,
+            Self::extract(&view.7, context)?,
+            Self::extract(&view.8, context)?,
+            Self::extract(&view.9, context)?,
+            Self::extract(&view.10, context)?,
+            Self::extract(&view.11, context)?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        backends::mock::MockText,
+        elements::{HStack, Text, VStack},
+        model::Model,
+        style::Color,
+        widgets::{Button, ButtonView},
+    };
+
+    #[test]
+    fn option_extraction() {
+        // Test extracting optional views
+        let ctx = RenderContext::new();
+
+        // Test Some(view)
+        let some_text = Some(Text::new("Present"));
+        let some_extracted = MockBackend::extract(&some_text, &ctx).unwrap();
+        assert!(some_extracted.is_some());
+        assert_eq!(some_extracted.unwrap().content, "Present");
+
+        // Test None
+        let none_text: Option<Text> = None;
+        let none_extracted = MockBackend::extract(&none_text, &ctx).unwrap();
+        assert!(none_extracted.is_none());
+    }
+
+    #[test]
+    fn tuple_extraction_comprehensive() {
+        let ctx = RenderContext::new();
+
+        // Test 2-tuple extraction
+        let tuple2 = (Text::new("First"), Text::new("Second"));
+        let extracted2 = MockBackend::extract(&tuple2, &ctx).unwrap();
+        assert_eq!(extracted2.0.content, "First");
+        assert_eq!(extracted2.1.content, "Second");
+
+        // Test 3-tuple extraction
+        let tuple3 = (Text::new("One"), Text::new("Two"), Text::new("Three"));
+        let extracted3 = MockBackend::extract(&tuple3, &ctx).unwrap();
+        assert_eq!(extracted3.0.content, "One");
+        assert_eq!(extracted3.1.content, "Two");
+        assert_eq!(extracted3.2.content, "Three");
+
+        // Test 4-tuple extraction
+        let tuple4 = (
+            Text::new("A"),
+            Text::new("B"),
+            Text::new("C"),
+            Text::new("D"),
+        );
+        let extracted4 = MockBackend::extract(&tuple4, &ctx).unwrap();
+        assert_eq!(extracted4.0.content, "A");
+        assert_eq!(extracted4.1.content, "B");
+        assert_eq!(extracted4.2.content, "C");
+        assert_eq!(extracted4.3.content, "D");
+    }
+
+    #[test]
+    fn tuple_mixed_types_extraction() {
+        // Test tuple with mixed view types
+        let text = Text::new("Hello").color(Color::RED);
+        let button = Button::new("Click me").background_color(Color::BLUE);
+        let tuple = (text, button.view());
+        let ctx = RenderContext::new();
+
+        let extracted = MockBackend::extract(&tuple, &ctx).unwrap();
+
+        assert_eq!(extracted.0.content, "Hello");
+        assert_eq!(extracted.0.color, Color::RED);
+        assert_eq!(extracted.1.text, "Click me");
+        assert_eq!(extracted.1.background_color, Color::BLUE);
+    }
+
+    #[test]
+    fn large_tuple_extraction() {
+        // Test larger tuple (5-tuple) to verify higher arity works
+        let texts = (
+            Text::new("1"),
+            Text::new("2"),
+            Text::new("3"),
+            Text::new("4"),
+            Text::new("5"),
+        );
+        let ctx = RenderContext::new();
+
+        let extracted = MockBackend::extract(&texts, &ctx).unwrap();
+
+        assert_eq!(extracted.0.content, "1");
+        assert_eq!(extracted.1.content, "2");
+        assert_eq!(extracted.2.content, "3");
+        assert_eq!(extracted.3.content, "4");
+        assert_eq!(extracted.4.content, "5");
+    }
+
+    #[test]
+    fn homogeneous_vec_extraction() {
+        let ctx = RenderContext::new();
+
+        let labels: Vec<Text> = ["One", "Two", "Three"].into_iter().map(Text::new).collect();
+        let extracted = MockBackend::extract(&labels, &ctx).unwrap();
+        let contents: Vec<_> = extracted.iter().map(|text| text.content.as_str()).collect();
+        assert_eq!(contents, vec!["One", "Two", "Three"]);
+
+        let empty: Vec<ButtonView> = Vec::new();
+        assert!(MockBackend::extract(&empty, &ctx).unwrap().is_empty());
+
+        // Homogeneous vectors compose with the static containers
+        let row = HStack::new(vec![Button::new("A").view(), Button::new("B").view()]);
+        let extracted = MockBackend::extract(&row, &ctx).unwrap();
+        assert_eq!(extracted.content.len(), 2);
+        assert_eq!(extracted.content[1].text, "B");
+    }
+
+    #[test]
+    fn array_extraction() {
+        let ctx = RenderContext::new();
+
+        let empty: [Text; 0] = [];
+        let extracted: [MockText; 0] = MockBackend::extract(&empty, &ctx).unwrap();
+        assert!(extracted.is_empty());
+
+        let cells = ["A", "B", "C", "D", "E"].map(Text::new);
+        let extracted = MockBackend::extract(&cells, &ctx).unwrap();
+        assert_eq!(
+            extracted.map(|text| text.content),
+            ["A", "B", "C", "D", "E"]
+        );
+
+        // Arrays nest inside stacks the same way tuples do
+        let grid = VStack::new([
+            HStack::new(["1", "2", "3"].map(Text::new)),
+            HStack::new(["4", "5", "6"].map(Text::new)),
+        ]);
+        let extracted = MockBackend::extract(&grid, &ctx).unwrap();
+        assert_eq!(extracted.content[1].content[2].content, "6");
+    }
+}
+
+// End of File
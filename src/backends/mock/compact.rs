@@ -0,0 +1,665 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Compact debug output for extracted views
+
+use std::fmt::Debug;
+
+use super::{
+    MockActivityIndicator, MockBadge, MockBorder, MockButton, MockCard, MockCheckbox,
+    MockDynamicChild, MockFlowLayout, MockGauge, MockGrid, MockHStack, MockIdentified, MockImage,
+    MockLabel, MockOpacity, MockPadding, MockPicker, MockRichText, MockScrollView, MockSlider,
+    MockSpacer, MockSparkline, MockSpinner, MockStepper, MockTable, MockTagged, MockText,
+    MockTextRef, MockToggle, MockTooltip, MockVStack, MockZStack,
+};
+use crate::{
+    elements::{
+        Alignment, Alignment2D, Badge, BorderStyle, Card, ContentMode, EdgeInsets, GaugeStyle,
+        Label, LayoutDirection, ScrollAxis, Spinner, SpinnerStyle, TooltipPlacement,
+    },
+    interaction::InteractionState,
+    style::{Color, TextStyle},
+    widgets::{ActivityIndicator, Button, CheckState, Toggle},
+};
+
+/// Terse debug formatting that leaves out fields at their default values.
+///
+/// Derived `Debug` output lists every field, which buries the interesting
+/// values of a large extracted tree. Compact output only shows a field when
+/// it differs from the value a freshly created view would have, such as a
+/// stack's spacing or a text's color. Content and other identifying fields
+/// are always shown.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{backends::mock::{CompactDebug, MockBackend}, prelude::*};
+///
+/// let view = VStack::new((Text::new("Title").color(Color::RED), Text::new("Body")));
+/// let extracted = MockBackend::extract(&view, &RenderContext::new()).unwrap();
+/// assert_eq!(
+///     extracted.debug_compact(),
+///     "MockVStack { content: (MockText { content: \"Title\", color: Color { r: 1.0, g: 0.0, b: 0.0, a: 1.0 } }, \
+///      MockText { content: \"Body\" }) }"
+/// );
+/// ```
+pub trait CompactDebug {
+    /// Append the compact representation to `out`.
+    fn write_compact(&self, out: &mut String);
+
+    /// Format the compact representation as a string.
+    fn debug_compact(&self) -> String {
+        let mut out = String::new();
+        self.write_compact(&mut out);
+        out
+    }
+}
+
+/// Writer for one struct in compact form, skipping fields at their defaults.
+struct CompactStruct<'a> {
+    out: &'a mut String,
+    has_fields: bool,
+}
+
+impl<'a> CompactStruct<'a> {
+    fn new(out: &'a mut String, name: &str) -> Self {
+        out.push_str(name);
+        Self {
+            out,
+            has_fields: false,
+        }
+    }
+
+    /// Start a field, writing the separator and its name.
+    fn begin(&mut self, name: &str) {
+        self.out
+            .push_str(if self.has_fields { ", " } else { " { " });
+        self.out.push_str(name);
+        self.out.push_str(": ");
+        self.has_fields = true;
+    }
+
+    /// Write a field that is always shown.
+    fn field(mut self, name: &str, value: &impl Debug) -> Self {
+        self.begin(name);
+        self.out.push_str(&format!("{:?}", value));
+        self
+    }
+
+    /// Write a field only when it differs from its default.
+    fn field_or_default<V: Debug + PartialEq>(self, name: &str, value: &V, default: &V) -> Self {
+        if value == default {
+            self
+        } else {
+            self.field(name, value)
+        }
+    }
+
+    /// Write child content in compact form.
+    fn child(mut self, name: &str, value: &impl CompactDebug) -> Self {
+        self.begin(name);
+        value.write_compact(self.out);
+        self
+    }
+
+    fn finish(self) {
+        if self.has_fields {
+            self.out.push_str(" }");
+        }
+    }
+}
+
+impl CompactDebug for MockText {
+    fn write_compact(&self, out: &mut String) {
+        let default = TextStyle::default();
+        CompactStruct::new(out, "MockText")
+            .field("content", &self.content)
+            .field_or_default("font_size", &self.font_size, &default.font_size)
+            .field_or_default("color", &self.color, &default.color)
+            .field_or_default("font_weight", &self.font_weight, &default.font_weight)
+            .field_or_default("font_style", &self.font_style, &default.font_style)
+            .field_or_default("font_family", &self.font_family, &default.font_family)
+            .field_or_default("alignment", &self.alignment, &default.alignment)
+            .field_or_default("line_spacing", &self.line_spacing, &default.line_spacing)
+            .field_or_default("line_limit", &self.line_limit, &default.line_limit)
+            .field_or_default("truncation", &self.truncation, &default.truncation)
+            // Only worth showing when the context changed something
+            .field_or_default("effective_style", &self.effective_style, &self.own_style())
+            .finish();
+    }
+}
+
+impl CompactDebug for MockRichText {
+    fn write_compact(&self, out: &mut String) {
+        let default = TextStyle::default();
+        CompactStruct::new(out, "MockRichText")
+            .field("spans", &self.spans)
+            .field_or_default("font_size", &self.font_size, &default.font_size)
+            .field_or_default("color", &self.color, &default.color)
+            .finish();
+    }
+}
+
+impl CompactDebug for MockTextRef<'_> {
+    fn write_compact(&self, out: &mut String) {
+        let default = TextStyle::default();
+        CompactStruct::new(out, "MockTextRef")
+            .field("content", &self.content)
+            .field_or_default("font_size", &self.font_size, &default.font_size)
+            .field_or_default("color", &self.color, &default.color)
+            .finish();
+    }
+}
+
+impl CompactDebug for MockImage {
+    fn write_compact(&self, out: &mut String) {
+        CompactStruct::new(out, "MockImage")
+            .field("source", &self.source)
+            .field_or_default("size", &self.size, &None)
+            .field_or_default("content_mode", &self.content_mode, &ContentMode::default())
+            .finish();
+    }
+}
+
+impl CompactDebug for MockGauge {
+    fn write_compact(&self, out: &mut String) {
+        CompactStruct::new(out, "MockGauge")
+            .field("fraction", &self.fraction)
+            .field_or_default("zone_color", &self.zone_color, &Color::BLUE)
+            .field_or_default("style", &self.style, &GaugeStyle::default())
+            .finish();
+    }
+}
+
+impl CompactDebug for MockSparkline {
+    fn write_compact(&self, out: &mut String) {
+        CompactStruct::new(out, "MockSparkline")
+            .field("normalized_points", &self.normalized_points)
+            .field_or_default("color", &self.color, &Color::BLUE)
+            .finish();
+    }
+}
+
+impl CompactDebug for MockLabel {
+    fn write_compact(&self, out: &mut String) {
+        CompactStruct::new(out, "MockLabel")
+            .child("icon", &self.icon)
+            .child("title", &self.title)
+            .field_or_default("spacing", &self.spacing, &Label::DEFAULT_SPACING)
+            .finish();
+    }
+}
+
+impl CompactDebug for MockButton {
+    fn write_compact(&self, out: &mut String) {
+        CompactStruct::new(out, "MockButton")
+            .field("text", &self.text)
+            .field_or_default(
+                "background_color",
+                &self.background_color,
+                &Button::DEFAULT_BACKGROUND,
+            )
+            .field_or_default("text_style", &self.text_style, &TextStyle::default())
+            .field_or_default(
+                "interaction_state",
+                &self.interaction_state,
+                &InteractionState::default(),
+            )
+            .field_or_default("opacity", &self.opacity, &1.0)
+            .field_or_default("focus_ring", &self.focus_ring, &None)
+            .field_or_default("press_feedback", &self.press_feedback, &None)
+            .finish();
+    }
+}
+
+impl CompactDebug for MockCheckbox {
+    fn write_compact(&self, out: &mut String) {
+        CompactStruct::new(out, "MockCheckbox")
+            .field("label", &self.label)
+            .field_or_default("state", &self.state, &CheckState::default())
+            .field_or_default("label_style", &self.label_style, &TextStyle::default())
+            .field_or_default(
+                "interaction_state",
+                &self.interaction_state,
+                &InteractionState::default(),
+            )
+            .field_or_default("opacity", &self.opacity, &1.0)
+            .finish();
+    }
+}
+
+impl CompactDebug for MockPicker {
+    fn write_compact(&self, out: &mut String) {
+        CompactStruct::new(out, "MockPicker")
+            .field("options", &self.options)
+            .field_or_default("selected_label", &self.selected_label, &None)
+            .field_or_default("is_open", &self.is_open, &false)
+            .field_or_default("label_style", &self.label_style, &TextStyle::default())
+            .field_or_default(
+                "interaction_state",
+                &self.interaction_state,
+                &InteractionState::default(),
+            )
+            .field_or_default("opacity", &self.opacity, &1.0)
+            .finish();
+    }
+}
+
+impl CompactDebug for MockSlider {
+    fn write_compact(&self, out: &mut String) {
+        CompactStruct::new(out, "MockSlider")
+            .field("value", &self.value)
+            .field("min", &self.min)
+            .field("max", &self.max)
+            .field_or_default("label_style", &self.label_style, &TextStyle::default())
+            .field_or_default(
+                "interaction_state",
+                &self.interaction_state,
+                &InteractionState::default(),
+            )
+            .field_or_default("opacity", &self.opacity, &1.0)
+            .finish();
+    }
+}
+
+impl CompactDebug for MockSpinner {
+    fn write_compact(&self, out: &mut String) {
+        CompactStruct::new(out, "MockSpinner")
+            .field_or_default("style", &self.style, &SpinnerStyle::default())
+            .field_or_default("size", &self.size, &Spinner::DEFAULT_SIZE)
+            .field("color", &self.color)
+            .finish();
+    }
+}
+
+impl CompactDebug for MockActivityIndicator {
+    fn write_compact(&self, out: &mut String) {
+        CompactStruct::new(out, "MockActivityIndicator")
+            .child("spinner", &self.spinner)
+            .field("phase", &self.phase)
+            .field_or_default(
+                "segments",
+                &self.segments,
+                &ActivityIndicator::default().segments,
+            )
+            .finish();
+    }
+}
+
+impl CompactDebug for MockStepper {
+    fn write_compact(&self, out: &mut String) {
+        CompactStruct::new(out, "MockStepper")
+            .field("value", &self.value)
+            .field("min", &self.min)
+            .field("max", &self.max)
+            .field_or_default("increment_enabled", &self.increment_enabled, &true)
+            .field_or_default("decrement_enabled", &self.decrement_enabled, &true)
+            .field_or_default(
+                "interaction_state",
+                &self.interaction_state,
+                &InteractionState::default(),
+            )
+            .field_or_default("opacity", &self.opacity, &1.0)
+            .finish();
+    }
+}
+
+impl CompactDebug for MockTable {
+    fn write_compact(&self, out: &mut String) {
+        let columns = self.headers.len();
+        CompactStruct::new(out, "MockTable")
+            .field("headers", &self.headers)
+            .field("rows", &self.rows)
+            .field_or_default(
+                "alignments",
+                &self.alignments,
+                &vec![Alignment::default(); columns],
+            )
+            .field_or_default("selected", &self.selected, &None)
+            .field_or_default("selected_column", &self.selected_column, &None)
+            .field_or_default("editable", &self.editable, &vec![false; columns])
+            .field_or_default("editing", &self.editing, &None)
+            .field_or_default("sort", &self.sort, &None)
+            .field_or_default(
+                "interaction_state",
+                &self.interaction_state,
+                &InteractionState::default(),
+            )
+            .field_or_default("opacity", &self.opacity, &1.0)
+            .finish();
+    }
+}
+
+impl CompactDebug for MockToggle {
+    fn write_compact(&self, out: &mut String) {
+        let default = Toggle::new(false);
+        CompactStruct::new(out, "MockToggle")
+            .field("is_on", &self.is_on)
+            .field_or_default("on_color", &self.on_color, &default.on_color)
+            .field_or_default("off_color", &self.off_color, &default.off_color)
+            .field_or_default(
+                "interaction_state",
+                &self.interaction_state,
+                &InteractionState::default(),
+            )
+            .field_or_default("opacity", &self.opacity, &1.0)
+            .finish();
+    }
+}
+
+impl CompactDebug for MockSpacer {
+    fn write_compact(&self, out: &mut String) {
+        CompactStruct::new(out, "MockSpacer")
+            .field_or_default("min_size", &self.min_size, &0.0)
+            .field_or_default("max_size", &self.max_size, &None)
+            .field_or_default("layout_priority", &self.layout_priority, &0.0)
+            .finish();
+    }
+}
+
+impl<T: CompactDebug> CompactDebug for MockVStack<T> {
+    fn write_compact(&self, out: &mut String) {
+        CompactStruct::new(out, "MockVStack")
+            .child("content", &self.content)
+            .field_or_default("alignment", &self.alignment, &Alignment::default())
+            .field_or_default(
+                "horizontal_edge",
+                &self.horizontal_edge,
+                &LayoutDirection::default().resolve(self.alignment),
+            )
+            .field_or_default("spacing", &self.spacing, &0.0)
+            .finish();
+    }
+}
+
+impl<T: CompactDebug> CompactDebug for MockHStack<T> {
+    fn write_compact(&self, out: &mut String) {
+        CompactStruct::new(out, "MockHStack")
+            .child("content", &self.content)
+            .field_or_default("alignment", &self.alignment, &Alignment::default())
+            .field_or_default(
+                "leading_edge",
+                &self.leading_edge,
+                &LayoutDirection::default().leading_edge(),
+            )
+            .field_or_default("spacing", &self.spacing, &0.0)
+            .finish();
+    }
+}
+
+impl<T: CompactDebug> CompactDebug for MockZStack<T> {
+    fn write_compact(&self, out: &mut String) {
+        CompactStruct::new(out, "MockZStack")
+            .child("content", &self.content)
+            .field_or_default("alignment", &self.alignment, &Alignment2D::default())
+            .finish();
+    }
+}
+
+impl CompactDebug for MockGrid {
+    fn write_compact(&self, out: &mut String) {
+        CompactStruct::new(out, "MockGrid")
+            .field("columns", &self.columns)
+            .field_or_default("row_spacing", &self.row_spacing, &0.0)
+            .field_or_default("column_spacing", &self.column_spacing, &0.0)
+            .child("content", &self.content)
+            .finish();
+    }
+}
+
+impl CompactDebug for MockFlowLayout {
+    fn write_compact(&self, out: &mut String) {
+        CompactStruct::new(out, "MockFlowLayout")
+            .field("max_width", &self.max_width)
+            .field_or_default("line_spacing", &self.line_spacing, &0.0)
+            .field_or_default("item_spacing", &self.item_spacing, &0.0)
+            .child("content", &self.content)
+            .finish();
+    }
+}
+
+impl<T: CompactDebug> CompactDebug for MockPadding<T> {
+    fn write_compact(&self, out: &mut String) {
+        CompactStruct::new(out, "MockPadding")
+            .field_or_default("insets", &self.insets, &EdgeInsets::default())
+            .child("content", &self.content)
+            .finish();
+    }
+}
+
+impl<T: CompactDebug> CompactDebug for MockBorder<T> {
+    fn write_compact(&self, out: &mut String) {
+        CompactStruct::new(out, "MockBorder")
+            .field_or_default("style", &self.style, &BorderStyle::default())
+            .child("content", &self.content)
+            .finish();
+    }
+}
+
+impl<T: CompactDebug> CompactDebug for MockOpacity<T> {
+    fn write_compact(&self, out: &mut String) {
+        CompactStruct::new(out, "MockOpacity")
+            .field("opacity", &self.opacity)
+            .child("content", &self.content)
+            .finish();
+    }
+}
+
+impl<T: CompactDebug> CompactDebug for MockBadge<T> {
+    fn write_compact(&self, out: &mut String) {
+        let mut badge = CompactStruct::new(out, "MockBadge");
+        if let Some(content) = &self.content {
+            badge = badge.child("content", content);
+        }
+        badge
+            .field_or_default(
+                "background",
+                &self.background,
+                &Badge::<()>::DEFAULT_BACKGROUND,
+            )
+            .field_or_default("alignment", &self.alignment, &Alignment2D::TOP_TRAILING)
+            .child("child", &self.child)
+            .finish();
+    }
+}
+
+impl<T: CompactDebug> CompactDebug for MockCard<T> {
+    fn write_compact(&self, out: &mut String) {
+        let defaults = Card::new(());
+        CompactStruct::new(out, "MockCard")
+            .field("background", &self.background)
+            .field_or_default(
+                "corner_radius",
+                &self.corner_radius,
+                &defaults.corner_radius,
+            )
+            .field_or_default("insets", &self.insets, &defaults.insets)
+            .field_or_default("shadow", &self.shadow, &defaults.shadow)
+            .child("content", &self.content)
+            .finish();
+    }
+}
+
+impl<T: CompactDebug> CompactDebug for MockTooltip<T> {
+    fn write_compact(&self, out: &mut String) {
+        CompactStruct::new(out, "MockTooltip")
+            .child("text", &self.text)
+            .field_or_default("placement", &self.placement, &TooltipPlacement::default())
+            .child("child", &self.child)
+            .finish();
+    }
+}
+
+impl<T: CompactDebug> CompactDebug for MockScrollView<T> {
+    fn write_compact(&self, out: &mut String) {
+        CompactStruct::new(out, "MockScrollView")
+            .field_or_default("axis", &self.axis, &ScrollAxis::default())
+            .field_or_default("shows_indicators", &self.shows_indicators, &true)
+            .child("content", &self.content)
+            .finish();
+    }
+}
+
+impl<T: CompactDebug> CompactDebug for MockIdentified<T> {
+    fn write_compact(&self, out: &mut String) {
+        CompactStruct::new(out, "MockIdentified")
+            .field("id", &self.id)
+            .child("content", &self.content)
+            .finish();
+    }
+}
+
+impl<T: CompactDebug> CompactDebug for MockTagged<T> {
+    fn write_compact(&self, out: &mut String) {
+        CompactStruct::new(out, "MockTagged")
+            .field("tags", &self.tags)
+            .child("content", &self.content)
+            .finish();
+    }
+}
+
+/// Dynamic children are written as the mock they hold, without the variant name
+impl CompactDebug for MockDynamicChild {
+    fn write_compact(&self, out: &mut String) {
+        match self {
+            MockDynamicChild::Text(text) => text.write_compact(out),
+            MockDynamicChild::RichText(text) => text.write_compact(out),
+            MockDynamicChild::Image(image) => image.write_compact(out),
+            MockDynamicChild::Gauge(gauge) => gauge.write_compact(out),
+            MockDynamicChild::Sparkline(sparkline) => sparkline.write_compact(out),
+            MockDynamicChild::Label(label) => label.write_compact(out),
+            MockDynamicChild::Button(button) => button.write_compact(out),
+            MockDynamicChild::Checkbox(checkbox) => checkbox.write_compact(out),
+            MockDynamicChild::Picker(picker) => picker.write_compact(out),
+            MockDynamicChild::Slider(slider) => slider.write_compact(out),
+            MockDynamicChild::Spinner(spinner) => spinner.write_compact(out),
+            MockDynamicChild::ActivityIndicator(indicator) => indicator.write_compact(out),
+            MockDynamicChild::Stepper(stepper) => stepper.write_compact(out),
+            MockDynamicChild::Table(table) => table.write_compact(out),
+            MockDynamicChild::Toggle(toggle) => toggle.write_compact(out),
+            MockDynamicChild::Spacer(spacer) => spacer.write_compact(out),
+            MockDynamicChild::VStack(stack) => stack.write_compact(out),
+            MockDynamicChild::HStack(stack) => stack.write_compact(out),
+            MockDynamicChild::ZStack(stack) => stack.write_compact(out),
+            MockDynamicChild::Grid(grid) => grid.write_compact(out),
+            MockDynamicChild::FlowLayout(flow) => flow.write_compact(out),
+            MockDynamicChild::Padding(padding) => padding.write_compact(out),
+            MockDynamicChild::Border(border) => border.write_compact(out),
+            MockDynamicChild::Opacity(opacity) => opacity.write_compact(out),
+            MockDynamicChild::Badge(badge) => badge.write_compact(out),
+            MockDynamicChild::Card(card) => card.write_compact(out),
+            MockDynamicChild::Tooltip(tooltip) => tooltip.write_compact(out),
+            MockDynamicChild::ScrollView(scroll) => scroll.write_compact(out),
+            MockDynamicChild::Identified(identified) => identified.write_compact(out),
+            MockDynamicChild::Tagged(tagged) => tagged.write_compact(out),
+        }
+    }
+}
+
+impl CompactDebug for () {
+    fn write_compact(&self, out: &mut String) {
+        out.push_str("()");
+    }
+}
+
+impl<T: CompactDebug> CompactDebug for Option<T> {
+    fn write_compact(&self, out: &mut String) {
+        match self {
+            Some(inner) => {
+                out.push_str("Some(");
+                inner.write_compact(out);
+                out.push(')');
+            }
+            None => out.push_str("None"),
+        }
+    }
+}
+
+impl<T: CompactDebug> CompactDebug for Vec<T> {
+    fn write_compact(&self, out: &mut String) {
+        out.push('[');
+        for (index, item) in self.iter().enumerate() {
+            if index > 0 {
+                out.push_str(", ");
+            }
+            item.write_compact(out);
+        }
+        out.push(']');
+    }
+}
+
+/// Implement compact formatting for one tuple arity.
+macro_rules! tuple_compact_debug {
+    ($first:ident . $first_index:tt $(, $name:ident . $index:tt)*) => {
+        impl<$first: CompactDebug $(, $name: CompactDebug)*> CompactDebug for ($first, $($name,)*) {
+            fn write_compact(&self, out: &mut String) {
+                out.push('(');
+                self.$first_index.write_compact(out);
+                $(
+                    out.push_str(", ");
+                    self.$index.write_compact(out);
+                )*
+                out.push(')');
+            }
+        }
+    };
+}
+
+tuple_compact_debug!(T1.0, T2.1);
+tuple_compact_debug!(T1.0, T2.1, T3.2);
+tuple_compact_debug!(T1.0, T2.1, T3.2, T4.3);
+tuple_compact_debug!(T1.0, T2.1, T3.2, T4.3, T5.4);
+tuple_compact_debug!(T1.0, T2.1, T3.2, T4.3, T5.4, T6.5);
+tuple_compact_debug!(T1.0, T2.1, T3.2, T4.3, T5.4, T6.5, T7.6);
+tuple_compact_debug!(T1.0, T2.1, T3.2, T4.3, T5.4, T6.5, T7.6, T8.7);
+tuple_compact_debug!(T1.0, T2.1, T3.2, T4.3, T5.4, T6.5, T7.6, T8.7, T9.8);
+tuple_compact_debug!(T1.0, T2.1, T3.2, T4.3, T5.4, T6.5, T7.6, T8.7, T9.8, T10.9);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        backends::mock::MockBackend,
+        elements::{Spacer, Text, VStack},
+        extraction::{RenderContext, ViewExtractor},
+        view::View,
+    };
+
+    #[test]
+    fn compact_debug_elides_defaults() {
+        let ctx = RenderContext::new();
+        let plain = VStack::new((Text::new("a"), Text::new("b")));
+        let extracted = MockBackend::extract(&plain, &ctx).unwrap();
+        let compact = extracted.debug_compact();
+        assert_eq!(
+            compact,
+            "MockVStack { content: (MockText { content: \"a\" }, MockText { content: \"b\" }) }"
+        );
+        assert!(compact.len() < format!("{:?}", extracted).len());
+
+        // Non-default values appear, defaults stay hidden
+        let styled = VStack::new((Text::new("a").font_size(20.0), Spacer::new()))
+            .spacing(8.0)
+            .alignment(Alignment::Center);
+        let compact = MockBackend::extract(&styled, &ctx).unwrap().debug_compact();
+        assert!(compact.contains("spacing: 8.0"));
+        assert!(compact.contains("alignment: Center"));
+        assert!(compact.contains("font_size: 20.0"));
+        assert!(compact.contains("MockSpacer)"));
+        assert!(!compact.contains("color"));
+
+        // Dynamic trees format the same way
+        let backend = MockBackend::new();
+        let children: Vec<Box<dyn View>> = vec![Box::new(Text::new("a")), Box::new(Text::new("b"))];
+        let dynamic = backend
+            .extract_dynamic(&VStack::new(children), &ctx)
+            .unwrap();
+        assert_eq!(
+            dynamic.debug_compact(),
+            "MockVStack { content: [MockText { content: \"a\" }, MockText { content: \"b\" }] }"
+        );
+    }
+}
+
+// End of File
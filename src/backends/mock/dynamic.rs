@@ -0,0 +1,718 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Streaming, validating and incremental extraction of dynamic view trees
+
+use std::{collections::BTreeSet, slice};
+
+use super::{MockBackend, MockDynamicChild};
+use crate::{
+    elements::{
+        Badge, Border, Card, FlowLayout, Grid, HStack, Identified, Opacity, Padding, ScrollView,
+        Tagged, Tooltip, VStack, ZStack,
+    },
+    extraction::{DirtySet, ExtractionError, ExtractionResult, RenderContext},
+    view::View,
+};
+
+impl MockBackend {
+    /// Extract a view tree incrementally, emitting each node to a sink.
+    ///
+    /// Instead of building an owned tree, every node is passed to `sink` as soon
+    /// as it is extracted together with its path of child indices from the root
+    /// (the root itself has an empty path). Dynamic containers are emitted with
+    /// empty content before their children (wrappers around a single child hold
+    /// an empty [`ZStack`] in its place), and children are visited depth-first
+    /// in order, so the emission sequence is fully deterministic. Only the node
+    /// currently being emitted is held in memory, which keeps processing of very
+    /// large layouts memory-bounded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::{prelude::*, backends::mock::{MockBackend, MockDynamicChild}};
+    ///
+    /// let backend = MockBackend::new();
+    /// let list = VStack::dynamic()
+    ///     .child(Box::new(Text::new("First")))
+    ///     .child(Box::new(Text::new("Second")));
+    ///
+    /// let mut paths = Vec::new();
+    /// backend
+    ///     .extract_streaming(&list, &RenderContext::new(), |path, _node| {
+    ///         paths.push(path.to_vec());
+    ///     })
+    ///     .unwrap();
+    /// assert_eq!(paths, vec![vec![], vec![0], vec![1]]);
+    /// ```
+    pub fn extract_streaming<F>(
+        &self,
+        view: &dyn View,
+        context: &RenderContext,
+        mut sink: F,
+    ) -> ExtractionResult<()>
+    where
+        F: FnMut(&[usize], MockDynamicChild),
+    {
+        let mut path = Vec::new();
+        self.stream_node(view, context, &mut path, &mut sink)
+    }
+
+    /// Emit a single node and recurse into its children when it is a dynamic container.
+    fn stream_node(
+        &self,
+        view: &dyn View,
+        context: &RenderContext,
+        path: &mut Vec<usize>,
+        sink: &mut dyn FnMut(&[usize], MockDynamicChild),
+    ) -> ExtractionResult<()> {
+        let (Some(children), Some(shell)) = (dynamic_children(view), shell_view(view)) else {
+            sink(path, self.extract_dynamic(view, context)?);
+            return Ok(());
+        };
+
+        // Containers are emitted as empty shells so their children can be streamed
+        // individually rather than materialized as part of the container output
+        sink(path, self.extract_dynamic(shell.as_ref(), context)?);
+        for (index, child) in children.iter().enumerate() {
+            path.push(index);
+            self.stream_node(child.as_ref(), context, path, sink)?;
+            path.pop();
+        }
+        Ok(())
+    }
+
+    /// Check that every node in a view tree can be extracted, without extracting it.
+    ///
+    /// Dynamic stacks are walked recursively and every other node is checked
+    /// against the registry. Unlike extraction, validation doesn't stop at the
+    /// first problem: all errors are collected and returned in depth-first order.
+    /// The render context is accepted for parity with extraction; registration
+    /// checks don't currently depend on it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::{prelude::*, backends::mock::MockBackend};
+    ///
+    /// let backend = MockBackend::new();
+    /// let tree = VStack::dynamic()
+    ///     .child(Box::new(Text::new("Title")))
+    ///     .child(Box::new(HStack::dynamic().child(Box::new(Spacer::new()))));
+    ///
+    /// assert!(backend.validate(&tree, &RenderContext::new()).is_ok());
+    /// ```
+    pub fn validate(
+        &self,
+        view: &dyn View,
+        _context: &RenderContext,
+    ) -> Result<(), Vec<ExtractionError>> {
+        let mut errors = Vec::new();
+        self.validate_node(view, &mut errors);
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Recursively validate a node and its dynamic children.
+    fn validate_node(&self, view: &dyn View, errors: &mut Vec<ExtractionError>) {
+        let Some(children) = dynamic_children(view) else {
+            if let Err(error) = self.registry.check_registered(view) {
+                errors.push(error);
+            }
+            return;
+        };
+
+        for child in children {
+            self.validate_node(child.as_ref(), errors);
+        }
+    }
+
+    /// Refresh a previously extracted tree, re-extracting only the dirty subtrees.
+    ///
+    /// `tree` must be the result of extracting an earlier version of `view`
+    /// with the same layout. Every [`Identified`] subtree whose id is marked
+    /// in `dirty` is re-extracted and replaced in place; the rest of the tree
+    /// is left untouched. The whole tree is re-extracted instead when `dirty`
+    /// requires it, when a marked id isn't found, or when the tree's shape no
+    /// longer matches the view. After a successful pass `dirty` is cleared.
+    /// On error the tree may be partially refreshed, and `dirty` is left as it
+    /// was so the pass can be retried.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::{
+    ///     prelude::*,
+    ///     backends::mock::{MockBackend, MockDynamicChild},
+    ///     extraction::DirtySet,
+    /// };
+    ///
+    /// let backend = MockBackend::new();
+    /// let ctx = RenderContext::new();
+    /// let screen = |count: i32| {
+    ///     VStack::dynamic()
+    ///         .child(Box::new(Text::new("Counter")))
+    ///         .child(Box::new(Text::new(format!("{}", count)).id("count").boxed()))
+    /// };
+    ///
+    /// let mut tree = backend.extract_dynamic(&screen(0), &ctx).unwrap();
+    ///
+    /// let mut dirty = DirtySet::new();
+    /// dirty.mark("count");
+    /// backend.reextract_dirty(&screen(1), &ctx, &mut tree, &mut dirty).unwrap();
+    /// assert!(dirty.is_clean());
+    ///
+    /// let MockDynamicChild::VStack(stack) = &tree else { unreachable!() };
+    /// assert!(matches!(
+    ///     &stack.content[1],
+    ///     MockDynamicChild::Identified(count)
+    ///         if matches!(&count.content, MockDynamicChild::Text(text) if text.content == "1")
+    /// ));
+    /// ```
+    pub fn reextract_dirty(
+        &self,
+        view: &dyn View,
+        context: &RenderContext,
+        tree: &mut MockDynamicChild,
+        dirty: &mut DirtySet,
+    ) -> ExtractionResult<()> {
+        if dirty.is_clean() {
+            return Ok(());
+        }
+
+        let mut pending: BTreeSet<String> = dirty.ids().map(str::to_string).collect();
+        let complete = !dirty.requires_full()
+            && self.patch_node(view, tree, context, &mut pending)?
+            && pending.is_empty();
+        if !complete {
+            *tree = self.extract_dynamic(view, context)?;
+        }
+        dirty.clear();
+        Ok(())
+    }
+
+    /// Extract only the subtree tagged with `id`, or `None` if no view has that id.
+    ///
+    /// The search follows dynamic containers, so the tagged view must be an
+    /// [`Identified`] boxed with [`Identified::boxed`]. The result is the
+    /// same as extracting the tagged node on its own, and nothing outside
+    /// it is extracted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::{prelude::*, backends::mock::{MockBackend, MockDynamicChild}};
+    ///
+    /// let backend = MockBackend::new();
+    /// let ctx = RenderContext::new();
+    /// let screen = VStack::dynamic()
+    ///     .child(Box::new(Text::new("Counter")))
+    ///     .child(Box::new(Text::new("3").id("count").boxed()));
+    ///
+    /// let count = backend.extract_subtree(&screen, &ctx, "count").unwrap();
+    /// assert!(matches!(
+    ///     count,
+    ///     Some(MockDynamicChild::Identified(count))
+    ///         if matches!(&count.content, MockDynamicChild::Text(text) if text.content == "3")
+    /// ));
+    /// assert!(backend.extract_subtree(&screen, &ctx, "missing").unwrap().is_none());
+    /// ```
+    pub fn extract_subtree(
+        &self,
+        view: &dyn View,
+        context: &RenderContext,
+        id: &str,
+    ) -> ExtractionResult<Option<MockDynamicChild>> {
+        find_identified(view, id)
+            .map(|node| self.extract_dynamic(node, context))
+            .transpose()
+    }
+
+    /// Re-extract the dirty subtrees below a node, returning false on a shape mismatch.
+    fn patch_node(
+        &self,
+        view: &dyn View,
+        node: &mut MockDynamicChild,
+        context: &RenderContext,
+        pending: &mut BTreeSet<String>,
+    ) -> ExtractionResult<bool> {
+        if pending.is_empty() {
+            return Ok(true);
+        }
+
+        if let Some(identified) = view.as_any().downcast_ref::<Identified<Box<dyn View>>>()
+            && pending.remove(&identified.id)
+        {
+            *node = self.extract_dynamic(view, context)?;
+            return Ok(true);
+        }
+
+        let Some(children) = dynamic_children(view) else {
+            // Leaves have nothing further to search
+            return Ok(true);
+        };
+        let Some(extracted) = node.children_mut() else {
+            return Ok(false);
+        };
+        if children.len() != extracted.len() {
+            return Ok(false);
+        }
+
+        for (child, extracted) in children.iter().zip(extracted) {
+            if !self.patch_node(child.as_ref(), extracted, context, pending)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+}
+
+/// Find the identified view with the given id, searching through dynamic containers.
+fn find_identified<'a>(view: &'a dyn View, id: &str) -> Option<&'a dyn View> {
+    if let Some(identified) = view.as_any().downcast_ref::<Identified<Box<dyn View>>>()
+        && identified.id == id
+    {
+        return Some(view);
+    }
+
+    dynamic_children(view)?
+        .iter()
+        .find_map(|child| find_identified(child.as_ref(), id))
+}
+
+/// Get the children of a dynamic container view, or `None` for any other view.
+fn dynamic_children(view: &dyn View) -> Option<&[Box<dyn View>]> {
+    let view_any = view.as_any();
+    if let Some(stack) = view_any.downcast_ref::<VStack<Vec<Box<dyn View>>>>() {
+        Some(&stack.content)
+    } else if let Some(stack) = view_any.downcast_ref::<HStack<Vec<Box<dyn View>>>>() {
+        Some(&stack.content)
+    } else if let Some(stack) = view_any.downcast_ref::<ZStack<Vec<Box<dyn View>>>>() {
+        Some(&stack.content)
+    } else if let Some(grid) = view_any.downcast_ref::<Grid>() {
+        Some(&grid.content)
+    } else if let Some(flow) = view_any.downcast_ref::<FlowLayout>() {
+        Some(&flow.content)
+    } else if let Some(padding) = view_any.downcast_ref::<Padding<Box<dyn View>>>() {
+        Some(slice::from_ref(&padding.content))
+    } else if let Some(border) = view_any.downcast_ref::<Border<Box<dyn View>>>() {
+        Some(slice::from_ref(&border.content))
+    } else if let Some(opacity) = view_any.downcast_ref::<Opacity<Box<dyn View>>>() {
+        Some(slice::from_ref(&opacity.content))
+    } else if let Some(badge) = view_any.downcast_ref::<Badge<Box<dyn View>>>() {
+        Some(slice::from_ref(&badge.child))
+    } else if let Some(card) = view_any.downcast_ref::<Card<Box<dyn View>>>() {
+        Some(slice::from_ref(&card.content))
+    } else if let Some(tooltip) = view_any.downcast_ref::<Tooltip<Box<dyn View>>>() {
+        Some(slice::from_ref(&tooltip.child))
+    } else if let Some(scroll) = view_any.downcast_ref::<ScrollView<Vec<Box<dyn View>>>>() {
+        Some(&scroll.content)
+    } else if let Some(identified) = view_any.downcast_ref::<Identified<Box<dyn View>>>() {
+        Some(slice::from_ref(&identified.content))
+    } else if let Some(tagged) = view_any.downcast_ref::<Tagged<Box<dyn View>>>() {
+        Some(slice::from_ref(&tagged.content))
+    } else {
+        None
+    }
+}
+
+/// Copy a dynamic container view without its children, or `None` for any other view.
+///
+/// Containers with a child list get an empty list. Wrappers around a single
+/// child get an empty dynamic [`ZStack`] in its place, which extracts to an
+/// overlay with nothing in it.
+fn shell_view(view: &dyn View) -> Option<Box<dyn View>> {
+    fn placeholder() -> Box<dyn View> {
+        Box::new(ZStack::dynamic())
+    }
+
+    let view_any = view.as_any();
+    let shell: Box<dyn View> =
+        if let Some(stack) = view_any.downcast_ref::<VStack<Vec<Box<dyn View>>>>() {
+            Box::new(VStack {
+                content: Vec::<Box<dyn View>>::new(),
+                alignment: stack.alignment,
+                spacing: stack.spacing,
+            })
+        } else if let Some(stack) = view_any.downcast_ref::<HStack<Vec<Box<dyn View>>>>() {
+            Box::new(HStack {
+                content: Vec::<Box<dyn View>>::new(),
+                alignment: stack.alignment,
+                spacing: stack.spacing,
+            })
+        } else if let Some(stack) = view_any.downcast_ref::<ZStack<Vec<Box<dyn View>>>>() {
+            Box::new(ZStack {
+                content: Vec::<Box<dyn View>>::new(),
+                alignment: stack.alignment,
+            })
+        } else if let Some(grid) = view_any.downcast_ref::<Grid>() {
+            Box::new(Grid {
+                content: Vec::new(),
+                columns: grid.columns,
+                row_spacing: grid.row_spacing,
+                column_spacing: grid.column_spacing,
+            })
+        } else if let Some(flow) = view_any.downcast_ref::<FlowLayout>() {
+            Box::new(FlowLayout {
+                content: Vec::new(),
+                max_width: flow.max_width,
+                line_spacing: flow.line_spacing,
+                item_spacing: flow.item_spacing,
+            })
+        } else if let Some(padding) = view_any.downcast_ref::<Padding<Box<dyn View>>>() {
+            Box::new(Padding::new(placeholder(), padding.insets))
+        } else if let Some(border) = view_any.downcast_ref::<Border<Box<dyn View>>>() {
+            Box::new(Border::new(placeholder(), border.style))
+        } else if let Some(opacity) = view_any.downcast_ref::<Opacity<Box<dyn View>>>() {
+            Box::new(Opacity {
+                opacity: opacity.opacity,
+                content: placeholder(),
+            })
+        } else if let Some(badge) = view_any.downcast_ref::<Badge<Box<dyn View>>>() {
+            Box::new(Badge {
+                content: badge.content.clone(),
+                background: badge.background,
+                alignment: badge.alignment,
+                child: placeholder(),
+            })
+        } else if let Some(card) = view_any.downcast_ref::<Card<Box<dyn View>>>() {
+            Box::new(Card {
+                background: card.background,
+                corner_radius: card.corner_radius,
+                insets: card.insets,
+                shadow: card.shadow,
+                content: placeholder(),
+            })
+        } else if let Some(tooltip) = view_any.downcast_ref::<Tooltip<Box<dyn View>>>() {
+            Box::new(Tooltip {
+                text: tooltip.text.clone(),
+                placement: tooltip.placement,
+                child: placeholder(),
+            })
+        } else if let Some(scroll) = view_any.downcast_ref::<ScrollView<Vec<Box<dyn View>>>>() {
+            Box::new(ScrollView {
+                content: Vec::<Box<dyn View>>::new(),
+                axis: scroll.axis,
+                shows_indicators: scroll.shows_indicators,
+            })
+        } else if let Some(identified) = view_any.downcast_ref::<Identified<Box<dyn View>>>() {
+            Box::new(Identified::new(identified.id.clone(), placeholder()))
+        } else if let Some(tagged) = view_any.downcast_ref::<Tagged<Box<dyn View>>>() {
+            Box::new(Tagged {
+                tags: tagged.tags.clone(),
+                content: placeholder(),
+            })
+        } else {
+            return None;
+        };
+    Some(shell)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        any::type_name,
+        sync::{Arc, Mutex},
+    };
+
+    use super::*;
+    use crate::{
+        elements::{EdgeInsets, Text},
+        model::Model,
+        widgets::Button,
+    };
+
+    #[test]
+    fn streaming_extraction_is_depth_first() {
+        let ctx = RenderContext::new();
+        let backend = MockBackend::new();
+
+        let tree = VStack::dynamic()
+            .child(Box::new(Text::new("Header")))
+            .child(Box::new(
+                HStack::dynamic()
+                    .child(Box::new(Text::new("Left")))
+                    .child(Box::new(Text::new("Right")))
+                    .spacing(4.0),
+            ))
+            .child(Box::new(Button::new("Footer").view()))
+            .spacing(12.0);
+
+        let mut emitted = Vec::new();
+        backend
+            .extract_streaming(&tree, &ctx, |path, node| {
+                emitted.push((path.to_vec(), node))
+            })
+            .unwrap();
+
+        // Containers come before their children, children in insertion order
+        let paths: Vec<Vec<usize>> = emitted.iter().map(|(path, _)| path.clone()).collect();
+        assert_eq!(
+            paths,
+            vec![vec![], vec![0], vec![1], vec![1, 0], vec![1, 1], vec![2]]
+        );
+        assert!(
+            matches!(&emitted[0].1, MockDynamicChild::VStack(stack) if stack.spacing == 12.0 && stack.content.is_empty())
+        );
+        assert!(
+            matches!(&emitted[2].1, MockDynamicChild::HStack(stack) if stack.spacing == 4.0 && stack.content.is_empty())
+        );
+        assert!(matches!(&emitted[4].1, MockDynamicChild::Text(text) if text.content == "Right"));
+        assert!(
+            matches!(&emitted[5].1, MockDynamicChild::Button(button) if button.text == "Footer")
+        );
+    }
+
+    #[test]
+    fn streaming_extraction_descends_into_every_container() {
+        let ctx = RenderContext::new();
+        let backend = MockBackend::new();
+
+        let rows = VStack::dynamic()
+            .child(Box::new(Text::new("One")))
+            .child(Box::new(Text::new("Two")));
+        let padded = Padding::new(Box::new(rows) as Box<dyn View>, EdgeInsets::all(8.0));
+        let tree = ScrollView::new(vec![Box::new(padded) as Box<dyn View>]);
+
+        let mut emitted = Vec::new();
+        backend
+            .extract_streaming(&tree, &ctx, |path, node| {
+                emitted.push((path.to_vec(), node))
+            })
+            .unwrap();
+
+        let paths: Vec<Vec<usize>> = emitted.iter().map(|(path, _)| path.clone()).collect();
+        assert_eq!(
+            paths,
+            vec![vec![], vec![0], vec![0, 0], vec![0, 0, 0], vec![0, 0, 1]]
+        );
+        assert!(
+            matches!(&emitted[0].1, MockDynamicChild::ScrollView(scroll) if scroll.content.is_empty())
+        );
+        // The wrapper's child is streamed separately, not nested in its shell
+        let MockDynamicChild::Padding(padding) = &emitted[1].1 else {
+            panic!("expected padding, got {:?}", emitted[1].1);
+        };
+        assert_eq!(padding.insets, EdgeInsets::all(8.0));
+        assert!(
+            matches!(&padding.content, MockDynamicChild::ZStack(stack) if stack.content.is_empty())
+        );
+        assert!(
+            matches!(&emitted[2].1, MockDynamicChild::VStack(stack) if stack.content.is_empty())
+        );
+        assert!(matches!(&emitted[4].1, MockDynamicChild::Text(text) if text.content == "Two"));
+    }
+
+    #[test]
+    fn streaming_extraction_large_tree() {
+        // 1 root + 100 rows + 100 * 99 cells = 10,001 nodes
+        let ctx = RenderContext::new();
+        let backend = MockBackend::new();
+
+        let mut root = VStack::dynamic_with_capacity(100);
+        for row in 0..100 {
+            let mut cells = HStack::dynamic_with_capacity(99);
+            for column in 0..99 {
+                cells = cells.child(Box::new(Text::new(format!("{}:{}", row, column))));
+            }
+            root = root.child(Box::new(cells));
+        }
+
+        // Only counters are kept; the extracted nodes are dropped as they arrive
+        let mut count = 0;
+        let mut deepest = 0;
+        backend
+            .extract_streaming(&root, &ctx, |path, _node| {
+                count += 1;
+                deepest = deepest.max(path.len());
+            })
+            .unwrap();
+
+        assert_eq!(count, 10_001);
+        assert_eq!(deepest, 2);
+    }
+
+    #[test]
+    fn extraction_errors_record_their_path() {
+        #[derive(Debug)]
+        struct Unregistered;
+
+        impl View for Unregistered {
+            fn as_any(&self) -> &dyn std::any::Any {
+                self
+            }
+        }
+
+        let ctx = RenderContext::new();
+        let backend = MockBackend::new();
+
+        let error = backend.extract_dynamic(&Unregistered, &ctx).unwrap_err();
+        assert!(error.path().is_empty());
+        assert!(error.to_string().ends_with("in the view registry"));
+
+        let layers = ZStack::dynamic()
+            .child(Box::new(Text::new("Back")))
+            .child(Box::new(Text::new("Middle")))
+            .child(Box::new(Unregistered));
+        let tree = ScrollView::new(vec![Box::new(layers) as Box<dyn View>]);
+        let error = backend.extract_dynamic(&tree, &ctx).unwrap_err();
+        assert_eq!(error.path(), ["ScrollView[0]", "ZStack[2]"]);
+        assert!(matches!(
+            &error,
+            ExtractionError::UnregisteredType { type_name, .. }
+                if *type_name == std::any::type_name::<Unregistered>()
+        ));
+        assert!(error.to_string().ends_with(" at ScrollView[0].ZStack[2]"));
+    }
+
+    #[test]
+    fn streaming_extraction_reports_unregistered_types() {
+        #[derive(Debug)]
+        struct Unregistered;
+
+        impl View for Unregistered {
+            fn as_any(&self) -> &dyn std::any::Any {
+                self
+            }
+        }
+
+        let ctx = RenderContext::new();
+        let backend = MockBackend::new();
+        let tree = VStack::dynamic()
+            .child(Box::new(Text::new("Fine")))
+            .child(Box::new(Unregistered));
+
+        let mut count = 0;
+        let result = backend.extract_streaming(&tree, &ctx, |_, _| count += 1);
+        assert!(matches!(
+            result,
+            Err(ExtractionError::UnregisteredType { .. })
+        ));
+        // Nodes before the failure were still emitted
+        assert_eq!(count, 2);
+    }
+
+    /// Build a counter screen whose title, count, and footer are identified.
+    fn counter_screen(count: i32) -> VStack<Vec<Box<dyn View>>> {
+        VStack::dynamic()
+            .child(Box::new(Text::new("Counter").id("title").boxed()))
+            .child(Box::new(HStack::dynamic().child(Box::new(
+                Text::new(format!("Count: {}", count)).id("count").boxed(),
+            ))))
+            .child(Box::new(
+                Button::new("Increment").view().id("footer").boxed(),
+            ))
+    }
+
+    /// Create a backend whose probe records the id of each identified view it extracts.
+    fn probed_backend() -> (MockBackend, Arc<Mutex<Vec<String>>>) {
+        let visits = Arc::new(Mutex::new(Vec::new()));
+        let recorder = visits.clone();
+        let backend = MockBackend::new().with_probe(move |view| {
+            let visit = match view.as_any().downcast_ref::<Identified<Box<dyn View>>>() {
+                Some(identified) => identified.id.clone(),
+                None => view.view_type_name().to_string(),
+            };
+            recorder.lock().unwrap().push(visit);
+        });
+        (backend, visits)
+    }
+
+    #[test]
+    fn dirty_reextraction_visits_only_marked_nodes() {
+        let ctx = RenderContext::new();
+        let (backend, visits) = probed_backend();
+
+        let mut tree = backend.extract_dynamic(&counter_screen(0), &ctx).unwrap();
+        visits.lock().unwrap().clear();
+
+        let mut dirty = DirtySet::new();
+        dirty.mark("count");
+        backend
+            .reextract_dirty(&counter_screen(1), &ctx, &mut tree, &mut dirty)
+            .unwrap();
+
+        assert_eq!(*visits.lock().unwrap(), vec!["count"]);
+        assert!(dirty.is_clean());
+        assert_eq!(
+            tree,
+            MockBackend::new()
+                .extract_dynamic(&counter_screen(1), &ctx)
+                .unwrap()
+        );
+
+        // A clean set leaves the tree alone
+        visits.lock().unwrap().clear();
+        backend
+            .reextract_dirty(&counter_screen(2), &ctx, &mut tree, &mut dirty)
+            .unwrap();
+        assert!(visits.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn subtree_extraction_matches_isolated_node() {
+        let ctx = RenderContext::new();
+        let (backend, visits) = probed_backend();
+        let display: Box<dyn View> = Box::new(Text::new("Count: 4").id("count").boxed());
+
+        let subtree = backend
+            .extract_subtree(&counter_screen(4), &ctx, "count")
+            .unwrap();
+        assert_eq!(visits.lock().unwrap().as_slice(), ["count"]);
+        assert_eq!(
+            subtree,
+            Some(
+                MockBackend::new()
+                    .extract_dynamic(display.as_ref(), &ctx)
+                    .unwrap()
+            )
+        );
+
+        // Missing ids are reported without extracting anything
+        visits.lock().unwrap().clear();
+        let missing = backend
+            .extract_subtree(&counter_screen(4), &ctx, "missing")
+            .unwrap();
+        assert_eq!(missing, None);
+        assert!(visits.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn untagged_or_unknown_changes_reextract_everything() {
+        let ctx = RenderContext::new();
+        let (backend, visits) = probed_backend();
+        let root = type_name::<VStack<Vec<Box<dyn View>>>>().to_string();
+
+        let mut tree = backend.extract_dynamic(&counter_screen(0), &ctx).unwrap();
+        visits.lock().unwrap().clear();
+
+        let mut dirty = DirtySet::new();
+        dirty.mark_all();
+        backend
+            .reextract_dirty(&counter_screen(1), &ctx, &mut tree, &mut dirty)
+            .unwrap();
+        assert_eq!(*visits.lock().unwrap(), vec![root.clone()]);
+        assert!(dirty.is_clean());
+
+        // An id that isn't in the tree can't be patched, so fall back as well
+        visits.lock().unwrap().clear();
+        dirty.mark("missing");
+        backend
+            .reextract_dirty(&counter_screen(2), &ctx, &mut tree, &mut dirty)
+            .unwrap();
+        assert_eq!(*visits.lock().unwrap(), vec![root]);
+        assert!(dirty.is_clean());
+        assert_eq!(
+            tree,
+            MockBackend::new()
+                .extract_dynamic(&counter_screen(2), &ctx)
+                .unwrap()
+        );
+    }
+}
+
+// End of File
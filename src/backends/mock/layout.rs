@@ -0,0 +1,526 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Mock extraction of stacks, grids and scroll views
+
+use std::fmt::Debug;
+
+use super::{MockBackend, MockDynamicChild};
+use crate::{
+    elements::{
+        Alignment, Alignment2D, FlowLayout, Grid, HStack, HorizontalEdge, ScrollAxis, ScrollView,
+        VStack, ZStack,
+    },
+    extraction::{ExtractionResult, RenderContext, ViewExtractor},
+    view::View,
+};
+
+/// Mock representation of a VStack for testing and debugging
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MockVStack<T> {
+    /// The extracted content of the VStack
+    pub content: T,
+    /// The horizontal alignment of child views
+    pub alignment: Alignment,
+    /// The edge the alignment resolved to, or `None` when centered
+    pub horizontal_edge: Option<HorizontalEdge>,
+    /// The spacing between child views
+    pub spacing: f32,
+}
+
+/// Statically typed VStack container extraction
+impl<T> ViewExtractor<VStack<T>> for MockBackend
+where
+    T: View,
+    Self: ViewExtractor<T>,
+{
+    type Output = MockVStack<<Self as ViewExtractor<T>>::Output>;
+
+    fn extract(view: &VStack<T>, context: &RenderContext) -> ExtractionResult<Self::Output> {
+        Ok(MockVStack {
+            content: Self::extract(&view.content, context)?,
+            alignment: view.alignment,
+            horizontal_edge: context.resolve_alignment(view.alignment),
+            spacing: view.spacing,
+        })
+    }
+}
+
+/// Dynamically typed VStack container extraction
+impl ViewExtractor<VStack<Vec<Box<dyn View>>>> for MockBackend {
+    type Output = MockVStack<Vec<MockDynamicChild>>;
+
+    fn extract(
+        view: &VStack<Vec<Box<dyn View>>>,
+        context: &RenderContext,
+    ) -> ExtractionResult<Self::Output> {
+        // Extract each child dynamically using the backend's registry
+        let extracted_children =
+            MockBackend::new().extract_children("VStack", &view.content, context);
+
+        Ok(MockVStack {
+            content: extracted_children?,
+            alignment: view.alignment,
+            horizontal_edge: context.resolve_alignment(view.alignment),
+            spacing: view.spacing,
+        })
+    }
+}
+
+/// Mock representation of an HStack for testing and debugging
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MockHStack<T> {
+    /// The extracted content of the HStack
+    pub content: T,
+    /// The vertical alignment of child views
+    pub alignment: Alignment,
+    /// The edge the first child is placed against
+    pub leading_edge: HorizontalEdge,
+    /// The spacing between child views
+    pub spacing: f32,
+}
+
+/// Statically typed HStack container extraction
+impl<T> ViewExtractor<HStack<T>> for MockBackend
+where
+    T: View,
+    Self: ViewExtractor<T>,
+{
+    type Output = MockHStack<<Self as ViewExtractor<T>>::Output>;
+
+    fn extract(view: &HStack<T>, context: &RenderContext) -> ExtractionResult<Self::Output> {
+        Ok(MockHStack {
+            content: Self::extract(&view.content, context)?,
+            alignment: view.alignment,
+            leading_edge: context.direction().leading_edge(),
+            spacing: view.spacing,
+        })
+    }
+}
+
+/// Dynamically typed HStack container extraction
+impl ViewExtractor<HStack<Vec<Box<dyn View>>>> for MockBackend {
+    type Output = MockHStack<Vec<MockDynamicChild>>;
+
+    fn extract(
+        view: &HStack<Vec<Box<dyn View>>>,
+        context: &RenderContext,
+    ) -> ExtractionResult<Self::Output> {
+        // Extract each child dynamically using the backend's registry
+        let extracted_children =
+            MockBackend::new().extract_children("HStack", &view.content, context);
+
+        Ok(MockHStack {
+            content: extracted_children?,
+            alignment: view.alignment,
+            leading_edge: context.direction().leading_edge(),
+            spacing: view.spacing,
+        })
+    }
+}
+
+/// Mock representation of a ZStack for testing and debugging
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MockZStack<T> {
+    /// The extracted content of the ZStack, from back to front
+    pub content: T,
+    /// The alignment of child views within the shared frame
+    pub alignment: Alignment2D,
+}
+
+/// Statically typed ZStack container extraction
+impl<T> ViewExtractor<ZStack<T>> for MockBackend
+where
+    T: View,
+    Self: ViewExtractor<T>,
+{
+    type Output = MockZStack<<Self as ViewExtractor<T>>::Output>;
+
+    fn extract(view: &ZStack<T>, context: &RenderContext) -> ExtractionResult<Self::Output> {
+        Ok(MockZStack {
+            content: Self::extract(&view.content, context)?,
+            alignment: view.alignment,
+        })
+    }
+}
+
+/// Dynamically typed ZStack container extraction
+impl ViewExtractor<ZStack<Vec<Box<dyn View>>>> for MockBackend {
+    type Output = MockZStack<Vec<MockDynamicChild>>;
+
+    fn extract(
+        view: &ZStack<Vec<Box<dyn View>>>,
+        context: &RenderContext,
+    ) -> ExtractionResult<Self::Output> {
+        // Extract each child dynamically using the backend's registry
+        let extracted_children =
+            MockBackend::new().extract_children("ZStack", &view.content, context);
+
+        Ok(MockZStack {
+            content: extracted_children?,
+            alignment: view.alignment,
+        })
+    }
+}
+
+/// Mock representation of a Grid for testing and debugging
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MockGrid {
+    /// Number of children per row; zero is treated as one
+    pub columns: usize,
+    /// Vertical spacing between rows
+    pub row_spacing: f32,
+    /// Horizontal spacing between columns
+    pub column_spacing: f32,
+    /// The extracted children, in reading order
+    pub content: Vec<MockDynamicChild>,
+}
+
+impl MockGrid {
+    /// Iterate over the extracted children one row at a time.
+    pub fn rows(&self) -> impl Iterator<Item = &[MockDynamicChild]> {
+        self.content.chunks(self.columns.max(1))
+    }
+}
+
+/// Grid extraction, which is always dynamic
+impl ViewExtractor<Grid> for MockBackend {
+    type Output = MockGrid;
+
+    fn extract(view: &Grid, context: &RenderContext) -> ExtractionResult<Self::Output> {
+        Ok(MockGrid {
+            columns: view.columns.max(1),
+            row_spacing: view.row_spacing,
+            column_spacing: view.column_spacing,
+            content: MockBackend::new().extract_children("Grid", &view.content, context)?,
+        })
+    }
+}
+
+/// Mock representation of a FlowLayout for testing and debugging
+///
+/// The children are kept in reading order; deciding where lines break needs
+/// measured sizes, which the mock backend doesn't have.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MockFlowLayout {
+    /// Width a line may reach before wrapping
+    pub max_width: f32,
+    /// Vertical spacing between lines
+    pub line_spacing: f32,
+    /// Horizontal spacing between children on a line
+    pub item_spacing: f32,
+    /// The extracted children, in reading order
+    pub content: Vec<MockDynamicChild>,
+}
+
+/// FlowLayout extraction, which is always dynamic
+impl ViewExtractor<FlowLayout> for MockBackend {
+    type Output = MockFlowLayout;
+
+    fn extract(view: &FlowLayout, context: &RenderContext) -> ExtractionResult<Self::Output> {
+        Ok(MockFlowLayout {
+            max_width: view.max_width,
+            line_spacing: view.line_spacing,
+            item_spacing: view.item_spacing,
+            content: MockBackend::new().extract_children("FlowLayout", &view.content, context)?,
+        })
+    }
+}
+
+/// Mock representation of a ScrollView for testing and debugging
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MockScrollView<T> {
+    /// The axes along which the content scrolls
+    pub axis: ScrollAxis,
+    /// Whether scroll indicators are shown
+    pub shows_indicators: bool,
+    /// The extracted scrollable content
+    pub content: T,
+}
+
+/// Statically typed ScrollView extraction
+impl<V> ViewExtractor<ScrollView<V>> for MockBackend
+where
+    V: View,
+    Self: ViewExtractor<V>,
+{
+    type Output = MockScrollView<<Self as ViewExtractor<V>>::Output>;
+
+    fn extract(view: &ScrollView<V>, context: &RenderContext) -> ExtractionResult<Self::Output> {
+        Ok(MockScrollView {
+            axis: view.axis,
+            shows_indicators: view.shows_indicators,
+            content: Self::extract(&view.content, context)?,
+        })
+    }
+}
+
+/// Dynamically typed ScrollView extraction
+impl ViewExtractor<ScrollView<Vec<Box<dyn View>>>> for MockBackend {
+    type Output = MockScrollView<Vec<MockDynamicChild>>;
+
+    fn extract(
+        view: &ScrollView<Vec<Box<dyn View>>>,
+        context: &RenderContext,
+    ) -> ExtractionResult<Self::Output> {
+        let content = MockBackend::new().extract_children("ScrollView", &view.content, context)?;
+
+        Ok(MockScrollView {
+            axis: view.axis,
+            shows_indicators: view.shows_indicators,
+            content,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        backends::mock::CompactDebug,
+        elements::{LayoutDirection, Text},
+        model::Model,
+        style::Color,
+        widgets::Button,
+    };
+
+    #[test]
+    fn stacks_resolve_alignment_through_layout_direction() {
+        let ltr = RenderContext::new();
+        let rtl = RenderContext::new().with_direction(LayoutDirection::RightToLeft);
+
+        let row = HStack::new((Text::new("Back"), Text::new("Title")));
+        assert_eq!(
+            MockBackend::extract(&row, &ltr).unwrap().leading_edge,
+            HorizontalEdge::Left
+        );
+        assert_eq!(
+            MockBackend::extract(&row, &rtl).unwrap().leading_edge,
+            HorizontalEdge::Right
+        );
+
+        let dynamic_row = HStack::dynamic().child(Box::new(Text::new("Back")));
+        assert_eq!(
+            MockBackend::extract(&dynamic_row, &rtl)
+                .unwrap()
+                .leading_edge,
+            HorizontalEdge::Right
+        );
+
+        let column = VStack::new((Text::new("a"), Text::new("b"))).alignment(Alignment::Trailing);
+        assert_eq!(
+            MockBackend::extract(&column, &ltr).unwrap().horizontal_edge,
+            Some(HorizontalEdge::Right)
+        );
+        assert_eq!(
+            MockBackend::extract(&column, &rtl).unwrap().horizontal_edge,
+            Some(HorizontalEdge::Left)
+        );
+
+        let centered = VStack::dynamic().alignment(Alignment::Center);
+        assert_eq!(
+            MockBackend::extract(&centered, &rtl)
+                .unwrap()
+                .horizontal_edge,
+            None
+        );
+
+        // Only a flipped edge shows up in compact output
+        let mut compact = String::new();
+        MockBackend::extract(&row, &rtl)
+            .unwrap()
+            .write_compact(&mut compact);
+        assert!(compact.contains("leading_edge: Right"));
+        compact.clear();
+        MockBackend::extract(&row, &ltr)
+            .unwrap()
+            .write_compact(&mut compact);
+        assert!(!compact.contains("leading_edge"));
+    }
+
+    #[test]
+    fn vstack_extraction_basic() {
+        // Test basic VStack extraction
+        let text1 = Text::new("Top");
+        let text2 = Text::new("Bottom");
+        let vstack = VStack::new((text1, text2));
+        let ctx = RenderContext::new();
+
+        let extracted = MockBackend::extract(&vstack, &ctx).unwrap();
+
+        assert_eq!(extracted.spacing, 0.0);
+        assert_eq!(extracted.content.0.content, "Top");
+        assert_eq!(extracted.content.1.content, "Bottom");
+    }
+
+    #[test]
+    fn vstack_extraction_with_spacing() {
+        // Test VStack with spacing
+        let text1 = Text::new("Top");
+        let text2 = Text::new("Bottom");
+        let vstack = VStack::new((text1, text2)).spacing(16.0);
+        let ctx = RenderContext::new();
+
+        let extracted = MockBackend::extract(&vstack, &ctx).unwrap();
+
+        assert_eq!(extracted.spacing, 16.0);
+        assert_eq!(extracted.alignment, Alignment::Leading);
+        assert_eq!(extracted.content.0.content, "Top");
+        assert_eq!(extracted.content.1.content, "Bottom");
+    }
+
+    #[test]
+    fn vstack_extraction_with_alignment() {
+        // Test VStack with alignment
+        let text1 = Text::new("Centered");
+        let text2 = Text::new("Content");
+        let vstack = VStack::new((text1, text2)).alignment(Alignment::Center);
+        let ctx = RenderContext::new();
+
+        let extracted = MockBackend::extract(&vstack, &ctx).unwrap();
+
+        assert_eq!(extracted.spacing, 0.0);
+        assert_eq!(extracted.alignment, Alignment::Center);
+        assert_eq!(extracted.content.0.content, "Centered");
+        assert_eq!(extracted.content.1.content, "Content");
+    }
+
+    #[test]
+    fn hstack_extraction_basic() {
+        // Test basic HStack extraction
+        let text1 = Text::new("Left");
+        let text2 = Text::new("Right");
+        let hstack = HStack::new((text1, text2));
+        let ctx = RenderContext::new();
+
+        let extracted = MockBackend::extract(&hstack, &ctx).unwrap();
+
+        assert_eq!(extracted.spacing, 0.0);
+        assert_eq!(extracted.content.0.content, "Left");
+        assert_eq!(extracted.content.1.content, "Right");
+    }
+
+    #[test]
+    fn hstack_extraction_with_spacing() {
+        // Test HStack with spacing
+        let text1 = Text::new("Left");
+        let text2 = Text::new("Right");
+        let hstack = HStack::new((text1, text2)).spacing(8.0);
+        let ctx = RenderContext::new();
+
+        let extracted = MockBackend::extract(&hstack, &ctx).unwrap();
+
+        assert_eq!(extracted.spacing, 8.0);
+        assert_eq!(extracted.alignment, Alignment::Leading);
+        assert_eq!(extracted.content.0.content, "Left");
+        assert_eq!(extracted.content.1.content, "Right");
+    }
+
+    #[test]
+    fn hstack_extraction_with_alignment() {
+        // Test HStack with alignment
+        let text1 = Text::new("Left");
+        let text2 = Text::new("Right");
+        let hstack = HStack::new((text1, text2)).alignment(Alignment::Trailing);
+        let ctx = RenderContext::new();
+
+        let extracted = MockBackend::extract(&hstack, &ctx).unwrap();
+
+        assert_eq!(extracted.spacing, 0.0);
+        assert_eq!(extracted.alignment, Alignment::Trailing);
+        assert_eq!(extracted.content.0.content, "Left");
+        assert_eq!(extracted.content.1.content, "Right");
+    }
+
+    #[test]
+    fn nested_containers_extraction() {
+        // Test nested container extraction
+        let inner_text1 = Text::new("Inner 1");
+        let inner_text2 = Text::new("Inner 2");
+        let inner_hstack = HStack::new((inner_text1, inner_text2)).spacing(4.0);
+
+        let outer_text = Text::new("Outer");
+        let outer_vstack = VStack::new((inner_hstack, outer_text)).spacing(12.0);
+
+        let ctx = RenderContext::new();
+        let extracted = MockBackend::extract(&outer_vstack, &ctx).unwrap();
+
+        assert_eq!(extracted.spacing, 12.0);
+        assert_eq!(extracted.content.0.spacing, 4.0);
+        assert_eq!(extracted.content.0.content.0.content, "Inner 1");
+        assert_eq!(extracted.content.0.content.1.content, "Inner 2");
+        assert_eq!(extracted.content.1.content, "Outer");
+    }
+
+    #[test]
+    fn container_with_mixed_content() {
+        // Test container with mixed content types
+        let text = Text::new("Label").color(Color::GREEN);
+        let button = Button::new("Action").background_color(Color::RED);
+        let vstack = VStack::new((text, button.view())).spacing(10.0);
+
+        let ctx = RenderContext::new();
+        let extracted = MockBackend::extract(&vstack, &ctx).unwrap();
+
+        assert_eq!(extracted.spacing, 10.0);
+        assert_eq!(extracted.content.0.content, "Label");
+        assert_eq!(extracted.content.0.color, Color::GREEN);
+        assert_eq!(extracted.content.1.text, "Action");
+        assert_eq!(extracted.content.1.background_color, Color::RED);
+    }
+
+    #[test]
+    fn complex_nested_hierarchy() {
+        // Test complex nested hierarchy with multiple levels
+        let header = Text::new("Header").font_size(24.0);
+
+        let row1 = HStack::new((Text::new("Col 1"), Text::new("Col 2"))).spacing(5.0);
+
+        let row2 = HStack::new((
+            Button::new("Button 1").view(),
+            Button::new("Button 2").view(),
+        ))
+        .spacing(5.0);
+
+        let content = VStack::new((row1, row2)).spacing(8.0);
+        let footer = Text::new("Footer").color(Color::BLUE);
+
+        let main_layout = VStack::new((header, content, footer)).spacing(16.0);
+
+        let ctx = RenderContext::new();
+        let extracted = MockBackend::extract(&main_layout, &ctx).unwrap();
+
+        // Verify structure
+        assert_eq!(extracted.spacing, 16.0);
+
+        // Header
+        assert_eq!(extracted.content.0.content, "Header");
+        assert_eq!(extracted.content.0.font_size, 24.0);
+
+        // Content (nested VStack)
+        assert_eq!(extracted.content.1.spacing, 8.0);
+
+        // Row 1 (HStack with texts)
+        assert_eq!(extracted.content.1.content.0.spacing, 5.0);
+        assert_eq!(extracted.content.1.content.0.content.0.content, "Col 1");
+        assert_eq!(extracted.content.1.content.0.content.1.content, "Col 2");
+
+        // Row 2 (HStack with buttons)
+        assert_eq!(extracted.content.1.content.1.spacing, 5.0);
+        assert_eq!(extracted.content.1.content.1.content.0.text, "Button 1");
+        assert_eq!(extracted.content.1.content.1.content.1.text, "Button 2");
+
+        // Footer
+        assert_eq!(extracted.content.2.content, "Footer");
+        assert_eq!(extracted.content.2.color, Color::BLUE);
+    }
+}
+
+// End of File
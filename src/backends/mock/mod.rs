@@ -0,0 +1,712 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Mock backend for testing view extraction
+//!
+//! The mock backend extracts views into simple, testable data structures.
+//! This allows verification that views are being created correctly and
+//! that their properties are accessible without needing a full rendering
+//! pipeline.
+//!
+//! The mock backend is also useful for automated testing, as it produces
+//! deterministic output that can be easily compared in assertions.
+
+use std::{any::type_name, fmt::Debug, slice};
+
+use crate::{
+    elements::{
+        Badge, Border, Card, FlowLayout, Gauge, Grid, HStack, Identified, Image, Label, Opacity,
+        Padding, RichText, ScrollView, Spacer, Sparkline, Spinner, Tagged, Text, Tooltip, VStack,
+        ZStack,
+    },
+    extraction::{
+        ExtractionError, ExtractionResult, RegistryResult, RenderContext, ViewExtractor,
+        ViewRegistry,
+    },
+    model::Model,
+    view::View,
+    widgets::{
+        ActivityIndicatorView, ButtonView, CheckboxView, PickerView, SliderView, StepperView,
+        TableView, ToggleView,
+    },
+};
+
+mod approx;
+mod collections;
+mod compact;
+mod dynamic;
+mod layout;
+mod modifiers;
+mod text;
+mod widgets;
+
+pub use approx::ApproxEq;
+pub use compact::CompactDebug;
+pub use layout::{MockFlowLayout, MockGrid, MockHStack, MockScrollView, MockVStack, MockZStack};
+pub use modifiers::{
+    MockBadge, MockBorder, MockCard, MockIdentified, MockOpacity, MockPadding, MockTagged,
+    MockTooltip,
+};
+pub use text::{
+    MockGauge, MockImage, MockLabel, MockRichText, MockSparkline, MockText, MockTextRef,
+};
+pub use widgets::{
+    MockActivityIndicator, MockButton, MockCheckbox, MockPicker, MockSlider, MockSpacer,
+    MockSpinner, MockStepper, MockTable, MockToggle,
+};
+
+/// Mock backend for testing view extraction.
+///
+/// The MockBackend extracts views into simple, testable data structures.
+/// This allows us to verify that views are being created correctly and
+/// that their properties are accessible without needing a full rendering
+/// pipeline.
+///
+/// The mock backend is also useful for automated testing, as it produces
+/// deterministic output that can be easily compared in assertions.
+///
+/// ## Ordering
+///
+/// The children of every dynamic container are extracted into the same
+/// positions they were inserted at, so a snapshot of a tree never depends on
+/// how extraction is scheduled. Any future change that extracts siblings
+/// concurrently must keep this guarantee by collecting results by index.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{prelude::*, backends::mock::MockBackend, extraction::ViewExtractor};
+///
+/// let text = Text::new("Hello, world!");
+/// let ctx = RenderContext::new();
+/// let extracted = MockBackend::extract(&text, &ctx).unwrap();
+/// assert_eq!(extracted.content, "Hello, world!");
+///
+/// // Or use the backend's dynamic extraction for trait objects
+/// let backend = MockBackend::new();
+/// let view: Box<dyn View> = Box::new(Text::new("Dynamic"));
+/// let dynamic_extracted = backend.extract_dynamic(view.as_ref(), &ctx).unwrap();
+/// ```
+pub struct MockBackend {
+    /// Type registry for dynamic view extraction
+    registry: ViewRegistry,
+    /// Observer notified of every view passed to dynamic extraction
+    probe: Option<ExtractionProbe>,
+}
+
+/// Callback that observes the views a [`MockBackend`] starts extracting.
+type ExtractionProbe = Box<dyn Fn(&dyn View) + Send + Sync>;
+
+impl MockBackend {
+    /// Create a new MockBackend with a configured type registry.
+    ///
+    /// This sets up all the view types that the MockBackend can handle,
+    /// including both static extraction and dynamic conversion functions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::backends::mock::MockBackend;
+    ///
+    /// let backend = MockBackend::new();
+    /// // Backend is ready to extract any registered view type
+    /// ```
+    pub fn new() -> Self {
+        let registry =
+            Self::build_registry().expect("MockBackend registers each view type exactly once");
+        Self {
+            registry,
+            probe: None,
+        }
+    }
+
+    /// Observe every view this backend starts extracting dynamically.
+    ///
+    /// The probe is called with each view passed to
+    /// [`MockBackend::extract_dynamic`], before it is extracted. Children of
+    /// dynamic containers are extracted as part of their parent and aren't
+    /// reported separately, so the probe shows where each extraction pass
+    /// started. Tests use it to check how much of a tree was re-extracted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::{Arc, Mutex};
+    /// use ironwood::{prelude::*, backends::mock::MockBackend};
+    ///
+    /// let visits = Arc::new(Mutex::new(Vec::new()));
+    /// let recorder = visits.clone();
+    /// let backend = MockBackend::new()
+    ///     .with_probe(move |view| recorder.lock().unwrap().push(view.view_type_name()));
+    ///
+    /// backend.extract_dynamic(&Text::new("Hi"), &RenderContext::new()).unwrap();
+    /// assert_eq!(*visits.lock().unwrap(), vec![std::any::type_name::<Text>()]);
+    /// ```
+    pub fn with_probe(mut self, probe: impl Fn(&dyn View) + Send + Sync + 'static) -> Self {
+        self.probe = Some(Box::new(probe));
+        self
+    }
+
+    /// Register every view type the MockBackend knows how to extract.
+    fn build_registry() -> RegistryResult<ViewRegistry> {
+        ViewRegistry::builder()
+            .register_full::<Text, MockBackend, _, _>(MockDynamicChild::Text)
+            .register_full::<RichText, MockBackend, _, _>(MockDynamicChild::RichText)
+            .register_full::<Image, MockBackend, _, _>(MockDynamicChild::Image)
+            .register_full::<Gauge, MockBackend, _, _>(MockDynamicChild::Gauge)
+            .register_full::<Sparkline, MockBackend, _, _>(MockDynamicChild::Sparkline)
+            .register_full::<Label, MockBackend, _, _>(|label| {
+                MockDynamicChild::Label(Box::new(label))
+            })
+            .register_full::<ButtonView, MockBackend, _, _>(MockDynamicChild::Button)
+            .register_full::<CheckboxView, MockBackend, _, _>(MockDynamicChild::Checkbox)
+            .register_full::<PickerView, MockBackend, _, _>(MockDynamicChild::Picker)
+            .register_full::<SliderView, MockBackend, _, _>(MockDynamicChild::Slider)
+            .register_full::<Spinner, MockBackend, _, _>(MockDynamicChild::Spinner)
+            .register_full::<ActivityIndicatorView, MockBackend, _, _>(
+                MockDynamicChild::ActivityIndicator,
+            )
+            .register_full::<StepperView, MockBackend, _, _>(MockDynamicChild::Stepper)
+            .register_full::<TableView, MockBackend, _, _>(MockDynamicChild::Table)
+            .register_full::<ToggleView, MockBackend, _, _>(MockDynamicChild::Toggle)
+            .register_full::<Spacer, MockBackend, _, _>(MockDynamicChild::Spacer)
+            .register_full::<VStack<Vec<Box<dyn View>>>, MockBackend, _, _>(
+                MockDynamicChild::VStack,
+            )
+            .register_full::<HStack<Vec<Box<dyn View>>>, MockBackend, _, _>(
+                MockDynamicChild::HStack,
+            )
+            .register_full::<ZStack<Vec<Box<dyn View>>>, MockBackend, _, _>(
+                MockDynamicChild::ZStack,
+            )
+            .register_full::<Padding<Box<dyn View>>, MockBackend, _, _>(|padding| {
+                MockDynamicChild::Padding(Box::new(padding))
+            })
+            .register_full::<Border<Box<dyn View>>, MockBackend, _, _>(|border| {
+                MockDynamicChild::Border(Box::new(border))
+            })
+            .register_full::<Opacity<Box<dyn View>>, MockBackend, _, _>(|opacity| {
+                MockDynamicChild::Opacity(Box::new(opacity))
+            })
+            .register_full::<Badge<Box<dyn View>>, MockBackend, _, _>(|badge| {
+                MockDynamicChild::Badge(Box::new(badge))
+            })
+            .register_full::<Card<Box<dyn View>>, MockBackend, _, _>(|card| {
+                MockDynamicChild::Card(Box::new(card))
+            })
+            .register_full::<Tooltip<Box<dyn View>>, MockBackend, _, _>(|tooltip| {
+                MockDynamicChild::Tooltip(Box::new(tooltip))
+            })
+            .register_full::<Grid, MockBackend, _, _>(MockDynamicChild::Grid)
+            .register_full::<FlowLayout, MockBackend, _, _>(MockDynamicChild::FlowLayout)
+            .register_full::<ScrollView<Vec<Box<dyn View>>>, MockBackend, _, _>(
+                MockDynamicChild::ScrollView,
+            )
+            .register_full::<Identified<Box<dyn View>>, MockBackend, _, _>(|identified| {
+                MockDynamicChild::Identified(Box::new(identified))
+            })
+            .register_full::<Tagged<Box<dyn View>>, MockBackend, _, _>(|tagged| {
+                MockDynamicChild::Tagged(Box::new(tagged))
+            })
+            .build()
+    }
+
+    /// Extract a view dynamically using the backend's type registry.
+    ///
+    /// This method can extract any view type that has been registered with
+    /// the backend, returning the appropriate MockDynamicChild variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::{prelude::*, backends::mock::MockBackend};
+    ///
+    /// let backend = MockBackend::new();
+    /// let view: Box<dyn View> = Box::new(Text::new("Hello"));
+    /// let ctx = RenderContext::new();
+    /// let extracted = backend.extract_dynamic(view.as_ref(), &ctx).unwrap();
+    /// ```
+    pub fn extract_dynamic(
+        &self,
+        view: &dyn View,
+        context: &RenderContext,
+    ) -> ExtractionResult<MockDynamicChild> {
+        if let Some(probe) = &self.probe {
+            probe(view);
+        }
+
+        // Extract and convert using the registry
+        let converted = self
+            .registry
+            .extract_and_convert::<MockBackend>(view, context)?;
+
+        // The registry guarantees this will be a MockDynamicChild
+        Ok(*converted.downcast::<MockDynamicChild>().map_err(|_| {
+            ExtractionError::OutputDowncastFailed {
+                expected_type: type_name::<MockDynamicChild>(),
+                path: Vec::new(),
+            }
+        })?)
+    }
+
+    /// Extract the children of a dynamic container, adding the child's position to any error.
+    fn extract_children(
+        &self,
+        container: &str,
+        children: &[Box<dyn View>],
+        context: &RenderContext,
+    ) -> ExtractionResult<Vec<MockDynamicChild>> {
+        // Output position must match insertion position; see "Ordering" above
+        children
+            .iter()
+            .enumerate()
+            .map(|(index, child)| {
+                MockDynamicChild::extract_from_view_with_backend(child.as_ref(), context, self)
+                    .map_err(|error| error.in_child(format!("{container}[{index}]")))
+            })
+            .collect()
+    }
+
+    /// Extract a single boxed view dynamically, without wrapping it in a container.
+    ///
+    /// This dispatches through the registry exactly like the children of a
+    /// dynamic stack. It exists because `&Box<dyn View>` doesn't coerce to the
+    /// `&dyn View` that [`MockBackend::extract_dynamic`] expects.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::{prelude::*, backends::mock::{MockBackend, MockDynamicChild}};
+    ///
+    /// let backend = MockBackend::new();
+    /// let view: Box<dyn View> = Box::new(Button::new("OK").view());
+    /// let extracted = backend.extract_boxed(&view, &RenderContext::new()).unwrap();
+    /// assert!(matches!(extracted, MockDynamicChild::Button(_)));
+    /// ```
+    #[allow(clippy::borrowed_box)]
+    pub fn extract_boxed(
+        &self,
+        view: &Box<dyn View>,
+        context: &RenderContext,
+    ) -> ExtractionResult<MockDynamicChild> {
+        self.extract_dynamic(view.as_ref(), context)
+    }
+
+    /// Get the names of every view type this backend can extract dynamically.
+    ///
+    /// Tooling can use this to check a tree before extraction and point at
+    /// the missing registration instead of failing midway.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::{prelude::*, backends::mock::MockBackend};
+    ///
+    /// let backend = MockBackend::new();
+    /// assert!(backend.registered_types().contains(&std::any::type_name::<Text>()));
+    /// ```
+    pub fn registered_types(&self) -> Vec<&'static str> {
+        self.registry.registered_types()
+    }
+}
+
+impl Default for MockBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A type-erased representation of extracted dynamic children.
+///
+/// This allows the mock backend to handle different types of extracted views
+/// in a uniform way while preserving type information for testing.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MockDynamicChild {
+    Text(MockText),
+    RichText(MockRichText),
+    Image(MockImage),
+    Gauge(MockGauge),
+    Sparkline(MockSparkline),
+    Label(Box<MockLabel>),
+    Button(MockButton),
+    Checkbox(MockCheckbox),
+    Picker(MockPicker),
+    Slider(MockSlider),
+    Spinner(MockSpinner),
+    ActivityIndicator(MockActivityIndicator),
+    Stepper(MockStepper),
+    Table(MockTable),
+    Toggle(MockToggle),
+    Spacer(MockSpacer),
+    VStack(MockVStack<Vec<MockDynamicChild>>),
+    HStack(MockHStack<Vec<MockDynamicChild>>),
+    ZStack(MockZStack<Vec<MockDynamicChild>>),
+    Grid(MockGrid),
+    FlowLayout(MockFlowLayout),
+    Padding(Box<MockPadding<MockDynamicChild>>),
+    Border(Box<MockBorder<MockDynamicChild>>),
+    Opacity(Box<MockOpacity<MockDynamicChild>>),
+    Badge(Box<MockBadge<MockDynamicChild>>),
+    Card(Box<MockCard<MockDynamicChild>>),
+    Tooltip(Box<MockTooltip<MockDynamicChild>>),
+    ScrollView(MockScrollView<Vec<MockDynamicChild>>),
+    Identified(Box<MockIdentified<MockDynamicChild>>),
+    Tagged(Box<MockTagged<MockDynamicChild>>),
+}
+
+impl MockDynamicChild {
+    /// Extract a view dynamically into a MockDynamicChild using a backend instance.
+    ///
+    /// This method uses the backend's type registry to eliminate hardcoded type checking.
+    /// All type dispatch is handled by the backend's registry.
+    pub fn extract_from_view_with_backend(
+        view: &dyn View,
+        context: &RenderContext,
+        backend: &MockBackend,
+    ) -> ExtractionResult<Self> {
+        backend.extract_dynamic(view, context)
+    }
+
+    /// Check whether two container nodes are the same kind with the same settings.
+    ///
+    /// Only the containers' own fields are compared, not their children, so
+    /// two stacks with different child lists still have the same shell.
+    /// Leaves never have a shell.
+    pub(crate) fn same_shell(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::VStack(left), Self::VStack(right)) => {
+                left.alignment == right.alignment
+                    && left.horizontal_edge == right.horizontal_edge
+                    && left.spacing == right.spacing
+            }
+            (Self::HStack(left), Self::HStack(right)) => {
+                left.alignment == right.alignment
+                    && left.leading_edge == right.leading_edge
+                    && left.spacing == right.spacing
+            }
+            (Self::ZStack(left), Self::ZStack(right)) => left.alignment == right.alignment,
+            (Self::Grid(left), Self::Grid(right)) => {
+                left.columns == right.columns
+                    && left.row_spacing == right.row_spacing
+                    && left.column_spacing == right.column_spacing
+            }
+            (Self::FlowLayout(left), Self::FlowLayout(right)) => {
+                left.max_width == right.max_width
+                    && left.line_spacing == right.line_spacing
+                    && left.item_spacing == right.item_spacing
+            }
+            (Self::Padding(left), Self::Padding(right)) => left.insets == right.insets,
+            (Self::Border(left), Self::Border(right)) => left.style == right.style,
+            (Self::Opacity(left), Self::Opacity(right)) => left.opacity == right.opacity,
+            (Self::Badge(left), Self::Badge(right)) => {
+                left.content == right.content
+                    && left.background == right.background
+                    && left.alignment == right.alignment
+            }
+            (Self::Card(left), Self::Card(right)) => {
+                left.background == right.background
+                    && left.corner_radius == right.corner_radius
+                    && left.insets == right.insets
+                    && left.shadow == right.shadow
+            }
+            (Self::Tooltip(left), Self::Tooltip(right)) => {
+                left.text == right.text && left.placement == right.placement
+            }
+            (Self::ScrollView(left), Self::ScrollView(right)) => {
+                left.axis == right.axis && left.shows_indicators == right.shows_indicators
+            }
+            (Self::Identified(left), Self::Identified(right)) => left.id == right.id,
+            (Self::Tagged(left), Self::Tagged(right)) => left.tags == right.tags,
+            _ => false,
+        }
+    }
+
+    /// Get the extracted children of a container node.
+    pub(crate) fn children(&self) -> Option<&[MockDynamicChild]> {
+        match self {
+            Self::VStack(stack) => Some(&stack.content),
+            Self::HStack(stack) => Some(&stack.content),
+            Self::ZStack(stack) => Some(&stack.content),
+            Self::Grid(grid) => Some(&grid.content),
+            Self::FlowLayout(flow) => Some(&flow.content),
+            Self::Padding(padding) => Some(slice::from_ref(&padding.content)),
+            Self::Border(border) => Some(slice::from_ref(&border.content)),
+            Self::Opacity(opacity) => Some(slice::from_ref(&opacity.content)),
+            Self::Badge(badge) => Some(slice::from_ref(&badge.child)),
+            Self::Card(card) => Some(slice::from_ref(&card.content)),
+            Self::Tooltip(tooltip) => Some(slice::from_ref(&tooltip.child)),
+            Self::ScrollView(scroll) => Some(&scroll.content),
+            Self::Identified(identified) => Some(slice::from_ref(&identified.content)),
+            Self::Tagged(tagged) => Some(slice::from_ref(&tagged.content)),
+            _ => None,
+        }
+    }
+
+    /// Get the child list of a node whose number of children can change.
+    pub(crate) fn child_list_mut(&mut self) -> Option<&mut Vec<MockDynamicChild>> {
+        match self {
+            Self::VStack(stack) => Some(&mut stack.content),
+            Self::HStack(stack) => Some(&mut stack.content),
+            Self::ZStack(stack) => Some(&mut stack.content),
+            Self::Grid(grid) => Some(&mut grid.content),
+            Self::FlowLayout(flow) => Some(&mut flow.content),
+            Self::ScrollView(scroll) => Some(&mut scroll.content),
+            _ => None,
+        }
+    }
+
+    /// Get mutable access to the extracted children of a container node.
+    pub(crate) fn children_mut(&mut self) -> Option<&mut [MockDynamicChild]> {
+        match self {
+            Self::VStack(stack) => Some(&mut stack.content),
+            Self::HStack(stack) => Some(&mut stack.content),
+            Self::ZStack(stack) => Some(&mut stack.content),
+            Self::Grid(grid) => Some(&mut grid.content),
+            Self::FlowLayout(flow) => Some(&mut flow.content),
+            Self::Padding(padding) => Some(slice::from_mut(&mut padding.content)),
+            Self::Border(border) => Some(slice::from_mut(&mut border.content)),
+            Self::Opacity(opacity) => Some(slice::from_mut(&mut opacity.content)),
+            Self::Badge(badge) => Some(slice::from_mut(&mut badge.child)),
+            Self::Card(card) => Some(slice::from_mut(&mut card.content)),
+            Self::Tooltip(tooltip) => Some(slice::from_mut(&mut tooltip.child)),
+            Self::ScrollView(scroll) => Some(&mut scroll.content),
+            Self::Identified(identified) => Some(slice::from_mut(&mut identified.content)),
+            Self::Tagged(tagged) => Some(slice::from_mut(&mut tagged.content)),
+            _ => None,
+        }
+    }
+}
+
+/// Assert that two models produce identical views when extracted by a backend.
+///
+/// Both models' views are extracted with `B` and the outputs compared. This
+/// keeps tests of update idempotence or equivalence to a single line.
+///
+/// # Panics
+///
+/// Panics if either extraction fails or if the extracted outputs differ.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{backends::mock::{MockBackend, assert_same_view}, prelude::*};
+///
+/// let button = Button::new("Save");
+/// let hovered_and_back = button.clone().hover().unhover();
+///
+/// assert_same_view::<_, MockBackend>(&button, &hovered_and_back, &RenderContext::new());
+/// ```
+#[track_caller]
+pub fn assert_same_view<M, B>(a: &M, b: &M, ctx: &RenderContext)
+where
+    M: Model,
+    B: ViewExtractor<M::View>,
+    B::Output: PartialEq + Debug,
+{
+    let left = B::extract(&a.view(), ctx).expect("failed to extract first model's view");
+    let right = B::extract(&b.view(), ctx).expect("failed to extract second model's view");
+    assert_eq!(left, right, "models produced different views");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{elements::Text, interaction::Enableable, model::Model, widgets::Button};
+
+    #[test]
+    fn backend_owns_registry_architecture() {
+        // This test demonstrates that the registry is now properly part of the backend object
+        // This allows for better encapsulation and potentially different backend configurations
+        let ctx = RenderContext::new();
+
+        // Create a backend instance - the registry is part of the backend
+        let backend = MockBackend::new();
+
+        // The backend can extract views dynamically using its own registry
+        let text_view = Text::new("Backend Test");
+        let button_view = Button::new("Backend Button").view();
+
+        // Extract using the backend's registry
+        let text_extracted = backend.extract_dynamic(&text_view, &ctx).unwrap();
+        let button_extracted = backend.extract_dynamic(&button_view, &ctx).unwrap();
+
+        // Verify the extractions worked correctly
+        assert!(
+            matches!(text_extracted, MockDynamicChild::Text(text) if text.content == "Backend Test")
+        );
+
+        assert!(
+            matches!(button_extracted, MockDynamicChild::Button(button) if button.text == "Backend Button")
+        );
+
+        // The backend encapsulates its registry - no global state needed
+        // Different backend instances could potentially have different registries
+        let another_backend = MockBackend::new();
+        let another_extracted = another_backend.extract_dynamic(&text_view, &ctx).unwrap();
+
+        assert!(
+            matches!(another_extracted, MockDynamicChild::Text(text) if text.content == "Backend Test")
+        );
+    }
+
+    #[test]
+    fn registry_based_dynamic_extraction_no_hardcoding() {
+        // This test demonstrates that the registry-based approach works
+        // without any hardcoded type checking in the extraction logic
+        let ctx = RenderContext::new();
+
+        // Create a dynamic container with mixed view types
+        let dynamic_views: Vec<Box<dyn View>> = vec![
+            Box::new(Text::new("Hello")),
+            Box::new(Button::new("Click me").view()),
+            Box::new(Spacer::min_size(10.0)),
+        ];
+
+        let dynamic_vstack = VStack::dynamic().children(dynamic_views).spacing(8.0);
+
+        // Extract the dynamic container
+        let extracted = MockBackend::extract(&dynamic_vstack, &ctx).unwrap();
+
+        // Verify the structure was extracted correctly
+        assert_eq!(extracted.spacing, 8.0);
+        assert_eq!(extracted.content.len(), 3);
+
+        // Verify each child was converted correctly by the registry
+        assert!(
+            matches!(&extracted.content[0], MockDynamicChild::Text(text) if text.content == "Hello")
+        );
+
+        assert!(
+            matches!(&extracted.content[1], MockDynamicChild::Button(button) if button.text == "Click me")
+        );
+
+        assert!(
+            matches!(&extracted.content[2], MockDynamicChild::Spacer(spacer) if spacer.min_size == 10.0)
+        );
+    }
+
+    #[test]
+    fn boxed_views_extract_without_container() {
+        let ctx = RenderContext::new();
+        let backend = MockBackend::new();
+
+        let view: Box<dyn View> = Box::new(Button::new("Boxed").disable().view());
+        let extracted = backend.extract_boxed(&view, &ctx).unwrap();
+        match extracted {
+            MockDynamicChild::Button(button) => {
+                assert_eq!(button.text, "Boxed");
+                assert!(!button.interaction_state.is_enabled());
+            }
+            other => panic!("expected a button, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn registered_types_lists_supported_views() {
+        #[derive(Debug)]
+        struct Unregistered;
+
+        impl View for Unregistered {
+            fn as_any(&self) -> &dyn std::any::Any {
+                self
+            }
+        }
+
+        let types = MockBackend::new().registered_types();
+        assert!(types.contains(&type_name::<Text>()));
+        assert!(types.contains(&type_name::<ButtonView>()));
+        assert!(types.contains(&type_name::<Spacer>()));
+        assert!(types.contains(&type_name::<VStack<Vec<Box<dyn View>>>>()));
+        assert!(types.contains(&type_name::<HStack<Vec<Box<dyn View>>>>()));
+        assert!(!types.contains(&type_name::<Unregistered>()));
+    }
+
+    #[test]
+    fn validate_reports_every_unregistered_node() {
+        #[derive(Debug)]
+        struct Unregistered;
+
+        impl View for Unregistered {
+            fn as_any(&self) -> &dyn std::any::Any {
+                self
+            }
+        }
+
+        let ctx = RenderContext::new();
+        let backend = MockBackend::new();
+
+        let valid = VStack::dynamic()
+            .child(Box::new(Text::new("Header")))
+            .child(Box::new(
+                HStack::dynamic()
+                    .child(Box::new(Button::new("OK").view()))
+                    .child(Box::new(Spacer::new())),
+            ));
+        assert!(backend.validate(&valid, &ctx).is_ok());
+
+        let invalid = VStack::dynamic()
+            .child(Box::new(Text::new("Header")))
+            .child(Box::new(HStack::dynamic().child(Box::new(Unregistered))));
+        let errors = backend.validate(&invalid, &ctx).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(
+            errors[0],
+            ExtractionError::UnregisteredType { type_name, .. }
+                if type_name == std::any::type_name::<Unregistered>()
+        ));
+    }
+
+    #[test]
+    fn nested_dynamic_containers_registry_based() {
+        // Test nested dynamic containers to ensure the registry handles
+        // recursive extraction correctly
+        let ctx = RenderContext::new();
+
+        // Create nested dynamic structure
+        let inner_hstack = HStack::dynamic()
+            .child(Box::new(Text::new("Left")))
+            .child(Box::new(Text::new("Right")))
+            .spacing(4.0);
+
+        let outer_vstack = VStack::dynamic()
+            .child(Box::new(Text::new("Header")))
+            .child(Box::new(inner_hstack))
+            .child(Box::new(Button::new("Footer Button").view()))
+            .spacing(12.0);
+
+        // Extract the nested structure
+        let extracted = MockBackend::extract(&outer_vstack, &ctx).unwrap();
+
+        // Verify the nested structure
+        assert_eq!(extracted.spacing, 12.0);
+        assert_eq!(extracted.content.len(), 3);
+
+        // Check header
+        assert!(
+            matches!(&extracted.content[0], MockDynamicChild::Text(text) if text.content == "Header")
+        );
+
+        // Check nested HStack
+        assert!(
+            matches!(&extracted.content[1], MockDynamicChild::HStack(hstack) if hstack.spacing == 4.0 && hstack.content.len() == 2)
+        );
+
+        if let MockDynamicChild::HStack(hstack) = &extracted.content[1] {
+            assert!(
+                matches!(&hstack.content[0], MockDynamicChild::Text(text) if text.content == "Left")
+            );
+            assert!(
+                matches!(&hstack.content[1], MockDynamicChild::Text(text) if text.content == "Right")
+            );
+        }
+
+        // Check footer button
+        assert!(
+            matches!(&extracted.content[2], MockDynamicChild::Button(button) if button.text == "Footer Button")
+        );
+    }
+}
+
+// End of File
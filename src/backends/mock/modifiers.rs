@@ -0,0 +1,413 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Mock extraction of views that wrap a single child
+
+use std::{collections::BTreeMap, fmt::Debug};
+
+use super::{MockBackend, MockDynamicChild, MockText};
+use crate::{
+    elements::{
+        Alignment2D, Badge, Border, BorderStyle, Card, EdgeInsets, Identified, MappedView, Opacity,
+        Padding, Shadow, Tagged, Text, Tooltip, TooltipPlacement,
+    },
+    extraction::{ExtractionResult, RenderContext, ViewExtractor},
+    message::Message,
+    style::{Color, SemanticColor},
+    view::View,
+};
+
+/// Mock representation of a Padding wrapper for testing and debugging
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MockPadding<T> {
+    /// The inset space around the content
+    pub insets: EdgeInsets,
+    /// The extracted padded content
+    pub content: T,
+}
+
+/// Statically typed Padding extraction
+impl<V> ViewExtractor<Padding<V>> for MockBackend
+where
+    V: View,
+    Self: ViewExtractor<V>,
+{
+    type Output = MockPadding<<Self as ViewExtractor<V>>::Output>;
+
+    fn extract(view: &Padding<V>, context: &RenderContext) -> ExtractionResult<Self::Output> {
+        Ok(MockPadding {
+            insets: view.insets,
+            content: Self::extract(&view.content, context)?,
+        })
+    }
+}
+
+/// Dynamically typed Padding extraction
+impl ViewExtractor<Padding<Box<dyn View>>> for MockBackend {
+    type Output = MockPadding<MockDynamicChild>;
+
+    fn extract(
+        view: &Padding<Box<dyn View>>,
+        context: &RenderContext,
+    ) -> ExtractionResult<Self::Output> {
+        let backend = MockBackend::new();
+        Ok(MockPadding {
+            insets: view.insets,
+            content: backend.extract_dynamic(view.content.as_ref(), context)?,
+        })
+    }
+}
+
+/// Mock representation of a Border wrapper for testing and debugging
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MockBorder<T> {
+    /// How the outline is drawn
+    pub style: BorderStyle,
+    /// The extracted outlined content
+    pub content: T,
+}
+
+/// Statically typed Border extraction
+impl<V> ViewExtractor<Border<V>> for MockBackend
+where
+    V: View,
+    Self: ViewExtractor<V>,
+{
+    type Output = MockBorder<<Self as ViewExtractor<V>>::Output>;
+
+    fn extract(view: &Border<V>, context: &RenderContext) -> ExtractionResult<Self::Output> {
+        Ok(MockBorder {
+            style: view.style,
+            content: Self::extract(&view.content, context)?,
+        })
+    }
+}
+
+/// Dynamically typed Border extraction
+impl ViewExtractor<Border<Box<dyn View>>> for MockBackend {
+    type Output = MockBorder<MockDynamicChild>;
+
+    fn extract(
+        view: &Border<Box<dyn View>>,
+        context: &RenderContext,
+    ) -> ExtractionResult<Self::Output> {
+        let backend = MockBackend::new();
+        Ok(MockBorder {
+            style: view.style,
+            content: backend.extract_dynamic(view.content.as_ref(), context)?,
+        })
+    }
+}
+
+/// Mock representation of an Opacity wrapper for testing and debugging
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MockOpacity<T> {
+    /// Alpha multiplier applied to the content
+    pub opacity: f32,
+    /// The extracted faded content
+    pub content: T,
+}
+
+/// Statically typed Opacity extraction
+impl<V> ViewExtractor<Opacity<V>> for MockBackend
+where
+    V: View,
+    Self: ViewExtractor<V>,
+{
+    type Output = MockOpacity<<Self as ViewExtractor<V>>::Output>;
+
+    fn extract(view: &Opacity<V>, context: &RenderContext) -> ExtractionResult<Self::Output> {
+        Ok(MockOpacity {
+            opacity: view.opacity,
+            content: Self::extract(&view.content, context)?,
+        })
+    }
+}
+
+/// Dynamically typed Opacity extraction
+impl ViewExtractor<Opacity<Box<dyn View>>> for MockBackend {
+    type Output = MockOpacity<MockDynamicChild>;
+
+    fn extract(
+        view: &Opacity<Box<dyn View>>,
+        context: &RenderContext,
+    ) -> ExtractionResult<Self::Output> {
+        let backend = MockBackend::new();
+        Ok(MockOpacity {
+            opacity: view.opacity,
+            content: backend.extract_dynamic(view.content.as_ref(), context)?,
+        })
+    }
+}
+
+/// Mock representation of a Badge overlay for testing and debugging
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MockBadge<T> {
+    /// Text shown inside the badge, or `None` for a plain dot
+    pub content: Option<MockText>,
+    /// Fill color of the badge
+    pub background: Color,
+    /// The corner of the child the badge is centered on
+    pub alignment: Alignment2D,
+    /// The extracted decorated view
+    pub child: T,
+}
+
+/// Statically typed Badge extraction
+impl<V> ViewExtractor<Badge<V>> for MockBackend
+where
+    V: View,
+    Self: ViewExtractor<V>,
+{
+    type Output = MockBadge<<Self as ViewExtractor<V>>::Output>;
+
+    fn extract(view: &Badge<V>, context: &RenderContext) -> ExtractionResult<Self::Output> {
+        Ok(MockBadge {
+            content: view
+                .content
+                .as_ref()
+                .map(|text| <Self as ViewExtractor<Text>>::extract(text, context))
+                .transpose()?,
+            background: view.background,
+            alignment: view.alignment,
+            child: Self::extract(&view.child, context)?,
+        })
+    }
+}
+
+/// Dynamically typed Badge extraction
+impl ViewExtractor<Badge<Box<dyn View>>> for MockBackend {
+    type Output = MockBadge<MockDynamicChild>;
+
+    fn extract(
+        view: &Badge<Box<dyn View>>,
+        context: &RenderContext,
+    ) -> ExtractionResult<Self::Output> {
+        let backend = MockBackend::new();
+        Ok(MockBadge {
+            content: view
+                .content
+                .as_ref()
+                .map(|text| <Self as ViewExtractor<Text>>::extract(text, context))
+                .transpose()?,
+            background: view.background,
+            alignment: view.alignment,
+            child: backend.extract_dynamic(view.child.as_ref(), context)?,
+        })
+    }
+}
+
+/// Mock representation of a Card container for testing and debugging
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MockCard<T> {
+    /// Fill color, resolved from the theme if unset
+    pub background: Color,
+    /// Radius of the rounded corners
+    pub corner_radius: f32,
+    /// Space between the card's edge and its content
+    pub insets: EdgeInsets,
+    /// Shadow drawn underneath the card, if any
+    pub shadow: Option<Shadow>,
+    /// The extracted content
+    pub content: T,
+}
+
+/// Statically typed Card extraction
+impl<V> ViewExtractor<Card<V>> for MockBackend
+where
+    V: View,
+    Self: ViewExtractor<V>,
+{
+    type Output = MockCard<<Self as ViewExtractor<V>>::Output>;
+
+    fn extract(view: &Card<V>, context: &RenderContext) -> ExtractionResult<Self::Output> {
+        Ok(MockCard {
+            background: card_background(view, context),
+            corner_radius: view.corner_radius,
+            insets: view.insets,
+            shadow: view.shadow,
+            content: Self::extract(&view.content, context)?,
+        })
+    }
+}
+
+/// Dynamically typed Card extraction
+impl ViewExtractor<Card<Box<dyn View>>> for MockBackend {
+    type Output = MockCard<MockDynamicChild>;
+
+    fn extract(
+        view: &Card<Box<dyn View>>,
+        context: &RenderContext,
+    ) -> ExtractionResult<Self::Output> {
+        let backend = MockBackend::new();
+        Ok(MockCard {
+            background: card_background(view, context),
+            corner_radius: view.corner_radius,
+            insets: view.insets,
+            shadow: view.shadow,
+            content: backend.extract_dynamic(view.content.as_ref(), context)?,
+        })
+    }
+}
+
+/// Resolve a card's fill, falling back to the theme's background color.
+fn card_background<V>(view: &Card<V>, context: &RenderContext) -> Color {
+    view.background
+        .unwrap_or_else(|| context.resolve_color(SemanticColor::Background))
+}
+
+/// Mock representation of a Tooltip wrapper for testing and debugging
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MockTooltip<T> {
+    /// The extracted help text
+    pub text: MockText,
+    /// The side of the child the text appears on
+    pub placement: TooltipPlacement,
+    /// The extracted view the help text describes
+    pub child: T,
+}
+
+/// Statically typed Tooltip extraction
+impl<V> ViewExtractor<Tooltip<V>> for MockBackend
+where
+    V: View,
+    Self: ViewExtractor<V>,
+{
+    type Output = MockTooltip<<Self as ViewExtractor<V>>::Output>;
+
+    fn extract(view: &Tooltip<V>, context: &RenderContext) -> ExtractionResult<Self::Output> {
+        Ok(MockTooltip {
+            text: <Self as ViewExtractor<Text>>::extract(&view.text, context)?,
+            placement: view.placement,
+            child: Self::extract(&view.child, context)?,
+        })
+    }
+}
+
+/// Dynamically typed Tooltip extraction
+impl ViewExtractor<Tooltip<Box<dyn View>>> for MockBackend {
+    type Output = MockTooltip<MockDynamicChild>;
+
+    fn extract(
+        view: &Tooltip<Box<dyn View>>,
+        context: &RenderContext,
+    ) -> ExtractionResult<Self::Output> {
+        let backend = MockBackend::new();
+        Ok(MockTooltip {
+            text: Self::extract(&view.text, context)?,
+            placement: view.placement,
+            child: backend.extract_dynamic(view.child.as_ref(), context)?,
+        })
+    }
+}
+
+/// Message mapping is invisible to rendering, so mapped views extract as their content
+impl<V, M, P> ViewExtractor<MappedView<V, M, P>> for MockBackend
+where
+    V: View,
+    M: Message,
+    P: Message,
+    Self: ViewExtractor<V>,
+{
+    type Output = <Self as ViewExtractor<V>>::Output;
+
+    fn extract(
+        view: &MappedView<V, M, P>,
+        context: &RenderContext,
+    ) -> ExtractionResult<Self::Output> {
+        Self::extract(&view.content, context)
+    }
+}
+
+/// Mock representation of an identified view for testing and debugging
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MockIdentified<T> {
+    /// The identifier of the view
+    pub id: String,
+    /// The extracted content
+    pub content: T,
+}
+
+/// Statically typed identified view extraction
+impl<V> ViewExtractor<Identified<V>> for MockBackend
+where
+    V: View,
+    Self: ViewExtractor<V>,
+{
+    type Output = MockIdentified<<Self as ViewExtractor<V>>::Output>;
+
+    fn extract(view: &Identified<V>, context: &RenderContext) -> ExtractionResult<Self::Output> {
+        Ok(MockIdentified {
+            id: view.id.clone(),
+            content: Self::extract(&view.content, context)?,
+        })
+    }
+}
+
+/// Dynamically typed identified view extraction
+impl ViewExtractor<Identified<Box<dyn View>>> for MockBackend {
+    type Output = MockIdentified<MockDynamicChild>;
+
+    fn extract(
+        view: &Identified<Box<dyn View>>,
+        context: &RenderContext,
+    ) -> ExtractionResult<Self::Output> {
+        let backend = MockBackend::new();
+        Ok(MockIdentified {
+            id: view.id.clone(),
+            content: backend.extract_dynamic(view.content.as_ref(), context)?,
+        })
+    }
+}
+
+/// Mock representation of a Tagged view for testing and debugging
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MockTagged<T> {
+    /// The metadata attached to the view, ordered by key
+    pub tags: BTreeMap<String, String>,
+    /// The extracted tagged content
+    pub content: T,
+}
+
+/// Statically typed Tagged extraction
+impl<V> ViewExtractor<Tagged<V>> for MockBackend
+where
+    V: View,
+    Self: ViewExtractor<V>,
+{
+    type Output = MockTagged<<Self as ViewExtractor<V>>::Output>;
+
+    fn extract(view: &Tagged<V>, context: &RenderContext) -> ExtractionResult<Self::Output> {
+        Ok(MockTagged {
+            tags: view.tags.clone(),
+            content: Self::extract(&view.content, context)?,
+        })
+    }
+}
+
+/// Dynamically typed Tagged extraction
+impl ViewExtractor<Tagged<Box<dyn View>>> for MockBackend {
+    type Output = MockTagged<MockDynamicChild>;
+
+    fn extract(
+        view: &Tagged<Box<dyn View>>,
+        context: &RenderContext,
+    ) -> ExtractionResult<Self::Output> {
+        let backend = MockBackend::new();
+        Ok(MockTagged {
+            tags: view.tags.clone(),
+            content: backend.extract_dynamic(view.content.as_ref(), context)?,
+        })
+    }
+}
+
+// End of File
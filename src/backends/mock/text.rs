@@ -0,0 +1,425 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Mock extraction of text and other content elements
+
+use std::fmt::Debug;
+
+use super::MockBackend;
+use crate::{
+    elements::{
+        ContentMode, Gauge, GaugeStyle, Image, ImageSource, Label, RichText, Sparkline, Text,
+        TextSpan,
+    },
+    extraction::{ExtractionResult, RenderContext, ViewExtractor},
+    style::{Color, FontFamily, FontStyle, FontWeight, TextAlignment, TextStyle, TruncationMode},
+};
+
+/// Mock representation of extracted text for testing.
+///
+/// This captures all the essential information from a Text view in a format
+/// that's easy to test against. The mock backend uses this to verify that
+/// text views are being extracted correctly.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MockText {
+    /// The text content
+    pub content: String,
+    /// Font size in logical pixels
+    pub font_size: f32,
+    /// Text color
+    pub color: Color,
+    /// Font weight
+    pub font_weight: FontWeight,
+    /// Upright or italic
+    pub font_style: FontStyle,
+    /// Typeface family, or `None` for the default font
+    pub font_family: Option<FontFamily>,
+    /// Horizontal alignment of lines
+    pub alignment: TextAlignment,
+    /// Line height as a multiple of the normal line height
+    pub line_spacing: f32,
+    /// Maximum number of lines to show, or `None` for no limit
+    pub line_limit: Option<usize>,
+    /// Where the text is cut off when it exceeds the line limit
+    pub truncation: TruncationMode,
+    /// The style to draw with after merging in the render context
+    ///
+    /// See [`RenderContext::effective_text_style`] for how the text's own
+    /// style combines with inherited defaults, the theme and the text scale.
+    pub effective_style: TextStyle,
+}
+
+impl MockText {
+    /// Rebuild the text's own style from the extracted properties.
+    pub(super) fn own_style(&self) -> TextStyle {
+        let style = TextStyle::new()
+            .font_size(self.font_size)
+            .color(self.color)
+            .font_weight(self.font_weight)
+            .font_style(self.font_style)
+            .alignment(self.alignment)
+            .line_spacing(self.line_spacing)
+            .truncation(self.truncation);
+        let style = match &self.font_family {
+            Some(family) => style.font_family(*family),
+            None => style,
+        };
+        match self.line_limit {
+            Some(lines) => style.line_limit(lines),
+            None => style,
+        }
+    }
+}
+
+impl ViewExtractor<Text> for MockBackend {
+    type Output = MockText;
+
+    fn extract(view: &Text, ctx: &RenderContext) -> ExtractionResult<Self::Output> {
+        // Extract all the essential data from the Text view
+        // This demonstrates how backends can access view properties
+        Ok(MockText {
+            content: view.content.to_string(),
+            font_size: ctx.scaled_font_size(view.style.font_size),
+            color: view.style.color,
+            font_weight: view.style.font_weight,
+            font_style: view.style.font_style,
+            font_family: view.style.font_family,
+            alignment: view.style.alignment,
+            line_spacing: view.style.line_spacing,
+            line_limit: view.style.line_limit,
+            truncation: view.style.truncation,
+            effective_style: ctx.effective_text_style(&view.style),
+        })
+    }
+}
+
+/// Mock representation of extracted rich text for testing.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MockRichText {
+    /// The spans, in reading order
+    pub spans: Vec<TextSpan>,
+    /// Font size in logical pixels, shared by every span
+    pub font_size: f32,
+    /// Text color, shared by every span
+    pub color: Color,
+}
+
+impl ViewExtractor<RichText> for MockBackend {
+    type Output = MockRichText;
+
+    fn extract(view: &RichText, ctx: &RenderContext) -> ExtractionResult<Self::Output> {
+        Ok(MockRichText {
+            spans: view.spans.clone(),
+            font_size: ctx.scaled_font_size(view.style.font_size),
+            color: view.style.color,
+        })
+    }
+}
+
+/// Mock representation of extracted text that borrows its content.
+///
+/// Unlike [`MockText`], this refers to the text buffer owned by the view,
+/// so extracting large text content doesn't copy it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MockTextRef<'a> {
+    /// The text content, borrowed from the view
+    pub content: &'a str,
+    /// Font size in logical pixels
+    pub font_size: f32,
+    /// Text color
+    pub color: Color,
+}
+
+impl MockBackend {
+    /// Extract a text view without copying its content.
+    ///
+    /// The owned [`ViewExtractor`] API is unchanged; this is an alternative for
+    /// callers that only need the extracted data while the view is alive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::{prelude::*, backends::mock::MockBackend};
+    ///
+    /// let text = Text::new("A long document body");
+    /// let extracted = MockBackend::extract_borrowed(&text, &RenderContext::new()).unwrap();
+    /// assert_eq!(extracted.content, "A long document body");
+    /// ```
+    pub fn extract_borrowed<'a>(
+        view: &'a Text,
+        ctx: &RenderContext,
+    ) -> ExtractionResult<MockTextRef<'a>> {
+        Ok(MockTextRef {
+            content: view.content.as_str(),
+            font_size: ctx.scaled_font_size(view.style.font_size),
+            color: view.style.color,
+        })
+    }
+}
+
+/// Mock representation of extracted image for testing.
+///
+/// The image is never loaded or decoded; only its descriptor is captured.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MockImage {
+    /// Where the image data comes from
+    pub source: ImageSource,
+    /// Explicit width and height, if any
+    pub size: Option<(f32, f32)>,
+    /// How the image is scaled to fit its frame
+    pub content_mode: ContentMode,
+}
+
+impl ViewExtractor<Image> for MockBackend {
+    type Output = MockImage;
+
+    fn extract(view: &Image, _ctx: &RenderContext) -> ExtractionResult<Self::Output> {
+        Ok(MockImage {
+            source: view.source.clone(),
+            size: view.size,
+            content_mode: view.content_mode,
+        })
+    }
+}
+
+/// Mock representation of extracted gauge for testing.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MockGauge {
+    /// How full the gauge is, from 0.0 to 1.0
+    pub fraction: f32,
+    /// Fill color for the zone the value is in
+    pub zone_color: Color,
+    /// Whether the gauge is drawn as a bar or an arc
+    pub style: GaugeStyle,
+}
+
+impl ViewExtractor<Gauge> for MockBackend {
+    type Output = MockGauge;
+
+    fn extract(view: &Gauge, _ctx: &RenderContext) -> ExtractionResult<Self::Output> {
+        Ok(MockGauge {
+            fraction: view.fraction(),
+            zone_color: view.zone_color(),
+            style: view.style,
+        })
+    }
+}
+
+/// Mock representation of extracted sparkline for testing.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MockSparkline {
+    /// The data series scaled to `[0.0, 1.0]`
+    pub normalized_points: Vec<f32>,
+    /// Color of the line
+    pub color: Color,
+}
+
+impl ViewExtractor<Sparkline> for MockBackend {
+    type Output = MockSparkline;
+
+    fn extract(view: &Sparkline, _ctx: &RenderContext) -> ExtractionResult<Self::Output> {
+        Ok(MockSparkline {
+            normalized_points: view.normalized_points(),
+            color: view.color,
+        })
+    }
+}
+
+/// Mock representation of extracted label for testing.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MockLabel {
+    /// The leading glyph, if any
+    pub icon: Option<MockText>,
+    /// The title text
+    pub title: MockText,
+    /// Space between the icon and the title
+    pub spacing: f32,
+}
+
+impl ViewExtractor<Label> for MockBackend {
+    type Output = MockLabel;
+
+    fn extract(view: &Label, ctx: &RenderContext) -> ExtractionResult<Self::Output> {
+        Ok(MockLabel {
+            icon: view
+                .icon
+                .as_ref()
+                .map(|icon| Self::extract(icon, ctx))
+                .transpose()?,
+            title: Self::extract(&view.title, ctx)?,
+            spacing: view.spacing,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        backends::mock::MockDynamicChild,
+        elements::{Text, VStack},
+        model::Model,
+        widgets::{Button, Checkbox, Picker, Slider},
+    };
+
+    #[test]
+    fn text_extraction() {
+        // Test extracting a basic text view
+        let text = Text::new("Hello, world!");
+        let ctx = RenderContext::new();
+
+        let extracted = MockBackend::extract(&text, &ctx).unwrap();
+
+        assert_eq!(extracted.content, "Hello, world!");
+        assert_eq!(extracted.font_size, 16.0);
+        assert_eq!(extracted.color, Color::BLACK);
+    }
+
+    #[test]
+    fn styled_text_extraction() {
+        // Test extracting a styled text view
+        let text = Text::new("Styled text").font_size(24.0).color(Color::RED);
+        let ctx = RenderContext::new();
+
+        let extracted = MockBackend::extract(&text, &ctx).unwrap();
+
+        assert_eq!(extracted.content, "Styled text");
+        assert_eq!(extracted.font_size, 24.0);
+        assert_eq!(extracted.color, Color::RED);
+        assert_eq!(extracted.font_weight, FontWeight::REGULAR);
+        assert_eq!(extracted.font_style, FontStyle::Normal);
+
+        let emphasized = Text::new("Heading").font_weight(FontWeight::BOLD).italic();
+        let extracted = MockBackend::extract(&emphasized, &ctx).unwrap();
+        assert_eq!(extracted.font_weight, FontWeight::BOLD);
+        assert_eq!(extracted.font_style, FontStyle::Italic);
+        assert_eq!(extracted.font_family, None);
+
+        let code = Text::new("fn main()").font_family(FontFamily::Monospace);
+        let extracted = MockBackend::extract(&code, &ctx).unwrap();
+        assert_eq!(extracted.font_family, Some(FontFamily::Monospace));
+        assert_eq!(extracted.alignment, TextAlignment::Leading);
+        assert_eq!(extracted.line_spacing, 1.0);
+
+        let paragraph = Text::new("One\nTwo")
+            .alignment(TextAlignment::Justified)
+            .line_spacing(1.5);
+        let extracted = MockBackend::extract(&paragraph, &ctx).unwrap();
+        assert_eq!(extracted.alignment, TextAlignment::Justified);
+        assert_eq!(extracted.line_spacing, 1.5);
+        assert_eq!(extracted.line_limit, None);
+
+        let label = Text::new("A long label")
+            .line_limit(1)
+            .truncation(TruncationMode::Middle);
+        let extracted = MockBackend::extract(&label, &ctx).unwrap();
+        assert_eq!(extracted.line_limit, Some(1));
+        assert_eq!(extracted.truncation, TruncationMode::Middle);
+    }
+
+    #[test]
+    fn text_scale_multiplies_font_sizes() {
+        let text = Text::new("Body");
+        let button = Button::new("Export Current Selection").max_label_width(80.0);
+
+        let ctx = RenderContext::new();
+        assert_eq!(MockBackend::extract(&text, &ctx).unwrap().font_size, 16.0);
+
+        let ctx = RenderContext::new().with_text_scale(1.5);
+        assert_eq!(MockBackend::extract(&text, &ctx).unwrap().font_size, 24.0);
+        let heading = Text::new("Title").font_size(32.0);
+        assert_eq!(
+            MockBackend::extract(&heading, &ctx).unwrap().font_size,
+            48.0
+        );
+
+        // Larger glyphs fit fewer characters in the same label width
+        let extracted = MockBackend::extract(&button.view(), &ctx).unwrap();
+        assert_eq!(extracted.text_style.font_size, 24.0);
+        assert_eq!(extracted.text, "Expor…");
+
+        // Widget labels are scaled the same way as plain text
+        let checkbox = Checkbox::new("Snap to grid").view();
+        let extracted = MockBackend::extract(&checkbox, &ctx).unwrap();
+        assert_eq!(extracted.label_style.font_size, 24.0);
+        let picker = Picker::new(["Low", "High"]).view();
+        let extracted = MockBackend::extract(&picker, &ctx).unwrap();
+        assert_eq!(extracted.label_style.font_size, 24.0);
+        let slider = Slider::new(0.0..=1.0).view();
+        let extracted = MockBackend::extract(&slider, &ctx).unwrap();
+        assert_eq!(extracted.label_style.font_size, 24.0);
+    }
+
+    #[test]
+    fn text_extracts_effective_style_from_context() {
+        use crate::style::{Theme, ThemeColors};
+
+        let dark = Theme::new(ThemeColors {
+            background: Color::BLACK,
+            foreground: Color::WHITE,
+            ..ThemeColors::LIGHT
+        });
+        let ctx = RenderContext::new()
+            .with_text_defaults(
+                TextStyle::new()
+                    .font_size(20.0)
+                    .font_weight(FontWeight::BOLD),
+            )
+            .with_text_scale(1.5)
+            .with_theme(dark);
+
+        let list = VStack::dynamic()
+            .child(Box::new(Text::new("Plain")))
+            .child(Box::new(Text::new("Own").font_size(12.0).color(Color::RED)))
+            .child(Box::new(
+                Text::new("Black").font_size(16.0).color(Color::BLACK),
+            ));
+        let extracted = MockBackend::extract(&list, &ctx).unwrap();
+        let styles: Vec<&TextStyle> = extracted
+            .content
+            .iter()
+            .map(|child| match child {
+                MockDynamicChild::Text(text) => &text.effective_style,
+                other => panic!("expected text, got {:?}", other),
+            })
+            .collect();
+
+        // Plain text inherits the defaults and the theme, then gets scaled
+        assert_eq!(styles[0].font_size, 30.0);
+        assert_eq!(styles[0].font_weight, FontWeight::BOLD);
+        assert_eq!(styles[0].color, Color::WHITE);
+
+        // Properties the text set itself win over inherited ones
+        assert_eq!(styles[1].font_size, 18.0);
+        assert_eq!(styles[1].color, Color::RED);
+        assert_eq!(styles[1].font_weight, FontWeight::BOLD);
+
+        // Explicit values win even when they equal the defaults
+        assert_eq!(styles[2].font_size, 24.0);
+        assert_eq!(styles[2].color, Color::BLACK);
+
+        // Without context settings the effective style is the text's own
+        let plain = MockBackend::extract(&Text::new("a"), &RenderContext::new()).unwrap();
+        assert_eq!(plain.effective_style, TextStyle::default());
+    }
+
+    #[test]
+    fn borrowed_text_extraction_does_not_copy() {
+        let ctx = RenderContext::new();
+        let text = Text::new("x".repeat(64 * 1024)).font_size(12.0);
+
+        let extracted = MockBackend::extract_borrowed(&text, &ctx).unwrap();
+        assert!(std::ptr::eq(extracted.content, text.content.as_str()));
+        assert_eq!(extracted.font_size, 12.0);
+        assert_eq!(extracted.color, Color::BLACK);
+    }
+}
+
+// End of File
@@ -0,0 +1,694 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Mock extraction of widget views
+
+use std::fmt::Debug;
+
+use super::MockBackend;
+use crate::{
+    elements::{Alignment, BorderStyle, Spacer, Spinner, SpinnerStyle},
+    extraction::{AccentColor, ExtractionResult, RenderContext, ViewExtractor},
+    interaction::InteractionState,
+    style::{Color, PressFeedback, SemanticColor, TextStyle},
+    widgets::{
+        ActivityIndicatorView, Button, ButtonView, CellEdit, CheckState, CheckboxView, PickerView,
+        SliderView, SortState, StepperView, TableView, ToggleView,
+    },
+};
+
+/// Mock representation of extracted button for testing.
+///
+/// This captures the information from a Button component that's relevant for
+/// display and rendering, including visual states like pressed/focused that
+/// affect how the button should appear on screen.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MockButton {
+    /// The laid-out button text, truncated if it exceeds the maximum label width
+    pub text: String,
+    /// Background color
+    pub background_color: Color,
+    /// Text styling properties
+    pub text_style: TextStyle,
+    /// The interaction state of the button
+    pub interaction_state: InteractionState,
+    /// Opacity to draw at, dimmed while disabled
+    pub opacity: f32,
+    /// Keyboard focus outline to draw around the button, if any
+    pub focus_ring: Option<BorderStyle>,
+    /// Push effect to draw while the button is pressed, if any
+    pub press_feedback: Option<PressFeedback>,
+}
+
+impl MockBackend {
+    /// Approximate glyph advance used for mock layout, as a fraction of the font size.
+    ///
+    /// The mock backend has no font metrics, so it lays text out as if every
+    /// character were half an em wide. This keeps truncation deterministic.
+    pub const GLYPH_ADVANCE: f32 = 0.5;
+
+    /// Lay out a button label at `font_size`, truncating it to the button's maximum label width.
+    fn layout_button_label(view: &ButtonView, font_size: f32) -> String {
+        let Some(max_width) = view.max_label_width else {
+            return view.text.content.to_string();
+        };
+
+        // Never shrink the label area below the minimum hit target
+        let width = max_width.max(Button::MIN_HIT_TARGET);
+        let glyph_width = font_size * Self::GLYPH_ADVANCE;
+        let max_chars = if glyph_width > 0.0 {
+            (width / glyph_width).floor() as usize
+        } else {
+            usize::MAX
+        };
+
+        view.text.content.truncated(max_chars).to_string()
+    }
+}
+
+impl ViewExtractor<ButtonView> for MockBackend {
+    type Output = MockButton;
+
+    fn extract(view: &ButtonView, ctx: &RenderContext) -> ExtractionResult<Self::Output> {
+        let (background_color, text_color) = if view.themed {
+            (
+                ctx.resolve_color(SemanticColor::Accent),
+                ctx.resolve_color(SemanticColor::OnAccent),
+            )
+        } else {
+            let background_color = view
+                .background_color
+                .or_else(|| ctx.get::<AccentColor>())
+                .unwrap_or(Button::DEFAULT_BACKGROUND);
+            (background_color, view.text.style.color)
+        };
+        let mut text_style = view.text.style;
+        text_style.font_size = ctx.scaled_font_size(text_style.font_size);
+        text_style.color = text_color;
+
+        // Extract button component display information for testing
+        Ok(MockButton {
+            text: Self::layout_button_label(view, text_style.font_size),
+            background_color,
+            text_style,
+            interaction_state: view.interaction_state,
+            opacity: view
+                .disabled_style
+                .effective_opacity(view.interaction_state),
+            focus_ring: view.focus_ring,
+            press_feedback: view.press_feedback,
+        })
+    }
+}
+
+/// Mock representation of extracted checkbox for testing.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MockCheckbox {
+    /// The current check state
+    pub state: CheckState,
+    /// The label text
+    pub label: String,
+    /// Label styling properties
+    pub label_style: TextStyle,
+    /// The interaction state of the checkbox
+    pub interaction_state: InteractionState,
+    /// Opacity to draw at, dimmed while disabled
+    pub opacity: f32,
+}
+
+impl ViewExtractor<CheckboxView> for MockBackend {
+    type Output = MockCheckbox;
+
+    fn extract(view: &CheckboxView, ctx: &RenderContext) -> ExtractionResult<Self::Output> {
+        Ok(MockCheckbox {
+            state: view.state,
+            label: view.label.content.to_string(),
+            label_style: ctx.effective_text_style(&view.label.style),
+            interaction_state: view.interaction_state,
+            opacity: view
+                .disabled_style
+                .effective_opacity(view.interaction_state),
+        })
+    }
+}
+
+/// Mock representation of extracted picker for testing.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MockPicker {
+    /// Label of the selected option, if any
+    pub selected_label: Option<String>,
+    /// Every option, in display order
+    pub options: Vec<String>,
+    /// Whether the dropdown list is open
+    pub is_open: bool,
+    /// Style the option labels are drawn with, resolved from the context
+    pub label_style: TextStyle,
+    /// The interaction state of the picker
+    pub interaction_state: InteractionState,
+    /// Opacity to draw at, dimmed while disabled
+    pub opacity: f32,
+}
+
+impl ViewExtractor<PickerView> for MockBackend {
+    type Output = MockPicker;
+
+    fn extract(view: &PickerView, ctx: &RenderContext) -> ExtractionResult<Self::Output> {
+        Ok(MockPicker {
+            selected_label: view.selected_label.clone(),
+            options: view.options.clone(),
+            is_open: view.is_open,
+            label_style: ctx.effective_text_style(&TextStyle::default()),
+            interaction_state: view.interaction_state,
+            opacity: view
+                .disabled_style
+                .effective_opacity(view.interaction_state),
+        })
+    }
+}
+
+/// Mock representation of extracted slider for testing.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MockSlider {
+    /// The current value
+    pub value: f32,
+    /// The lower bound of the range
+    pub min: f32,
+    /// The upper bound of the range
+    pub max: f32,
+    /// Style the value label is drawn with, resolved from the context
+    pub label_style: TextStyle,
+    /// The interaction state of the slider thumb
+    pub interaction_state: InteractionState,
+    /// Opacity to draw at, dimmed while disabled
+    pub opacity: f32,
+}
+
+impl ViewExtractor<SliderView> for MockBackend {
+    type Output = MockSlider;
+
+    fn extract(view: &SliderView, ctx: &RenderContext) -> ExtractionResult<Self::Output> {
+        Ok(MockSlider {
+            value: view.value,
+            min: view.min,
+            max: view.max,
+            label_style: ctx.effective_text_style(&TextStyle::default()),
+            interaction_state: view.interaction_state,
+            opacity: view
+                .disabled_style
+                .effective_opacity(view.interaction_state),
+        })
+    }
+}
+
+/// Mock representation of extracted spinner for testing.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MockSpinner {
+    /// The shape to draw
+    pub style: SpinnerStyle,
+    /// Width and height of the indicator in logical pixels
+    pub size: f32,
+    /// Color of the indicator, resolved from the text color if unset
+    pub color: Color,
+}
+
+impl ViewExtractor<Spinner> for MockBackend {
+    type Output = MockSpinner;
+
+    fn extract(view: &Spinner, ctx: &RenderContext) -> ExtractionResult<Self::Output> {
+        let color = view
+            .color
+            .unwrap_or_else(|| ctx.effective_text_style(&TextStyle::default()).color);
+        Ok(MockSpinner {
+            style: view.style,
+            size: view.size,
+            color,
+        })
+    }
+}
+
+/// Mock representation of extracted activity indicator for testing.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MockActivityIndicator {
+    /// The extracted spinner
+    pub spinner: MockSpinner,
+    /// The highlighted segment
+    pub phase: usize,
+    /// Number of segments in one full turn
+    pub segments: usize,
+}
+
+impl ViewExtractor<ActivityIndicatorView> for MockBackend {
+    type Output = MockActivityIndicator;
+
+    fn extract(
+        view: &ActivityIndicatorView,
+        ctx: &RenderContext,
+    ) -> ExtractionResult<Self::Output> {
+        Ok(MockActivityIndicator {
+            spinner: MockBackend::extract(&view.spinner, ctx)?,
+            phase: view.phase,
+            segments: view.segments,
+        })
+    }
+}
+
+/// Mock representation of extracted stepper for testing.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MockStepper {
+    /// The current value
+    pub value: i32,
+    /// The lower bound of the range
+    pub min: i32,
+    /// The upper bound of the range
+    pub max: i32,
+    /// Whether the increment control can be used
+    pub increment_enabled: bool,
+    /// Whether the decrement control can be used
+    pub decrement_enabled: bool,
+    /// The interaction state of the stepper
+    pub interaction_state: InteractionState,
+    /// Opacity to draw at, dimmed while disabled
+    pub opacity: f32,
+}
+
+impl ViewExtractor<StepperView> for MockBackend {
+    type Output = MockStepper;
+
+    fn extract(view: &StepperView, _ctx: &RenderContext) -> ExtractionResult<Self::Output> {
+        Ok(MockStepper {
+            value: view.value,
+            min: view.min,
+            max: view.max,
+            increment_enabled: view.increment_enabled,
+            decrement_enabled: view.decrement_enabled,
+            interaction_state: view.interaction_state,
+            opacity: view
+                .disabled_style
+                .effective_opacity(view.interaction_state),
+        })
+    }
+}
+
+/// Mock representation of extracted table for testing.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MockTable {
+    /// Header text of each column
+    pub headers: Vec<String>,
+    /// Horizontal alignment of each column
+    pub alignments: Vec<Alignment>,
+    /// Cell text of each row, in display order
+    pub rows: Vec<Vec<String>>,
+    /// Display position of the selected row, if any
+    pub selected: Option<usize>,
+    /// Column of the selected cell within the selected row, if any
+    pub selected_column: Option<usize>,
+    /// Whether cells in each column can be edited
+    pub editable: Vec<bool>,
+    /// The cell being edited, if any, with its row as a display position
+    pub editing: Option<CellEdit>,
+    /// The current sort, if any
+    pub sort: Option<SortState>,
+    /// The interaction state of the table
+    pub interaction_state: InteractionState,
+    /// Opacity to draw at, dimmed while disabled
+    pub opacity: f32,
+}
+
+impl ViewExtractor<TableView> for MockBackend {
+    type Output = MockTable;
+
+    fn extract(view: &TableView, _ctx: &RenderContext) -> ExtractionResult<Self::Output> {
+        Ok(MockTable {
+            headers: view.headers.clone(),
+            alignments: view.alignments.clone(),
+            rows: view.rows.clone(),
+            selected: view.selected,
+            selected_column: view.selected_column,
+            editable: view.editable.clone(),
+            editing: view.editing.clone(),
+            sort: view.sort,
+            interaction_state: view.interaction_state,
+            opacity: view
+                .disabled_style
+                .effective_opacity(view.interaction_state),
+        })
+    }
+}
+
+/// Mock representation of extracted toggle for testing.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MockToggle {
+    /// Whether the toggle is switched on
+    pub is_on: bool,
+    /// Track color while switched on
+    pub on_color: Color,
+    /// Track color while switched off
+    pub off_color: Color,
+    /// The interaction state of the toggle
+    pub interaction_state: InteractionState,
+    /// Opacity to draw at, dimmed while disabled
+    pub opacity: f32,
+}
+
+impl ViewExtractor<ToggleView> for MockBackend {
+    type Output = MockToggle;
+
+    fn extract(view: &ToggleView, _ctx: &RenderContext) -> ExtractionResult<Self::Output> {
+        Ok(MockToggle {
+            is_on: view.is_on,
+            on_color: view.on_color,
+            off_color: view.off_color,
+            interaction_state: view.interaction_state,
+            opacity: view
+                .disabled_style
+                .effective_opacity(view.interaction_state),
+        })
+    }
+}
+
+/// Mock representation of extracted spacer for testing.
+///
+/// This captures the spacer properties that affect layout calculations.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MockSpacer {
+    /// Minimum size for the spacer in logical pixels
+    pub min_size: f32,
+    /// Largest size the spacer may grow to, if capped
+    pub max_size: Option<f32>,
+    /// Priority when competing with other spacers for leftover space
+    pub layout_priority: f32,
+}
+
+impl ViewExtractor<Spacer> for MockBackend {
+    type Output = MockSpacer;
+
+    fn extract(view: &Spacer, _ctx: &RenderContext) -> ExtractionResult<Self::Output> {
+        Ok(MockSpacer {
+            min_size: view.min_size,
+            max_size: view.max_size,
+            layout_priority: view.layout_priority,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        elements::Text,
+        interaction::{Enableable, Focusable, Hoverable, InteractionMessage, Pressable},
+        model::Model,
+        style::ThemeColors,
+        widgets::ButtonMessage,
+    };
+
+    #[test]
+    fn button_extraction_basic() {
+        // Test extracting a basic button component
+        let button = Button::new("Click me");
+        let ctx = RenderContext::new();
+
+        let extracted = MockBackend::extract(&button.view(), &ctx).unwrap();
+
+        assert_eq!(extracted.text, "Click me");
+        assert_eq!(extracted.background_color, Color::rgb(0.9, 0.9, 0.9));
+        assert_eq!(extracted.text_style.color, Color::BLACK);
+        assert!(extracted.interaction_state.is_enabled());
+        assert!(!extracted.interaction_state.is_pressed());
+        assert!(!extracted.interaction_state.is_focused());
+        assert!(!extracted.interaction_state.is_hovered());
+    }
+
+    #[test]
+    fn button_extraction_disabled() {
+        // Test extracting a disabled button
+        let button = Button::new("Disabled").disable();
+        let ctx = RenderContext::new();
+
+        let extracted = MockBackend::extract(&button.view(), &ctx).unwrap();
+
+        assert_eq!(extracted.text, "Disabled");
+        assert!(!extracted.interaction_state.is_enabled());
+        assert!(!extracted.interaction_state.is_pressed());
+        assert!(!extracted.interaction_state.is_focused());
+        assert!(!extracted.interaction_state.is_hovered());
+    }
+
+    #[test]
+    fn button_extraction_visual_states() {
+        // Test extracting button visual states that affect rendering
+        let ctx = RenderContext::new();
+
+        // Test clicked button (no state change)
+        let clicked_button = Button::new("Clicked").update(ButtonMessage::Clicked);
+        let clicked_extracted = MockBackend::extract(&clicked_button.view(), &ctx).unwrap();
+        assert_eq!(clicked_extracted.text, "Clicked");
+        assert!(clicked_extracted.interaction_state.is_enabled());
+        assert!(!clicked_extracted.interaction_state.is_pressed());
+        assert!(!clicked_extracted.interaction_state.is_focused());
+        assert!(!clicked_extracted.interaction_state.is_hovered());
+
+        // Test focused button
+        let focused_button = Button::new("Focused").update(ButtonMessage::Interaction(
+            InteractionMessage::FocusChanged(true),
+        ));
+        let focused_extracted = MockBackend::extract(&focused_button.view(), &ctx).unwrap();
+        assert_eq!(focused_extracted.text, "Focused");
+        assert!(focused_extracted.interaction_state.is_enabled());
+        assert!(!focused_extracted.interaction_state.is_pressed());
+        assert!(focused_extracted.interaction_state.is_focused());
+        assert!(!focused_extracted.interaction_state.is_hovered());
+
+        // Test pressed button (via press state change)
+        let pressed_button = Button::new("Pressed").update(ButtonMessage::Interaction(
+            InteractionMessage::PressStateChanged(true),
+        ));
+        let pressed_extracted = MockBackend::extract(&pressed_button.view(), &ctx).unwrap();
+        assert_eq!(pressed_extracted.text, "Pressed");
+        assert!(pressed_extracted.interaction_state.is_enabled());
+        assert!(pressed_extracted.interaction_state.is_pressed());
+        assert!(!pressed_extracted.interaction_state.is_focused());
+        assert!(!pressed_extracted.interaction_state.is_hovered());
+
+        // Test hovered button
+        let hovered_button = Button::new("Hovered").update(ButtonMessage::Interaction(
+            InteractionMessage::HoverChanged(true),
+        ));
+        let hovered_extracted = MockBackend::extract(&hovered_button.view(), &ctx).unwrap();
+        assert_eq!(hovered_extracted.text, "Hovered");
+        assert!(hovered_extracted.interaction_state.is_enabled());
+        assert!(!hovered_extracted.interaction_state.is_pressed());
+        assert!(!hovered_extracted.interaction_state.is_focused());
+        assert!(hovered_extracted.interaction_state.is_hovered());
+    }
+
+    #[test]
+    fn styled_button_extraction() {
+        // Test extracting a styled button
+        let button = Button::new("Styled")
+            .background_color(Color::BLUE)
+            .with_text(|text| text.color(Color::WHITE))
+            .enable();
+        let ctx = RenderContext::new();
+
+        let extracted = MockBackend::extract(&button.view(), &ctx).unwrap();
+
+        assert_eq!(extracted.text, "Styled");
+        assert_eq!(extracted.background_color, Color::BLUE);
+        assert_eq!(extracted.text_style.color, Color::WHITE);
+        assert!(extracted.interaction_state.is_enabled());
+    }
+
+    #[test]
+    fn button_label_truncation() {
+        let ctx = RenderContext::new();
+
+        // Without a width limit the full label is always shown
+        let unlimited = Button::new("Export Current Selection");
+        let extracted = MockBackend::extract(&unlimited.view(), &ctx).unwrap();
+        assert_eq!(extracted.text, "Export Current Selection");
+
+        // A short label within the limit is untouched
+        let short = Button::new("Save").max_label_width(80.0);
+        let extracted = MockBackend::extract(&short.view(), &ctx).unwrap();
+        assert_eq!(extracted.text, "Save");
+
+        // 80px at 16px font fits 10 mock glyphs, the last being the ellipsis
+        let long = Button::new("Export Current Selection").max_label_width(80.0);
+        let extracted = MockBackend::extract(&long.view(), &ctx).unwrap();
+        assert_eq!(extracted.text, "Export Cu…");
+
+        // Narrow frames are clamped to the minimum hit target (44px = 5 glyphs)
+        let narrow = Button::new("Export Current Selection").max_label_width(4.0);
+        let extracted = MockBackend::extract(&narrow.view(), &ctx).unwrap();
+        assert_eq!(extracted.text, "Expo…");
+
+        // The model keeps the full label; only the laid-out text is shortened
+        assert_eq!(narrow.text.content, "Export Current Selection");
+    }
+
+    #[test]
+    fn focused_button_extracts_focus_ring() {
+        let ctx = RenderContext::new();
+        let button = Button::new("Next");
+
+        let extracted = MockBackend::extract(&button.view(), &ctx).unwrap();
+        assert_eq!(extracted.focus_ring, None);
+
+        let focused = button.focus();
+        let extracted = MockBackend::extract(&focused.view(), &ctx).unwrap();
+        assert_eq!(extracted.focus_ring, Some(Button::DEFAULT_FOCUS_RING));
+
+        // Custom styles are used, and disabled buttons never show a ring
+        let style = BorderStyle::new(3.0, Color::ORANGE).corner_radius(4.0);
+        let custom = focused.focus_ring_style(style);
+        let extracted = MockBackend::extract(&custom.view(), &ctx).unwrap();
+        assert_eq!(extracted.focus_ring, Some(style));
+        let extracted = MockBackend::extract(&custom.disable().view(), &ctx).unwrap();
+        assert_eq!(extracted.focus_ring, None);
+    }
+
+    #[test]
+    fn pressed_button_extracts_press_feedback() {
+        let ctx = RenderContext::new();
+        let button = Button::new("Go").press_feedback_style(PressFeedback::new(0.95));
+
+        let extracted = MockBackend::extract(&button.view(), &ctx).unwrap();
+        assert_eq!(extracted.press_feedback, None);
+
+        let pressed = button.press();
+        let extracted = MockBackend::extract(&pressed.view(), &ctx).unwrap();
+        assert_eq!(extracted.press_feedback.map(|f| f.scale), Some(0.95));
+
+        let released = pressed.release();
+        let extracted = MockBackend::extract(&released.view(), &ctx).unwrap();
+        assert_eq!(extracted.press_feedback, None);
+
+        // Buttons without configured feedback never report any
+        let plain = Button::new("Go").press();
+        let extracted = MockBackend::extract(&plain.view(), &ctx).unwrap();
+        assert_eq!(extracted.press_feedback, None);
+    }
+
+    #[test]
+    fn unstyled_buttons_take_the_environment_accent() {
+        let mut ctx = RenderContext::new();
+        let plain = Button::new("Save").view();
+        let custom = Button::new("Delete").background_color(Color::RED).view();
+
+        assert_eq!(
+            MockBackend::extract(&plain, &ctx).unwrap().background_color,
+            Button::DEFAULT_BACKGROUND
+        );
+
+        ctx.set::<AccentColor>(Some(Color::GREEN));
+        assert_eq!(
+            MockBackend::extract(&plain, &ctx).unwrap().background_color,
+            Color::GREEN
+        );
+        assert_eq!(
+            MockBackend::extract(&custom, &ctx)
+                .unwrap()
+                .background_color,
+            Color::RED
+        );
+    }
+
+    #[test]
+    fn themed_button_switches_to_high_contrast_palette() {
+        let button = Button::new("Continue").themed().view();
+
+        let normal = MockBackend::extract(&button, &RenderContext::new()).unwrap();
+        assert_eq!(normal.background_color, ThemeColors::LIGHT.accent);
+        assert_eq!(normal.text_style.color, ThemeColors::LIGHT.on_accent);
+
+        let ctx = RenderContext::new().with_high_contrast(true);
+        let contrast = MockBackend::extract(&button, &ctx).unwrap();
+        assert_eq!(contrast.background_color, Color::BLACK);
+        assert_eq!(contrast.text_style.color, Color::WHITE);
+        assert_ne!(contrast.background_color, normal.background_color);
+
+        // Buttons with their own colors ignore the theme
+        let plain = Button::new("Cancel").background_color(Color::RED).view();
+        let extracted = MockBackend::extract(&plain, &ctx).unwrap();
+        assert_eq!(extracted.background_color, Color::RED);
+        assert_eq!(extracted.text_style.color, Color::BLACK);
+    }
+
+    #[test]
+    fn extraction_preserves_view_data() {
+        // Test that extraction doesn't modify the original view
+        let original_text = Text::new("Original").font_size(20.0);
+        let original_button = Button::new("Original").enable();
+        let ctx = RenderContext::new();
+
+        // Extract views
+        let _extracted_text = MockBackend::extract(&original_text, &ctx).unwrap();
+        let _extracted_button = MockBackend::extract(&original_button.view(), &ctx).unwrap();
+
+        // Original views should be unchanged
+        assert_eq!(original_text.content, "Original");
+        assert_eq!(original_text.style.font_size, 20.0);
+        assert_eq!(original_button.text.content, "Original");
+        assert!(original_button.is_enabled());
+    }
+
+    #[test]
+    fn spacer_extraction() {
+        // Test extracting spacer views
+        let ctx = RenderContext::new();
+
+        // Test default spacer
+        let spacer = Spacer::new();
+        let extracted = MockBackend::extract(&spacer, &ctx).unwrap();
+        assert_eq!(extracted.min_size, 0.0);
+
+        // Test spacer with minimum size
+        let sized_spacer = Spacer::min_size(20.0);
+        let sized_extracted = MockBackend::extract(&sized_spacer, &ctx).unwrap();
+        assert_eq!(sized_extracted.min_size, 20.0);
+        assert_eq!(sized_extracted.max_size, None);
+        assert_eq!(sized_extracted.layout_priority, 0.0);
+
+        // Test capped spacer with a priority
+        let capped = Spacer::min_size(10.0).max_size(30.0).layout_priority(2.0);
+        let capped_extracted = MockBackend::extract(&capped, &ctx).unwrap();
+        assert_eq!(capped_extracted.max_size, Some(30.0));
+        assert_eq!(capped_extracted.layout_priority, 2.0);
+
+        // A cap below the minimum is raised to it
+        assert_eq!(Spacer::min_size(10.0).max_size(4.0).max_size, Some(10.0));
+    }
+
+    #[test]
+    fn disabled_widgets_are_dimmed() {
+        use crate::style::DisabledStyle;
+
+        let ctx = RenderContext::new();
+        let button = Button::new("Dim me")
+            .background_color(Color::BLUE)
+            .disabled_style(DisabledStyle::new().opacity(0.4));
+
+        let enabled = MockBackend::extract(&button.view(), &ctx).unwrap();
+        assert_eq!(enabled.opacity, 1.0);
+
+        let disabled = MockBackend::extract(&button.disable().view(), &ctx).unwrap();
+        assert_eq!(disabled.opacity, 0.4);
+        // Dimming is reported separately from the configured colors
+        assert_eq!(disabled.background_color, Color::BLUE);
+        assert_eq!(disabled.text_style.color, Color::BLACK);
+    }
+}
+
+// End of File
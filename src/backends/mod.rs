@@ -10,11 +10,30 @@
 //!
 //! Available backends:
 //! - `mock`: Testing backend that extracts views into simple data structures
+//! - `terminal`: Character-grid backend for command-line apps, printed with ANSI colors
+//! - `composite`: Routes each view type to whichever backend was registered for it
+//! - `patch`: Diffs extracted mock trees and applies the edits in place
+//!
+//! With the `bincode` feature, the `snapshot` module encodes extracted mock
+//! trees into compact binary snapshots. With the `json` feature, the `json`
+//! module renders them as tagged JSON for readable snapshot tests.
 
+pub mod composite;
+#[cfg(feature = "json")]
+pub mod json;
 pub mod mock;
+pub mod patch;
+#[cfg(feature = "bincode")]
+pub mod snapshot;
+pub mod terminal;
 
+pub use composite::CompositeBackend;
 pub use mock::{
-    MockBackend, MockButton, MockDynamicChild, MockHStack, MockSpacer, MockText, MockVStack,
+    ApproxEq, CompactDebug, MockActivityIndicator, MockBackend, MockBadge, MockBorder, MockButton,
+    MockCard, MockCheckbox, MockDynamicChild, MockFlowLayout, MockGauge, MockGrid, MockHStack,
+    MockIdentified, MockImage, MockLabel, MockOpacity, MockPadding, MockPicker, MockRichText,
+    MockScrollView, MockSlider, MockSpacer, MockSparkline, MockSpinner, MockStepper, MockTable,
+    MockTagged, MockText, MockTextRef, MockToggle, MockTooltip, MockVStack, MockZStack,
 };
 
 // End of File
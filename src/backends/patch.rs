@@ -0,0 +1,352 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Diffing and patching of extracted mock trees
+//!
+//! A retained-mode backend keeps one extracted tree alive and changes it in
+//! place instead of rebuilding it on every update. [`diff`] describes how to
+//! turn an old tree into a new one as a list of [`ViewPatch`]es, and
+//! [`apply_patches`] performs those edits on the old tree. Unchanged subtrees
+//! produce no patches, so the edits touch only what actually changed.
+//!
+//! Nodes are addressed by their path of child indices from the root, the
+//! same scheme used by [`MockBackend::extract_streaming`](super::MockBackend::extract_streaming).
+
+use super::mock::MockDynamicChild;
+
+/// A single edit to an extracted tree.
+///
+/// Paths list child indices from the root, so an empty path is the root
+/// itself.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ViewPatch {
+    /// Replace the node at `path`, including its children, with `node`
+    Replace {
+        /// Location of the node to replace
+        path: Vec<usize>,
+        /// The node to put in its place
+        node: MockDynamicChild,
+    },
+    /// Insert `node` as child `index` of the container at `path`
+    Insert {
+        /// Location of the container
+        path: Vec<usize>,
+        /// Position the new child takes; later children shift up
+        index: usize,
+        /// The child to insert
+        node: MockDynamicChild,
+    },
+    /// Remove child `index` from the container at `path`
+    Remove {
+        /// Location of the container
+        path: Vec<usize>,
+        /// Position of the child to remove; later children shift down
+        index: usize,
+    },
+}
+
+/// Errors that can occur while applying patches.
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum PatchError {
+    /// A path doesn't lead to a node in the tree.
+    #[error("No node at path {path:?}")]
+    InvalidPath {
+        /// The path that couldn't be followed
+        path: Vec<usize>,
+    },
+
+    /// An insertion or removal targets a node whose children can't change.
+    #[error("Node at path {path:?} has no resizable child list")]
+    NotAContainer {
+        /// Location of the node
+        path: Vec<usize>,
+    },
+
+    /// An insertion or removal index is past the end of the child list.
+    #[error("Child index {index} is out of range for the container at {path:?}")]
+    IndexOutOfRange {
+        /// Location of the container
+        path: Vec<usize>,
+        /// The offending index
+        index: usize,
+    },
+}
+
+/// Result type for patch operations.
+pub type PatchResult<T> = Result<T, PatchError>;
+
+/// Compute the patches that turn `old` into `new`.
+///
+/// Containers of the same kind with the same settings are kept and their
+/// children compared position by position; children past the shorter list
+/// are inserted or removed at the end. Any other difference replaces the
+/// node outright.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{
+///     backends::{
+///         mock::MockBackend,
+///         patch::{ViewPatch, apply_patches, diff},
+///     },
+///     prelude::*,
+/// };
+///
+/// let backend = MockBackend::new();
+/// let ctx = RenderContext::new();
+/// let render = |count: i32| {
+///     let view = VStack::dynamic()
+///         .child(Box::new(Text::new("Counter")))
+///         .child(Box::new(Text::new(format!("Count: {count}"))));
+///     backend.extract_dynamic(&view, &ctx).unwrap()
+/// };
+///
+/// let mut tree = render(0);
+/// let patches = diff(&tree, &render(1));
+/// assert!(matches!(patches.as_slice(), [ViewPatch::Replace { path, .. }] if path == &[1]));
+///
+/// apply_patches(&mut tree, &patches)?;
+/// assert_eq!(tree, render(1));
+/// # Ok::<(), ironwood::backends::patch::PatchError>(())
+/// ```
+pub fn diff(old: &MockDynamicChild, new: &MockDynamicChild) -> Vec<ViewPatch> {
+    let mut patches = Vec::new();
+    diff_node(old, new, &mut Vec::new(), &mut patches);
+    patches
+}
+
+/// Apply patches to a tree in order.
+///
+/// Patches produced by [`diff`] transform the old tree into the new tree
+/// exactly. Patches are applied one at a time, so if one fails the tree
+/// keeps the edits made before it.
+///
+/// # Errors
+///
+/// Returns a [`PatchError`] if a patch's path doesn't exist in the tree, or
+/// it inserts into or removes from something that isn't a resizable
+/// container, or its index is out of range.
+pub fn apply_patches(tree: &mut MockDynamicChild, patches: &[ViewPatch]) -> PatchResult<()> {
+    for patch in patches {
+        match patch {
+            ViewPatch::Replace { path, node } => {
+                *node_at_mut(tree, path)? = node.clone();
+            }
+            ViewPatch::Insert { path, index, node } => {
+                let children = child_list_at_mut(tree, path)?;
+                if *index > children.len() {
+                    return Err(PatchError::IndexOutOfRange {
+                        path: path.clone(),
+                        index: *index,
+                    });
+                }
+                children.insert(*index, node.clone());
+            }
+            ViewPatch::Remove { path, index } => {
+                let children = child_list_at_mut(tree, path)?;
+                if *index >= children.len() {
+                    return Err(PatchError::IndexOutOfRange {
+                        path: path.clone(),
+                        index: *index,
+                    });
+                }
+                children.remove(*index);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Record the patches for one node and recurse into matching children.
+fn diff_node(
+    old: &MockDynamicChild,
+    new: &MockDynamicChild,
+    path: &mut Vec<usize>,
+    patches: &mut Vec<ViewPatch>,
+) {
+    if old == new {
+        return;
+    }
+
+    let (Some(old_children), Some(new_children)) = (old.children(), new.children()) else {
+        patches.push(ViewPatch::Replace {
+            path: path.clone(),
+            node: new.clone(),
+        });
+        return;
+    };
+    if !old.same_shell(new) {
+        patches.push(ViewPatch::Replace {
+            path: path.clone(),
+            node: new.clone(),
+        });
+        return;
+    }
+
+    let shared = old_children.len().min(new_children.len());
+    for (index, (old_child, new_child)) in old_children.iter().zip(new_children).enumerate() {
+        path.push(index);
+        diff_node(old_child, new_child, path, patches);
+        path.pop();
+    }
+    for (index, node) in new_children.iter().enumerate().skip(shared) {
+        patches.push(ViewPatch::Insert {
+            path: path.clone(),
+            index,
+            node: node.clone(),
+        });
+    }
+    // Remove from the end so earlier indices stay valid
+    for index in (shared..old_children.len()).rev() {
+        patches.push(ViewPatch::Remove {
+            path: path.clone(),
+            index,
+        });
+    }
+}
+
+/// Follow a path of child indices to a node.
+fn node_at_mut<'a>(
+    tree: &'a mut MockDynamicChild,
+    path: &[usize],
+) -> PatchResult<&'a mut MockDynamicChild> {
+    let invalid = || PatchError::InvalidPath {
+        path: path.to_vec(),
+    };
+    let mut node = tree;
+    for &index in path {
+        node = node
+            .children_mut()
+            .and_then(|children| children.get_mut(index))
+            .ok_or_else(invalid)?;
+    }
+    Ok(node)
+}
+
+/// Follow a path to a container whose children can be inserted and removed.
+fn child_list_at_mut<'a>(
+    tree: &'a mut MockDynamicChild,
+    path: &[usize],
+) -> PatchResult<&'a mut Vec<MockDynamicChild>> {
+    node_at_mut(tree, path)?
+        .child_list_mut()
+        .ok_or_else(|| PatchError::NotAContainer {
+            path: path.to_vec(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        backends::mock::MockBackend,
+        elements::{HStack, Padding, Text, VStack},
+        extraction::RenderContext,
+        model::Model,
+        view::View,
+        widgets::Button,
+    };
+
+    /// Extract a counter screen showing `count`, with one history row per step.
+    fn counter(count: usize) -> MockDynamicChild {
+        let history = VStack::from_children(
+            (0..count).map(|step| Box::new(Text::new(format!("+1 ({step})"))) as Box<dyn View>),
+        );
+        let view = VStack::dynamic()
+            .child(Box::new(Text::new("Counter")))
+            .child(Box::new(Padding::new(
+                Box::new(Text::new(format!("Count: {count}"))) as Box<dyn View>,
+                Default::default(),
+            )))
+            .child(Box::new(
+                HStack::dynamic()
+                    .child(Box::new(Button::new("-").view()))
+                    .child(Box::new(Button::new("+").view())),
+            ))
+            .child(Box::new(history));
+        MockBackend::new()
+            .extract_dynamic(&view, &RenderContext::new())
+            .unwrap()
+    }
+
+    #[test]
+    fn applying_a_diff_reproduces_the_new_tree() {
+        for (from, to) in [(0, 1), (1, 3), (3, 1), (2, 0), (2, 2)] {
+            let mut tree = counter(from);
+            let target = counter(to);
+            let patches = diff(&tree, &target);
+            apply_patches(&mut tree, &patches).unwrap();
+            assert_eq!(tree, target, "patching {from} -> {to}");
+        }
+    }
+
+    #[test]
+    fn diff_touches_only_what_changed() {
+        assert!(diff(&counter(2), &counter(2)).is_empty());
+
+        // The count text changes and one history row is added
+        let patches = diff(&counter(1), &counter(2));
+        assert_eq!(patches.len(), 2);
+        assert!(matches!(&patches[0], ViewPatch::Replace { path, .. } if path == &[1, 0]));
+        assert!(matches!(&patches[1], ViewPatch::Insert { path, index: 1, .. } if path == &[3]));
+
+        // A container whose settings changed is replaced wholesale
+        let old = MockBackend::new()
+            .extract_dynamic(&VStack::dynamic().spacing(4.0), &RenderContext::new())
+            .unwrap();
+        let new = MockBackend::new()
+            .extract_dynamic(&VStack::dynamic().spacing(8.0), &RenderContext::new())
+            .unwrap();
+        assert!(
+            matches!(diff(&old, &new).as_slice(), [ViewPatch::Replace { path, .. }] if path.is_empty())
+        );
+    }
+
+    #[test]
+    fn invalid_patches_are_reported() {
+        let mut tree = counter(1);
+        let text = counter(0);
+
+        let error = apply_patches(
+            &mut tree,
+            &[ViewPatch::Replace {
+                path: vec![9],
+                node: text.clone(),
+            }],
+        )
+        .unwrap_err();
+        assert_eq!(error, PatchError::InvalidPath { path: vec![9] });
+
+        let error = apply_patches(
+            &mut tree,
+            &[ViewPatch::Remove {
+                path: vec![1],
+                index: 0,
+            }],
+        )
+        .unwrap_err();
+        assert_eq!(error, PatchError::NotAContainer { path: vec![1] });
+
+        let error = apply_patches(
+            &mut tree,
+            &[ViewPatch::Insert {
+                path: vec![2],
+                index: 5,
+                node: text,
+            }],
+        )
+        .unwrap_err();
+        assert_eq!(
+            error,
+            PatchError::IndexOutOfRange {
+                path: vec![2],
+                index: 5
+            }
+        );
+    }
+}
+
+// End of File
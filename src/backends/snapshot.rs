@@ -0,0 +1,110 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Compact binary snapshots of extracted view trees
+//!
+//! Extracted mock trees are plain data, which makes them convenient snapshots
+//! of a UI at a point in time. This module encodes them with bincode so large
+//! snapshots can be stored or sent between processes cheaply and decoded back
+//! into an identical tree.
+//!
+//! Available with the `bincode` feature.
+
+use bincode::{
+    config,
+    error::{DecodeError, EncodeError},
+};
+use serde::{Serialize, de::DeserializeOwned};
+
+/// Errors that can occur while encoding or decoding a snapshot.
+#[derive(Debug, thiserror::Error)]
+pub enum SnapshotError {
+    /// The tree couldn't be encoded.
+    #[error("Failed to encode snapshot: {0}")]
+    Encode(#[from] EncodeError),
+
+    /// The bytes don't describe a tree of the requested type.
+    #[error("Failed to decode snapshot: {0}")]
+    Decode(#[from] DecodeError),
+
+    /// The bytes contain data past the end of the decoded tree.
+    #[error("Snapshot has {trailing} unexpected trailing bytes")]
+    TrailingBytes {
+        /// Number of bytes left over after decoding
+        trailing: usize,
+    },
+}
+
+/// Result type for snapshot operations.
+pub type SnapshotResult<T> = Result<T, SnapshotError>;
+
+/// Encode an extracted tree into a compact binary snapshot.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{
+///     prelude::*,
+///     backends::{mock::{MockBackend, MockText}, snapshot},
+/// };
+///
+/// let extracted = MockBackend::extract(&Text::new("Hello"), &RenderContext::new()).unwrap();
+/// let bytes = snapshot::to_bytes(&extracted).unwrap();
+/// let decoded: MockText = snapshot::from_bytes(&bytes).unwrap();
+/// assert_eq!(decoded, extracted);
+/// ```
+pub fn to_bytes<T: Serialize>(tree: &T) -> SnapshotResult<Vec<u8>> {
+    Ok(bincode::serde::encode_to_vec(tree, config::standard())?)
+}
+
+/// Decode a binary snapshot produced by [`to_bytes`].
+///
+/// The type to decode must match the type that was encoded. The whole input
+/// must be consumed; leftover bytes are reported as an error because they
+/// usually mean the snapshot was truncated or concatenated by mistake.
+pub fn from_bytes<T: DeserializeOwned>(bytes: &[u8]) -> SnapshotResult<T> {
+    let (tree, read) = bincode::serde::decode_from_slice(bytes, config::standard())?;
+    match bytes.len() - read {
+        0 => Ok(tree),
+        trailing => Err(SnapshotError::TrailingBytes { trailing }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        backends::mock::{MockBackend, MockDynamicChild},
+        elements::{Text, VStack},
+        extraction::RenderContext,
+        model::Model,
+        widgets::Button,
+    };
+
+    #[test]
+    fn dynamic_tree_round_trips() {
+        let tree = VStack::dynamic()
+            .child(Box::new(Text::new("Title").font_size(20.0)))
+            .child(Box::new(Button::new("Go").view()));
+        let extracted = MockBackend::new()
+            .extract_dynamic(&tree, &RenderContext::new())
+            .unwrap();
+
+        let bytes = to_bytes(&extracted).unwrap();
+        let decoded: MockDynamicChild = from_bytes(&bytes).unwrap();
+        assert_eq!(decoded, extracted);
+    }
+
+    #[test]
+    fn trailing_bytes_are_rejected() {
+        let mut bytes = to_bytes(&42u32).unwrap();
+        bytes.push(0);
+        assert!(matches!(
+            from_bytes::<u32>(&bytes),
+            Err(SnapshotError::TrailingBytes { trailing: 1 })
+        ));
+    }
+}
+
+// End of File
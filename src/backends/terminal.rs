@@ -0,0 +1,451 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Terminal backend that lays views out on a character grid
+//!
+//! The terminal backend extracts views into rectangular grids of cells, each
+//! holding one character with optional foreground and background colors.
+//! Text is laid out one character per cell, and stacks place their children's
+//! grids next to each other. The finished grid can be printed to any terminal
+//! that understands 24-bit ANSI color codes.
+//!
+//! Layout is intrinsic: every grid is exactly as large as its content, so
+//! spacers can't expand and take their minimum size instead. Logical pixels
+//! are converted to cells using [`CELL_WIDTH`] and [`CELL_HEIGHT`].
+
+use std::iter;
+
+use crate::{
+    elements::{Alignment, HStack, Spacer, Text, VStack},
+    extraction::{ExtractionResult, RenderContext, ViewExtractor},
+    style::Color,
+    view::View,
+};
+
+/// Width of one terminal cell in logical pixels.
+pub const CELL_WIDTH: f32 = 8.0;
+
+/// Height of one terminal cell in logical pixels.
+pub const CELL_HEIGHT: f32 = 16.0;
+
+/// A single character position in a terminal grid.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cell {
+    /// The character drawn in this cell
+    pub ch: char,
+    /// Foreground color, or the terminal's default when `None`
+    pub fg: Option<Color>,
+    /// Background color, or the terminal's default when `None`
+    pub bg: Option<Color>,
+}
+
+impl Cell {
+    /// An empty cell in the terminal's default colors.
+    pub const BLANK: Cell = Cell {
+        ch: ' ',
+        fg: None,
+        bg: None,
+    };
+}
+
+impl Default for Cell {
+    fn default() -> Self {
+        Self::BLANK
+    }
+}
+
+/// Rows of cells, top to bottom; every row has the same length.
+pub type Grid = Vec<Vec<Cell>>;
+
+/// A child of a stack, extracted but not yet positioned.
+///
+/// Spacers are kept separate from grids because their size depends on the
+/// axis of the stack they end up in.
+#[derive(Debug, Clone, PartialEq)]
+pub enum StackItem {
+    /// A child laid out as a grid
+    Grid(Grid),
+    /// A spacer and its minimum size in logical pixels
+    Spacer {
+        /// Minimum size along the stack's axis
+        min_size: f32,
+    },
+}
+
+/// Backend that renders views to a terminal.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{backends::terminal::TerminalBackend, prelude::*};
+///
+/// let view = VStack::new((Text::new("Hello"), Text::new("terminal")));
+/// let grid = TerminalBackend::extract(&view, &RenderContext::new()).unwrap();
+/// assert_eq!(TerminalBackend::to_plain_string(&grid), "Hello   \nterminal");
+///
+/// // Colored output for printing
+/// println!("{}", TerminalBackend::to_string(&grid));
+/// ```
+pub struct TerminalBackend;
+
+impl TerminalBackend {
+    /// Render a grid as text with ANSI escape codes for its colors.
+    ///
+    /// Colors are written as 24-bit codes and reset at the end of each row,
+    /// so the output can be printed line by line.
+    pub fn to_string(grid: &Grid) -> String {
+        let mut out = String::new();
+        for (index, row) in grid.iter().enumerate() {
+            if index > 0 {
+                out.push('\n');
+            }
+            let mut current = (None, None);
+            for cell in row {
+                let style = (cell.fg, cell.bg);
+                if style != current {
+                    if current != (None, None) {
+                        out.push_str(ANSI_RESET);
+                    }
+                    if let Some(fg) = cell.fg {
+                        out.push_str(&ansi_color(38, fg));
+                    }
+                    if let Some(bg) = cell.bg {
+                        out.push_str(&ansi_color(48, bg));
+                    }
+                    current = style;
+                }
+                out.push(cell.ch);
+            }
+            if current != (None, None) {
+                out.push_str(ANSI_RESET);
+            }
+        }
+        out
+    }
+
+    /// Render a grid as text without any colors.
+    pub fn to_plain_string(grid: &Grid) -> String {
+        grid.iter()
+            .map(|row| row.iter().map(|cell| cell.ch).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Extract a stack child, keeping spacers for the stack to size.
+    fn stack_item<V: View>(view: &V, context: &RenderContext) -> ExtractionResult<StackItem>
+    where
+        Self: ViewExtractor<V, Output = Grid>,
+    {
+        Ok(match view.as_any().downcast_ref::<Spacer>() {
+            Some(spacer) => StackItem::Spacer {
+                min_size: spacer.min_size,
+            },
+            None => StackItem::Grid(Self::extract(view, context)?),
+        })
+    }
+}
+
+/// Escape sequence that restores the terminal's default colors.
+const ANSI_RESET: &str = "\x1b[0m";
+
+/// Escape sequence selecting a 24-bit color; `layer` is 38 for foreground, 48 for background.
+fn ansi_color(layer: u8, color: Color) -> String {
+    let channel = |value: f32| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+    format!(
+        "\x1b[{};2;{};{};{}m",
+        layer,
+        channel(color.r),
+        channel(color.g),
+        channel(color.b)
+    )
+}
+
+/// Convert a length in logical pixels to a whole number of cells.
+fn to_cells(length: f32, cell_size: f32) -> usize {
+    (length.max(0.0) / cell_size).round() as usize
+}
+
+/// Width of a grid, relying on every row having the same length.
+fn grid_width(grid: &Grid) -> usize {
+    grid.first().map_or(0, Vec::len)
+}
+
+/// Split `extra` cells into the amounts before and after content.
+fn align_offsets(extra: usize, alignment: Alignment) -> (usize, usize) {
+    match alignment {
+        Alignment::Leading => (0, extra),
+        Alignment::Center => (extra / 2, extra - extra / 2),
+        Alignment::Trailing => (extra, 0),
+    }
+}
+
+/// Text laid out one character per cell, one row per line
+impl ViewExtractor<Text> for TerminalBackend {
+    type Output = Grid;
+
+    fn extract(view: &Text, _ctx: &RenderContext) -> ExtractionResult<Self::Output> {
+        let cell = Cell {
+            fg: Some(view.style.color),
+            ..Cell::BLANK
+        };
+        let mut rows: Grid = view
+            .content
+            .split('\n')
+            .map(|line| line.chars().map(|ch| Cell { ch, ..cell }).collect())
+            .collect();
+        let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+        for row in &mut rows {
+            row.resize(width, Cell::BLANK);
+        }
+        Ok(rows)
+    }
+}
+
+/// A spacer on its own is a column of blank rows; stacks size it along their axis
+impl ViewExtractor<Spacer> for TerminalBackend {
+    type Output = Grid;
+
+    fn extract(view: &Spacer, _ctx: &RenderContext) -> ExtractionResult<Self::Output> {
+        Ok(vec![
+            Vec::new();
+            to_cells(view.min_size, CELL_HEIGHT).max(1)
+        ])
+    }
+}
+
+/// Unit views take no space
+impl ViewExtractor<()> for TerminalBackend {
+    type Output = Grid;
+
+    fn extract(_view: &(), _ctx: &RenderContext) -> ExtractionResult<Self::Output> {
+        Ok(Grid::new())
+    }
+}
+
+/// Optional views produce an empty grid when absent
+impl<V> ViewExtractor<Option<V>> for TerminalBackend
+where
+    V: View,
+    Self: ViewExtractor<V, Output = Grid>,
+{
+    type Output = Grid;
+
+    fn extract(view: &Option<V>, context: &RenderContext) -> ExtractionResult<Self::Output> {
+        match view {
+            Some(inner) => Self::extract(inner, context),
+            None => Ok(Grid::new()),
+        }
+    }
+}
+
+/// Homogeneous collections become stack items in order
+impl<V> ViewExtractor<Vec<V>> for TerminalBackend
+where
+    V: View,
+    Self: ViewExtractor<V, Output = Grid>,
+{
+    type Output = Vec<StackItem>;
+
+    fn extract(view: &Vec<V>, context: &RenderContext) -> ExtractionResult<Self::Output> {
+        view.iter()
+            .map(|item| Self::stack_item::<V>(item, context))
+            .collect()
+    }
+}
+
+/// Implement tuple extraction into stack items for one tuple arity.
+macro_rules! tuple_stack_items {
+    ($($name:ident . $index:tt),+) => {
+        impl<$($name),+> ViewExtractor<($($name,)+)> for TerminalBackend
+        where
+            $($name: View,)+
+            $(Self: ViewExtractor<$name, Output = Grid>,)+
+        {
+            type Output = Vec<StackItem>;
+
+            fn extract(
+                view: &($($name,)+),
+                context: &RenderContext,
+            ) -> ExtractionResult<Self::Output> {
+                Ok(vec![$(Self::stack_item::<$name>(&view.$index, context)?),+])
+            }
+        }
+    };
+}
+
+tuple_stack_items!(V1.0, V2.1);
+tuple_stack_items!(V1.0, V2.1, V3.2);
+tuple_stack_items!(V1.0, V2.1, V3.2, V4.3);
+tuple_stack_items!(V1.0, V2.1, V3.2, V4.3, V5.4);
+tuple_stack_items!(V1.0, V2.1, V3.2, V4.3, V5.4, V6.5);
+tuple_stack_items!(V1.0, V2.1, V3.2, V4.3, V5.4, V6.5, V7.6);
+tuple_stack_items!(V1.0, V2.1, V3.2, V4.3, V5.4, V6.5, V7.6, V8.7);
+tuple_stack_items!(V1.0, V2.1, V3.2, V4.3, V5.4, V6.5, V7.6, V8.7, V9.8);
+tuple_stack_items!(V1.0, V2.1, V3.2, V4.3, V5.4, V6.5, V7.6, V8.7, V9.8, V10.9);
+
+/// Children stacked top to bottom, aligned horizontally within the widest child
+impl<T> ViewExtractor<VStack<T>> for TerminalBackend
+where
+    T: View,
+    Self: ViewExtractor<T, Output = Vec<StackItem>>,
+{
+    type Output = Grid;
+
+    fn extract(view: &VStack<T>, context: &RenderContext) -> ExtractionResult<Self::Output> {
+        let blocks: Vec<Grid> = Self::extract(&view.content, context)?
+            .into_iter()
+            .filter_map(|item| match item {
+                StackItem::Grid(grid) if grid.is_empty() => None,
+                StackItem::Grid(grid) => Some(grid),
+                StackItem::Spacer { min_size } => {
+                    Some(vec![Vec::new(); to_cells(min_size, CELL_HEIGHT).max(1)])
+                }
+            })
+            .collect();
+        let width = blocks.iter().map(grid_width).max().unwrap_or(0);
+        let gap = to_cells(view.spacing, CELL_HEIGHT);
+
+        let mut rows = Grid::new();
+        for (index, block) in blocks.into_iter().enumerate() {
+            if index > 0 {
+                rows.extend(iter::repeat_n(vec![Cell::BLANK; width], gap));
+            }
+            for row in block {
+                let (before, after) = align_offsets(width - row.len(), view.alignment);
+                let mut aligned = vec![Cell::BLANK; before];
+                aligned.extend(row);
+                aligned.resize(aligned.len() + after, Cell::BLANK);
+                rows.push(aligned);
+            }
+        }
+        Ok(rows)
+    }
+}
+
+/// Children placed left to right, aligned vertically within the tallest child
+impl<T> ViewExtractor<HStack<T>> for TerminalBackend
+where
+    T: View,
+    Self: ViewExtractor<T, Output = Vec<StackItem>>,
+{
+    type Output = Grid;
+
+    fn extract(view: &HStack<T>, context: &RenderContext) -> ExtractionResult<Self::Output> {
+        // Each block is a width and its rows; spacers are blank columns with no rows
+        let blocks: Vec<(usize, Grid)> = Self::extract(&view.content, context)?
+            .into_iter()
+            .filter_map(|item| match item {
+                StackItem::Grid(grid) if grid.is_empty() => None,
+                StackItem::Grid(grid) => Some((grid_width(&grid), grid)),
+                StackItem::Spacer { min_size } => {
+                    Some((to_cells(min_size, CELL_WIDTH).max(1), Grid::new()))
+                }
+            })
+            .collect();
+        let height = blocks.iter().map(|(_, grid)| grid.len()).max().unwrap_or(0);
+        let gap = to_cells(view.spacing, CELL_WIDTH);
+
+        let mut rows: Grid = vec![Vec::new(); height];
+        for (index, (width, block)) in blocks.into_iter().enumerate() {
+            if index > 0 {
+                for row in &mut rows {
+                    row.extend(iter::repeat_n(Cell::BLANK, gap));
+                }
+            }
+            let (top, bottom) = align_offsets(height - block.len(), view.alignment);
+            let padded = iter::repeat_n(None, top)
+                .chain(block.into_iter().map(Some))
+                .chain(iter::repeat_n(None, bottom));
+            for (row, cells) in rows.iter_mut().zip(padded) {
+                match cells {
+                    Some(cells) => row.extend(cells),
+                    None => row.extend(iter::repeat_n(Cell::BLANK, width)),
+                }
+            }
+        }
+        Ok(rows)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Extract a view and render it without colors.
+    fn render<V: View>(view: &V) -> String
+    where
+        TerminalBackend: ViewExtractor<V, Output = Grid>,
+    {
+        let grid = TerminalBackend::extract(view, &RenderContext::new()).unwrap();
+        TerminalBackend::to_plain_string(&grid)
+    }
+
+    #[test]
+    fn text_fills_cells_with_its_color() {
+        let grid = TerminalBackend::extract(
+            &Text::new("hi\nthere").color(Color::RED),
+            &RenderContext::new(),
+        )
+        .unwrap();
+        assert_eq!(grid.len(), 2);
+        assert!(grid.iter().all(|row| row.len() == 5));
+        assert_eq!(grid[0][1].ch, 'i');
+        assert_eq!(grid[0][1].fg, Some(Color::RED));
+        assert_eq!(grid[0][4], Cell::BLANK);
+    }
+
+    #[test]
+    fn stacks_apply_spacing_and_alignment() {
+        let column = VStack::new((Text::new("a"), Text::new("wide")))
+            .spacing(CELL_HEIGHT)
+            .alignment(Alignment::Trailing);
+        assert_eq!(render(&column), "   a\n    \nwide");
+
+        let row = HStack::new((Text::new("x"), Text::new("y\nz")))
+            .spacing(2.0 * CELL_WIDTH)
+            .alignment(Alignment::Trailing);
+        assert_eq!(render(&row), "   y\nx  z");
+    }
+
+    #[test]
+    fn spacers_follow_the_stack_axis() {
+        let row = HStack::new((
+            Text::new("L"),
+            Spacer::min_size(3.0 * CELL_WIDTH),
+            Text::new("R"),
+        ));
+        assert_eq!(render(&row), "L   R");
+
+        let column = VStack::new((Text::new("T"), Spacer::new(), Text::new("B")));
+        assert_eq!(render(&column), "T\n \nB");
+    }
+
+    #[test]
+    fn nested_stacks_and_optional_children() {
+        // Absent children take no space and add no spacing
+        let view = VStack::new((
+            HStack::new((Text::new("ab"), Text::new("cd"))),
+            None::<Text>,
+            VStack::new(vec![Text::new("e"), Text::new("f")]),
+        ))
+        .spacing(CELL_HEIGHT);
+        assert_eq!(render(&view), "abcd\n    \ne   \nf   ");
+    }
+
+    #[test]
+    fn ansi_output_resets_colors_per_row() {
+        let grid = TerminalBackend::extract(
+            &HStack::new((Text::new("A").color(Color::RED), Text::new("B"))),
+            &RenderContext::new(),
+        )
+        .unwrap();
+        assert_eq!(
+            TerminalBackend::to_string(&grid),
+            "\x1b[38;2;255;0;0mA\x1b[0m\x1b[38;2;0;0;0mB\x1b[0m"
+        );
+        assert_eq!(TerminalBackend::to_string(&vec![vec![Cell::BLANK]]), " ");
+    }
+}
+
+// End of File
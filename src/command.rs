@@ -0,0 +1,208 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Deferred effects and scheduling
+//!
+//! A Command is work that happens outside `update`, such as a network
+//! request or reading a file, and reports back with a message once it's
+//! done. Commands are plain values: creating one doesn't run it, so models
+//! stay pure and the runtime decides where and when the work happens.
+//!
+//! The CommandScheduler lets a runtime cap how many commands are in flight
+//! at once. Commands beyond the cap wait in a queue and start in the order
+//! they were scheduled as earlier ones finish.
+
+use std::{
+    collections::VecDeque,
+    fmt::{Debug, Formatter, Result as FormatterResult},
+};
+
+/// Work performed outside `update` that produces a message when done.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::command::Command;
+///
+/// let load = Command::new(|| "loaded".to_string());
+/// assert_eq!(load.run(), "loaded");
+/// ```
+pub struct Command<M> {
+    work: Box<dyn FnOnce() -> M + Send>,
+}
+
+impl<M: 'static> Command<M> {
+    /// Create a command that runs `work` and reports its result.
+    pub fn new(work: impl FnOnce() -> M + Send + 'static) -> Self {
+        Self {
+            work: Box::new(work),
+        }
+    }
+
+    /// Perform the work, returning the message it produces.
+    pub fn run(self) -> M {
+        (self.work)()
+    }
+
+    /// Convert the produced message, typically into a parent's message type.
+    pub fn map<P: 'static>(self, f: impl FnOnce(M) -> P + Send + 'static) -> Command<P> {
+        Command::new(move || f(self.run()))
+    }
+}
+
+impl<M> Debug for Command<M> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FormatterResult {
+        f.debug_struct("Command").finish_non_exhaustive()
+    }
+}
+
+/// FIFO queue of commands with a cap on how many run at once.
+///
+/// The scheduler only does the bookkeeping: the runtime takes commands with
+/// [`CommandScheduler::next_ready`], runs them wherever it likes, and calls
+/// [`CommandScheduler::finish`] when each one completes.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::command::{Command, CommandScheduler};
+///
+/// let mut scheduler = CommandScheduler::new(1);
+/// scheduler.schedule(Command::new(|| 1));
+/// scheduler.schedule(Command::new(|| 2));
+///
+/// let first = scheduler.next_ready().unwrap();
+/// assert!(scheduler.next_ready().is_none()); // At the cap
+/// assert_eq!(first.run(), 1);
+///
+/// scheduler.finish();
+/// assert_eq!(scheduler.next_ready().unwrap().run(), 2);
+/// ```
+#[derive(Debug)]
+pub struct CommandScheduler<M> {
+    queue: VecDeque<Command<M>>,
+    max_in_flight: usize,
+    in_flight: usize,
+}
+
+impl<M: 'static> CommandScheduler<M> {
+    /// Create a scheduler that runs at most `max_in_flight` commands at once (at least one).
+    pub fn new(max_in_flight: usize) -> Self {
+        Self {
+            queue: VecDeque::new(),
+            max_in_flight: max_in_flight.max(1),
+            in_flight: 0,
+        }
+    }
+
+    /// Add a command to the back of the queue.
+    pub fn schedule(&mut self, command: Command<M>) {
+        self.queue.push_back(command);
+    }
+
+    /// Take the oldest queued command if there is room to start it.
+    ///
+    /// The returned command counts as in flight until [`finish`](Self::finish)
+    /// is called for it.
+    pub fn next_ready(&mut self) -> Option<Command<M>> {
+        if self.in_flight >= self.max_in_flight {
+            return None;
+        }
+        let command = self.queue.pop_front()?;
+        self.in_flight += 1;
+        Some(command)
+    }
+
+    /// Record that an in-flight command completed, making room for another.
+    pub fn finish(&mut self) {
+        self.in_flight = self.in_flight.saturating_sub(1);
+    }
+
+    /// Get the maximum number of commands that run at once.
+    pub fn max_in_flight(&self) -> usize {
+        self.max_in_flight
+    }
+
+    /// Get the number of commands currently running.
+    pub fn in_flight(&self) -> usize {
+        self.in_flight
+    }
+
+    /// Get the number of commands waiting to start.
+    pub fn queued(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// Check whether every scheduled command has finished.
+    pub fn is_idle(&self) -> bool {
+        self.in_flight == 0 && self.queue.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+            mpsc,
+        },
+        thread,
+        time::Duration,
+    };
+
+    use super::*;
+
+    #[test]
+    fn scheduler_caps_concurrency_and_keeps_fifo_order() {
+        let running = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+
+        let mut scheduler = CommandScheduler::new(2);
+        for id in 0..5 {
+            let running = running.clone();
+            let peak = peak.clone();
+            scheduler.schedule(Command::new(move || {
+                let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                thread::sleep(Duration::from_millis(10));
+                running.fetch_sub(1, Ordering::SeqCst);
+                id
+            }));
+        }
+
+        // Run commands on worker threads and feed their messages back
+        let (sender, receiver) = mpsc::channel();
+        let mut started = Vec::new();
+        let mut completed = Vec::new();
+        while !scheduler.is_idle() {
+            while let Some(command) = scheduler.next_ready() {
+                assert!(scheduler.in_flight() <= 2);
+                started.push(scheduler.queued());
+                let sender = sender.clone();
+                thread::spawn(move || sender.send(command.run()).unwrap());
+            }
+            completed.push(receiver.recv().unwrap());
+            scheduler.finish();
+        }
+
+        assert!(peak.load(Ordering::SeqCst) <= 2);
+        assert_eq!(started, vec![4, 3, 2, 1, 0]);
+        completed.sort();
+        assert_eq!(completed, vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn mapped_commands_report_parent_messages() {
+        #[derive(Debug, PartialEq)]
+        enum Parent {
+            Loaded(u32),
+        }
+
+        let command = Command::new(|| 7).map(Parent::Loaded);
+        assert_eq!(command.run(), Parent::Loaded(7));
+    }
+}
+
+// End of File
@@ -0,0 +1,159 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Badge overlay for counts and status dots
+//!
+//! A badge is the small marker drawn over a corner of an icon or avatar to
+//! show an unread count or that something needs attention. It overlays its
+//! child the way a two-layer [`ZStack`](super::ZStack) would, but keeps the
+//! marker and the view it decorates together so backends can size and place
+//! the marker relative to the child.
+
+use std::any::Any;
+
+use crate::{
+    elements::{Alignment2D, Text},
+    style::Color,
+    view::View,
+};
+
+/// A view with a small count or dot drawn over one of its corners.
+///
+/// Most code creates badges with [`View::badge`]. A badge without content
+/// is drawn as a plain dot.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{prelude::*, elements::Alignment2D};
+///
+/// let inbox = Text::new("📥").badge().count(3);
+/// assert_eq!(inbox.content.as_ref().map(|text| text.content.as_str()), Some("3"));
+/// assert_eq!(inbox.alignment, Alignment2D::TOP_TRAILING);
+///
+/// let status = Text::new("Ada").badge().background(Color::GREEN);
+/// assert_eq!(status.content, None);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Badge<V> {
+    /// Text shown inside the badge, or `None` for a plain dot
+    pub content: Option<Text>,
+    /// Fill color of the badge
+    pub background: Color,
+    /// The corner of the child the badge is centered on
+    pub alignment: Alignment2D,
+    /// The decorated view
+    pub child: V,
+}
+
+impl<V> Badge<V> {
+    /// Fill color used unless another is configured.
+    pub const DEFAULT_BACKGROUND: Color = Color::RED;
+
+    /// Place a dot on the top trailing corner of a view.
+    pub fn new(child: V) -> Self {
+        Self {
+            content: None,
+            background: Self::DEFAULT_BACKGROUND,
+            alignment: Alignment2D::TOP_TRAILING,
+            child,
+        }
+    }
+
+    /// Show a number inside the badge.
+    pub fn count(self, count: usize) -> Self {
+        self.text(count.to_string())
+    }
+
+    /// Show text inside the badge.
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.content = Some(Text::new(text.into()));
+        self
+    }
+
+    /// Style the badge text, if any is set.
+    pub fn with_content(mut self, f: impl FnOnce(Text) -> Text) -> Self {
+        self.content = self.content.map(f);
+        self
+    }
+
+    /// Set the fill color of the badge.
+    pub fn background(mut self, background: Color) -> Self {
+        self.background = background;
+        self
+    }
+
+    /// Set the corner of the child the badge is centered on.
+    pub fn alignment(mut self, alignment: Alignment2D) -> Self {
+        self.alignment = alignment;
+        self
+    }
+}
+
+impl<V: View> View for Badge<V> {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+// Dynamic badge implementation
+impl View for Badge<Box<dyn View>> {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        backends::mock::{MockBackend, MockDynamicChild},
+        elements::{HStack, VStack},
+        extraction::{RenderContext, ViewExtractor},
+        model::Model,
+        widgets::Button,
+    };
+
+    #[test]
+    fn badge_extracts_content_and_child() {
+        let ctx = RenderContext::new().with_text_scale(2.0);
+        let view = Button::new("Inbox")
+            .view()
+            .badge()
+            .count(12)
+            .with_content(|text| text.color(Color::WHITE))
+            .alignment(Alignment2D::TOP_LEADING);
+
+        let extracted = MockBackend::extract(&view, &ctx).unwrap();
+        let content = extracted.content.expect("count");
+        assert_eq!(content.content, "12");
+        assert_eq!(content.color, Color::WHITE);
+        assert_eq!(content.font_size, 32.0);
+        assert_eq!(extracted.background, Color::RED);
+        assert_eq!(extracted.alignment, Alignment2D::TOP_LEADING);
+        assert_eq!(extracted.child.text, "Inbox");
+    }
+
+    #[test]
+    fn dynamic_badges_keep_their_child() {
+        let dot: Box<dyn View> = Box::new(
+            Badge::new(Box::new(Text::new("Ada")) as Box<dyn View>).background(Color::GREEN),
+        );
+        let row = HStack::dynamic().child(dot);
+        let list = VStack::dynamic().child(Box::new(row));
+
+        let extracted = MockBackend::extract(&list, &RenderContext::new()).unwrap();
+        let MockDynamicChild::HStack(row) = &extracted.content[0] else {
+            panic!("expected row, got {:?}", extracted.content[0]);
+        };
+        let MockDynamicChild::Badge(badge) = &row.content[0] else {
+            panic!("expected badge, got {:?}", row.content[0]);
+        };
+        assert_eq!(badge.content, None);
+        assert_eq!(badge.background, Color::GREEN);
+        assert!(matches!(&badge.child, MockDynamicChild::Text(text) if text.content == "Ada"));
+    }
+}
+
+// End of File
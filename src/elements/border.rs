@@ -0,0 +1,162 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Border wrapper for outlining a view
+//!
+//! Border draws an outline around a single view and rounds the corners of
+//! the outlined area. Like Padding, it wraps its content rather than adding
+//! fields to every view type, so it can be combined with other wrappers in
+//! any order: a padded view with a border outlines the padding, while a
+//! bordered view with padding leaves space outside the outline.
+
+use std::any::Any;
+
+use crate::{style::Color, view::View};
+
+/// Appearance of an outline drawn around a view.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{prelude::*, elements::BorderStyle};
+///
+/// let style = BorderStyle::new(2.0, Color::BLUE).corner_radius(6.0);
+/// assert_eq!(style.width, 2.0);
+/// assert_eq!(style.corner_radius, 6.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BorderStyle {
+    /// Stroke width in logical pixels
+    pub width: f32,
+    /// Stroke color
+    pub color: Color,
+    /// Radius of the rounded corners in logical pixels, or zero for square corners
+    pub corner_radius: f32,
+}
+
+impl BorderStyle {
+    /// Create a square-cornered border with the given width and color.
+    pub const fn new(width: f32, color: Color) -> Self {
+        Self {
+            width,
+            color,
+            corner_radius: 0.0,
+        }
+    }
+
+    /// Round the corners with the given radius.
+    pub fn corner_radius(mut self, radius: f32) -> Self {
+        self.corner_radius = radius;
+        self
+    }
+}
+
+impl Default for BorderStyle {
+    /// A one pixel black border with square corners.
+    fn default() -> Self {
+        Self::new(1.0, Color::BLACK)
+    }
+}
+
+/// A view with an outline drawn around it.
+///
+/// Most code creates borders with [`View::border`]. Border works with
+/// statically typed children as well as boxed dynamic views.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{prelude::*, elements::{BorderStyle, Padding}};
+///
+/// // An outlined, rounded button with space between its label and the outline
+/// let button = Padding::symmetric(Button::new("Open").view(), 12.0, 6.0)
+///     .border(BorderStyle::new(1.0, Color::GRAY).corner_radius(8.0));
+/// assert_eq!(button.style.corner_radius, 8.0);
+/// assert_eq!(button.content.insets.leading, 12.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Border<V> {
+    /// How the outline is drawn
+    pub style: BorderStyle,
+    /// The outlined view
+    pub content: V,
+}
+
+impl<V> Border<V> {
+    /// Wrap a view with an outline.
+    pub fn new(content: V, style: BorderStyle) -> Self {
+        Self { style, content }
+    }
+}
+
+impl<V: View> View for Border<V> {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+// Dynamic border implementation
+impl View for Border<Box<dyn View>> {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        backends::mock::{MockBackend, MockDynamicChild},
+        elements::{Padding, Text, VStack},
+        extraction::{RenderContext, ViewExtractor},
+        model::Model,
+        widgets::Button,
+    };
+
+    #[test]
+    fn border_style_survives_extraction() {
+        let ctx = RenderContext::new();
+        let style = BorderStyle::new(2.0, Color::RED).corner_radius(4.0);
+
+        let text = Text::new("Outlined").border(style);
+        let extracted = MockBackend::extract(&text, &ctx).unwrap();
+        assert_eq!(extracted.style, style);
+        assert_eq!(extracted.content.content, "Outlined");
+
+        let button = Button::new("Rounded").view().border(style);
+        let extracted = MockBackend::extract(&button, &ctx).unwrap();
+        assert_eq!(extracted.style.corner_radius, 4.0);
+        assert_eq!(extracted.content.text, "Rounded");
+    }
+
+    #[test]
+    fn borders_compose_with_padding() {
+        let ctx = RenderContext::new();
+
+        // Padding inside the border, then the border inside more padding
+        let card = Padding::all(
+            Padding::all(Text::new("Card"), 8.0).border(BorderStyle::default()),
+            4.0,
+        );
+        let extracted = MockBackend::extract(&card, &ctx).unwrap();
+        assert_eq!(extracted.insets.top, 4.0);
+        assert_eq!(extracted.content.style, BorderStyle::default());
+        assert_eq!(extracted.content.content.insets.top, 8.0);
+        assert_eq!(extracted.content.content.content.content, "Card");
+
+        // Dynamic children keep their borders
+        let list = VStack::dynamic()
+            .child(Box::new(Text::new("One")))
+            .map_children(|child| Box::new(Border::new(child, BorderStyle::default())));
+        let extracted = MockBackend::extract(&list, &ctx).unwrap();
+        let MockDynamicChild::Border(border) = &extracted.content[0] else {
+            panic!("expected border, got {:?}", extracted.content[0]);
+        };
+        assert_eq!(border.style.width, 1.0);
+        assert!(matches!(border.content, MockDynamicChild::Text(_)));
+    }
+}
+
+// End of File
@@ -0,0 +1,268 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Card container for grouping content on a raised surface
+//!
+//! Dashboards and settings screens group related content into cards: a
+//! filled, rounded surface with padding inside and a soft shadow underneath.
+//! Card bundles those four settings into one wrapper with sensible defaults,
+//! instead of stacking padding, fill, corner and shadow wrappers by hand.
+//! [`Elevation`] and [`CardPadding`] name the common presets.
+
+use std::any::Any;
+
+use crate::{elements::EdgeInsets, style::Color, view::View};
+
+/// A soft shadow drawn underneath a view.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{prelude::*, elements::Shadow};
+///
+/// let shadow = Shadow::new(Color::rgba(0.0, 0.0, 0.0, 0.3), 4.0).offset(0.0, 2.0);
+/// assert_eq!(shadow.radius, 4.0);
+/// assert_eq!(shadow.offset_y, 2.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Shadow {
+    /// Shadow color, usually translucent black
+    pub color: Color,
+    /// Blur radius in logical pixels
+    pub radius: f32,
+    /// Horizontal offset in logical pixels, positive toward the trailing edge
+    pub offset_x: f32,
+    /// Vertical offset in logical pixels, positive downwards
+    pub offset_y: f32,
+}
+
+impl Shadow {
+    /// Create a shadow directly underneath the view with the given blur radius.
+    pub const fn new(color: Color, radius: f32) -> Self {
+        Self {
+            color,
+            radius,
+            offset_x: 0.0,
+            offset_y: 0.0,
+        }
+    }
+
+    /// Move the shadow away from the view.
+    pub const fn offset(mut self, x: f32, y: f32) -> Self {
+        self.offset_x = x;
+        self.offset_y = y;
+        self
+    }
+}
+
+/// How far a card appears to float above the surface behind it.
+///
+/// Each level maps to a preset [`Shadow`]; higher levels cast larger, more
+/// offset shadows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Elevation {
+    /// No shadow, for cards that sit flush with their surroundings
+    Flat,
+    /// A faint shadow for densely packed cards
+    Low,
+    /// The standard card shadow
+    #[default]
+    Medium,
+    /// A pronounced shadow for content that should stand out
+    High,
+}
+
+impl Elevation {
+    /// Get the shadow cast at this elevation, if any.
+    pub const fn shadow(self) -> Option<Shadow> {
+        let color = Color::rgba(0.0, 0.0, 0.0, 0.2);
+        match self {
+            Self::Flat => None,
+            Self::Low => Some(Shadow::new(color, 2.0).offset(0.0, 1.0)),
+            Self::Medium => Some(Shadow::new(color, 6.0).offset(0.0, 3.0)),
+            Self::High => Some(Shadow::new(color, 16.0).offset(0.0, 8.0)),
+        }
+    }
+}
+
+/// Preset amounts of space between a card's edge and its content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CardPadding {
+    /// 8 logical pixels on every edge
+    Compact,
+    /// 16 logical pixels on every edge
+    #[default]
+    Regular,
+    /// 24 logical pixels on every edge
+    Spacious,
+}
+
+impl CardPadding {
+    /// Get the insets for this preset.
+    pub const fn insets(self) -> EdgeInsets {
+        match self {
+            Self::Compact => EdgeInsets::all(8.0),
+            Self::Regular => EdgeInsets::all(16.0),
+            Self::Spacious => EdgeInsets::all(24.0),
+        }
+    }
+}
+
+/// A view drawn on a filled, rounded surface with padding and a shadow.
+///
+/// A new card has [`CardPadding::Regular`] insets, an 8 pixel corner radius,
+/// a [`Elevation::Medium`] shadow, and is filled with the theme's background
+/// color. Each setting can be overridden.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{prelude::*, elements::{Card, CardPadding, EdgeInsets, Elevation}};
+///
+/// let stats = Card::new(VStack::new((Text::new("Revenue"), Text::new("$12,400"))))
+///     .padding(CardPadding::Spacious)
+///     .elevation(Elevation::High)
+///     .corner_radius(12.0);
+///
+/// assert_eq!(stats.insets, EdgeInsets::all(24.0));
+/// assert_eq!(stats.shadow, Elevation::High.shadow());
+/// assert_eq!(stats.background, None);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Card<V> {
+    /// Fill color, or `None` for the theme's background color
+    pub background: Option<Color>,
+    /// Radius of the rounded corners in logical pixels
+    pub corner_radius: f32,
+    /// Space between the card's edge and its content
+    pub insets: EdgeInsets,
+    /// Shadow drawn underneath the card, if any
+    pub shadow: Option<Shadow>,
+    /// The card's content
+    pub content: V,
+}
+
+impl<V> Card<V> {
+    /// Place a view on a card with the default settings.
+    pub fn new(content: V) -> Self {
+        Self {
+            background: None,
+            corner_radius: 8.0,
+            insets: CardPadding::default().insets(),
+            shadow: Elevation::default().shadow(),
+            content,
+        }
+    }
+
+    /// Set the fill color instead of following the theme.
+    pub fn background(mut self, background: Color) -> Self {
+        self.background = Some(background);
+        self
+    }
+
+    /// Set the corner radius, or zero for square corners.
+    ///
+    /// Negative radii are treated as zero.
+    pub fn corner_radius(mut self, radius: f32) -> Self {
+        self.corner_radius = radius.max(0.0);
+        self
+    }
+
+    /// Use one of the preset paddings.
+    pub fn padding(mut self, padding: CardPadding) -> Self {
+        self.insets = padding.insets();
+        self
+    }
+
+    /// Set custom insets between the card's edge and its content.
+    pub fn insets(mut self, insets: EdgeInsets) -> Self {
+        self.insets = insets;
+        self
+    }
+
+    /// Use the shadow of one of the preset elevations.
+    pub fn elevation(mut self, elevation: Elevation) -> Self {
+        self.shadow = elevation.shadow();
+        self
+    }
+
+    /// Use a custom shadow.
+    pub fn shadow(mut self, shadow: Shadow) -> Self {
+        self.shadow = Some(shadow);
+        self
+    }
+}
+
+impl<V: View> View for Card<V> {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+// Dynamic card implementation
+impl View for Card<Box<dyn View>> {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        backends::mock::{MockBackend, MockDynamicChild},
+        elements::{Text, VStack},
+        extraction::{RenderContext, ViewExtractor},
+        style::{Theme, ThemeColors},
+    };
+
+    #[test]
+    fn card_defaults_and_overrides() {
+        let card = Card::new(Text::new("Plain"));
+        assert_eq!(card.insets, EdgeInsets::all(16.0));
+        assert_eq!(card.corner_radius, 8.0);
+        assert_eq!(card.shadow, Elevation::Medium.shadow());
+
+        let flat = card
+            .elevation(Elevation::Flat)
+            .insets(EdgeInsets::symmetric(12.0, 4.0))
+            .corner_radius(-1.0)
+            .background(Color::YELLOW);
+        assert_eq!(flat.shadow, None);
+        assert_eq!(flat.insets.leading, 12.0);
+        assert_eq!(flat.corner_radius, 0.0);
+        assert_eq!(flat.background, Some(Color::YELLOW));
+    }
+
+    #[test]
+    fn card_background_follows_the_theme_unless_set() {
+        let dark = RenderContext::new().with_theme(Theme::new(ThemeColors {
+            background: Color::BLACK,
+            ..ThemeColors::LIGHT
+        }));
+
+        let extracted = MockBackend::extract(&Card::new(Text::new("Revenue")), &dark).unwrap();
+        assert_eq!(extracted.background, Color::BLACK);
+        assert_eq!(extracted.insets, CardPadding::Regular.insets());
+        assert_eq!(extracted.content.content, "Revenue");
+
+        let tinted = Card::new(Box::new(Text::new("Alerts")) as Box<dyn View>)
+            .background(Color::ORANGE)
+            .padding(CardPadding::Compact);
+        let column = VStack::dynamic().child(Box::new(tinted));
+        let extracted = MockBackend::extract(&column, &dark).unwrap();
+        let MockDynamicChild::Card(card) = &extracted.content[0] else {
+            panic!("expected card, got {:?}", extracted.content[0]);
+        };
+        assert_eq!(card.background, Color::ORANGE);
+        assert_eq!(card.insets, EdgeInsets::all(8.0));
+        assert_eq!(card.shadow, Elevation::Medium.shadow());
+        assert!(matches!(&card.content, MockDynamicChild::Text(text) if text.content == "Alerts"));
+    }
+}
+
+// End of File
@@ -0,0 +1,185 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Gauge element for showing a value within a range
+//!
+//! A Gauge displays how far a value sits between a minimum and a maximum, as
+//! a filled bar or arc. Optional zones recolor the fill once the value
+//! crosses a threshold, for example green for normal readings, amber for
+//! warnings, and red for critical ones.
+
+use std::{any::Any, ops::RangeInclusive};
+
+use crate::{style::Color, view::View};
+
+/// The shape a gauge is drawn as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GaugeStyle {
+    /// A straight bar filled from the leading edge
+    #[default]
+    Bar,
+    /// A circular arc filled clockwise
+    Arc,
+}
+
+/// A colored band of a gauge, starting at a threshold value.
+///
+/// A zone applies from its threshold up to the threshold of the next zone.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GaugeZone {
+    /// The value at which this zone begins
+    pub from: f32,
+    /// The fill color while the value is in this zone
+    pub color: Color,
+}
+
+/// A view that shows a value within a range.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{prelude::*, elements::Gauge};
+///
+/// let cpu = Gauge::new(92.0, 0.0..=100.0)
+///     .zone(0.0, Color::GREEN)
+///     .zone(70.0, Color::rgb(1.0, 0.75, 0.0))
+///     .zone(90.0, Color::RED);
+///
+/// assert_eq!(cpu.fraction(), 0.92);
+/// assert_eq!(cpu.zone_color(), Color::RED);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gauge {
+    /// The value being displayed
+    pub value: f32,
+    /// The value at an empty gauge
+    pub min: f32,
+    /// The value at a full gauge
+    pub max: f32,
+    /// Fill color used when the value is below every zone
+    pub color: Color,
+    /// Threshold bands, ordered by their starting value
+    pub zones: Vec<GaugeZone>,
+    /// Whether the gauge is drawn as a bar or an arc
+    pub style: GaugeStyle,
+}
+
+impl Gauge {
+    /// Create a blue bar gauge showing `value` within `range`, with no zones.
+    pub fn new(value: f32, range: RangeInclusive<f32>) -> Self {
+        Self {
+            value,
+            min: *range.start(),
+            max: *range.end(),
+            color: Color::BLUE,
+            zones: Vec::new(),
+            style: GaugeStyle::default(),
+        }
+    }
+
+    /// Set the fill color used when the value is below every zone.
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Add a zone that colors the fill once the value reaches `from`.
+    ///
+    /// Zones can be added in any order.
+    pub fn zone(mut self, from: f32, color: Color) -> Self {
+        let index = self.zones.partition_point(|zone| zone.from <= from);
+        self.zones.insert(index, GaugeZone { from, color });
+        self
+    }
+
+    /// Set whether the gauge is drawn as a bar or an arc.
+    pub fn style(mut self, style: GaugeStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Get how full the gauge is, from 0.0 at `min` to 1.0 at `max`.
+    ///
+    /// Values outside the range are clamped. An empty range, where `max`
+    /// isn't greater than `min`, is full once the value reaches `max`.
+    pub fn fraction(&self) -> f32 {
+        if self.max <= self.min {
+            return if self.value >= self.max { 1.0 } else { 0.0 };
+        }
+        ((self.value - self.min) / (self.max - self.min)).clamp(0.0, 1.0)
+    }
+
+    /// Get the fill color for the current value.
+    ///
+    /// This is the color of the highest zone whose threshold the value has
+    /// reached, or the gauge's base color if it hasn't reached any.
+    pub fn zone_color(&self) -> Color {
+        self.zones
+            .iter()
+            .rev()
+            .find(|zone| self.value >= zone.from)
+            .map_or(self.color, |zone| zone.color)
+    }
+}
+
+impl View for Gauge {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        backends::mock::MockBackend,
+        extraction::{RenderContext, ViewExtractor},
+    };
+
+    const AMBER: Color = Color::rgb(1.0, 0.75, 0.0);
+
+    fn temperature(value: f32) -> Gauge {
+        Gauge::new(value, 20.0..=120.0)
+            .zone(90.0, Color::RED)
+            .zone(20.0, Color::GREEN)
+            .zone(60.0, AMBER)
+    }
+
+    #[test]
+    fn fraction_is_relative_to_range() {
+        assert_eq!(temperature(20.0).fraction(), 0.0);
+        assert_eq!(temperature(45.0).fraction(), 0.25);
+        assert_eq!(temperature(120.0).fraction(), 1.0);
+        assert_eq!(temperature(-10.0).fraction(), 0.0);
+        assert_eq!(temperature(500.0).fraction(), 1.0);
+
+        assert_eq!(Gauge::new(5.0, 5.0..=5.0).fraction(), 1.0);
+        assert_eq!(Gauge::new(4.0, 5.0..=5.0).fraction(), 0.0);
+    }
+
+    #[test]
+    fn zone_color_follows_thresholds() {
+        assert_eq!(temperature(30.0).zone_color(), Color::GREEN);
+        assert_eq!(temperature(60.0).zone_color(), AMBER);
+        assert_eq!(temperature(89.9).zone_color(), AMBER);
+        assert_eq!(temperature(95.0).zone_color(), Color::RED);
+
+        // Below every zone, the base color applies
+        assert_eq!(temperature(10.0).zone_color(), Color::BLUE);
+        assert_eq!(Gauge::new(1.0, 0.0..=2.0).zone_color(), Color::BLUE);
+    }
+
+    #[test]
+    fn gauge_extraction() {
+        let gauge = temperature(100.0).style(GaugeStyle::Arc);
+        let extracted = MockBackend::extract(&gauge, &RenderContext::new()).unwrap();
+        assert_eq!(extracted.fraction, 0.8);
+        assert_eq!(extracted.zone_color, Color::RED);
+        assert_eq!(extracted.style, GaugeStyle::Arc);
+    }
+}
+
+// End of File
@@ -0,0 +1,106 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Stable identifiers for views
+//!
+//! Identified wraps a view with a string id that stays the same across
+//! updates. Ids let models and backends refer to a specific part of the view
+//! tree, for example to re-extract only the subtrees a model marked as dirty,
+//! without depending on the tree's shape.
+
+use std::any::Any;
+
+use crate::view::View;
+
+/// A view tagged with a stable identifier.
+///
+/// Ids should be unique within a tree. Most code creates identified views
+/// with [`View::id`]. Children of dynamic containers should be boxed with
+/// [`Identified::boxed`] so backends can recognize the id at runtime.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{prelude::*, elements::Identified};
+///
+/// let count = Text::new("Count: 0").id("count");
+/// assert_eq!(count.id, "count");
+///
+/// let list = VStack::dynamic()
+///     .child(Box::new(Text::new("Counter").id("title").boxed()))
+///     .child(Box::new(count.boxed()));
+/// assert_eq!(list.content.len(), 2);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Identified<V> {
+    /// The identifier of the wrapped view
+    pub id: String,
+    /// The identified view
+    pub content: V,
+}
+
+impl<V> Identified<V> {
+    /// Tag a view with an identifier.
+    pub fn new(id: impl Into<String>, content: V) -> Self {
+        Self {
+            id: id.into(),
+            content,
+        }
+    }
+}
+
+impl<V: View> Identified<V> {
+    /// Box the content, producing the dynamic form used inside dynamic containers.
+    ///
+    /// The id stays on the outside, where backends can find it without
+    /// knowing the content's concrete type.
+    pub fn boxed(self) -> Identified<Box<dyn View>> {
+        Identified {
+            id: self.id,
+            content: Box::new(self.content),
+        }
+    }
+}
+
+impl<V: View> View for Identified<V> {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+// Dynamic identified view implementation
+impl View for Identified<Box<dyn View>> {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        backends::mock::{MockBackend, MockDynamicChild},
+        elements::{Text, VStack},
+        extraction::{RenderContext, ViewExtractor},
+    };
+
+    #[test]
+    fn identified_extraction_keeps_id() {
+        let ctx = RenderContext::new();
+
+        let extracted = MockBackend::extract(&Text::new("Total").id("total"), &ctx).unwrap();
+        assert_eq!(extracted.id, "total");
+        assert_eq!(extracted.content.content, "Total");
+
+        let list = VStack::dynamic().child(Box::new(Text::new("Row").id("row").boxed()));
+        let extracted = MockBackend::extract(&list, &ctx).unwrap();
+        assert!(matches!(
+            &extracted.content[0],
+            MockDynamicChild::Identified(row)
+                if row.id == "row" && matches!(row.content, MockDynamicChild::Text(_))
+        ));
+    }
+}
+
+// End of File
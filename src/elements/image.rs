@@ -0,0 +1,181 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Image component for displaying pictures and icons
+//!
+//! The Image component is a view that describes which image to show and how
+//! it should fit its frame. Like Text, it's a pure data structure: loading
+//! and decoding the image is left entirely to backends.
+
+use std::{
+    any::Any,
+    fmt::{Debug, Formatter, Result as FormatterResult},
+    sync::Arc,
+};
+
+use crate::view::View;
+
+/// Where an image's pixel data comes from.
+///
+/// Sources are descriptors only. Backends decide how to resolve names, fetch
+/// URLs, and decode bytes.
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ImageSource {
+    /// An image asset looked up by name, such as a bundled icon
+    Named(String),
+    /// Encoded image data held in memory and shared between views
+    Bytes(Arc<Vec<u8>>),
+    /// An image loaded from a URL
+    Url(String),
+}
+
+impl Debug for ImageSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FormatterResult {
+        match self {
+            Self::Named(name) => f.debug_tuple("Named").field(name).finish(),
+            // Avoid dumping the encoded data into debug output
+            Self::Bytes(bytes) => write!(f, "Bytes({} bytes)", bytes.len()),
+            Self::Url(url) => f.debug_tuple("Url").field(url).finish(),
+        }
+    }
+}
+
+/// How an image is scaled to fit its frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ContentMode {
+    /// Scale to fit entirely within the frame, preserving aspect ratio
+    #[default]
+    Fit,
+    /// Scale to cover the whole frame, preserving aspect ratio and cropping
+    Fill,
+    /// Scale to exactly match the frame, ignoring aspect ratio
+    Stretch,
+}
+
+/// A view that displays an image.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{prelude::*, elements::{ContentMode, Image}};
+///
+/// let avatar = Image::url("https://example.com/avatar.png")
+///     .size(64.0, 64.0)
+///     .content_mode(ContentMode::Fill);
+///
+/// assert_eq!(avatar.size, Some((64.0, 64.0)));
+/// assert_eq!(avatar.content_mode, ContentMode::Fill);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Image {
+    /// Where the image data comes from
+    pub source: ImageSource,
+    /// Explicit width and height in logical pixels, if any
+    pub size: Option<(f32, f32)>,
+    /// How the image is scaled to fit its frame
+    pub content_mode: ContentMode,
+}
+
+impl Image {
+    /// Create an image from a source descriptor.
+    ///
+    /// The image has no explicit size and uses [`ContentMode::Fit`].
+    pub fn new(source: ImageSource) -> Self {
+        Self {
+            source,
+            size: None,
+            content_mode: ContentMode::default(),
+        }
+    }
+
+    /// Create an image that refers to a named asset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::{prelude::*, elements::{Image, ImageSource}};
+    ///
+    /// let icon = Image::named("folder");
+    /// assert_eq!(icon.source, ImageSource::Named("folder".to_string()));
+    /// ```
+    pub fn named(name: impl Into<String>) -> Self {
+        Self::new(ImageSource::Named(name.into()))
+    }
+
+    /// Create an image from encoded bytes.
+    ///
+    /// The bytes are shared, so cloning the image doesn't copy the data.
+    pub fn bytes(bytes: impl Into<Arc<Vec<u8>>>) -> Self {
+        Self::new(ImageSource::Bytes(bytes.into()))
+    }
+
+    /// Create an image loaded from a URL.
+    pub fn url(url: impl Into<String>) -> Self {
+        Self::new(ImageSource::Url(url.into()))
+    }
+
+    /// Set an explicit size for this image.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - Width in logical pixels
+    /// * `height` - Height in logical pixels
+    pub fn size(mut self, width: f32, height: f32) -> Self {
+        self.size = Some((width, height));
+        self
+    }
+
+    /// Set how this image is scaled to fit its frame.
+    pub fn content_mode(mut self, mode: ContentMode) -> Self {
+        self.content_mode = mode;
+        self
+    }
+}
+
+impl View for Image {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        backends::mock::MockBackend,
+        extraction::{RenderContext, ViewExtractor},
+    };
+
+    #[test]
+    fn image_extraction_preserves_descriptor() {
+        let ctx = RenderContext::new();
+
+        let data = Arc::new(vec![0x89, b'P', b'N', b'G']);
+        let image = Image::bytes(data.clone())
+            .size(32.0, 16.0)
+            .content_mode(ContentMode::Stretch);
+        let extracted = MockBackend::extract(&image, &ctx).unwrap();
+        assert!(matches!(
+            &extracted.source,
+            ImageSource::Bytes(bytes) if Arc::ptr_eq(bytes, &data)
+        ));
+        assert_eq!(extracted.size, Some((32.0, 16.0)));
+        assert_eq!(extracted.content_mode, ContentMode::Stretch);
+
+        let extracted = MockBackend::extract(&Image::named("logo"), &ctx).unwrap();
+        assert_eq!(extracted.source, ImageSource::Named("logo".to_string()));
+        assert_eq!(extracted.size, None);
+        assert_eq!(extracted.content_mode, ContentMode::Fit);
+    }
+
+    #[test]
+    fn image_source_debug_hides_bytes() {
+        let source = ImageSource::Bytes(Arc::new(vec![0; 1024]));
+        assert_eq!(format!("{:?}", source), "Bytes(1024 bytes)");
+    }
+}
+
+// End of File
@@ -0,0 +1,128 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Label element pairing an icon with a title
+//!
+//! Buttons, menu items and list rows often show a small glyph in front of
+//! their text. A Label keeps that pair together as one view, so backends can
+//! treat the icon and title as a unit instead of recognizing an ad hoc
+//! horizontal stack.
+
+use std::any::Any;
+
+use crate::{elements::Text, view::View};
+
+/// A title with an optional leading icon.
+///
+/// The icon is a text glyph, such as an emoji or a character from an icon
+/// font, placed before the title with [`spacing`](Label::spacing) between
+/// them.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{prelude::*, elements::Label, style::FontWeight};
+///
+/// let inbox = Label::new("Inbox")
+///     .icon("📥")
+///     .with_title(|title| title.font_weight(FontWeight::BOLD));
+///
+/// assert_eq!(inbox.title.content, "Inbox");
+/// assert_eq!(inbox.icon.as_ref().map(|icon| icon.content.as_str()), Some("📥"));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Label {
+    /// The leading glyph, if any
+    pub icon: Option<Text>,
+    /// The title text
+    pub title: Text,
+    /// Space between the icon and the title in logical pixels
+    pub spacing: f32,
+}
+
+impl Label {
+    /// Space between icon and title unless another is configured.
+    pub const DEFAULT_SPACING: f32 = 6.0;
+
+    /// Create a label with a title and no icon.
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            icon: None,
+            title: Text::new(title.into()),
+            spacing: Self::DEFAULT_SPACING,
+        }
+    }
+
+    /// Set the glyph shown before the title.
+    pub fn icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(Text::new(icon.into()));
+        self
+    }
+
+    /// Style the icon, if one is set.
+    pub fn with_icon(mut self, f: impl FnOnce(Text) -> Text) -> Self {
+        self.icon = self.icon.map(f);
+        self
+    }
+
+    /// Style the title.
+    pub fn with_title(mut self, f: impl FnOnce(Text) -> Text) -> Self {
+        self.title = f(self.title);
+        self
+    }
+
+    /// Set the space between the icon and the title.
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+}
+
+impl View for Label {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        backends::mock::{MockBackend, MockDynamicChild},
+        elements::VStack,
+        extraction::{RenderContext, ViewExtractor},
+        style::Color,
+    };
+
+    #[test]
+    fn label_extracts_icon_title_and_spacing() {
+        let ctx = RenderContext::new().with_text_scale(2.0);
+        let label = Label::new("Settings")
+            .icon("⚙")
+            .with_icon(|icon| icon.color(Color::BLUE))
+            .spacing(4.0);
+
+        let extracted = MockBackend::extract(&label, &ctx).unwrap();
+        let icon = extracted.icon.expect("icon");
+        assert_eq!(icon.content, "⚙");
+        assert_eq!(icon.color, Color::BLUE);
+        assert_eq!(extracted.title.content, "Settings");
+        assert_eq!(extracted.title.font_size, 32.0);
+        assert_eq!(extracted.spacing, 4.0);
+
+        // Styling an absent icon does nothing
+        let plain = Label::new("Plain").with_icon(|icon| icon.color(Color::RED));
+        assert_eq!(plain.icon, None);
+        assert_eq!(plain.spacing, Label::DEFAULT_SPACING);
+
+        let list = VStack::dynamic().child(Box::new(plain));
+        let extracted = MockBackend::extract(&list, &ctx).unwrap();
+        assert!(matches!(
+            &extracted.content[0],
+            MockDynamicChild::Label(label) if label.icon.is_none() && label.title.content == "Plain"
+        ));
+    }
+}
+
+// End of File
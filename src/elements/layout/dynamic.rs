@@ -2,312 +2,11 @@
 // If a copy of the MPL was not distributed with this file,
 // You can obtain one at <https://mozilla.org/MPL/2.0/>.
 
-//! Layout container views for organizing child views
-//!
-//! Container views organize child views in specific layouts. They are pure
-//! data structures that describe layout intent - the actual positioning
-//! and sizing is handled by backends through the ViewExtractor pattern.
-
-use std::any::Any;
+//! Stacks whose children are chosen at runtime
 
+use super::{Alignment2D, HStack, StackDefaults, VStack, ZStack};
 use crate::view::View;
 
-/// Alignment options for layout containers.
-///
-/// Determines how child views are aligned within their container.
-/// The actual alignment behavior is implemented by backends during extraction.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum Alignment {
-    /// Align to the leading edge (left in LTR, right in RTL, top in vertical)
-    Leading,
-    /// Center alignment
-    Center,
-    /// Align to the trailing edge (right in LTR, left in RTL, bottom in vertical)
-    Trailing,
-}
-
-impl Default for Alignment {
-    fn default() -> Self {
-        Self::Leading
-    }
-}
-
-/// A flexible space that expands to fill available space.
-///
-/// Spacer is useful for pushing elements apart in stacks, creating flexible
-/// layouts, and centering content. The actual space calculation is performed
-/// by backends during extraction.
-///
-/// # Examples
-///
-/// ```
-/// use ironwood::{HStack, Text, Spacer};
-///
-/// // Push button to the right side
-/// let toolbar = HStack::new((
-///     Text::new("Title"),
-///     Spacer::new(),
-///     Text::new("Button"),
-/// ));
-/// ```
-#[derive(Debug, Clone, PartialEq)]
-pub struct Spacer {
-    /// Minimum size for the spacer in logical pixels
-    pub min_size: f32,
-}
-
-impl Spacer {
-    /// Creates a new spacer with no minimum size.
-    ///
-    /// The spacer will expand to fill all available space.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ironwood::Spacer;
-    ///
-    /// let spacer = Spacer::new();
-    /// ```
-    pub fn new() -> Self {
-        Self { min_size: 0.0 }
-    }
-
-    /// Creates a spacer with a minimum size.
-    ///
-    /// The spacer will take at least the specified size, but can expand further
-    /// if more space is available.
-    ///
-    /// # Arguments
-    ///
-    /// * `min_size` - The minimum size in logical pixels
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ironwood::Spacer;
-    ///
-    /// let spacer = Spacer::min_size(20.0);
-    /// ```
-    pub fn min_size(min_size: f32) -> Self {
-        Self { min_size }
-    }
-}
-
-impl Default for Spacer {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
-impl View for Spacer {
-    fn as_any(&self) -> &dyn Any {
-        self
-    }
-}
-
-/// Vertical stack container that arranges children vertically.
-///
-/// VStack arranges its children in a vertical column with configurable spacing
-/// between elements. The actual layout calculations are performed by backends
-/// during extraction.
-///
-/// # Examples
-///
-/// ```
-/// use ironwood::{VStack, Text};
-///
-/// let stack = VStack::new((
-///     Text::new("First item"),
-///     Text::new("Second item"),
-/// )).spacing(8.0);
-/// ```
-#[derive(Debug, Clone, PartialEq)]
-pub struct VStack<T> {
-    /// The child views to arrange vertically
-    pub content: T,
-    /// Horizontal alignment of child views
-    pub alignment: Alignment,
-    /// Spacing between child views in logical pixels
-    pub spacing: f32,
-}
-
-impl<T: View> VStack<T> {
-    /// Creates a new vertical stack with the given content.
-    ///
-    /// # Arguments
-    ///
-    /// * `content` - The child views to arrange vertically
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ironwood::{VStack, Text};
-    ///
-    /// let stack = VStack::new((
-    ///     Text::new("Top"),
-    ///     Text::new("Bottom"),
-    /// ));
-    /// ```
-    pub fn new(content: T) -> Self {
-        Self {
-            content,
-            alignment: Alignment::default(),
-            spacing: 0.0,
-        }
-    }
-
-    /// Sets the spacing between child views.
-    ///
-    /// # Arguments
-    ///
-    /// * `spacing` - The spacing in logical pixels
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ironwood::{VStack, Text};
-    ///
-    /// let stack = VStack::new((
-    ///     Text::new("Top"),
-    ///     Text::new("Bottom"),
-    /// )).spacing(16.0);
-    /// ```
-    pub fn spacing(mut self, spacing: f32) -> Self {
-        self.spacing = spacing;
-        self
-    }
-
-    /// Sets the horizontal alignment of child views.
-    ///
-    /// # Arguments
-    ///
-    /// * `alignment` - The alignment option for child views
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ironwood::{VStack, Text, Alignment};
-    ///
-    /// let stack = VStack::new((
-    ///     Text::new("Centered"),
-    ///     Text::new("Content"),
-    /// )).alignment(Alignment::Center);
-    /// ```
-    pub fn alignment(mut self, alignment: Alignment) -> Self {
-        self.alignment = alignment;
-        self
-    }
-}
-
-impl<T: View> View for VStack<T> {
-    fn as_any(&self) -> &dyn Any {
-        self
-    }
-}
-
-/// Horizontal stack container that arranges children horizontally.
-///
-/// HStack arranges its children in a horizontal row with configurable spacing
-/// between elements. The actual layout calculations are performed by backends
-/// during extraction.
-///
-/// # Examples
-///
-/// ```
-/// use ironwood::{HStack, Text};
-///
-/// let stack = HStack::new((
-///     Text::new("Left"),
-///     Text::new("Right"),
-/// )).spacing(8.0);
-/// ```
-#[derive(Debug, Clone, PartialEq)]
-pub struct HStack<T> {
-    /// The child views to arrange horizontally
-    pub content: T,
-    /// Vertical alignment of child views
-    pub alignment: Alignment,
-    /// Spacing between child views in logical pixels
-    pub spacing: f32,
-}
-
-impl<T: View> HStack<T> {
-    /// Creates a new horizontal stack with the given content.
-    ///
-    /// # Arguments
-    ///
-    /// * `content` - The child views to arrange horizontally
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ironwood::{HStack, Text};
-    ///
-    /// let stack = HStack::new((
-    ///     Text::new("Left"),
-    ///     Text::new("Right"),
-    /// ));
-    /// ```
-    pub fn new(content: T) -> Self {
-        Self {
-            content,
-            alignment: Alignment::default(),
-            spacing: 0.0,
-        }
-    }
-
-    /// Sets the spacing between child views.
-    ///
-    /// # Arguments
-    ///
-    /// * `spacing` - The spacing in logical pixels
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ironwood::{HStack, Text};
-    ///
-    /// let stack = HStack::new((
-    ///     Text::new("Left"),
-    ///     Text::new("Right"),
-    /// )).spacing(16.0);
-    /// ```
-    pub fn spacing(mut self, spacing: f32) -> Self {
-        self.spacing = spacing;
-        self
-    }
-
-    /// Sets the vertical alignment of child views.
-    ///
-    /// # Arguments
-    ///
-    /// * `alignment` - The alignment option for child views
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ironwood::{HStack, Text, Alignment};
-    ///
-    /// let stack = HStack::new((
-    ///     Text::new("Left"),
-    ///     Text::new("Right"),
-    /// )).alignment(Alignment::Center);
-    /// ```
-    pub fn alignment(mut self, alignment: Alignment) -> Self {
-        self.alignment = alignment;
-        self
-    }
-}
-
-impl<T: View> View for HStack<T> {
-    fn as_any(&self) -> &dyn Any {
-        self
-    }
-}
-
-// Dynamic container implementations for Vec<Box<dyn View>>
-// These provide the same API as the tuple-based containers but work with dynamic children
-
 impl VStack<Vec<Box<dyn View>>> {
     /// Create a new empty dynamic vertical stack.
     ///
@@ -332,13 +31,38 @@ impl VStack<Vec<Box<dyn View>>> {
     /// form = form.child(Box::new(Button::new("Submit").view()));
     /// ```
     pub fn dynamic() -> Self {
+        let defaults = StackDefaults::current();
         Self {
             content: Vec::new(),
-            alignment: Alignment::Leading,
-            spacing: 0.0,
+            alignment: defaults.alignment,
+            spacing: defaults.spacing,
         }
     }
 
+    /// Create a new empty dynamic stack with room for `capacity` children.
+    ///
+    /// This behaves like [`VStack::dynamic`] but pre-allocates the child list,
+    /// so adding up to `capacity` children never reallocates.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// // Build a long list without repeated reallocation
+    /// let rows = 1000;
+    /// let mut list = VStack::dynamic_with_capacity(rows);
+    /// for i in 0..rows {
+    ///     list = list.child(Box::new(Text::new(format!("Row {}", i))));
+    /// }
+    /// assert_eq!(list.content.len(), rows);
+    /// ```
+    pub fn dynamic_with_capacity(capacity: usize) -> Self {
+        let mut stack = Self::dynamic();
+        stack.content.reserve_exact(capacity);
+        stack
+    }
+
     /// Set the children for this stack.
     ///
     /// ## Example
@@ -407,6 +131,31 @@ impl VStack<Vec<Box<dyn View>>> {
         self
     }
 
+    /// Transform every child currently in this stack with the same function.
+    ///
+    /// Children keep their order, and children added afterwards with
+    /// [`VStack::child`] are not affected.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// // Put a spacer before every entry
+    /// let list = VStack::dynamic()
+    ///     .child(Box::new(Text::new("First")))
+    ///     .child(Box::new(Text::new("Second")))
+    ///     .map_children(|child| {
+    ///         Box::new(HStack::dynamic().child(Box::new(Spacer::new())).child(child))
+    ///     })
+    ///     .child(Box::new(Text::new("Footer")));
+    /// assert_eq!(list.content.len(), 3);
+    /// ```
+    pub fn map_children(mut self, f: impl Fn(Box<dyn View>) -> Box<dyn View>) -> Self {
+        self.content = self.content.into_iter().map(f).collect();
+        self
+    }
+
     /// Convenience for creating dynamic stacks from collections.
     ///
     /// ## Example
@@ -430,6 +179,38 @@ impl VStack<Vec<Box<dyn View>>> {
     {
         Self::dynamic().children(iter.into_iter().collect())
     }
+
+    /// Create a dynamic stack with a separator between each pair of children.
+    ///
+    /// `separator` is called once per gap to build a fresh separator view.
+    /// No separator is added before the first child or after the last, so
+    /// empty and single-child inputs contain no separators at all.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// // Divide list sections with a spacer
+    /// let sections = ["Inbox", "Drafts", "Sent"]
+    ///     .map(|name| Box::new(Text::new(name)) as Box<dyn View>);
+    /// let stack = VStack::interspersed(sections, || Box::new(Spacer::min_size(1.0)));
+    /// assert_eq!(stack.content.len(), 5);
+    /// ```
+    pub fn interspersed<I, F>(iter: I, separator: F) -> Self
+    where
+        I: IntoIterator<Item = Box<dyn View>>,
+        F: Fn() -> Box<dyn View>,
+    {
+        let mut content = Vec::new();
+        for (index, child) in iter.into_iter().enumerate() {
+            if index > 0 {
+                content.push(separator());
+            }
+            content.push(child);
+        }
+        Self::dynamic().children(content)
+    }
 }
 
 impl HStack<Vec<Box<dyn View>>> {
@@ -456,13 +237,38 @@ impl HStack<Vec<Box<dyn View>>> {
     /// toolbar = toolbar.child(Box::new(Button::new("⋮").view()));
     /// ```
     pub fn dynamic() -> Self {
+        let defaults = StackDefaults::current();
         Self {
             content: Vec::new(),
-            alignment: Alignment::Leading,
-            spacing: 0.0,
+            alignment: defaults.alignment,
+            spacing: defaults.spacing,
         }
     }
 
+    /// Create a new empty dynamic stack with room for `capacity` children.
+    ///
+    /// This behaves like [`HStack::dynamic`] but pre-allocates the child list,
+    /// so adding up to `capacity` children never reallocates.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// // Pre-size a row of tab buttons
+    /// let tabs = ["Home", "Search", "Profile"];
+    /// let mut tab_bar = HStack::dynamic_with_capacity(tabs.len());
+    /// for tab in tabs {
+    ///     tab_bar = tab_bar.child(Box::new(Button::new(tab).view()));
+    /// }
+    /// assert!(tab_bar.content.capacity() >= tabs.len());
+    /// ```
+    pub fn dynamic_with_capacity(capacity: usize) -> Self {
+        let mut stack = Self::dynamic();
+        stack.content.reserve_exact(capacity);
+        stack
+    }
+
     /// Set the children for this stack.
     ///
     /// ## Example
@@ -538,6 +344,31 @@ impl HStack<Vec<Box<dyn View>>> {
         self
     }
 
+    /// Transform every child currently in this stack with the same function.
+    ///
+    /// Children keep their order, and children added afterwards with
+    /// [`HStack::child`] are not affected.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// // Put a spacer before every entry
+    /// let list = HStack::dynamic()
+    ///     .child(Box::new(Text::new("First")))
+    ///     .child(Box::new(Text::new("Second")))
+    ///     .map_children(|child| {
+    ///         Box::new(VStack::dynamic().child(Box::new(Spacer::new())).child(child))
+    ///     })
+    ///     .child(Box::new(Text::new("Footer")));
+    /// assert_eq!(list.content.len(), 3);
+    /// ```
+    pub fn map_children(mut self, f: impl Fn(Box<dyn View>) -> Box<dyn View>) -> Self {
+        self.content = self.content.into_iter().map(f).collect();
+        self
+    }
+
     /// Convenience for creating dynamic stacks from collections.
     ///
     /// ## Example
@@ -562,6 +393,94 @@ impl HStack<Vec<Box<dyn View>>> {
     {
         Self::dynamic().children(iter.into_iter().collect())
     }
+
+    /// Create a dynamic stack with a separator between each pair of children.
+    ///
+    /// `separator` is called once per gap to build a fresh separator view.
+    /// No separator is added before the first child or after the last, so
+    /// empty and single-child inputs contain no separators at all.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// // Build a breadcrumb trail
+    /// let crumbs = ["Home", "Projects", "Ironwood"]
+    ///     .map(|crumb| Box::new(Text::new(crumb)) as Box<dyn View>);
+    /// let stack = HStack::interspersed(crumbs, || Box::new(Text::new("›")));
+    /// assert_eq!(stack.content.len(), 5);
+    /// ```
+    pub fn interspersed<I, F>(iter: I, separator: F) -> Self
+    where
+        I: IntoIterator<Item = Box<dyn View>>,
+        F: Fn() -> Box<dyn View>,
+    {
+        let mut content = Vec::new();
+        for (index, child) in iter.into_iter().enumerate() {
+            if index > 0 {
+                content.push(separator());
+            }
+            content.push(child);
+        }
+        Self::dynamic().children(content)
+    }
+}
+
+impl ZStack<Vec<Box<dyn View>>> {
+    /// Create a new empty dynamic depth stack.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ironwood::{prelude::*, elements::ZStack};
+    ///
+    /// // Only show the modal overlay while it is open
+    /// let modal_open = true;
+    /// let mut screen = ZStack::dynamic().child(Box::new(Text::new("Main content")));
+    ///
+    /// if modal_open {
+    ///     screen = screen.child(Box::new(Text::new("Are you sure?")));
+    /// }
+    /// ```
+    pub fn dynamic() -> Self {
+        Self {
+            content: Vec::new(),
+            alignment: Alignment2D::default(),
+        }
+    }
+
+    /// Set the children for this stack, from back to front.
+    pub fn children(mut self, children: Vec<Box<dyn View>>) -> Self {
+        self.content = children;
+        self
+    }
+
+    /// Add a single child in front of the existing children.
+    pub fn child(mut self, child: Box<dyn View>) -> Self {
+        self.content.push(child);
+        self
+    }
+
+    /// Convenience for creating dynamic stacks from collections.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ironwood::{prelude::*, elements::ZStack};
+    ///
+    /// // Layer a stack of cards
+    /// let cards = ["Back", "Middle", "Front"]
+    ///     .map(|card| Box::new(Text::new(card)) as Box<dyn View>);
+    /// let deck = ZStack::from_children(cards);
+    /// assert_eq!(deck.content.len(), 3);
+    /// ```
+    pub fn from_children<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = Box<dyn View>>,
+    {
+        Self::dynamic().children(iter.into_iter().collect())
+    }
 }
 
 #[cfg(test)]
@@ -570,29 +489,11 @@ mod tests {
     use crate::{
         backends::mock::MockBackend,
         elements::Text,
+        elements::{Alignment, Spacer},
         extraction::{RenderContext, ViewExtractor},
         model::Model,
     };
 
-    #[test]
-    fn container_configuration_and_extraction() {
-        let ctx = RenderContext::new();
-
-        // Test spacer configuration
-        let spacer = Spacer::min_size(100.0);
-        assert_eq!(spacer.min_size, 100.0);
-
-        // Test container spacing with extraction
-        let vstack = VStack::new(Text::new("Test")).spacing(5.0);
-        let extracted = MockBackend::extract(&vstack, &ctx).unwrap();
-        assert_eq!(extracted.spacing, 5.0);
-
-        // Test fractional values work correctly
-        let fractional_spacing = VStack::new(Text::new("Test")).spacing(2.5);
-        let extracted = MockBackend::extract(&fractional_spacing, &ctx).unwrap();
-        assert_eq!(extracted.spacing, 2.5);
-    }
-
     #[test]
     fn dynamic_container_patterns() {
         use crate::widgets::Button;
@@ -674,6 +575,26 @@ mod tests {
         assert_eq!(extracted.content.len(), 1000);
     }
 
+    #[test]
+    fn dynamic_with_capacity_avoids_reallocation() {
+        // Pre-sizing should keep the child buffer in place for the whole build
+        let mut stack = VStack::dynamic_with_capacity(1000);
+        let initial_capacity = stack.content.capacity();
+        let initial_buffer = stack.content.as_ptr();
+        assert!(initial_capacity >= 1000);
+
+        for i in 0..1000 {
+            stack = stack.child(Box::new(Text::new(format!("Item {}", i))));
+            assert_eq!(stack.content.capacity(), initial_capacity);
+        }
+        assert_eq!(stack.content.len(), 1000);
+        assert_eq!(stack.content.as_ptr(), initial_buffer);
+
+        let hstack = HStack::dynamic_with_capacity(3);
+        assert!(hstack.content.is_empty());
+        assert!(hstack.content.capacity() >= 3);
+    }
+
     #[test]
     fn mixed_static_dynamic_integration() {
         use crate::widgets::Button;
@@ -723,6 +644,81 @@ mod tests {
         assert_eq!(moved_stack.content.len(), 2);
         assert_eq!(moved_stack.spacing, 8.0);
     }
+
+    #[test]
+    fn map_children_wraps_each_existing_child() {
+        use crate::backends::mock::MockDynamicChild;
+
+        let ctx = RenderContext::new();
+        let stack = VStack::dynamic()
+            .child(Box::new(Text::new("One")))
+            .child(Box::new(Text::new("Two")))
+            .child(Box::new(Text::new("Three")))
+            .map_children(|child| {
+                Box::new(
+                    HStack::dynamic()
+                        .spacing(4.0)
+                        .child(Box::new(Spacer::min_size(8.0)))
+                        .child(child),
+                )
+            })
+            .child(Box::new(Text::new("Unwrapped")));
+
+        let extracted = MockBackend::extract(&stack, &ctx).unwrap();
+        assert_eq!(extracted.content.len(), 4);
+
+        for (child, label) in extracted.content[..3].iter().zip(["One", "Two", "Three"]) {
+            let MockDynamicChild::HStack(wrapper) = child else {
+                panic!("expected padded wrapper, got {:?}", child);
+            };
+            assert_eq!(wrapper.spacing, 4.0);
+            assert!(matches!(
+                &wrapper.content[0],
+                MockDynamicChild::Spacer(spacer) if spacer.min_size == 8.0
+            ));
+            assert!(matches!(
+                &wrapper.content[1],
+                MockDynamicChild::Text(text) if text.content == label
+            ));
+        }
+        assert!(matches!(
+            &extracted.content[3],
+            MockDynamicChild::Text(text) if text.content == "Unwrapped"
+        ));
+    }
+
+    #[test]
+    fn interspersed_places_separators_between_children() {
+        use crate::backends::mock::MockDynamicChild;
+
+        let ctx = RenderContext::new();
+        let divider = || Box::new(Spacer::min_size(1.0)) as Box<dyn View>;
+        let texts = |labels: &[&'static str]| {
+            labels
+                .iter()
+                .map(|label| Box::new(Text::new(*label)) as Box<dyn View>)
+                .collect::<Vec<_>>()
+        };
+
+        let stack = VStack::interspersed(texts(&["A", "B", "C"]), divider);
+        let extracted = MockBackend::extract(&stack, &ctx).unwrap();
+        assert_eq!(extracted.content.len(), 5);
+        for (index, child) in extracted.content.iter().enumerate() {
+            if index % 2 == 0 {
+                assert!(matches!(child, MockDynamicChild::Text(_)));
+            } else {
+                assert!(matches!(child, MockDynamicChild::Spacer(_)));
+            }
+        }
+
+        assert!(VStack::interspersed(texts(&[]), divider).content.is_empty());
+        assert_eq!(
+            HStack::interspersed(texts(&["Only"]), divider)
+                .content
+                .len(),
+            1
+        );
+    }
 }
 
 // End of File
@@ -0,0 +1,308 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Grid and flow layouts
+
+use std::any::Any;
+
+use super::StackDefaults;
+use crate::view::View;
+
+/// A container that arranges children in a grid with a fixed number of columns.
+///
+/// Children flow left to right and wrap onto a new row after every
+/// `columns` children, so rows never need to be built by hand. The last row
+/// may be partially filled. Like dynamic stacks, a grid holds boxed children
+/// of any view type. Spacing starts from the installed [`StackDefaults`].
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{prelude::*, elements::Grid};
+///
+/// let keypad = Grid::from_children(3, (1..=9).map(|key| {
+///     Box::new(Button::new(key.to_string()).view()) as Box<dyn View>
+/// }))
+/// .spacing(4.0);
+///
+/// assert_eq!(keypad.rows(), 3);
+/// assert_eq!(keypad.position(4), (1, 1));
+/// ```
+#[derive(Debug)]
+pub struct Grid {
+    /// The child views, in reading order
+    pub content: Vec<Box<dyn View>>,
+    /// Number of children per row; zero is treated as one
+    pub columns: usize,
+    /// Vertical spacing between rows in logical pixels
+    pub row_spacing: f32,
+    /// Horizontal spacing between columns in logical pixels
+    pub column_spacing: f32,
+}
+
+impl Grid {
+    /// Create an empty grid with the given number of columns (at least one).
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ironwood::{prelude::*, elements::{Grid, Image}};
+    ///
+    /// let gallery = Grid::new(4)
+    ///     .child(Box::new(Image::named("sunset")))
+    ///     .child(Box::new(Image::named("harbor")));
+    /// assert_eq!(gallery.rows(), 1);
+    /// ```
+    pub fn new(columns: usize) -> Self {
+        let defaults = StackDefaults::current();
+        Self {
+            content: Vec::new(),
+            columns: columns.max(1),
+            row_spacing: defaults.spacing,
+            column_spacing: defaults.spacing,
+        }
+    }
+
+    /// Create a grid from a collection of children.
+    pub fn from_children<I>(columns: usize, iter: I) -> Self
+    where
+        I: IntoIterator<Item = Box<dyn View>>,
+    {
+        Self::new(columns).children(iter.into_iter().collect())
+    }
+
+    /// Set the children for this grid, in reading order.
+    pub fn children(mut self, children: Vec<Box<dyn View>>) -> Self {
+        self.content = children;
+        self
+    }
+
+    /// Add a single child after the existing children.
+    pub fn child(mut self, child: Box<dyn View>) -> Self {
+        self.content.push(child);
+        self
+    }
+
+    /// Set the vertical spacing between rows.
+    pub fn row_spacing(mut self, spacing: f32) -> Self {
+        self.row_spacing = spacing;
+        self
+    }
+
+    /// Set the horizontal spacing between columns.
+    pub fn column_spacing(mut self, spacing: f32) -> Self {
+        self.column_spacing = spacing;
+        self
+    }
+
+    /// Set the same spacing between rows and between columns.
+    pub fn spacing(self, spacing: f32) -> Self {
+        self.row_spacing(spacing).column_spacing(spacing)
+    }
+
+    /// Get the number of rows the children fill.
+    pub fn rows(&self) -> usize {
+        self.content.len().div_ceil(self.columns.max(1))
+    }
+
+    /// Get the row and column the child at `index` is placed in.
+    pub fn position(&self, index: usize) -> (usize, usize) {
+        let columns = self.columns.max(1);
+        (index / columns, index % columns)
+    }
+}
+
+impl View for Grid {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// A container that lays children out in lines, wrapping when a line is full.
+///
+/// Children are placed left to right and move onto a new line whenever the
+/// next child would overflow `max_width`, which suits chips, tags and other
+/// content whose count varies. How many children fit on a line depends on
+/// their measured sizes, so the flow layout only records the constraint and
+/// spacings; backends perform the actual wrapping. Spacing starts from the
+/// installed [`StackDefaults`].
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{prelude::*, elements::FlowLayout};
+///
+/// let tags = FlowLayout::from_children(240.0, ["rust", "ui", "declarative"].map(|tag| {
+///     Box::new(Text::new(tag)) as Box<dyn View>
+/// }))
+/// .item_spacing(6.0)
+/// .line_spacing(4.0);
+///
+/// assert_eq!(tags.content.len(), 3);
+/// assert_eq!(tags.max_width, 240.0);
+/// ```
+#[derive(Debug)]
+pub struct FlowLayout {
+    /// The child views, in reading order
+    pub content: Vec<Box<dyn View>>,
+    /// Width a line may reach before wrapping, in logical pixels
+    pub max_width: f32,
+    /// Vertical spacing between lines in logical pixels
+    pub line_spacing: f32,
+    /// Horizontal spacing between children on a line in logical pixels
+    pub item_spacing: f32,
+}
+
+impl FlowLayout {
+    /// Create an empty flow layout that wraps at `max_width`.
+    ///
+    /// Negative or NaN widths are treated as zero, which places every child
+    /// on its own line.
+    pub fn new(max_width: f32) -> Self {
+        let defaults = StackDefaults::current();
+        Self {
+            content: Vec::new(),
+            max_width: max_width.max(0.0),
+            line_spacing: defaults.spacing,
+            item_spacing: defaults.spacing,
+        }
+    }
+
+    /// Create a flow layout from a collection of children.
+    pub fn from_children<I>(max_width: f32, iter: I) -> Self
+    where
+        I: IntoIterator<Item = Box<dyn View>>,
+    {
+        Self::new(max_width).children(iter.into_iter().collect())
+    }
+
+    /// Set the children for this flow layout, in reading order.
+    pub fn children(mut self, children: Vec<Box<dyn View>>) -> Self {
+        self.content = children;
+        self
+    }
+
+    /// Add a single child after the existing children.
+    pub fn child(mut self, child: Box<dyn View>) -> Self {
+        self.content.push(child);
+        self
+    }
+
+    /// Set the vertical spacing between wrapped lines.
+    pub fn line_spacing(mut self, spacing: f32) -> Self {
+        self.line_spacing = spacing;
+        self
+    }
+
+    /// Set the horizontal spacing between children on the same line.
+    pub fn item_spacing(mut self, spacing: f32) -> Self {
+        self.item_spacing = spacing;
+        self
+    }
+}
+
+impl View for FlowLayout {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        backends::mock::{MockBackend, MockGrid},
+        elements::{Spacer, Text, VStack},
+        extraction::{RenderContext, ViewExtractor},
+    };
+
+    #[test]
+    fn grid_flows_children_into_rows() {
+        use crate::backends::mock::MockDynamicChild;
+
+        let ctx = RenderContext::new();
+        let cells = (1..=5).map(|n| Box::new(Text::new(n.to_string())) as Box<dyn View>);
+        let grid = Grid::from_children(2, cells)
+            .row_spacing(6.0)
+            .column_spacing(4.0);
+        assert_eq!(grid.rows(), 3);
+        assert_eq!(grid.position(0), (0, 0));
+        assert_eq!(grid.position(3), (1, 1));
+        assert_eq!(grid.position(4), (2, 0));
+
+        let extracted = MockBackend::extract(&grid, &ctx).unwrap();
+        assert_eq!(extracted.columns, 2);
+        assert_eq!(extracted.row_spacing, 6.0);
+        assert_eq!(extracted.column_spacing, 4.0);
+
+        let rows: Vec<Vec<String>> = extracted
+            .rows()
+            .map(|row| {
+                row.iter()
+                    .map(|cell| match cell {
+                        MockDynamicChild::Text(text) => text.content.clone(),
+                        other => panic!("unexpected cell {:?}", other),
+                    })
+                    .collect()
+            })
+            .collect();
+        assert_eq!(rows, vec![vec!["1", "2"], vec!["3", "4"], vec!["5"]]);
+
+        // Grids nest inside dynamic containers, and zero columns means one
+        let stack = VStack::dynamic().child(Box::new(Grid::new(0).spacing(2.0)));
+        let extracted = MockBackend::extract(&stack, &ctx).unwrap();
+        let MockDynamicChild::Grid(grid) = &extracted.content[0] else {
+            panic!("expected grid, got {:?}", extracted.content[0]);
+        };
+        assert_eq!(grid.columns, 1);
+        assert_eq!((grid.row_spacing, grid.column_spacing), (2.0, 2.0));
+        assert!(grid.content.is_empty());
+
+        // The field is public, so zero can also be assigned directly
+        let mut direct =
+            Grid::from_children(2, (0..3).map(|_| Box::new(Spacer::new()) as Box<dyn View>));
+        direct.columns = 0;
+        assert_eq!(direct.rows(), 3);
+        assert_eq!(direct.position(2), (2, 0));
+        let extracted = MockBackend::extract(&direct, &ctx).unwrap();
+        assert_eq!(extracted.columns, 1);
+        assert_eq!(extracted.rows().count(), 3);
+        let zero = MockGrid {
+            columns: 0,
+            ..extracted
+        };
+        assert_eq!(zero.rows().count(), 3);
+    }
+
+    #[test]
+    fn flow_layout_extracts_its_constraint_and_children() {
+        use crate::backends::mock::MockDynamicChild;
+
+        let ctx = RenderContext::new();
+        let chips = ["red", "green", "blue"].map(|chip| Box::new(Text::new(chip)) as Box<dyn View>);
+        let flow = FlowLayout::from_children(120.0, chips)
+            .line_spacing(8.0)
+            .item_spacing(2.0);
+
+        let extracted = MockBackend::extract(&flow, &ctx).unwrap();
+        assert_eq!(extracted.max_width, 120.0);
+        assert_eq!(extracted.line_spacing, 8.0);
+        assert_eq!(extracted.item_spacing, 2.0);
+        assert_eq!(extracted.content.len(), 3);
+        assert!(
+            matches!(&extracted.content[2], MockDynamicChild::Text(text) if text.content == "blue")
+        );
+
+        // Flow layouts nest in dynamic containers, and bad widths clamp to zero
+        let stack = VStack::dynamic().child(Box::new(FlowLayout::new(f32::NAN)));
+        let extracted = MockBackend::extract(&stack, &ctx).unwrap();
+        let MockDynamicChild::FlowLayout(flow) = &extracted.content[0] else {
+            panic!("expected flow layout, got {:?}", extracted.content[0]);
+        };
+        assert_eq!(flow.max_width, 0.0);
+        assert_eq!(FlowLayout::new(-5.0).max_width, 0.0);
+    }
+}
+
+// End of File
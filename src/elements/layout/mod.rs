@@ -0,0 +1,412 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Layout container views for organizing child views
+//!
+//! Container views organize child views in specific layouts. They are pure
+//! data structures that describe layout intent - the actual positioning
+//! and sizing is handled by backends through the ViewExtractor pattern.
+
+use std::{
+    any::Any,
+    sync::{PoisonError, RwLock},
+};
+
+use crate::view::View;
+
+mod dynamic;
+mod grid;
+mod stack;
+
+pub use grid::{FlowLayout, Grid};
+pub use stack::{HStack, Stack, VStack, ZStack};
+
+/// Alignment options for layout containers.
+///
+/// Determines how child views are aligned within their container.
+/// The actual alignment behavior is implemented by backends during extraction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Alignment {
+    /// Align to the leading edge (left in LTR, right in RTL, top in vertical)
+    #[default]
+    Leading,
+    /// Center alignment
+    Center,
+    /// Align to the trailing edge (right in LTR, left in RTL, bottom in vertical)
+    Trailing,
+}
+
+/// The reading direction that leading and trailing alignment follow.
+///
+/// Backends resolve [`Alignment::Leading`] and [`Alignment::Trailing`] on the
+/// horizontal axis through the direction in the
+/// [`RenderContext`](crate::extraction::RenderContext).
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{Alignment, HorizontalEdge, LayoutDirection};
+///
+/// let rtl = LayoutDirection::RightToLeft;
+/// assert_eq!(rtl.resolve(Alignment::Leading), Some(HorizontalEdge::Right));
+/// assert_eq!(rtl.resolve(Alignment::Center), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LayoutDirection {
+    /// Text and rows run from left to right, as in English
+    #[default]
+    LeftToRight,
+    /// Text and rows run from right to left, as in Arabic or Hebrew
+    RightToLeft,
+}
+
+impl LayoutDirection {
+    /// Get the edge that leading content is placed against.
+    pub fn leading_edge(self) -> HorizontalEdge {
+        match self {
+            Self::LeftToRight => HorizontalEdge::Left,
+            Self::RightToLeft => HorizontalEdge::Right,
+        }
+    }
+
+    /// Get the edge that trailing content is placed against.
+    pub fn trailing_edge(self) -> HorizontalEdge {
+        match self {
+            Self::LeftToRight => HorizontalEdge::Right,
+            Self::RightToLeft => HorizontalEdge::Left,
+        }
+    }
+
+    /// Resolve a horizontal alignment to the edge it places content against.
+    ///
+    /// Returns `None` for [`Alignment::Center`], which has no edge.
+    pub fn resolve(self, alignment: Alignment) -> Option<HorizontalEdge> {
+        match alignment {
+            Alignment::Leading => Some(self.leading_edge()),
+            Alignment::Center => None,
+            Alignment::Trailing => Some(self.trailing_edge()),
+        }
+    }
+}
+
+/// A physical horizontal edge, independent of reading direction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HorizontalEdge {
+    /// The left edge
+    Left,
+    /// The right edge
+    Right,
+}
+
+/// Two-dimensional alignment for containers that position children on both axes.
+///
+/// The horizontal component uses leading/trailing in the LTR sense, and the
+/// vertical component treats leading as top and trailing as bottom.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{Alignment, elements::Alignment2D};
+///
+/// let badge = Alignment2D::TOP_TRAILING;
+/// assert_eq!(badge, Alignment2D::new(Alignment::Trailing, Alignment::Leading));
+/// assert_eq!(Alignment2D::default(), Alignment2D::CENTER);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Alignment2D {
+    /// Alignment along the horizontal axis
+    pub horizontal: Alignment,
+    /// Alignment along the vertical axis
+    pub vertical: Alignment,
+}
+
+impl Alignment2D {
+    /// Align to the top leading corner.
+    pub const TOP_LEADING: Self = Self::new(Alignment::Leading, Alignment::Leading);
+    /// Align to the center of the top edge.
+    pub const TOP: Self = Self::new(Alignment::Center, Alignment::Leading);
+    /// Align to the top trailing corner.
+    pub const TOP_TRAILING: Self = Self::new(Alignment::Trailing, Alignment::Leading);
+    /// Align to the center of the leading edge.
+    pub const LEADING: Self = Self::new(Alignment::Leading, Alignment::Center);
+    /// Align to the center on both axes.
+    pub const CENTER: Self = Self::new(Alignment::Center, Alignment::Center);
+    /// Align to the center of the trailing edge.
+    pub const TRAILING: Self = Self::new(Alignment::Trailing, Alignment::Center);
+    /// Align to the bottom leading corner.
+    pub const BOTTOM_LEADING: Self = Self::new(Alignment::Leading, Alignment::Trailing);
+    /// Align to the center of the bottom edge.
+    pub const BOTTOM: Self = Self::new(Alignment::Center, Alignment::Trailing);
+    /// Align to the bottom trailing corner.
+    pub const BOTTOM_TRAILING: Self = Self::new(Alignment::Trailing, Alignment::Trailing);
+
+    /// Create an alignment from its horizontal and vertical components.
+    pub const fn new(horizontal: Alignment, vertical: Alignment) -> Self {
+        Self {
+            horizontal,
+            vertical,
+        }
+    }
+}
+
+impl Default for Alignment2D {
+    /// Center children on both axes.
+    fn default() -> Self {
+        Self::CENTER
+    }
+}
+
+/// Process-wide defaults picked up by newly created stacks.
+///
+/// Stacks are plain data with no access to a render context at construction,
+/// so the defaults live in a global that `VStack::new`, `HStack::new` and the
+/// `dynamic()` constructors read once when a stack is built.
+static STACK_DEFAULTS: RwLock<StackDefaults> = RwLock::new(StackDefaults::new());
+
+/// Default spacing and alignment applied to newly created stacks.
+///
+/// Design systems usually settle on a standard gap between stacked elements.
+/// Installing a `StackDefaults` makes every stack created afterwards start
+/// with that spacing and alignment, so `.spacing()` only needs to be called
+/// where a layout deviates from the convention. Stacks that set spacing or
+/// alignment explicitly are unaffected.
+///
+/// The built-in defaults are zero spacing and leading alignment.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{elements::StackDefaults, Text, VStack};
+///
+/// StackDefaults::new().spacing(8.0).install();
+///
+/// let stack = VStack::new((Text::new("Top"), Text::new("Bottom")));
+/// assert_eq!(stack.spacing, 8.0);
+///
+/// // Explicit configuration still wins
+/// let tight = VStack::new(Text::new("Tight")).spacing(2.0);
+/// assert_eq!(tight.spacing, 2.0);
+///
+/// StackDefaults::reset();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StackDefaults {
+    /// Spacing between child views in logical pixels
+    pub spacing: f32,
+    /// Alignment of child views along the cross axis
+    pub alignment: Alignment,
+}
+
+impl StackDefaults {
+    /// Create the built-in stack defaults (zero spacing, leading alignment).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::{elements::StackDefaults, Alignment};
+    ///
+    /// let defaults = StackDefaults::new();
+    /// assert_eq!(defaults.spacing, 0.0);
+    /// assert_eq!(defaults.alignment, Alignment::Leading);
+    /// ```
+    pub const fn new() -> Self {
+        Self {
+            spacing: 0.0,
+            alignment: Alignment::Leading,
+        }
+    }
+
+    /// Set the default spacing between child views.
+    ///
+    /// # Arguments
+    ///
+    /// * `spacing` - The spacing in logical pixels
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Set the default alignment of child views.
+    ///
+    /// # Arguments
+    ///
+    /// * `alignment` - The alignment option for child views
+    pub fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Install these defaults for all stacks created from now on.
+    ///
+    /// This is typically done once during application startup. Stacks that
+    /// already exist keep the spacing and alignment they were created with.
+    pub fn install(self) {
+        *STACK_DEFAULTS
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) = self;
+    }
+
+    /// Get the currently installed stack defaults.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::elements::StackDefaults;
+    ///
+    /// // Derive a variation of whatever is currently installed
+    /// let roomier = StackDefaults::current().spacing(16.0);
+    /// assert_eq!(roomier.spacing, 16.0);
+    /// ```
+    pub fn current() -> Self {
+        *STACK_DEFAULTS
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Restore the built-in defaults (zero spacing, leading alignment).
+    pub fn reset() {
+        Self::new().install();
+    }
+}
+
+impl Default for StackDefaults {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A flexible space that expands to fill available space.
+///
+/// Spacer is useful for pushing elements apart in stacks, creating flexible
+/// layouts, and centering content. The actual space calculation is performed
+/// by backends during extraction.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{HStack, Text, Spacer};
+///
+/// // Push button to the right side
+/// let toolbar = HStack::new((
+///     Text::new("Title"),
+///     Spacer::new(),
+///     Text::new("Button"),
+/// ));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Spacer {
+    /// Minimum size for the spacer in logical pixels
+    pub min_size: f32,
+    /// Largest size the spacer may grow to, or `None` to grow without limit
+    pub max_size: Option<f32>,
+    /// Share of leftover space relative to competing spacers; higher wins
+    pub layout_priority: f32,
+}
+
+impl Spacer {
+    /// Creates a new spacer with no minimum size.
+    ///
+    /// The spacer will expand to fill all available space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::Spacer;
+    ///
+    /// let spacer = Spacer::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::min_size(0.0)
+    }
+
+    /// Creates a spacer with a minimum size.
+    ///
+    /// The spacer will take at least the specified size, but can expand further
+    /// if more space is available.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_size` - The minimum size in logical pixels
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::Spacer;
+    ///
+    /// let spacer = Spacer::min_size(20.0);
+    /// ```
+    pub fn min_size(min_size: f32) -> Self {
+        Self {
+            min_size,
+            max_size: None,
+            layout_priority: 0.0,
+        }
+    }
+
+    /// Caps how far the spacer may grow.
+    ///
+    /// A cap below the minimum size is raised to the minimum size.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_size` - The maximum size in logical pixels
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::Spacer;
+    ///
+    /// let gutter = Spacer::min_size(8.0).max_size(32.0);
+    /// assert_eq!(gutter.max_size, Some(32.0));
+    /// ```
+    pub fn max_size(mut self, max_size: f32) -> Self {
+        self.max_size = Some(max_size.max(self.min_size));
+        self
+    }
+
+    /// Sets the spacer's priority when several spacers compete for leftover space.
+    ///
+    /// Backends give leftover space to the highest-priority spacers first and
+    /// only pass it on to lower priorities once those have reached their
+    /// maximum size. Spacers start at priority 0.
+    ///
+    /// # Arguments
+    ///
+    /// * `priority` - The relative layout priority
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::{HStack, Spacer, Text};
+    ///
+    /// // The leading gap fills up to 40 pixels before the trailing one grows
+    /// let row = HStack::new((
+    ///     Spacer::new().max_size(40.0).layout_priority(1.0),
+    ///     Text::new("Title"),
+    ///     Spacer::new(),
+    /// ));
+    /// ```
+    pub fn layout_priority(mut self, priority: f32) -> Self {
+        self.layout_priority = priority;
+        self
+    }
+}
+
+impl Default for Spacer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl View for Spacer {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+// End of File
@@ -0,0 +1,447 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Vertical, horizontal and layered stacks
+
+use std::any::Any;
+
+use super::{Alignment, Alignment2D, StackDefaults};
+use crate::view::View;
+
+/// Vertical stack container that arranges children vertically.
+///
+/// VStack arranges its children in a vertical column with configurable spacing
+/// between elements. The actual layout calculations are performed by backends
+/// during extraction.
+///
+/// Stacks don't implement `Default`: their children are part of the type, and
+/// most child views have no meaningful default. Use [`VStack::dynamic`] to start from an empty dynamic stack.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{VStack, Text};
+///
+/// let stack = VStack::new((
+///     Text::new("First item"),
+///     Text::new("Second item"),
+/// )).spacing(8.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct VStack<T> {
+    /// The child views to arrange vertically
+    pub content: T,
+    /// Horizontal alignment of child views
+    pub alignment: Alignment,
+    /// Spacing between child views in logical pixels
+    pub spacing: f32,
+}
+
+impl<T: View> VStack<T> {
+    /// Creates a new vertical stack with the given content.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - The child views to arrange vertically
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::{VStack, Text};
+    ///
+    /// let stack = VStack::new((
+    ///     Text::new("Top"),
+    ///     Text::new("Bottom"),
+    /// ));
+    /// ```
+    pub fn new(content: T) -> Self {
+        let defaults = StackDefaults::current();
+        Self {
+            content,
+            alignment: defaults.alignment,
+            spacing: defaults.spacing,
+        }
+    }
+
+    /// Sets the spacing between child views.
+    ///
+    /// # Arguments
+    ///
+    /// * `spacing` - The spacing in logical pixels
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::{VStack, Text};
+    ///
+    /// let stack = VStack::new((
+    ///     Text::new("Top"),
+    ///     Text::new("Bottom"),
+    /// )).spacing(16.0);
+    /// ```
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the horizontal alignment of child views.
+    ///
+    /// # Arguments
+    ///
+    /// * `alignment` - The alignment option for child views
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::{VStack, Text, Alignment};
+    ///
+    /// let stack = VStack::new((
+    ///     Text::new("Centered"),
+    ///     Text::new("Content"),
+    /// )).alignment(Alignment::Center);
+    /// ```
+    pub fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+}
+
+impl<T: View> View for VStack<T> {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Horizontal stack container that arranges children horizontally.
+///
+/// HStack arranges its children in a horizontal row with configurable spacing
+/// between elements. The actual layout calculations are performed by backends
+/// during extraction.
+///
+/// Stacks don't implement `Default`: their children are part of the type, and
+/// most child views have no meaningful default. Use [`HStack::dynamic`] to start from an empty dynamic stack.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{HStack, Text};
+///
+/// let stack = HStack::new((
+///     Text::new("Left"),
+///     Text::new("Right"),
+/// )).spacing(8.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct HStack<T> {
+    /// The child views to arrange horizontally
+    pub content: T,
+    /// Vertical alignment of child views
+    pub alignment: Alignment,
+    /// Spacing between child views in logical pixels
+    pub spacing: f32,
+}
+
+impl<T: View> HStack<T> {
+    /// Creates a new horizontal stack with the given content.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - The child views to arrange horizontally
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::{HStack, Text};
+    ///
+    /// let stack = HStack::new((
+    ///     Text::new("Left"),
+    ///     Text::new("Right"),
+    /// ));
+    /// ```
+    pub fn new(content: T) -> Self {
+        let defaults = StackDefaults::current();
+        Self {
+            content,
+            alignment: defaults.alignment,
+            spacing: defaults.spacing,
+        }
+    }
+
+    /// Sets the spacing between child views.
+    ///
+    /// # Arguments
+    ///
+    /// * `spacing` - The spacing in logical pixels
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::{HStack, Text};
+    ///
+    /// let stack = HStack::new((
+    ///     Text::new("Left"),
+    ///     Text::new("Right"),
+    /// )).spacing(16.0);
+    /// ```
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the vertical alignment of child views.
+    ///
+    /// # Arguments
+    ///
+    /// * `alignment` - The alignment option for child views
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::{HStack, Text, Alignment};
+    ///
+    /// let stack = HStack::new((
+    ///     Text::new("Left"),
+    ///     Text::new("Right"),
+    /// )).alignment(Alignment::Center);
+    /// ```
+    pub fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+}
+
+impl<T: View> View for HStack<T> {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+// Dynamic container implementations for Vec<Box<dyn View>>
+// These provide the same API as the tuple-based containers but work with dynamic children
+
+/// Depth stack container that layers children on top of each other.
+///
+/// ZStack draws its children back-to-front: the first child is at the back
+/// and each following child is drawn over the previous ones. All children
+/// share the same frame and are positioned within it by a two-dimensional
+/// alignment, which makes ZStack the building block for badges, overlays,
+/// and watermarks.
+///
+/// Stacks don't implement `Default`: their children are part of the type, and
+/// most child views have no meaningful default.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{Text, elements::{Alignment2D, ZStack}};
+///
+/// // Put an unread count in the top trailing corner of an icon
+/// let badge = ZStack::new((
+///     Text::new("Inbox"),
+///     Text::new("3"),
+/// )).alignment(Alignment2D::TOP_TRAILING);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ZStack<T> {
+    /// The child views to layer, from back to front
+    pub content: T,
+    /// Alignment of child views within the shared frame
+    pub alignment: Alignment2D,
+}
+
+impl<T: View> ZStack<T> {
+    /// Creates a new depth stack with the given content, centered on both axes.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - The child views to layer, from back to front
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::{Text, elements::{Alignment2D, ZStack}};
+    ///
+    /// let stack = ZStack::new((Text::new("Back"), Text::new("Front")));
+    /// assert_eq!(stack.alignment, Alignment2D::CENTER);
+    /// ```
+    pub fn new(content: T) -> Self {
+        Self {
+            content,
+            alignment: Alignment2D::default(),
+        }
+    }
+
+    /// Sets the alignment of child views within the shared frame.
+    ///
+    /// # Arguments
+    ///
+    /// * `alignment` - The two-dimensional alignment for child views
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::{Text, elements::{Alignment2D, ZStack}};
+    ///
+    /// let watermark = ZStack::new((
+    ///     Text::new("Document body"),
+    ///     Text::new("DRAFT"),
+    /// )).alignment(Alignment2D::BOTTOM_TRAILING);
+    /// ```
+    pub fn alignment(mut self, alignment: Alignment2D) -> Self {
+        self.alignment = alignment;
+        self
+    }
+}
+
+impl<T: View> View for ZStack<T> {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Read access to the configuration shared by [`VStack`] and [`HStack`].
+///
+/// Both tuple-based and dynamic stacks implement this, so generic code can
+/// read alignment and spacing without caring which form it was handed. The
+/// values returned are the ones extraction uses: explicitly configured
+/// values, or the [`StackDefaults`] installed when the stack was built. The
+/// inherent builder methods share these names, so on a concrete stack call
+/// them through the trait, e.g. `Stack::spacing(&stack)`; inside a function
+/// bounded by `S: Stack`, `stack.spacing()` works directly.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::prelude::*;
+///
+/// fn gap<S: Stack>(stack: &S) -> f32 {
+///     stack.spacing()
+/// }
+///
+/// let fixed = VStack::new((Text::new("A"), Text::new("B"))).spacing(12.0);
+/// let dynamic = HStack::dynamic().spacing(6.0).alignment(Alignment::Center);
+///
+/// assert_eq!(gap(&fixed), 12.0);
+/// assert_eq!(gap(&dynamic), 6.0);
+/// assert_eq!(Stack::alignment(&dynamic), Alignment::Center);
+/// ```
+pub trait Stack: View {
+    /// Effective cross-axis alignment of the stack's children.
+    fn alignment(&self) -> Alignment;
+
+    /// Effective spacing between adjacent children in logical pixels.
+    fn spacing(&self) -> f32;
+}
+
+impl<T: View> Stack for VStack<T> {
+    fn alignment(&self) -> Alignment {
+        self.alignment
+    }
+
+    fn spacing(&self) -> f32 {
+        self.spacing
+    }
+}
+
+impl<T: View> Stack for HStack<T> {
+    fn alignment(&self) -> Alignment {
+        self.alignment
+    }
+
+    fn spacing(&self) -> f32 {
+        self.spacing
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        backends::mock::MockBackend,
+        elements::{Spacer, Text},
+        extraction::{RenderContext, ViewExtractor},
+        model::Model,
+    };
+
+    #[test]
+    fn container_configuration_and_extraction() {
+        let ctx = RenderContext::new();
+
+        // Test spacer configuration
+        let spacer = Spacer::min_size(100.0);
+        assert_eq!(spacer.min_size, 100.0);
+
+        // Test container spacing with extraction
+        let vstack = VStack::new(Text::new("Test")).spacing(5.0);
+        let extracted = MockBackend::extract(&vstack, &ctx).unwrap();
+        assert_eq!(extracted.spacing, 5.0);
+
+        // Test fractional values work correctly
+        let fractional_spacing = VStack::new(Text::new("Test")).spacing(2.5);
+        let extracted = MockBackend::extract(&fractional_spacing, &ctx).unwrap();
+        assert_eq!(extracted.spacing, 2.5);
+    }
+
+    #[test]
+    fn zstack_layers_children_with_alignment() {
+        use crate::{backends::mock::MockDynamicChild, widgets::Button};
+
+        let ctx = RenderContext::new();
+
+        let badge =
+            ZStack::new((Text::new("Icon"), Text::new("3"))).alignment(Alignment2D::TOP_TRAILING);
+        let extracted = MockBackend::extract(&badge, &ctx).unwrap();
+        assert_eq!(extracted.alignment, Alignment2D::TOP_TRAILING);
+        assert_eq!(extracted.content.0.content, "Icon");
+        assert_eq!(extracted.content.1.content, "3");
+
+        let overlay = ZStack::dynamic()
+            .child(Box::new(Text::new("Back")))
+            .child(Box::new(Button::new("Front").view()));
+        let extracted = MockBackend::extract(&overlay, &ctx).unwrap();
+        assert_eq!(extracted.alignment, Alignment2D::CENTER);
+        assert!(matches!(
+            &extracted.content[0],
+            MockDynamicChild::Text(text) if text.content == "Back"
+        ));
+        assert!(matches!(&extracted.content[1], MockDynamicChild::Button(_)));
+
+        // Dynamic depth stacks can be nested inside other dynamic containers
+        let nested = VStack::dynamic().child(Box::new(overlay));
+        let extracted = MockBackend::extract(&nested, &ctx).unwrap();
+        assert!(matches!(
+            &extracted.content[0],
+            MockDynamicChild::ZStack(stack) if stack.content.len() == 2
+        ));
+    }
+
+    #[test]
+    fn stack_trait_reads_both_forms() {
+        fn read<S: Stack>(stack: &S) -> (Alignment, f32) {
+            (stack.alignment(), stack.spacing())
+        }
+
+        let fixed = VStack::new((Text::new("A"), Text::new("B")))
+            .spacing(10.0)
+            .alignment(Alignment::Trailing);
+        let dynamic = VStack::dynamic().spacing(3.0).alignment(Alignment::Center);
+        assert_eq!(read(&fixed), (Alignment::Trailing, 10.0));
+        assert_eq!(read(&dynamic), (Alignment::Center, 3.0));
+
+        let row = HStack::new((Text::new("A"), Text::new("B"))).spacing(7.0);
+        assert_eq!(Stack::spacing(&row), 7.0);
+        assert_eq!(Stack::alignment(&row), row.alignment);
+        assert_eq!(Stack::spacing(&HStack::dynamic().spacing(1.5)), 1.5);
+
+        // Unconfigured stacks report the defaults they were built with
+        let plain = HStack::dynamic();
+        let defaults = StackDefaults::new();
+        assert_eq!(read(&plain), (defaults.alignment, defaults.spacing));
+        let ctx = RenderContext::new();
+        let extracted = MockBackend::extract(&plain, &ctx).unwrap();
+        assert_eq!(read(&plain), (extracted.alignment, extracted.spacing));
+    }
+}
+
+// End of File
@@ -0,0 +1,134 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Message mapping for embedding components in a parent
+//!
+//! A reusable component is written against its own message type, like
+//! `ButtonMessage`. To embed it in a larger model, its view is wrapped with
+//! a function that turns its messages into the parent's message type. The
+//! wrapper is invisible to backends, which extract the content unchanged;
+//! the mapping is only applied when an event from the content is routed
+//! back to the parent.
+
+use std::{any::Any, fmt, sync::Arc};
+
+use crate::{message::Message, view::View};
+
+/// A view whose messages are converted into a parent's message type.
+///
+/// Most code creates mapped views with [`View::map_messages`]. Mapped views
+/// are extracted exactly like their content.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::prelude::*;
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// enum EditorMessage {
+///     Save(ButtonMessage),
+/// }
+///
+/// impl Message for EditorMessage {}
+///
+/// let save = Button::new("Save").view().map_messages(EditorMessage::Save);
+/// assert_eq!(
+///     save.route(ButtonMessage::Clicked),
+///     EditorMessage::Save(ButtonMessage::Clicked)
+/// );
+/// ```
+pub struct MappedView<V, M, P> {
+    /// The view whose messages are mapped
+    pub content: V,
+    map: Arc<dyn Fn(M) -> P + Send + Sync>,
+}
+
+impl<V, M: Message, P: Message> MappedView<V, M, P> {
+    /// Wrap a view with a function converting its messages.
+    pub fn new(content: V, map: impl Fn(M) -> P + Send + Sync + 'static) -> Self {
+        Self {
+            content,
+            map: Arc::new(map),
+        }
+    }
+
+    /// Convert a message produced by the content into the parent's message type.
+    pub fn route(&self, message: M) -> P {
+        (self.map)(message)
+    }
+}
+
+impl<V: Clone, M, P> Clone for MappedView<V, M, P> {
+    fn clone(&self) -> Self {
+        Self {
+            content: self.content.clone(),
+            map: Arc::clone(&self.map),
+        }
+    }
+}
+
+impl<V: fmt::Debug, M, P> fmt::Debug for MappedView<V, M, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MappedView")
+            .field("content", &self.content)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<V: View, M: Message, P: Message> View for MappedView<V, M, P> {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        backends::mock::MockBackend,
+        extraction::{RenderContext, ViewExtractor},
+        model::Model,
+        widgets::{Button, ButtonMessage},
+    };
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum ParentMessage {
+        Save(ButtonMessage),
+        Cancel(ButtonMessage),
+    }
+
+    impl Message for ParentMessage {}
+
+    #[test]
+    fn routed_click_arrives_as_parent_variant() {
+        let save = Button::new("Save").view().map_messages(ParentMessage::Save);
+        let cancel = Button::new("Cancel")
+            .view()
+            .map_messages(ParentMessage::Cancel);
+
+        assert_eq!(
+            save.route(ButtonMessage::Clicked),
+            ParentMessage::Save(ButtonMessage::Clicked)
+        );
+        assert_eq!(
+            cancel.clone().route(ButtonMessage::Clicked),
+            ParentMessage::Cancel(ButtonMessage::Clicked)
+        );
+    }
+
+    #[test]
+    fn extraction_passes_through() {
+        let ctx = RenderContext::new();
+        let button = Button::new("Save");
+
+        let mapped = button.view().map_messages(ParentMessage::Save);
+        let extracted = MockBackend::extract(&mapped, &ctx).unwrap();
+        assert_eq!(
+            extracted,
+            MockBackend::extract(&button.view(), &ctx).unwrap()
+        );
+    }
+}
+
+// End of File
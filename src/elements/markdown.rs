@@ -0,0 +1,259 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Markdown conversion for content-driven views
+//!
+//! Documentation pages and help panels are usually written as Markdown rather
+//! than assembled view by view. This module converts basic Markdown into a
+//! dynamic VStack with one text view per block.
+//!
+//! Available with the `markdown` feature.
+
+use pulldown_cmark::{Event, HeadingLevel, Parser, Tag};
+
+use crate::{
+    elements::{RichText, Text, TextSpan, VStack},
+    style::TextStyle,
+    view::View,
+};
+
+/// Font size for each heading level, from `#` to `######`.
+const HEADING_FONT_SIZES: [f32; 6] = [32.0, 24.0, 20.0, 18.0, 16.0, 16.0];
+
+/// Convert Markdown into a vertical stack of text views.
+///
+/// Each heading, paragraph, list item, and code block becomes one child of
+/// the stack, in document order. Headings are sized by level, list items are
+/// prefixed with a bullet or their number, and blocks containing bold or
+/// italic text become [`RichText`]; all other blocks are plain [`Text`].
+/// Syntax without a view equivalent, such as links, inline code, and block
+/// quotes, degrades to its plain text content.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{elements::from_markdown, prelude::*};
+///
+/// let help = from_markdown("# Shortcuts\n\n- **Ctrl+S** saves\n- *Ctrl+Q* quits");
+/// let stack = help.as_any().downcast_ref::<VStack<Vec<Box<dyn View>>>>().unwrap();
+/// assert_eq!(stack.content.len(), 3);
+/// ```
+pub fn from_markdown(source: &str) -> Box<dyn View> {
+    let mut builder = BlockBuilder::default();
+    for event in Parser::new(source) {
+        builder.handle(event);
+    }
+    builder.flush();
+    Box::new(VStack::from_children(builder.blocks))
+}
+
+/// Accumulates inline text into block-level views while walking parser events.
+#[derive(Default)]
+struct BlockBuilder {
+    /// Finished blocks, in document order
+    blocks: Vec<Box<dyn View>>,
+    /// Spans of the block currently being built
+    spans: Vec<TextSpan>,
+    /// Nesting depth of strong emphasis
+    bold: usize,
+    /// Nesting depth of emphasis
+    italic: usize,
+    /// Level of the heading being built, if any
+    heading: Option<HeadingLevel>,
+    /// Open lists, innermost last, with the next number for ordered lists
+    lists: Vec<Option<u64>>,
+    /// Marker for the list item being built, added when it is flushed
+    item_marker: Option<String>,
+}
+
+impl BlockBuilder {
+    fn handle(&mut self, event: Event<'_>) {
+        match event {
+            Event::Start(Tag::Heading(level, ..)) => {
+                self.flush();
+                self.heading = Some(level);
+            }
+            Event::End(Tag::Heading(..)) => {
+                self.flush();
+                self.heading = None;
+            }
+            Event::Start(Tag::List(first)) => {
+                // Text of the enclosing item comes before its nested list
+                self.flush();
+                self.lists.push(first);
+            }
+            Event::End(Tag::List(_)) => {
+                self.flush();
+                self.lists.pop();
+            }
+            Event::Start(Tag::Item) => {
+                self.flush();
+                let indent = "  ".repeat(self.lists.len().saturating_sub(1));
+                let marker = match self.lists.last_mut() {
+                    Some(Some(number)) => {
+                        *number += 1;
+                        format!("{}. ", *number - 1)
+                    }
+                    _ => "• ".to_string(),
+                };
+                self.item_marker = Some(indent + &marker);
+            }
+            Event::Start(Tag::Emphasis) => self.italic += 1,
+            Event::End(Tag::Emphasis) => self.italic = self.italic.saturating_sub(1),
+            Event::Start(Tag::Strong) => self.bold += 1,
+            Event::End(Tag::Strong) => self.bold = self.bold.saturating_sub(1),
+            Event::Start(Tag::CodeBlock(_))
+            | Event::End(Tag::Paragraph | Tag::Item | Tag::CodeBlock(_) | Tag::BlockQuote)
+            | Event::Rule => self.flush(),
+            Event::Text(text) | Event::Code(text) | Event::Html(text) => self.push_text(&text),
+            Event::SoftBreak => self.push_text(" "),
+            Event::HardBreak => self.push_text("\n"),
+            Event::TaskListMarker(checked) => self.push_text(if checked { "[x] " } else { "[ ] " }),
+            Event::FootnoteReference(label) => self.push_text(&format!("[{}]", label)),
+            // Other tags only wrap inline text, which is kept as-is
+            Event::Start(_) | Event::End(_) => {}
+        }
+    }
+
+    /// Append text with the current emphasis, merging it into the last span if it matches.
+    fn push_text(&mut self, text: &str) {
+        let (bold, italic) = (self.bold > 0, self.italic > 0);
+        match self.spans.last_mut() {
+            Some(span) if span.bold == bold && span.italic == italic => {
+                span.content.push_str(text);
+            }
+            _ => self.spans.push(TextSpan {
+                content: text.to_string(),
+                bold,
+                italic,
+            }),
+        }
+    }
+
+    /// Finish the current block, if it has any text, and add it to the stack.
+    fn flush(&mut self) {
+        let mut spans = std::mem::take(&mut self.spans);
+        // Code blocks end with a newline that shouldn't render as an empty line
+        if let Some(last) = spans.last_mut() {
+            last.content.truncate(last.content.trim_end().len());
+        }
+        spans.retain(|span| !span.content.is_empty());
+        if spans.is_empty() {
+            return;
+        }
+
+        if let Some(marker) = self.item_marker.take() {
+            spans.insert(0, TextSpan::new(marker));
+        }
+
+        let font_size = match self.heading {
+            Some(level) => HEADING_FONT_SIZES[level as usize - 1],
+            None => TextStyle::default().font_size,
+        };
+
+        let block: Box<dyn View> = if spans.iter().all(TextSpan::is_plain) {
+            let content: String = spans.into_iter().map(|span| span.content).collect();
+            Box::new(Text::new(content).font_size(font_size))
+        } else {
+            Box::new(RichText::from_spans(spans).font_size(font_size))
+        };
+        self.blocks.push(block);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        backends::mock::{MockBackend, MockDynamicChild},
+        extraction::RenderContext,
+    };
+
+    /// Convert Markdown and extract the children of the resulting stack.
+    fn extract_blocks(source: &str) -> Vec<MockDynamicChild> {
+        let view = from_markdown(source);
+        match MockBackend::new()
+            .extract_dynamic(view.as_ref(), &RenderContext::new())
+            .unwrap()
+        {
+            MockDynamicChild::VStack(stack) => stack.content,
+            other => panic!("expected a VStack, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn heading_is_larger_than_body() {
+        let blocks = extract_blocks("# Title\n\nbody");
+        assert_eq!(blocks.len(), 2);
+
+        let MockDynamicChild::Text(title) = &blocks[0] else {
+            panic!("expected title text, got {:?}", blocks[0]);
+        };
+        let MockDynamicChild::Text(body) = &blocks[1] else {
+            panic!("expected body text, got {:?}", blocks[1]);
+        };
+        assert_eq!(title.content, "Title");
+        assert_eq!(body.content, "body");
+        assert!(title.font_size > body.font_size);
+
+        let sizes: Vec<f32> = extract_blocks("# 1\n## 2\n### 3")
+            .iter()
+            .map(|block| match block {
+                MockDynamicChild::Text(text) => text.font_size,
+                other => panic!("expected heading text, got {:?}", other),
+            })
+            .collect();
+        assert_eq!(sizes, vec![32.0, 24.0, 20.0]);
+    }
+
+    #[test]
+    fn emphasis_becomes_rich_text() {
+        let blocks = extract_blocks("Press **Save** or *cancel*.");
+        let MockDynamicChild::RichText(paragraph) = &blocks[0] else {
+            panic!("expected rich text, got {:?}", blocks[0]);
+        };
+        assert_eq!(
+            paragraph.spans,
+            vec![
+                TextSpan::new("Press "),
+                TextSpan::new("Save").bold(),
+                TextSpan::new(" or "),
+                TextSpan::new("cancel").italic(),
+                TextSpan::new("."),
+            ]
+        );
+    }
+
+    #[test]
+    fn list_items_are_prefixed() {
+        let contents: Vec<String> = extract_blocks("- apples\n- pears\n  1. green\n  2. red")
+            .into_iter()
+            .map(|block| match block {
+                MockDynamicChild::Text(text) => text.content,
+                other => panic!("expected list text, got {:?}", other),
+            })
+            .collect();
+        assert_eq!(
+            contents,
+            vec!["• apples", "• pears", "  1. green", "  2. red"]
+        );
+    }
+
+    #[test]
+    fn unsupported_syntax_degrades_to_plain_text() {
+        let blocks = extract_blocks(
+            "> See [the guide](https://example.com) or run `help`.\n\n```\nlet x = 1;\n```",
+        );
+        let contents: Vec<&str> = blocks
+            .iter()
+            .map(|block| match block {
+                MockDynamicChild::Text(text) => text.content.as_str(),
+                other => panic!("expected plain text, got {:?}", other),
+            })
+            .collect();
+        assert_eq!(contents, vec!["See the guide or run help.", "let x = 1;"]);
+    }
+}
+
+// End of File
@@ -12,10 +12,49 @@
 //! These elements are pure data structures that describe what should
 //! be displayed, with all styling and content configured at creation time.
 
+pub mod badge;
+pub mod border;
+pub mod card;
+pub mod gauge;
+pub mod identified;
+pub mod image;
+pub mod label;
 pub mod layout;
+pub mod mapped;
+#[cfg(feature = "markdown")]
+pub mod markdown;
+pub mod opacity;
+pub mod padding;
+pub mod rich_text;
+pub mod scroll;
+pub mod sparkline;
+pub mod spinner;
+pub mod tagged;
 pub mod text;
+pub mod tooltip;
 
-pub use layout::{Alignment, HStack, Spacer, VStack};
-pub use text::Text;
+pub use badge::Badge;
+pub use border::{Border, BorderStyle};
+pub use card::{Card, CardPadding, Elevation, Shadow};
+pub use gauge::{Gauge, GaugeStyle, GaugeZone};
+pub use identified::Identified;
+pub use image::{ContentMode, Image, ImageSource};
+pub use label::Label;
+pub use layout::{
+    Alignment, Alignment2D, FlowLayout, Grid, HStack, HorizontalEdge, LayoutDirection, Spacer,
+    Stack, StackDefaults, VStack, ZStack,
+};
+pub use mapped::MappedView;
+#[cfg(feature = "markdown")]
+pub use markdown::from_markdown;
+pub use opacity::Opacity;
+pub use padding::{EdgeInsets, Padding};
+pub use rich_text::{RichText, TextSpan};
+pub use scroll::{ScrollAxis, ScrollView};
+pub use sparkline::Sparkline;
+pub use spinner::{Spinner, SpinnerStyle};
+pub use tagged::Tagged;
+pub use text::{Text, TextContent, TextInterner};
+pub use tooltip::{Tooltip, TooltipPlacement};
 
 // End of File
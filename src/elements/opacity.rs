@@ -0,0 +1,109 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Opacity wrapper for fading a whole subtree
+//!
+//! Opacity multiplies the alpha of everything drawn by the wrapped view, on
+//! top of the alpha of the view's own colors. Fading a section this way
+//! leaves every child's colors untouched, so the section can be restored
+//! by removing the wrapper. Nested wrappers multiply.
+
+use std::any::Any;
+
+use crate::view::View;
+
+/// A view drawn with reduced opacity.
+///
+/// Most code creates opacity wrappers with [`View::opacity`]. The opacity
+/// is always between 0.0 (invisible) and 1.0 (unchanged).
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::prelude::*;
+///
+/// let faded = Text::new("Unavailable").opacity(0.4);
+/// assert_eq!(faded.opacity, 0.4);
+///
+/// // Values outside the range are clamped
+/// assert_eq!(Text::new("Solid").opacity(1.5).opacity, 1.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Opacity<V> {
+    /// Alpha multiplier applied to the content, from 0.0 to 1.0
+    pub opacity: f32,
+    /// The faded view
+    pub content: V,
+}
+
+impl<V> Opacity<V> {
+    /// Wrap a view with an alpha multiplier, clamped to 0.0–1.0.
+    ///
+    /// A value that isn't a number leaves the content fully opaque.
+    pub fn new(content: V, opacity: f32) -> Self {
+        let opacity = if opacity.is_nan() {
+            1.0
+        } else {
+            opacity.clamp(0.0, 1.0)
+        };
+        Self { opacity, content }
+    }
+}
+
+impl<V: View> View for Opacity<V> {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+// Dynamic opacity implementation
+impl View for Opacity<Box<dyn View>> {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        backends::mock::{MockBackend, MockDynamicChild},
+        elements::{Text, VStack},
+        extraction::{RenderContext, ViewExtractor},
+        style::Color,
+    };
+
+    #[test]
+    fn opacity_is_clamped() {
+        assert_eq!(Opacity::new(Text::new("a"), 1.5).opacity, 1.0);
+        assert_eq!(Opacity::new(Text::new("a"), -0.5).opacity, 0.0);
+        assert_eq!(Opacity::new(Text::new("a"), f32::NAN).opacity, 1.0);
+        assert_eq!(Opacity::new(Text::new("a"), 0.25).opacity, 0.25);
+    }
+
+    #[test]
+    fn nested_opacity_extracts_without_touching_colors() {
+        let ctx = RenderContext::new();
+        let color = Color::rgba(1.0, 0.0, 0.0, 0.8);
+
+        let view = Text::new("Inner").color(color).opacity(0.5).opacity(0.4);
+        let extracted = MockBackend::extract(&view, &ctx).unwrap();
+        assert_eq!(extracted.opacity, 0.4);
+        assert_eq!(extracted.content.opacity, 0.5);
+        assert_eq!(extracted.content.content.color, color);
+
+        // Dynamic children keep their wrappers
+        let list = VStack::dynamic()
+            .child(Box::new(Text::new("One")))
+            .map_children(|child| Box::new(Opacity::new(child, 0.3)));
+        let extracted = MockBackend::extract(&list, &ctx).unwrap();
+        let MockDynamicChild::Opacity(faded) = &extracted.content[0] else {
+            panic!("expected opacity, got {:?}", extracted.content[0]);
+        };
+        assert_eq!(faded.opacity, 0.3);
+        assert!(matches!(faded.content, MockDynamicChild::Text(_)));
+    }
+}
+
+// End of File
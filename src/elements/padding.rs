@@ -0,0 +1,189 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Padding wrapper for adding inset space around a view
+//!
+//! Stacks only control the spacing between their children. Padding wraps a
+//! single view and describes the space to leave around it on each edge, so
+//! individual elements can be inset without a backend-specific mechanism.
+
+use std::any::Any;
+
+use crate::view::View;
+
+/// Inset distances for each edge of a view, in logical pixels.
+///
+/// Leading and trailing follow the layout direction (left and right in LTR).
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::elements::EdgeInsets;
+///
+/// let insets = EdgeInsets::symmetric(16.0, 8.0);
+/// assert_eq!(insets.leading, 16.0);
+/// assert_eq!(insets.top, 8.0);
+/// assert_eq!(insets.horizontal(), 32.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EdgeInsets {
+    /// Space above the view
+    pub top: f32,
+    /// Space before the view along the layout direction
+    pub leading: f32,
+    /// Space below the view
+    pub bottom: f32,
+    /// Space after the view along the layout direction
+    pub trailing: f32,
+}
+
+impl EdgeInsets {
+    /// Insets of zero on every edge.
+    pub const ZERO: Self = Self::new(0.0, 0.0, 0.0, 0.0);
+
+    /// Create insets with an explicit value for each edge.
+    pub const fn new(top: f32, leading: f32, bottom: f32, trailing: f32) -> Self {
+        Self {
+            top,
+            leading,
+            bottom,
+            trailing,
+        }
+    }
+
+    /// Create insets with the same value on every edge.
+    pub const fn all(inset: f32) -> Self {
+        Self::new(inset, inset, inset, inset)
+    }
+
+    /// Create insets with one value for the leading and trailing edges and
+    /// another for the top and bottom edges.
+    pub const fn symmetric(horizontal: f32, vertical: f32) -> Self {
+        Self::new(vertical, horizontal, vertical, horizontal)
+    }
+
+    /// Get the total horizontal inset (leading plus trailing).
+    pub fn horizontal(&self) -> f32 {
+        self.leading + self.trailing
+    }
+
+    /// Get the total vertical inset (top plus bottom).
+    pub fn vertical(&self) -> f32 {
+        self.top + self.bottom
+    }
+}
+
+/// A view wrapped with inset space on each edge.
+///
+/// Padding works with statically typed children as well as boxed dynamic
+/// views, so it can also wrap the children of dynamic stacks.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{prelude::*, elements::{EdgeInsets, Padding}};
+///
+/// let card = Padding::all(Text::new("Card body"), 12.0);
+/// assert_eq!(card.insets, EdgeInsets::all(12.0));
+///
+/// // Pad every row of a dynamic list
+/// let list = VStack::dynamic()
+///     .child(Box::new(Text::new("First")))
+///     .child(Box::new(Text::new("Second")))
+///     .map_children(|child| Box::new(Padding::symmetric(child, 16.0, 4.0)));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Padding<V> {
+    /// The inset space around the content
+    pub insets: EdgeInsets,
+    /// The padded view
+    pub content: V,
+}
+
+impl<V> Padding<V> {
+    /// Wrap a view with the given insets.
+    pub fn new(content: V, insets: EdgeInsets) -> Self {
+        Self { insets, content }
+    }
+
+    /// Wrap a view with the same inset on every edge.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::{Text, elements::Padding};
+    ///
+    /// let padded = Padding::all(Text::new("Hello"), 8.0);
+    /// assert_eq!(padded.insets.top, 8.0);
+    /// assert_eq!(padded.insets.trailing, 8.0);
+    /// ```
+    pub fn all(content: V, inset: f32) -> Self {
+        Self::new(content, EdgeInsets::all(inset))
+    }
+
+    /// Wrap a view with separate horizontal and vertical insets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::{Text, elements::Padding};
+    ///
+    /// let padded = Padding::symmetric(Text::new("Hello"), 16.0, 4.0);
+    /// assert_eq!(padded.insets.leading, 16.0);
+    /// assert_eq!(padded.insets.bottom, 4.0);
+    /// ```
+    pub fn symmetric(content: V, horizontal: f32, vertical: f32) -> Self {
+        Self::new(content, EdgeInsets::symmetric(horizontal, vertical))
+    }
+}
+
+impl<V: View> View for Padding<V> {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+// Dynamic padding implementation
+impl View for Padding<Box<dyn View>> {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        backends::mock::{MockBackend, MockDynamicChild},
+        elements::{Text, VStack},
+        extraction::{RenderContext, ViewExtractor},
+    };
+
+    #[test]
+    fn padding_extraction() {
+        let ctx = RenderContext::new();
+
+        let padded = Padding::symmetric(Text::new("Label"), 10.0, 2.0);
+        let extracted = MockBackend::extract(&padded, &ctx).unwrap();
+        assert_eq!(extracted.insets, EdgeInsets::new(2.0, 10.0, 2.0, 10.0));
+        assert_eq!(extracted.content.content, "Label");
+
+        let list = VStack::dynamic()
+            .child(Box::new(Text::new("One")))
+            .child(Box::new(Text::new("Two")))
+            .map_children(|child| Box::new(Padding::all(child, 6.0)));
+        let extracted = MockBackend::extract(&list, &ctx).unwrap();
+        assert_eq!(extracted.content.len(), 2);
+        for child in &extracted.content {
+            let MockDynamicChild::Padding(padding) = child else {
+                panic!("expected padding, got {:?}", child);
+            };
+            assert_eq!(padding.insets, EdgeInsets::all(6.0));
+            assert!(matches!(padding.content, MockDynamicChild::Text(_)));
+        }
+    }
+}
+
+// End of File
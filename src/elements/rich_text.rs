@@ -0,0 +1,162 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Rich text component for runs of mixed emphasis
+//!
+//! Text applies a single style to all of its content. RichText is a sequence
+//! of spans that share a base style but can each be bold or italic, for
+//! paragraphs that emphasize individual words.
+
+use std::any::Any;
+
+use crate::{
+    style::{Color, TextStyle},
+    view::View,
+};
+
+/// A run of text within a [`RichText`] view.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::elements::TextSpan;
+///
+/// let span = TextSpan::new("important").bold();
+/// assert!(span.bold);
+/// assert!(!span.italic);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TextSpan {
+    /// The text of this span
+    pub content: String,
+    /// Whether the span is drawn in a bold weight
+    pub bold: bool,
+    /// Whether the span is drawn in italics
+    pub italic: bool,
+}
+
+impl TextSpan {
+    /// Create a plain span.
+    pub fn new(content: impl Into<String>) -> Self {
+        Self {
+            content: content.into(),
+            bold: false,
+            italic: false,
+        }
+    }
+
+    /// Draw this span in a bold weight.
+    pub fn bold(mut self) -> Self {
+        self.bold = true;
+        self
+    }
+
+    /// Draw this span in italics.
+    pub fn italic(mut self) -> Self {
+        self.italic = true;
+        self
+    }
+
+    /// Check whether this span has no emphasis.
+    pub fn is_plain(&self) -> bool {
+        !self.bold && !self.italic
+    }
+}
+
+/// Text view made of spans with individual emphasis.
+///
+/// All spans share the view's font size and color.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{prelude::*, elements::{RichText, TextSpan}};
+///
+/// let warning = RichText::new()
+///     .span(TextSpan::new("Warning: ").bold())
+///     .span(TextSpan::new("unsaved changes"))
+///     .color(Color::RED);
+///
+/// assert_eq!(warning.plain_text(), "Warning: unsaved changes");
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct RichText {
+    /// The spans, in reading order
+    pub spans: Vec<TextSpan>,
+    /// Styling shared by every span
+    pub style: TextStyle,
+}
+
+impl RichText {
+    /// Create rich text with no spans and the default text style.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create rich text from a sequence of spans.
+    pub fn from_spans(spans: impl IntoIterator<Item = TextSpan>) -> Self {
+        Self {
+            spans: spans.into_iter().collect(),
+            style: TextStyle::default(),
+        }
+    }
+
+    /// Append a span.
+    pub fn span(mut self, span: TextSpan) -> Self {
+        self.spans.push(span);
+        self
+    }
+
+    /// Set the font size shared by every span.
+    pub fn font_size(mut self, size: f32) -> Self {
+        self.style = self.style.font_size(size);
+        self
+    }
+
+    /// Set the color shared by every span.
+    pub fn color(mut self, color: Color) -> Self {
+        self.style = self.style.color(color);
+        self
+    }
+
+    /// Get the content of every span joined together, without emphasis.
+    pub fn plain_text(&self) -> String {
+        self.spans
+            .iter()
+            .map(|span| span.content.as_str())
+            .collect()
+    }
+}
+
+impl View for RichText {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        backends::mock::MockBackend,
+        extraction::{RenderContext, ViewExtractor},
+    };
+
+    #[test]
+    fn rich_text_extraction_keeps_spans() {
+        let text =
+            RichText::from_spans([TextSpan::new("Read "), TextSpan::new("carefully").italic()])
+                .font_size(14.0);
+
+        let extracted = MockBackend::extract(&text, &RenderContext::new()).unwrap();
+        assert_eq!(extracted.spans.len(), 2);
+        assert!(extracted.spans[0].is_plain());
+        assert!(extracted.spans[1].italic);
+        assert_eq!(extracted.font_size, 14.0);
+        assert_eq!(extracted.color, Color::BLACK);
+    }
+}
+
+// End of File
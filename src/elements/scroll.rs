@@ -0,0 +1,142 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Scroll container for content larger than its frame
+//!
+//! ScrollView marks its child as scrollable along one or both axes. Like the
+//! other layout containers it only describes intent: backends decide how to
+//! clip the content, track the scroll offset, and draw indicators.
+
+use std::any::Any;
+
+use crate::view::View;
+
+/// The axes along which a [`ScrollView`] can scroll.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ScrollAxis {
+    /// Scroll up and down
+    #[default]
+    Vertical,
+    /// Scroll left and right
+    Horizontal,
+    /// Scroll in both directions
+    Both,
+}
+
+/// A container that lets its content scroll within its frame.
+///
+/// The content can be any view, including a dynamic `Vec<Box<dyn View>>`.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{prelude::*, elements::{ScrollAxis, ScrollView}};
+///
+/// let rows: Vec<Box<dyn View>> = (0..100)
+///     .map(|i| Box::new(Text::new(format!("Row {}", i))) as Box<dyn View>)
+///     .collect();
+///
+/// let list = ScrollView::new(rows).shows_indicators(false);
+/// assert_eq!(list.axis, ScrollAxis::Vertical);
+/// assert!(!list.shows_indicators);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScrollView<V> {
+    /// The scrollable content
+    pub content: V,
+    /// The axes along which the content scrolls
+    pub axis: ScrollAxis,
+    /// Whether scroll indicators are shown
+    pub shows_indicators: bool,
+}
+
+impl<V: View> ScrollView<V> {
+    /// Create a vertically scrolling container with indicators shown.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::{Text, elements::ScrollView};
+    ///
+    /// let scroll = ScrollView::new(Text::new("Long article"));
+    /// assert!(scroll.shows_indicators);
+    /// ```
+    pub fn new(content: V) -> Self {
+        Self {
+            content,
+            axis: ScrollAxis::default(),
+            shows_indicators: true,
+        }
+    }
+
+    /// Set the axes along which the content scrolls.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::{HStack, Text, elements::{ScrollAxis, ScrollView}};
+    ///
+    /// let carousel = ScrollView::new(HStack::new((Text::new("A"), Text::new("B"))))
+    ///     .axis(ScrollAxis::Horizontal);
+    /// assert_eq!(carousel.axis, ScrollAxis::Horizontal);
+    /// ```
+    pub fn axis(mut self, axis: ScrollAxis) -> Self {
+        self.axis = axis;
+        self
+    }
+
+    /// Set whether scroll indicators are shown.
+    pub fn shows_indicators(mut self, shows_indicators: bool) -> Self {
+        self.shows_indicators = shows_indicators;
+        self
+    }
+}
+
+impl<V: View> View for ScrollView<V> {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        backends::mock::{MockBackend, MockDynamicChild},
+        elements::{Text, VStack},
+        extraction::{RenderContext, ViewExtractor},
+    };
+
+    #[test]
+    fn scroll_view_extraction() {
+        let ctx = RenderContext::new();
+
+        let article = ScrollView::new(VStack::new((Text::new("Title"), Text::new("Body"))))
+            .axis(ScrollAxis::Both);
+        let extracted = MockBackend::extract(&article, &ctx).unwrap();
+        assert_eq!(extracted.axis, ScrollAxis::Both);
+        assert!(extracted.shows_indicators);
+        assert_eq!(extracted.content.content.1.content, "Body");
+
+        let rows: Vec<Box<dyn View>> = (0..3)
+            .map(|i| Box::new(Text::new(format!("Row {}", i))) as Box<dyn View>)
+            .collect();
+        let list = ScrollView::new(rows).shows_indicators(false);
+        let extracted = MockBackend::extract(&list, &ctx).unwrap();
+        assert_eq!(extracted.axis, ScrollAxis::Vertical);
+        assert!(!extracted.shows_indicators);
+        assert_eq!(extracted.content.len(), 3);
+
+        // Dynamic scroll views can themselves be dynamic children
+        let screen = VStack::dynamic().child(Box::new(list));
+        let extracted = MockBackend::extract(&screen, &ctx).unwrap();
+        assert!(matches!(
+            &extracted.content[0],
+            MockDynamicChild::ScrollView(scroll) if scroll.content.len() == 3
+        ));
+    }
+}
+
+// End of File
@@ -0,0 +1,116 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Sparkline element for small inline trend charts
+//!
+//! A Sparkline draws a data series as a small line without axes or labels,
+//! next to a number or inside a table cell, where only the shape of the trend
+//! matters. Points are scaled to the series' own minimum and maximum.
+
+use std::any::Any;
+
+use crate::{style::Color, view::View};
+
+/// A view that draws a data series as a small line chart.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{prelude::*, elements::Sparkline};
+///
+/// let trend = Sparkline::new(vec![10.0, 15.0, 20.0]).color(Color::GREEN);
+/// assert_eq!(trend.normalized_points(), vec![0.0, 0.5, 1.0]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sparkline {
+    /// The data series, in drawing order
+    pub data: Vec<f32>,
+    /// Color of the line
+    pub color: Color,
+}
+
+impl Sparkline {
+    /// Create a blue sparkline for a data series.
+    pub fn new(data: Vec<f32>) -> Self {
+        Self {
+            data,
+            color: Color::BLUE,
+        }
+    }
+
+    /// Set the color of the line.
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Scale the data to `[0.0, 1.0]`, where 0.0 is the smallest value and 1.0 the largest.
+    ///
+    /// A series whose values are all equal has no range to scale by and is
+    /// drawn as a flat line at 0.5. An empty series has no points.
+    pub fn normalized_points(&self) -> Vec<f32> {
+        let min = self.data.iter().copied().fold(f32::INFINITY, f32::min);
+        let max = self.data.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+        let range = max - min;
+        self.data
+            .iter()
+            .map(|value| {
+                if range > 0.0 && range.is_finite() {
+                    ((value - min) / range).clamp(0.0, 1.0)
+                } else {
+                    0.5
+                }
+            })
+            .collect()
+    }
+}
+
+impl View for Sparkline {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        backends::mock::MockBackend,
+        extraction::{RenderContext, ViewExtractor},
+    };
+
+    #[test]
+    fn points_scale_to_series_range() {
+        let ascending = Sparkline::new(vec![1.0, 2.0, 3.0]).normalized_points();
+        assert_eq!(ascending, vec![0.0, 0.5, 1.0]);
+
+        let mixed = Sparkline::new(vec![-4.0, 4.0, 0.0, -2.0]).normalized_points();
+        assert_eq!(mixed, vec![0.0, 1.0, 0.5, 0.25]);
+    }
+
+    #[test]
+    fn flat_and_empty_series_are_valid() {
+        assert_eq!(
+            Sparkline::new(vec![7.0, 7.0, 7.0]).normalized_points(),
+            vec![0.5, 0.5, 0.5]
+        );
+        assert_eq!(Sparkline::new(vec![3.0]).normalized_points(), vec![0.5]);
+        assert!(Sparkline::new(Vec::new()).normalized_points().is_empty());
+    }
+
+    #[test]
+    fn sparkline_extraction() {
+        let sparkline = Sparkline::new(vec![1.0, 2.0, 3.0]).color(Color::RED);
+        let extracted = MockBackend::extract(&sparkline, &RenderContext::new()).unwrap();
+        assert!(
+            extracted
+                .normalized_points
+                .windows(2)
+                .all(|pair| pair[0] < pair[1])
+        );
+        assert_eq!(extracted.color, Color::RED);
+    }
+}
+
+// End of File
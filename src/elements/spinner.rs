@@ -0,0 +1,140 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Spinner element for indeterminate progress
+//!
+//! A Spinner shows that work is happening without saying how much is left.
+//! It is pure data describing how the indicator should look: a
+//! [`SpinnerStyle`], a size and a color. Animating it is left to the backend;
+//! applications that drive the animation themselves can use the
+//! [`ActivityIndicator`](crate::widgets::ActivityIndicator) widget instead.
+
+use std::any::Any;
+
+use crate::{style::Color, view::View};
+
+/// The shape a spinner is drawn as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SpinnerStyle {
+    /// Segments arranged around a circle
+    #[default]
+    Circular,
+    /// A row of dots that pulse in turn
+    Dots,
+    /// A short band sweeping along a track
+    Bar,
+}
+
+/// Busy indicator for work of unknown length.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{prelude::*, elements::{Spinner, SpinnerStyle}};
+///
+/// let spinner = Spinner::new().style(SpinnerStyle::Dots).size(16.0).color(Color::BLUE);
+/// assert_eq!(spinner.style, SpinnerStyle::Dots);
+/// assert_eq!(spinner.size, 16.0);
+/// assert_eq!(spinner.color, Some(Color::BLUE));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spinner {
+    /// The shape to draw
+    pub style: SpinnerStyle,
+    /// Width and height of the indicator in logical pixels
+    pub size: f32,
+    /// Color of the indicator, or `None` for the current text color
+    pub color: Option<Color>,
+}
+
+impl Spinner {
+    /// Size of a medium spinner, used unless another is configured.
+    pub const DEFAULT_SIZE: f32 = 24.0;
+
+    /// Create a medium circular spinner.
+    ///
+    /// The spinner has no color of its own, so it is drawn in the current
+    /// text color.
+    pub fn new() -> Self {
+        Self {
+            style: SpinnerStyle::default(),
+            size: Self::DEFAULT_SIZE,
+            color: None,
+        }
+    }
+
+    /// Set the shape the spinner is drawn as.
+    pub fn style(mut self, style: SpinnerStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the width and height of the indicator.
+    ///
+    /// Negative sizes are treated as zero.
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = size.max(0.0);
+        self
+    }
+
+    /// Set the indicator color instead of following the text color.
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+}
+
+impl Default for Spinner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl View for Spinner {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        backends::mock::MockBackend,
+        extraction::{ForegroundColor, RenderContext, ViewExtractor},
+        style::TextStyle,
+    };
+
+    #[test]
+    fn spinner_extraction() {
+        let extracted = MockBackend::extract(&Spinner::new(), &RenderContext::new()).unwrap();
+        assert_eq!(extracted.style, SpinnerStyle::Circular);
+        assert_eq!(extracted.size, Spinner::DEFAULT_SIZE);
+    }
+
+    #[test]
+    fn spinner_color_follows_text_color_unless_set() {
+        let ctx = RenderContext::new();
+        let plain = MockBackend::extract(&Spinner::new(), &ctx).unwrap();
+        assert_eq!(
+            plain.color,
+            ctx.effective_text_style(&TextStyle::default()).color
+        );
+
+        let mut ctx = RenderContext::new();
+        ctx.set::<ForegroundColor>(Some(Color::GREEN));
+        let inherited = MockBackend::extract(&Spinner::new(), &ctx).unwrap();
+        assert_eq!(inherited.color, Color::GREEN);
+
+        let spinner = Spinner::new().style(SpinnerStyle::Bar).color(Color::RED);
+        let explicit = MockBackend::extract(&spinner, &ctx).unwrap();
+        assert_eq!(explicit.color, Color::RED);
+        assert_eq!(explicit.style, SpinnerStyle::Bar);
+
+        assert_eq!(Spinner::new().size(-4.0).size, 0.0);
+    }
+}
+
+// End of File
@@ -0,0 +1,139 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Free-form metadata attached to views
+//!
+//! Tagged wraps a view with string key/value pairs that backends pass
+//! through untouched, so analytics or telemetry backends can annotate the
+//! elements they see. Tags never change how the wrapped view is laid out or
+//! extracted. Unlike an [`Identified`](crate::elements::Identified) id, tags
+//! aren't used to look anything up and don't need to be unique.
+
+use std::{any::Any, collections::BTreeMap};
+
+use crate::view::View;
+
+/// A view annotated with metadata tags.
+///
+/// Most code creates tagged views with [`View::tag`]. Tagging an already
+/// tagged view adds to the same map rather than wrapping it again, and a
+/// repeated key keeps its latest value. Children of dynamic containers should
+/// be boxed with [`Tagged::boxed`] so backends can find the tags at runtime.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::prelude::*;
+///
+/// let signup = Button::new("Sign up")
+///     .view()
+///     .tag("analytics_id", "signup_button")
+///     .tag("screen", "welcome");
+///
+/// assert_eq!(signup.get("analytics_id"), Some("signup_button"));
+/// assert_eq!(signup.tags.len(), 2);
+/// assert_eq!(signup.content.text.content, "Sign up");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tagged<V> {
+    /// The metadata attached to the view, ordered by key
+    pub tags: BTreeMap<String, String>,
+    /// The tagged view
+    pub content: V,
+}
+
+impl<V> Tagged<V> {
+    /// Wrap a view with an empty set of tags.
+    pub fn new(content: V) -> Self {
+        Self {
+            tags: BTreeMap::new(),
+            content,
+        }
+    }
+
+    /// Add a tag, replacing any earlier value for the same key.
+    pub fn tag(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.tags.insert(key.into(), value.into());
+        self
+    }
+
+    /// Get the value of a tag.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.tags.get(key).map(String::as_str)
+    }
+}
+
+impl<V: View> Tagged<V> {
+    /// Box the content, producing the dynamic form used inside dynamic containers.
+    pub fn boxed(self) -> Tagged<Box<dyn View>> {
+        Tagged {
+            tags: self.tags,
+            content: Box::new(self.content),
+        }
+    }
+}
+
+impl<V: View> View for Tagged<V> {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+// Dynamic tagged view implementation
+impl View for Tagged<Box<dyn View>> {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        backends::mock::{MockBackend, MockDynamicChild},
+        elements::{Text, VStack},
+        extraction::{RenderContext, ViewExtractor},
+        model::Model,
+        widgets::Button,
+    };
+
+    #[test]
+    fn tags_accumulate_and_extract_beside_the_view() {
+        let ctx = RenderContext::new();
+        let button = Button::new("Sign up").view();
+
+        let tagged = button
+            .clone()
+            .tag("analytics_id", "signup")
+            .tag("variant", "a")
+            .tag("analytics_id", "signup_button");
+        assert_eq!(tagged.tags.len(), 2);
+
+        let extracted = MockBackend::extract(&tagged, &ctx).unwrap();
+        assert_eq!(
+            extracted.tags.get("analytics_id").map(String::as_str),
+            Some("signup_button")
+        );
+        assert_eq!(extracted.tags.get("variant").map(String::as_str), Some("a"));
+        assert_eq!(
+            extracted.content,
+            MockBackend::extract(&button, &ctx).unwrap()
+        );
+
+        let list = VStack::dynamic().child(Box::new(
+            Text::new("Row").tag("analytics_id", "row").boxed(),
+        ));
+        let extracted = MockBackend::extract(&list, &ctx).unwrap();
+        let MockDynamicChild::Tagged(row) = &extracted.content[0] else {
+            panic!("expected tagged view, got {:?}", extracted.content[0]);
+        };
+        assert_eq!(
+            row.tags.get("analytics_id").map(String::as_str),
+            Some("row")
+        );
+        assert!(matches!(row.content, MockDynamicChild::Text(_)));
+    }
+}
+
+// End of File
@@ -7,13 +7,306 @@
 //! The Text component is a view that represents styled text content.
 //! It's a pure data structure that describes how text should appear.
 
-use std::any::Any;
+use std::{
+    any::Any,
+    borrow::Cow,
+    collections::HashSet,
+    fmt::{Debug, Display, Formatter, Result as FormatterResult},
+    ops::Deref,
+    sync::{Arc, LazyLock, Mutex, PoisonError},
+};
 
 use crate::{
-    style::{Color, TextStyle},
+    style::{Color, FontFamily, FontStyle, FontWeight, TextAlignment, TextStyle, TruncationMode},
     view::View,
 };
 
+/// Storage for the string displayed by a [`Text`] view.
+///
+/// Text content can come from three places, each stored without unnecessary
+/// copying: string literals are borrowed for the lifetime of the program,
+/// runtime strings are owned, and interned strings share a reference-counted
+/// allocation with every other text holding the same content.
+///
+/// `TextContent` dereferences to `str`, and equality is defined on the string
+/// value alone, so the storage strategy never affects comparisons or extraction.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::elements::TextContent;
+///
+/// let borrowed = TextContent::from("Label");
+/// let owned = TextContent::from(String::from("Label"));
+/// assert_eq!(borrowed, owned);
+/// assert_eq!(borrowed.len(), 5);
+/// ```
+#[derive(Clone)]
+pub enum TextContent {
+    /// A string literal borrowed for the lifetime of the program
+    Static(&'static str),
+    /// A string owned by this text
+    Owned(String),
+    /// A string shared with other texts, typically through a [`TextInterner`]
+    Shared(Arc<str>),
+}
+
+impl TextContent {
+    /// Character appended to content shortened by [`TextContent::truncated`].
+    pub const ELLIPSIS: char = '…';
+
+    /// Get the content as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Static(content) => content,
+            Self::Owned(content) => content,
+            Self::Shared(content) => content,
+        }
+    }
+
+    /// Shorten the content to at most `max_chars` characters.
+    ///
+    /// Content that already fits is returned unchanged. Longer content keeps
+    /// its leading characters and ends in [`TextContent::ELLIPSIS`], which
+    /// counts towards the limit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::elements::TextContent;
+    ///
+    /// let label = TextContent::from("Export Selection");
+    /// assert_eq!(label.truncated(7), "Export…");
+    /// assert_eq!(label.truncated(40), "Export Selection");
+    /// ```
+    pub fn truncated(&self, max_chars: usize) -> TextContent {
+        if self.chars().count() <= max_chars {
+            return self.clone();
+        }
+        if max_chars == 0 {
+            return Self::Static("");
+        }
+
+        let mut shortened: String = self.chars().take(max_chars - 1).collect();
+        shortened.push(Self::ELLIPSIS);
+        Self::Owned(shortened)
+    }
+}
+
+impl Deref for TextContent {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for TextContent {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Debug for TextContent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FormatterResult {
+        Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl Display for TextContent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FormatterResult {
+        Display::fmt(self.as_str(), f)
+    }
+}
+
+impl PartialEq for TextContent {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for TextContent {}
+
+impl PartialEq<str> for TextContent {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for TextContent {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialEq<String> for TextContent {
+    fn eq(&self, other: &String) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl From<&'static str> for TextContent {
+    fn from(content: &'static str) -> Self {
+        Self::Static(content)
+    }
+}
+
+impl From<String> for TextContent {
+    fn from(content: String) -> Self {
+        Self::Owned(content)
+    }
+}
+
+impl From<&String> for TextContent {
+    fn from(content: &String) -> Self {
+        Self::Owned(content.clone())
+    }
+}
+
+impl From<char> for TextContent {
+    fn from(content: char) -> Self {
+        Self::Owned(content.to_string())
+    }
+}
+
+impl From<Cow<'static, str>> for TextContent {
+    fn from(content: Cow<'static, str>) -> Self {
+        match content {
+            Cow::Borrowed(content) => Self::Static(content),
+            Cow::Owned(content) => Self::Owned(content),
+        }
+    }
+}
+
+impl From<Arc<str>> for TextContent {
+    fn from(content: Arc<str>) -> Self {
+        Self::Shared(content)
+    }
+}
+
+/// A bounded, thread-safe pool of shared strings.
+///
+/// Large lists often repeat the same handful of labels thousands of times.
+/// Interning stores each distinct string once and hands out reference-counted
+/// handles to it, so repeated labels share a single allocation.
+///
+/// The capacity is a hard limit: the pool never retains more strings than
+/// that. When it is full, strings no text holds a handle to anymore are
+/// dropped to make room. Finding them takes a pass over the pool, so a full
+/// pool sweeps at most once per `capacity` newly created strings, which keeps
+/// interning amortized constant time. New strings that find no room are
+/// still returned as shared handles but are not retained for reuse. Calling
+/// [`TextInterner::clear`] drops the pool's references; handles already held
+/// by texts stay valid.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::Arc;
+/// use ironwood::elements::TextInterner;
+///
+/// let interner = TextInterner::new(16);
+/// let first = interner.intern("Item");
+/// let second = interner.intern("Item");
+/// assert!(Arc::ptr_eq(&first, &second));
+/// assert_eq!(interner.len(), 1);
+/// ```
+#[derive(Debug)]
+pub struct TextInterner {
+    /// Retained strings and sweep bookkeeping
+    pool: Mutex<Pool>,
+    /// Maximum number of distinct strings retained
+    capacity: usize,
+}
+
+/// The state a [`TextInterner`] guards with its lock.
+#[derive(Debug, Default)]
+struct Pool {
+    /// Distinct strings currently retained
+    strings: HashSet<Arc<str>>,
+    /// Strings created since the last sweep for unused strings
+    created_since_sweep: usize,
+}
+
+/// The process-wide pool used by [`Text::interned`]
+static GLOBAL_INTERNER: LazyLock<TextInterner> =
+    LazyLock::new(|| TextInterner::new(TextInterner::DEFAULT_CAPACITY));
+
+impl TextInterner {
+    /// Number of distinct strings retained by the global pool.
+    pub const DEFAULT_CAPACITY: usize = 4096;
+
+    /// Create an empty pool that retains at most `capacity` distinct strings.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            pool: Mutex::new(Pool::default()),
+            capacity,
+        }
+    }
+
+    /// Get the process-wide pool used by [`Text::interned`].
+    pub fn global() -> &'static TextInterner {
+        &GLOBAL_INTERNER
+    }
+
+    /// Get a shared handle to the given string.
+    ///
+    /// Returns the pooled handle when the string has been interned before.
+    /// Otherwise a new handle is created and retained if the pool has room,
+    /// possibly after dropping strings only the pool still refers to.
+    pub fn intern(&self, content: &str) -> Arc<str> {
+        let mut pool = self.pool.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(existing) = pool.strings.get(content) {
+            return Arc::clone(existing);
+        }
+
+        let shared: Arc<str> = Arc::from(content);
+        pool.created_since_sweep += 1;
+        if pool.strings.len() >= self.capacity && pool.created_since_sweep >= self.capacity {
+            // Handles are only cloned under the lock, so a count of one can't
+            // change while the sweep runs
+            pool.strings
+                .retain(|retained| Arc::strong_count(retained) > 1);
+            pool.created_since_sweep = 0;
+        }
+        if pool.strings.len() < self.capacity {
+            pool.strings.insert(Arc::clone(&shared));
+        }
+        shared
+    }
+
+    /// Get the number of distinct strings currently retained.
+    pub fn len(&self) -> usize {
+        self.pool
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .strings
+            .len()
+    }
+
+    /// Check whether the pool currently retains no strings.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the maximum number of distinct strings the pool retains.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Drop every string retained by the pool.
+    ///
+    /// Texts created from the pool keep their content; only future
+    /// interning stops sharing with them.
+    pub fn clear(&self) {
+        self.pool
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .strings
+            .clear();
+    }
+}
+
 /// Text view for displaying styled text content.
 ///
 /// Text views are pure data structures that describe how text should appear.
@@ -33,7 +326,12 @@ use crate::{
 #[derive(Debug, Clone, PartialEq)]
 pub struct Text {
     /// The text content to display
-    pub content: String,
+    ///
+    /// Static string literals are borrowed rather than copied, so literal
+    /// labels never allocate, and labels created with [`Text::interned`]
+    /// share storage with identical labels. [`Text::content()`] reads the
+    /// content as a `&str` whichever way it is stored.
+    pub content: TextContent,
     /// Text styling properties
     pub style: TextStyle,
 }
@@ -42,7 +340,9 @@ impl Text {
     /// Create a new text view with the specified content.
     ///
     /// Uses default styling (16px black text) that can be customized
-    /// using the builder methods.
+    /// using the builder methods. String literals are borrowed rather than
+    /// copied, so the common `Text::new("Label")` never allocates; owned
+    /// strings are stored as they are.
     ///
     /// # Examples
     ///
@@ -50,15 +350,86 @@ impl Text {
     /// use ironwood::prelude::*;
     ///
     /// let text = Text::new("Hello, world!");
-    /// assert_eq!(text.content, "Hello, world!");
+    /// assert_eq!(text.content(), "Hello, world!");
+    ///
+    /// let count = 3;
+    /// let text = Text::new(format!("{} items", count));
+    /// assert_eq!(text.content(), "3 items");
     /// ```
-    pub fn new(content: impl Into<String>) -> Self {
+    pub fn new(content: impl Into<TextContent>) -> Self {
         Self {
             content: content.into(),
             style: TextStyle::default(),
         }
     }
 
+    /// Get the text content as a string slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// assert_eq!(Text::new("Save").content().len(), 4);
+    /// ```
+    pub fn content(&self) -> &str {
+        self.content.as_str()
+    }
+
+    /// Create a new text view that borrows a static string.
+    ///
+    /// This is what [`Text::new`] does with a string literal, spelled out for
+    /// call sites that want to guarantee the content is never copied to the
+    /// heap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// static LABEL: &str = "Save";
+    /// let text = Text::static_str(LABEL);
+    /// assert_eq!(text.content, "Save");
+    /// assert_eq!(text.content.as_ptr(), LABEL.as_ptr());
+    /// ```
+    pub fn static_str(content: &'static str) -> Self {
+        Self {
+            content: TextContent::Static(content),
+            style: TextStyle::default(),
+        }
+    }
+
+    /// Create a new text view whose content is shared through the global interner.
+    ///
+    /// Identical labels created this way share a single allocation, which keeps
+    /// memory use flat in large lists that repeat the same strings. Interned
+    /// texts compare and extract exactly like texts created with [`Text::new`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// let first = Text::interned("Item");
+    /// let second = Text::interned("Item");
+    /// assert_eq!(first.content.as_ptr(), second.content.as_ptr());
+    /// assert_eq!(first, Text::new("Item"));
+    /// ```
+    pub fn interned(content: &str) -> Self {
+        Self::shared(TextInterner::global().intern(content))
+    }
+
+    /// Create a new text view from an already shared string.
+    ///
+    /// This is useful together with a dedicated [`TextInterner`] when a
+    /// separate pool is preferred over the global one.
+    pub fn shared(content: Arc<str>) -> Self {
+        Self {
+            content: TextContent::Shared(content),
+            style: TextStyle::default(),
+        }
+    }
+
     /// Set the font size for this text.
     ///
     /// Font size is specified in logical pixels. The actual rendered size
@@ -91,6 +462,116 @@ impl Text {
         self.style = self.style.color(color);
         self
     }
+
+    /// Set the font weight for this text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::{prelude::*, style::FontWeight};
+    ///
+    /// let heading = Text::new("Settings").font_weight(FontWeight::BOLD);
+    /// assert_eq!(heading.style.font_weight, FontWeight::BOLD);
+    /// ```
+    pub fn font_weight(mut self, weight: FontWeight) -> Self {
+        self.style = self.style.font_weight(weight);
+        self
+    }
+
+    /// Draw this text in italics.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::{prelude::*, style::FontStyle};
+    ///
+    /// let aside = Text::new("optional").italic();
+    /// assert_eq!(aside.style.font_style, FontStyle::Italic);
+    /// ```
+    pub fn italic(mut self) -> Self {
+        self.style = self.style.font_style(FontStyle::Italic);
+        self
+    }
+
+    /// Set the font family for this text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::{prelude::*, style::FontFamily};
+    ///
+    /// let snippet = Text::new("cargo build").font_family(FontFamily::Monospace);
+    /// assert_eq!(snippet.style.font_family, Some(FontFamily::Monospace));
+    /// ```
+    pub fn font_family(mut self, family: FontFamily) -> Self {
+        self.style = self.style.font_family(family);
+        self
+    }
+
+    /// Set how the lines of this text are aligned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::{prelude::*, style::TextAlignment};
+    ///
+    /// let caption = Text::new("Figure 1\nThe assembled part").alignment(TextAlignment::Center);
+    /// assert_eq!(caption.style.alignment, TextAlignment::Center);
+    /// ```
+    pub fn alignment(mut self, alignment: TextAlignment) -> Self {
+        self.style = self.style.alignment(alignment);
+        self
+    }
+
+    /// Set the line height of this text as a multiple of the normal line height.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// let paragraph = Text::new("First line\nSecond line").line_spacing(1.5);
+    /// assert_eq!(paragraph.style.line_spacing, 1.5);
+    /// ```
+    pub fn line_spacing(mut self, spacing: f32) -> Self {
+        self.style = self.style.line_spacing(spacing);
+        self
+    }
+
+    /// Show at most `lines` lines of this text.
+    ///
+    /// Text beyond the limit is cut off according to the
+    /// [truncation mode](Text::truncation), which defaults to the tail.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// let row_title = Text::new("A very long document name").line_limit(1);
+    /// assert_eq!(row_title.style.line_limit, Some(1));
+    /// ```
+    pub fn line_limit(mut self, lines: usize) -> Self {
+        self.style = self.style.line_limit(lines);
+        self
+    }
+
+    /// Set where this text is cut off when it exceeds its line limit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::{prelude::*, style::TruncationMode};
+    ///
+    /// let path = Text::new("/home/user/projects/ironwood/src/lib.rs")
+    ///     .line_limit(1)
+    ///     .truncation(TruncationMode::Middle);
+    /// assert_eq!(path.style.truncation, TruncationMode::Middle);
+    /// ```
+    pub fn truncation(mut self, mode: TruncationMode) -> Self {
+        self.style = self.style.truncation(mode);
+        self
+    }
 }
 
 impl View for Text {
@@ -125,6 +606,147 @@ mod tests {
         assert_eq!(chained.style.color, Color::BLUE);
     }
 
+    #[test]
+    fn static_text_borrows_literal() {
+        use crate::{
+            backends::mock::MockBackend,
+            extraction::{RenderContext, ViewExtractor},
+        };
+
+        static LABELS: [&str; 3] = ["+", "-", "Reset"];
+
+        // Every static label points directly at the literal's storage
+        let texts: Vec<Text> = (0..1000)
+            .map(|i| match i % 2 {
+                0 => Text::static_str(LABELS[i % LABELS.len()]),
+                _ => Text::new(LABELS[i % LABELS.len()]),
+            })
+            .collect();
+        for (i, text) in texts.iter().enumerate() {
+            let label = LABELS[i % LABELS.len()];
+            assert!(matches!(text.content, TextContent::Static(_)));
+            assert_eq!(text.content.as_ptr(), label.as_ptr());
+        }
+
+        // Owned and borrowed text compare and extract identically
+        let ctx = RenderContext::new();
+        let owned = Text::new(String::from("Reset")).color(Color::RED);
+        let borrowed = Text::static_str("Reset").color(Color::RED);
+        assert_eq!(owned, borrowed);
+        assert_eq!(
+            MockBackend::extract(&owned, &ctx).unwrap(),
+            MockBackend::extract(&borrowed, &ctx).unwrap()
+        );
+    }
+
+    #[test]
+    fn interned_text_shares_storage() {
+        use crate::{
+            backends::mock::MockBackend,
+            extraction::{RenderContext, ViewExtractor},
+        };
+
+        // Every interned "Item" shares the same backing allocation
+        let items: Vec<Text> = (0..1000).map(|_| Text::interned("Item")).collect();
+        let first = items[0].content.as_ptr();
+        assert!(items.iter().all(|item| item.content.as_ptr() == first));
+
+        // Interned text behaves exactly like plain text
+        let ctx = RenderContext::new();
+        let plain = Text::new("Item").font_size(12.0);
+        let interned = Text::interned("Item").font_size(12.0);
+        assert_eq!(plain, interned);
+        assert_eq!(
+            MockBackend::extract(&plain, &ctx).unwrap(),
+            MockBackend::extract(&interned, &ctx).unwrap()
+        );
+    }
+
+    #[test]
+    fn interner_is_bounded_and_clearable() {
+        let interner = TextInterner::new(2);
+        let plus = interner.intern("+");
+        let minus = interner.intern("-");
+
+        // A full pool still hands out content but stops retaining new strings
+        let overflow = interner.intern("Reset");
+        assert_eq!(&*overflow, "Reset");
+        assert_eq!(interner.len(), 2);
+        assert!(!Arc::ptr_eq(&overflow, &interner.intern("Reset")));
+        assert_eq!(&*minus, "-");
+
+        // Clearing empties the pool while existing handles stay valid
+        interner.clear();
+        assert!(interner.is_empty());
+        assert_eq!(&*plus, "+");
+        assert!(!Arc::ptr_eq(&plus, &interner.intern("+")));
+    }
+
+    #[test]
+    fn full_interner_evicts_strings_no_longer_in_use() {
+        let interner = TextInterner::new(2);
+        let kept = interner.intern("Kept");
+        let freed = interner.intern("Freed");
+        drop(freed);
+
+        // The full pool drops the string nobody holds to make room
+        let fresh = interner.intern("Fresh");
+        assert_eq!(interner.len(), 2);
+        assert!(Arc::ptr_eq(&fresh, &interner.intern("Fresh")));
+        assert!(Arc::ptr_eq(&kept, &interner.intern("Kept")));
+
+        // Once every retained string is in use, new strings aren't retained
+        let overflow = interner.intern("Overflow");
+        assert!(!Arc::ptr_eq(&overflow, &interner.intern("Overflow")));
+        assert_eq!(interner.len(), 2);
+    }
+
+    #[test]
+    fn full_interner_never_exceeds_its_capacity() {
+        let interner = TextInterner::new(4);
+        let held: Vec<Arc<str>> = ["A", "B", "C", "D"]
+            .into_iter()
+            .map(|label| interner.intern(label))
+            .collect();
+
+        // With every retained string in use, a stream of new strings is
+        // served without growing the pool
+        for i in 0..100 {
+            let label = format!("Row {}", i);
+            assert_eq!(&*interner.intern(&label), label);
+            assert_eq!(interner.len(), 4);
+        }
+        assert!(
+            held.iter()
+                .all(|label| Arc::ptr_eq(label, &interner.intern(label)))
+        );
+
+        // Releasing the handles lets a later sweep reclaim the space
+        drop(held);
+        let labels: Vec<Arc<str>> = (0..4)
+            .map(|i| interner.intern(&format!("New {}", i)))
+            .collect();
+        assert_eq!(interner.len(), 4);
+        assert!(Arc::ptr_eq(&labels[3], &interner.intern("New 3")));
+    }
+
+    #[test]
+    fn content_truncation() {
+        let content = TextContent::from("Hello, world!");
+
+        // Content that fits is untouched, including the exact-fit boundary
+        assert_eq!(content.truncated(100), "Hello, world!");
+        assert_eq!(content.truncated(13), "Hello, world!");
+
+        // Longer content keeps its head and ends in an ellipsis within the limit
+        assert_eq!(content.truncated(6), "Hello…");
+        assert_eq!(content.truncated(1), "…");
+        assert_eq!(content.truncated(0), "");
+
+        // Limits count characters, not bytes
+        assert_eq!(TextContent::from("héllo wörld").truncated(5), "héll…");
+    }
+
     #[test]
     fn text_edge_cases() {
         use crate::{
@@ -0,0 +1,147 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Tooltip wrapper attaching help text to a view
+//!
+//! A tooltip is short help text revealed while the pointer hovers over a
+//! view. The wrapper only records which text belongs to which view and where
+//! it should appear; whether it is currently visible follows the child's own
+//! hover state (see [`Hoverable`](crate::interaction::Hoverable)), and how
+//! the popup is drawn is up to the backend. Backends without popups can
+//! still expose the text to assistive technology.
+
+use std::any::Any;
+
+use crate::{elements::Text, view::View};
+
+/// The side of a view its tooltip appears on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TooltipPlacement {
+    /// Above the view
+    #[default]
+    Top,
+    /// Below the view
+    Bottom,
+    /// Before the view along the layout direction
+    Leading,
+    /// After the view along the layout direction
+    Trailing,
+}
+
+/// A view with help text revealed on hover.
+///
+/// Most code creates tooltips with [`View::tooltip`].
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{prelude::*, elements::TooltipPlacement};
+///
+/// let save = Button::new("💾")
+///     .view()
+///     .tooltip("Save the document")
+///     .placement(TooltipPlacement::Bottom);
+///
+/// assert_eq!(save.text.content, "Save the document");
+/// assert_eq!(save.placement, TooltipPlacement::Bottom);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tooltip<V> {
+    /// The help text
+    pub text: Text,
+    /// The side of the child the text appears on
+    pub placement: TooltipPlacement,
+    /// The view the help text describes
+    pub child: V,
+}
+
+impl<V> Tooltip<V> {
+    /// Attach help text to a view, shown above it.
+    pub fn new(child: V, text: impl Into<String>) -> Self {
+        Self {
+            text: Text::new(text.into()),
+            placement: TooltipPlacement::default(),
+            child,
+        }
+    }
+
+    /// Set the side of the view the text appears on.
+    pub fn placement(mut self, placement: TooltipPlacement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Style the help text.
+    pub fn with_text(mut self, f: impl FnOnce(Text) -> Text) -> Self {
+        self.text = f(self.text);
+        self
+    }
+}
+
+impl<V: View> View for Tooltip<V> {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+// Dynamic tooltip implementation
+impl View for Tooltip<Box<dyn View>> {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        backends::mock::{MockBackend, MockDynamicChild},
+        elements::VStack,
+        extraction::{RenderContext, ViewExtractor},
+        interaction::Hoverable,
+        model::Model,
+        style::Color,
+        widgets::Button,
+    };
+
+    #[test]
+    fn tooltip_extracts_text_placement_and_child() {
+        let ctx = RenderContext::new();
+        let view = Button::new("?")
+            .hover()
+            .view()
+            .tooltip("Show keyboard shortcuts")
+            .placement(TooltipPlacement::Trailing)
+            .with_text(|text| text.color(Color::WHITE));
+
+        let extracted = MockBackend::extract(&view, &ctx).unwrap();
+        assert_eq!(extracted.text.content, "Show keyboard shortcuts");
+        assert_eq!(extracted.text.color, Color::WHITE);
+        assert_eq!(extracted.placement, TooltipPlacement::Trailing);
+        // Visibility follows the child's hover state
+        assert!(extracted.child.interaction_state.is_hovered());
+    }
+
+    #[test]
+    fn dynamic_tooltips_keep_their_child() {
+        let help = Tooltip::new(
+            Box::new(Text::new("API key")) as Box<dyn View>,
+            "Found in settings",
+        );
+        let form = VStack::dynamic().child(Box::new(help));
+
+        let extracted = MockBackend::extract(&form, &RenderContext::new()).unwrap();
+        let MockDynamicChild::Tooltip(tooltip) = &extracted.content[0] else {
+            panic!("expected tooltip, got {:?}", extracted.content[0]);
+        };
+        assert_eq!(tooltip.text.content, "Found in settings");
+        assert_eq!(tooltip.placement, TooltipPlacement::Top);
+        assert!(
+            matches!(&tooltip.child, MockDynamicChild::Text(text) if text.content == "API key")
+        );
+    }
+}
+
+// End of File
@@ -0,0 +1,527 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Incremental extraction: dirty tracking, caching and frame scheduling
+
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    fmt::Debug,
+    hash::Hash,
+};
+
+use super::{ExtractionResult, RenderContext};
+use crate::model::Model;
+
+/// Record of which parts of a view tree changed since the last extraction.
+///
+/// Models mark the ids of the [`Identified`](crate::elements::Identified)
+/// subtrees their updates affected, and backends that support partial
+/// re-extraction refresh only those subtrees. A change that can't be tied to
+/// an id is recorded with [`DirtySet::mark_all`], which makes the next pass
+/// re-extract the whole tree. Backends clear the set after a successful pass.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::extraction::DirtySet;
+///
+/// let mut dirty = DirtySet::new();
+/// assert!(dirty.is_clean());
+///
+/// dirty.mark("count");
+/// assert!(dirty.contains("count"));
+/// assert!(!dirty.requires_full());
+///
+/// dirty.mark_all();
+/// assert!(dirty.requires_full());
+///
+/// dirty.clear();
+/// assert!(dirty.is_clean());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DirtySet {
+    ids: BTreeSet<String>,
+    full: bool,
+}
+
+impl DirtySet {
+    /// Create an empty set with nothing marked dirty.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Mark the subtree with the given id as changed.
+    pub fn mark(&mut self, id: impl Into<String>) {
+        self.ids.insert(id.into());
+    }
+
+    /// Mark a change that isn't tied to an id, requiring a full re-extraction.
+    pub fn mark_all(&mut self) {
+        self.full = true;
+    }
+
+    /// Check whether nothing has been marked since the last clear.
+    pub fn is_clean(&self) -> bool {
+        !self.full && self.ids.is_empty()
+    }
+
+    /// Check whether the whole tree must be re-extracted.
+    pub fn requires_full(&self) -> bool {
+        self.full
+    }
+
+    /// Check whether the subtree with the given id has been marked.
+    pub fn contains(&self, id: &str) -> bool {
+        self.ids.contains(id)
+    }
+
+    /// Iterate over the marked ids in sorted order.
+    pub fn ids(&self) -> impl Iterator<Item = &str> {
+        self.ids.iter().map(String::as_str)
+    }
+
+    /// Reset the set after an extraction pass.
+    pub fn clear(&mut self) {
+        self.ids.clear();
+        self.full = false;
+    }
+}
+
+/// Bounded cache of extracted trees keyed by the model state they came from.
+///
+/// Models are immutable and extraction is pure, so a model state that has been
+/// seen before always extracts to the same tree under the same render context.
+/// The cache looks models up by hash and confirms matches by equality, so
+/// distinct states never share an entry even if their hashes collide. Trees
+/// extracted under an earlier [context generation](RenderContext::generation)
+/// are discarded, so a change to the text scale, theme or environment is
+/// never answered with a stale tree. Once full, the least recently used entry
+/// is evicted. Failed extractions are not cached.
+///
+/// [`RenderScheduler::render_frame_cached`] consults a cache when rendering
+/// frames.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{backends::mock::MockBackend, extraction::ExtractionCache, prelude::*};
+///
+/// let ctx = RenderContext::new();
+/// let mut cache = ExtractionCache::new(8);
+///
+/// let extract =
+///     |label: &String, ctx: &RenderContext| MockBackend::extract(&Text::new(label), ctx);
+/// let first = cache.get_or_extract(&"Ready".to_string(), &ctx, extract).unwrap();
+/// let again = cache.get_or_extract(&"Ready".to_string(), &ctx, extract).unwrap();
+///
+/// assert_eq!(first, again);
+/// assert_eq!((cache.hits(), cache.misses()), (1, 1));
+///
+/// // A different context extracts afresh
+/// let larger = ctx.clone().with_text_scale(1.5);
+/// let scaled = cache.get_or_extract(&"Ready".to_string(), &larger, extract).unwrap();
+/// assert_eq!(scaled.font_size, 24.0);
+/// assert_eq!(cache.misses(), 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ExtractionCache<M, T> {
+    entries: HashMap<M, CacheEntry<T>>,
+    /// Cached models ordered by when they were last used, oldest first
+    recency: BTreeMap<u64, M>,
+    /// Generation of the context the cached trees were extracted under
+    generation: Option<u64>,
+    capacity: usize,
+    clock: u64,
+    hits: u64,
+    misses: u64,
+}
+
+/// A cached tree and the time it was last used.
+#[derive(Debug, Clone)]
+struct CacheEntry<T> {
+    tree: T,
+    last_used: u64,
+}
+
+impl<M: Hash + Eq + Clone, T: Clone> ExtractionCache<M, T> {
+    /// Create a cache holding at most `capacity` trees (at least one).
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: HashMap::new(),
+            recency: BTreeMap::new(),
+            generation: None,
+            capacity: capacity.max(1),
+            clock: 0,
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// Get the tree cached for `model`, extracting and caching it if needed.
+    ///
+    /// `extract` only runs on a cache miss. If `context` has a different
+    /// [generation](RenderContext::generation) than the one the cached trees
+    /// were extracted under, every cached tree is discarded first.
+    pub fn get_or_extract(
+        &mut self,
+        model: &M,
+        context: &RenderContext,
+        extract: impl FnOnce(&M, &RenderContext) -> ExtractionResult<T>,
+    ) -> ExtractionResult<T> {
+        if self.generation != Some(context.generation()) {
+            self.clear();
+            self.generation = Some(context.generation());
+        }
+
+        self.clock += 1;
+        if let Some(entry) = self.entries.get_mut(model) {
+            self.recency.remove(&entry.last_used);
+            self.recency.insert(self.clock, model.clone());
+            entry.last_used = self.clock;
+            self.hits += 1;
+            return Ok(entry.tree.clone());
+        }
+
+        self.misses += 1;
+        let tree = extract(model, context)?;
+        if self.entries.len() >= self.capacity {
+            self.evict_least_recently_used();
+        }
+        self.recency.insert(self.clock, model.clone());
+        self.entries.insert(
+            model.clone(),
+            CacheEntry {
+                tree: tree.clone(),
+                last_used: self.clock,
+            },
+        );
+        Ok(tree)
+    }
+
+    /// Check whether a tree is cached for `model`, without counting a use.
+    pub fn contains(&self, model: &M) -> bool {
+        self.entries.contains_key(model)
+    }
+
+    /// Get the number of cached trees.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check whether the cache holds no trees.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Get the maximum number of cached trees.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Get the number of lookups answered from the cache.
+    pub fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    /// Get the number of lookups that had to extract.
+    pub fn misses(&self) -> u64 {
+        self.misses
+    }
+
+    /// Remove every cached tree, keeping the statistics.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.recency.clear();
+    }
+
+    /// Remove the entry that has gone unused the longest.
+    fn evict_least_recently_used(&mut self) {
+        if let Some((_, model)) = self.recency.pop_first() {
+            self.entries.remove(&model);
+        }
+    }
+}
+
+/// Coalesces bursts of messages into one extraction per frame.
+///
+/// Messages are applied to the model as soon as they are dispatched, but
+/// the view is only extracted when the next frame is rendered, and only if
+/// something changed since the last one. During bursts such as a drag or
+/// fast typing, intermediate states are skipped while the latest state is
+/// always the one rendered.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{backends::mock::MockBackend, extraction::RenderScheduler, prelude::*};
+///
+/// let ctx = RenderContext::new();
+/// let mut scheduler = RenderScheduler::new(Button::new("Drag"));
+///
+/// scheduler.dispatch(ButtonMessage::Interaction(InteractionMessage::HoverChanged(true)));
+/// scheduler.dispatch(ButtonMessage::Interaction(InteractionMessage::PressStateChanged(true)));
+///
+/// let frame = scheduler.render_frame(|button| MockBackend::extract(&button.view(), &ctx));
+/// let extracted = frame.unwrap().unwrap();
+/// assert!(extracted.interaction_state.is_pressed());
+///
+/// // Nothing changed, so the next frame skips extraction
+/// let frame = scheduler.render_frame(|button| MockBackend::extract(&button.view(), &ctx));
+/// assert!(frame.unwrap().is_none());
+/// ```
+#[derive(Debug, Clone)]
+pub struct RenderScheduler<M> {
+    model: M,
+    dirty: bool,
+}
+
+impl<M: Model> RenderScheduler<M> {
+    /// Start scheduling renders for a model, which needs rendering once.
+    pub fn new(model: M) -> Self {
+        Self { model, dirty: true }
+    }
+
+    /// Get the current model state.
+    pub fn model(&self) -> &M {
+        &self.model
+    }
+
+    /// Apply a message to the model, deferring extraction to the next frame.
+    pub fn dispatch(&mut self, message: M::Message) {
+        let model = self.model.clone();
+        self.model = model.update(message);
+        self.dirty = true;
+    }
+
+    /// Check whether the model changed since the last rendered frame.
+    pub fn needs_render(&self) -> bool {
+        self.dirty
+    }
+
+    /// Render a frame, extracting the current state only if it changed.
+    ///
+    /// Returns `None` when nothing was dispatched since the last frame. If
+    /// extraction fails, the frame is still considered pending and the next
+    /// call tries again.
+    pub fn render_frame<T>(
+        &mut self,
+        extract: impl FnOnce(&M) -> ExtractionResult<T>,
+    ) -> ExtractionResult<Option<T>> {
+        if !self.dirty {
+            return Ok(None);
+        }
+        let tree = extract(&self.model)?;
+        self.dirty = false;
+        Ok(Some(tree))
+    }
+}
+
+impl<M: Model + Hash + Eq> RenderScheduler<M> {
+    /// Render a frame like [`render_frame`](Self::render_frame), reusing
+    /// trees cached for states that were rendered before.
+    ///
+    /// Dispatching messages that return the model to an earlier state then
+    /// costs a cache lookup instead of an extraction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::{
+    ///     backends::mock::MockBackend,
+    ///     extraction::{ExtractionCache, RenderScheduler},
+    ///     prelude::*,
+    /// };
+    ///
+    /// #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    /// struct Light {
+    ///     on: bool,
+    /// }
+    ///
+    /// #[derive(Debug, Clone)]
+    /// struct Flip;
+    ///
+    /// impl Message for Flip {}
+    ///
+    /// impl Model for Light {
+    ///     type Message = Flip;
+    ///     type View = Text;
+    ///
+    ///     fn update(self, _message: Flip) -> Self {
+    ///         Self { on: !self.on }
+    ///     }
+    ///
+    ///     fn view(&self) -> Text {
+    ///         Text::new(if self.on { "On" } else { "Off" })
+    ///     }
+    /// }
+    ///
+    /// let ctx = RenderContext::new();
+    /// let mut cache = ExtractionCache::new(16);
+    /// let mut scheduler = RenderScheduler::new(Light { on: false });
+    /// let extract = |light: &Light, ctx: &RenderContext| MockBackend::extract(&light.view(), ctx);
+    ///
+    /// scheduler.render_frame_cached(&mut cache, &ctx, extract).unwrap();
+    /// scheduler.dispatch(Flip);
+    /// scheduler.render_frame_cached(&mut cache, &ctx, extract).unwrap();
+    /// scheduler.dispatch(Flip);
+    ///
+    /// // Back to the first state, which is served from the cache
+    /// let frame = scheduler.render_frame_cached(&mut cache, &ctx, extract).unwrap();
+    /// assert_eq!(frame.unwrap().content, "Off");
+    /// assert_eq!((cache.hits(), cache.misses()), (1, 2));
+    /// ```
+    pub fn render_frame_cached<T: Clone>(
+        &mut self,
+        cache: &mut ExtractionCache<M, T>,
+        context: &RenderContext,
+        extract: impl FnOnce(&M, &RenderContext) -> ExtractionResult<T>,
+    ) -> ExtractionResult<Option<T>> {
+        if !self.dirty {
+            return Ok(None);
+        }
+        let tree = cache.get_or_extract(&self.model, context, extract)?;
+        self.dirty = false;
+        Ok(Some(tree))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{elements::Text, extraction::ViewExtractor};
+
+    #[test]
+    fn extraction_cache_reuses_revisited_states() {
+        use std::cell::Cell;
+
+        use crate::backends::mock::MockBackend;
+
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        struct Counter {
+            count: i32,
+        }
+
+        let ctx = RenderContext::new();
+        let runs = Cell::new(0);
+        let extract = |model: &Counter, ctx: &RenderContext| {
+            runs.set(runs.get() + 1);
+            MockBackend::extract(&Text::new(format!("Count: {}", model.count)), ctx)
+        };
+
+        // Toggle between two states: only the first visit to each extracts
+        let mut cache = ExtractionCache::new(4);
+        for count in [0, 1, 0, 1] {
+            let tree = cache
+                .get_or_extract(&Counter { count }, &ctx, extract)
+                .unwrap();
+            assert_eq!(tree.content, format!("Count: {}", count));
+        }
+        assert_eq!(runs.get(), 2);
+        assert_eq!((cache.hits(), cache.misses()), (2, 2));
+
+        // The least recently used state is evicted once the cache is full
+        let mut cache = ExtractionCache::new(2);
+        cache
+            .get_or_extract(&Counter { count: 0 }, &ctx, extract)
+            .unwrap();
+        cache
+            .get_or_extract(&Counter { count: 1 }, &ctx, extract)
+            .unwrap();
+        cache
+            .get_or_extract(&Counter { count: 0 }, &ctx, extract)
+            .unwrap();
+        cache
+            .get_or_extract(&Counter { count: 2 }, &ctx, extract)
+            .unwrap();
+        assert_eq!(cache.len(), 2);
+        assert!(cache.contains(&Counter { count: 0 }));
+        assert!(!cache.contains(&Counter { count: 1 }));
+        assert!(cache.contains(&Counter { count: 2 }));
+
+        // A changed context never gets a tree extracted under the old one
+        runs.set(0);
+        let scaled = ctx.clone().with_text_scale(2.0);
+        let tree = cache
+            .get_or_extract(&Counter { count: 0 }, &scaled, extract)
+            .unwrap();
+        assert_eq!(tree.font_size, 32.0);
+        assert_eq!(runs.get(), 1);
+        assert_eq!(cache.len(), 1);
+
+        // Clones share the generation, so they keep hitting the cache
+        cache
+            .get_or_extract(&Counter { count: 0 }, &scaled.child(), extract)
+            .unwrap();
+        assert_eq!(runs.get(), 1);
+
+        // So does a fresh context with the default settings
+        let mut cache = ExtractionCache::new(2);
+        cache
+            .get_or_extract(&Counter { count: 0 }, &ctx, extract)
+            .unwrap();
+        cache
+            .get_or_extract(&Counter { count: 0 }, &RenderContext::new(), extract)
+            .unwrap();
+        assert_eq!(cache.hits(), 1);
+    }
+
+    #[test]
+    fn render_scheduler_extracts_once_per_frame() {
+        use std::cell::Cell;
+
+        use crate::{backends::mock::MockBackend, message::Message};
+
+        #[derive(Debug, Clone, PartialEq)]
+        struct Counter {
+            count: i32,
+        }
+
+        #[derive(Debug, Clone)]
+        struct Increment;
+
+        impl Message for Increment {}
+
+        impl Model for Counter {
+            type Message = Increment;
+            type View = Text;
+
+            fn update(self, _message: Self::Message) -> Self {
+                Self {
+                    count: self.count + 1,
+                }
+            }
+
+            fn view(&self) -> Self::View {
+                Text::new(format!("Count: {}", self.count))
+            }
+        }
+
+        let ctx = RenderContext::new();
+        let runs = Cell::new(0);
+        let extract = |model: &Counter| {
+            runs.set(runs.get() + 1);
+            MockBackend::extract(&model.view(), &ctx)
+        };
+
+        let mut scheduler = RenderScheduler::new(Counter { count: 0 });
+        scheduler.render_frame(extract).unwrap();
+        runs.set(0);
+
+        for _ in 0..10 {
+            scheduler.dispatch(Increment);
+        }
+        assert!(scheduler.needs_render());
+
+        let frame = scheduler.render_frame(extract).unwrap();
+        assert_eq!(runs.get(), 1);
+        assert_eq!(frame.unwrap().content, "Count: 10");
+        assert_eq!(scheduler.model().count, 10);
+
+        // A quiet frame does no work
+        assert!(scheduler.render_frame(extract).unwrap().is_none());
+        assert_eq!(runs.get(), 1);
+    }
+}
+
+// End of File
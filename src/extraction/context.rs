@@ -0,0 +1,537 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Render context and the typed environment it carries
+
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    fmt::{Debug, Formatter, Result as FormatterResult},
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+};
+
+use crate::{
+    elements::{Alignment, HorizontalEdge, LayoutDirection},
+    style::{Color, SemanticColor, TextStyle, Theme, ThemeColors},
+};
+
+/// A typed key for an ambient value stored in a [`RenderContext`].
+///
+/// Each key is its own type, so values can't collide and always come back
+/// with the type they were stored with. Keys that were never set read as
+/// their [`default_value`](EnvKey::default_value).
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{prelude::*, extraction::EnvKey};
+///
+/// struct Locale;
+///
+/// impl EnvKey for Locale {
+///     type Value = String;
+///
+///     fn default_value() -> String {
+///         "en-US".to_string()
+///     }
+/// }
+///
+/// let mut ctx = RenderContext::new();
+/// assert_eq!(ctx.get::<Locale>(), "en-US");
+///
+/// ctx.set::<Locale>("fr-FR".to_string());
+/// assert_eq!(ctx.get::<Locale>(), "fr-FR");
+/// ```
+pub trait EnvKey: 'static {
+    /// The type of value stored under this key
+    type Value: Clone + Send + Sync + 'static;
+
+    /// The value read when the key hasn't been set.
+    fn default_value() -> Self::Value;
+}
+
+/// Environment key for the color text falls back to when it sets none.
+///
+/// When unset, text uses the theme's foreground color instead.
+pub struct ForegroundColor;
+
+impl EnvKey for ForegroundColor {
+    type Value = Option<Color>;
+
+    fn default_value() -> Option<Color> {
+        None
+    }
+}
+
+/// Environment key for the fill of buttons that set no background color.
+///
+/// When unset, such buttons use
+/// [`Button::DEFAULT_BACKGROUND`](crate::widgets::Button::DEFAULT_BACKGROUND).
+pub struct AccentColor;
+
+impl EnvKey for AccentColor {
+    type Value = Option<Color>;
+
+    fn default_value() -> Option<Color> {
+        None
+    }
+}
+
+/// Environment key for the multiplier applied to every font size.
+///
+/// Read it through [`RenderContext::text_scale`], which keeps the value
+/// within the supported range.
+pub struct TextScale;
+
+impl EnvKey for TextScale {
+    type Value = f32;
+
+    fn default_value() -> f32 {
+        1.0
+    }
+}
+
+/// Environment key for skipping animated transitions.
+pub struct ReduceMotion;
+
+impl EnvKey for ReduceMotion {
+    type Value = bool;
+
+    fn default_value() -> bool {
+        false
+    }
+}
+
+/// Environment key for the theme semantic colors resolve through.
+pub struct CurrentTheme;
+
+impl EnvKey for CurrentTheme {
+    type Value = Theme;
+
+    fn default_value() -> Theme {
+        DEFAULT_THEME
+    }
+}
+
+/// Theme used by contexts that don't set one.
+static DEFAULT_THEME: Theme = Theme::new(ThemeColors::LIGHT);
+
+/// Environment key for resolving semantic colors to their high-contrast variants.
+pub struct HighContrast;
+
+impl EnvKey for HighContrast {
+    type Value = bool;
+
+    fn default_value() -> bool {
+        false
+    }
+}
+
+/// Environment key for the reading direction leading and trailing resolve through.
+pub struct ReadingDirection;
+
+impl EnvKey for ReadingDirection {
+    type Value = LayoutDirection;
+
+    fn default_value() -> LayoutDirection {
+        LayoutDirection::LeftToRight
+    }
+}
+
+/// Environment key for the style text inherits for properties it didn't set.
+pub struct TextDefaults;
+
+impl EnvKey for TextDefaults {
+    type Value = Option<TextStyle>;
+
+    fn default_value() -> Option<TextStyle> {
+        None
+    }
+}
+
+/// Type-keyed store behind [`RenderContext::get`] and [`RenderContext::set`].
+///
+/// Values are shared, so cloning a context for a scoped override is cheap.
+#[derive(Clone, Default)]
+struct Environment {
+    values: HashMap<TypeId, Arc<dyn Any + Send + Sync>>,
+}
+
+impl Debug for Environment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FormatterResult {
+        f.debug_struct("Environment")
+            .field("values", &self.values.len())
+            .finish()
+    }
+}
+
+/// Context provided to view extractors during rendering.
+///
+/// The render context contains platform-specific information that backends
+/// need to properly extract and render views, such as the user's
+/// accessibility preferences. Settings apply to the whole tree being
+/// extracted. Each one is stored under an [`EnvKey`]; the `with_*` methods
+/// are shorthands for [`set`](Self::set) and the getters read the same keys
+/// that [`get`](Self::get) does.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{prelude::*, elements::{HorizontalEdge, LayoutDirection}, style::SemanticColor};
+///
+/// // The user asked the OS for larger text
+/// let ctx = RenderContext::new().with_text_scale(1.5);
+/// assert_eq!(ctx.scaled_font_size(16.0), 24.0);
+///
+/// // Semantic colors follow the high-contrast setting
+/// let ctx = RenderContext::new().with_high_contrast(true);
+/// assert_eq!(ctx.resolve_color(SemanticColor::Accent), Color::BLACK);
+///
+/// // Leading alignment follows the reading direction
+/// let ctx = RenderContext::new().with_direction(LayoutDirection::RightToLeft);
+/// assert_eq!(ctx.resolve_alignment(Alignment::Leading), Some(HorizontalEdge::Right));
+/// ```
+#[derive(Debug, Clone)]
+pub struct RenderContext {
+    /// Every setting, stored by [`EnvKey`]
+    environment: Environment,
+    /// Identifies these settings; see [`generation`](Self::generation)
+    generation: u64,
+}
+
+/// Source of fresh [`RenderContext::generation`] values.
+///
+/// Zero is reserved for the settings of [`RenderContext::new`], so every
+/// change draws a number no other context has used.
+static NEXT_GENERATION: AtomicU64 = AtomicU64::new(1);
+
+impl RenderContext {
+    /// Smallest supported text scale.
+    pub const MIN_TEXT_SCALE: f32 = 0.5;
+
+    /// Largest supported text scale.
+    pub const MAX_TEXT_SCALE: f32 = 3.0;
+
+    /// Create a new render context with default settings.
+    pub fn new() -> Self {
+        Self {
+            environment: Environment::default(),
+            generation: 0,
+        }
+    }
+
+    /// Get an identifier for this context's settings.
+    ///
+    /// Every change to a setting or environment value gives the context a
+    /// new generation that no other context shares, so two contexts with the
+    /// same generation are guaranteed to extract identically. Clones keep
+    /// the generation of the context they were cloned from until they change.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// let ctx = RenderContext::new();
+    /// assert_eq!(ctx.child().generation(), ctx.generation());
+    ///
+    /// let scaled = ctx.clone().with_text_scale(1.5);
+    /// assert_ne!(scaled.generation(), ctx.generation());
+    /// ```
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Record that a setting changed.
+    fn touch(&mut self) {
+        self.generation = NEXT_GENERATION.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Clone this context so a subtree can override values without
+    /// affecting its siblings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::{extraction::ForegroundColor, prelude::*};
+    ///
+    /// let ctx = RenderContext::new();
+    /// let mut sidebar = ctx.child();
+    /// sidebar.set::<ForegroundColor>(Some(Color::WHITE));
+    ///
+    /// assert_eq!(sidebar.get::<ForegroundColor>(), Some(Color::WHITE));
+    /// assert_eq!(ctx.get::<ForegroundColor>(), None);
+    /// ```
+    pub fn child(&self) -> Self {
+        self.clone()
+    }
+
+    /// Store an environment value, replacing any earlier value for the key.
+    pub fn set<K: EnvKey>(&mut self, value: K::Value) {
+        self.environment
+            .values
+            .insert(TypeId::of::<K>(), Arc::new(value));
+        self.touch();
+    }
+
+    /// Read an environment value, or the key's default if it wasn't set.
+    pub fn get<K: EnvKey>(&self) -> K::Value {
+        self.lookup::<K>().cloned().unwrap_or_else(K::default_value)
+    }
+
+    /// Borrow an environment value, if it was set.
+    fn lookup<K: EnvKey>(&self) -> Option<&K::Value> {
+        self.environment
+            .values
+            .get(&TypeId::of::<K>())
+            .and_then(|value| value.downcast_ref::<K::Value>())
+    }
+
+    /// Scale every font size by `scale`, as with an OS "larger text" setting.
+    ///
+    /// The scale is clamped to [`MIN_TEXT_SCALE`](Self::MIN_TEXT_SCALE) and
+    /// [`MAX_TEXT_SCALE`](Self::MAX_TEXT_SCALE) so text stays legible and
+    /// layouts stay usable. A scale that isn't a number leaves text unscaled.
+    pub fn with_text_scale(mut self, scale: f32) -> Self {
+        self.set::<TextScale>(scale);
+        self
+    }
+
+    /// Get the multiplier applied to every font size.
+    ///
+    /// Values stored directly under [`TextScale`] are clamped the same way
+    /// as those passed to [`with_text_scale`](Self::with_text_scale).
+    pub fn text_scale(&self) -> f32 {
+        let scale = self.get::<TextScale>();
+        if scale.is_nan() {
+            1.0
+        } else {
+            scale.clamp(Self::MIN_TEXT_SCALE, Self::MAX_TEXT_SCALE)
+        }
+    }
+
+    /// Apply the text scale to a font size chosen by a view.
+    pub fn scaled_font_size(&self, size: f32) -> f32 {
+        size * self.text_scale()
+    }
+
+    /// Skip animated transitions, as with an OS "reduce motion" setting.
+    ///
+    /// Animations consult this flag and jump straight to their end values
+    /// when it is set.
+    pub fn with_reduce_motion(mut self, reduce_motion: bool) -> Self {
+        self.set::<ReduceMotion>(reduce_motion);
+        self
+    }
+
+    /// Check whether animated transitions should be skipped.
+    pub fn reduce_motion(&self) -> bool {
+        self.get::<ReduceMotion>()
+    }
+
+    /// Resolve semantic colors through `theme` instead of the default theme.
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.set::<CurrentTheme>(theme);
+        self
+    }
+
+    /// Get the theme semantic colors resolve through.
+    pub fn theme(&self) -> &Theme {
+        self.lookup::<CurrentTheme>().unwrap_or(&DEFAULT_THEME)
+    }
+
+    /// Use the theme's high-contrast colors, as with an OS "increase contrast" setting.
+    pub fn with_high_contrast(mut self, high_contrast: bool) -> Self {
+        self.set::<HighContrast>(high_contrast);
+        self
+    }
+
+    /// Check whether semantic colors resolve to their high-contrast variants.
+    pub fn high_contrast(&self) -> bool {
+        self.get::<HighContrast>()
+    }
+
+    /// Get the concrete color for a semantic color under the current settings.
+    pub fn resolve_color(&self, color: SemanticColor) -> Color {
+        self.theme().resolve(color, self.high_contrast())
+    }
+
+    /// Lay content out in `direction`, as with a right-to-left system language.
+    pub fn with_direction(mut self, direction: LayoutDirection) -> Self {
+        self.set::<ReadingDirection>(direction);
+        self
+    }
+
+    /// Get the reading direction leading and trailing resolve through.
+    pub fn direction(&self) -> LayoutDirection {
+        self.get::<ReadingDirection>()
+    }
+
+    /// Get the edge a horizontal alignment places content against.
+    ///
+    /// Returns `None` for [`Alignment::Center`], which has no edge.
+    pub fn resolve_alignment(&self, alignment: Alignment) -> Option<HorizontalEdge> {
+        self.direction().resolve(alignment)
+    }
+
+    /// Give text in the tree a default style to inherit.
+    ///
+    /// Text keeps every property it set itself and takes the rest from
+    /// `style`. See [`effective_text_style`](Self::effective_text_style).
+    pub fn with_text_defaults(mut self, style: TextStyle) -> Self {
+        self.set::<TextDefaults>(Some(style));
+        self
+    }
+
+    /// Get the style text inherits from, if one was set.
+    pub fn text_defaults(&self) -> Option<&TextStyle> {
+        self.lookup::<TextDefaults>().and_then(Option::as_ref)
+    }
+
+    /// Resolve a text's own style into the style it should be drawn with.
+    ///
+    /// Properties the text didn't set are taken from the
+    /// [text defaults](Self::with_text_defaults). A color that is still
+    /// unset afterwards becomes the environment's [`ForegroundColor`], or
+    /// the theme's foreground color if that isn't set either. Finally the
+    /// font size is multiplied by the text scale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::{prelude::*, style::FontWeight};
+    ///
+    /// let ctx = RenderContext::new()
+    ///     .with_text_defaults(TextStyle::new().font_size(20.0))
+    ///     .with_text_scale(1.5);
+    ///
+    /// let style = ctx.effective_text_style(&TextStyle::new().font_weight(FontWeight::BOLD));
+    /// assert_eq!(style.font_size, 30.0);
+    /// assert_eq!(style.font_weight, FontWeight::BOLD);
+    /// ```
+    pub fn effective_text_style(&self, style: &TextStyle) -> TextStyle {
+        let mut effective = match self.text_defaults() {
+            Some(defaults) => style.inherit(defaults),
+            None => *style,
+        };
+        if !effective.has_explicit_color() {
+            effective.color = self
+                .get::<ForegroundColor>()
+                .unwrap_or_else(|| self.resolve_color(SemanticColor::Foreground));
+        }
+        effective.font_size = self.scaled_font_size(effective.font_size);
+        effective
+    }
+}
+
+impl Default for RenderContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{elements::Text, extraction::ViewExtractor};
+
+    #[test]
+    fn environment_values_are_typed_and_scoped() {
+        use crate::backends::mock::MockBackend;
+
+        struct Locale;
+
+        impl EnvKey for Locale {
+            type Value = &'static str;
+
+            fn default_value() -> &'static str {
+                "en"
+            }
+        }
+
+        struct PageSize;
+
+        impl EnvKey for PageSize {
+            type Value = usize;
+
+            fn default_value() -> usize {
+                20
+            }
+        }
+
+        let mut ctx = RenderContext::new();
+        assert_eq!(ctx.get::<Locale>(), "en");
+        ctx.set::<Locale>("de");
+        ctx.set::<PageSize>(50);
+        assert_eq!(ctx.get::<Locale>(), "de");
+        assert_eq!(ctx.get::<PageSize>(), 50);
+
+        // Overrides in a child context stay in the child
+        let mut child = ctx.child();
+        child.set::<Locale>("ja");
+        child.set::<ForegroundColor>(Some(Color::BLUE));
+        assert_eq!(child.get::<Locale>(), "ja");
+        assert_eq!(child.get::<PageSize>(), 50);
+        assert_eq!(ctx.get::<Locale>(), "de");
+
+        // Text without its own color picks up the environment's foreground
+        let plain = MockBackend::extract(&Text::new("a"), &child).unwrap();
+        assert_eq!(plain.effective_style.color, Color::BLUE);
+        let red = MockBackend::extract(&Text::new("a").color(Color::RED), &child).unwrap();
+        assert_eq!(red.effective_style.color, Color::RED);
+        let parent = MockBackend::extract(&Text::new("a"), &ctx).unwrap();
+        assert_eq!(parent.effective_style.color, Color::BLACK);
+    }
+
+    #[test]
+    fn built_in_settings_are_environment_keys() {
+        let mut ctx = RenderContext::new();
+        ctx.set::<TextScale>(2.0);
+        ctx.set::<HighContrast>(true);
+        ctx.set::<ReadingDirection>(LayoutDirection::RightToLeft);
+        ctx.set::<TextDefaults>(Some(TextStyle::new().font_size(20.0)));
+
+        let shorthand = RenderContext::new()
+            .with_text_scale(2.0)
+            .with_high_contrast(true)
+            .with_direction(LayoutDirection::RightToLeft)
+            .with_text_defaults(TextStyle::new().font_size(20.0));
+        assert_eq!(ctx.text_scale(), shorthand.text_scale());
+        assert_eq!(ctx.high_contrast(), shorthand.high_contrast());
+        assert_eq!(ctx.direction(), shorthand.direction());
+        assert_eq!(ctx.text_defaults(), shorthand.text_defaults());
+
+        // Children inherit them like any other key
+        assert_eq!(ctx.child().get::<TextScale>(), 2.0);
+
+        // Values set directly are kept within the supported range
+        ctx.set::<TextScale>(100.0);
+        assert_eq!(ctx.text_scale(), RenderContext::MAX_TEXT_SCALE);
+        ctx.set::<TextScale>(f32::NAN);
+        assert_eq!(ctx.text_scale(), 1.0);
+    }
+
+    #[test]
+    fn text_scale_is_clamped() {
+        assert_eq!(RenderContext::new().text_scale(), 1.0);
+        assert_eq!(RenderContext::new().with_text_scale(2.0).text_scale(), 2.0);
+        assert_eq!(
+            RenderContext::new().with_text_scale(10.0).text_scale(),
+            RenderContext::MAX_TEXT_SCALE
+        );
+        assert_eq!(
+            RenderContext::new().with_text_scale(0.0).text_scale(),
+            RenderContext::MIN_TEXT_SCALE
+        );
+        assert_eq!(
+            RenderContext::new().with_text_scale(f32::NAN).text_scale(),
+            1.0
+        );
+    }
+}
+
+// End of File
@@ -0,0 +1,294 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! View extraction system for Ironwood UI Framework
+//!
+//! The extraction system defines the interface between view descriptions and
+//! backend rendering implementations. Views are pure data structures that
+//! describe what should be displayed, while the ViewExtractor pattern allows
+//! different backends to process these descriptions in platform-specific ways.
+//!
+//! This separation enables the same view hierarchy to be rendered on different
+//! platforms (GPU, native widgets, web, testing) without changing application code.
+//!
+//! ## Dynamic Extraction
+//!
+//! The extraction system also provides a `ViewRegistry` for dynamic view extraction,
+//! enabling runtime type dispatch for view extraction. This allows backends to
+//! extract any registered view type from a `Box<dyn View>` without knowing the
+//! concrete type at compile time.
+//!
+//! ## Render Context
+//!
+//! Every setting a [`RenderContext`] carries, from the text scale and theme to
+//! application-defined values, lives in one typed environment keyed by
+//! [`EnvKey`]. Built-in settings have `with_*` shorthands and getters that
+//! validate values, but they propagate exactly like custom keys.
+
+use std::{any::TypeId, fmt::Debug};
+
+use crate::view::View;
+
+mod cache;
+mod context;
+mod registry;
+
+pub use cache::{DirtySet, ExtractionCache, RenderScheduler};
+pub use context::{
+    AccentColor, CurrentTheme, EnvKey, ForegroundColor, HighContrast, ReadingDirection,
+    ReduceMotion, RenderContext, TextDefaults, TextScale,
+};
+pub use registry::{ViewRegistry, ViewRegistryBuilder};
+
+/// Errors that can occur during view extraction.
+///
+/// These errors represent various failure modes in the dynamic view extraction
+/// system, providing detailed context for debugging and error handling.
+#[derive(Debug, thiserror::Error)]
+pub enum ExtractionError {
+    /// A view type is not registered in the view registry.
+    ///
+    /// This occurs when attempting to extract a view type that hasn't been
+    /// registered with the backend's registry. The error includes both the
+    /// human-readable type name and the TypeId for debugging.
+    #[error(
+        "View type '{type_name}' is not registered in the view registry{}",
+        display_path(.path)
+    )]
+    UnregisteredType {
+        /// Human-readable name of the unregistered type
+        type_name: &'static str,
+        /// TypeId of the unregistered type for debugging
+        type_id: TypeId,
+        /// Location of the failing view within the tree, outermost first
+        path: Vec<String>,
+    },
+
+    /// Failed to downcast a view to the expected concrete type.
+    ///
+    /// This indicates a type registry invariant violation where the stored
+    /// extraction function expects a different type than what was provided.
+    #[error(
+        "Failed to downcast view to expected type '{expected_type}'{}",
+        display_path(.path)
+    )]
+    DowncastFailed {
+        /// The expected concrete type name
+        expected_type: &'static str,
+        /// The actual TypeId that was encountered
+        actual_type_id: TypeId,
+        /// Location of the failing view within the tree, outermost first
+        path: Vec<String>,
+    },
+
+    /// Failed to downcast extracted output to the expected type.
+    ///
+    /// This occurs when the extraction function returns a different type
+    /// than expected, indicating a mismatch in the registry configuration.
+    #[error(
+        "Failed to downcast extracted output to expected type '{expected_type}'{}",
+        display_path(.path)
+    )]
+    OutputDowncastFailed {
+        /// The expected output type name
+        expected_type: &'static str,
+        /// Location of the failing view within the tree, outermost first
+        path: Vec<String>,
+    },
+}
+
+impl ExtractionError {
+    /// Get the location of the failing view, outermost container first.
+    ///
+    /// Each segment names a dynamic container and the index of the child
+    /// the failure happened in, such as `VStack[1]`. The path is empty when
+    /// the failing view is the one that was extracted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::any::Any;
+    /// use ironwood::{prelude::*, backends::mock::MockBackend};
+    ///
+    /// #[derive(Debug)]
+    /// struct Unregistered;
+    ///
+    /// impl View for Unregistered {
+    ///     fn as_any(&self) -> &dyn Any {
+    ///         self
+    ///     }
+    /// }
+    ///
+    /// let tree = VStack::dynamic()
+    ///     .child(Box::new(Text::new("Title")))
+    ///     .child(Box::new(HStack::dynamic().child(Box::new(Unregistered))));
+    /// let error = MockBackend::new()
+    ///     .extract_dynamic(&tree, &RenderContext::new())
+    ///     .unwrap_err();
+    ///
+    /// assert_eq!(error.path(), ["VStack[1]", "HStack[0]"]);
+    /// assert!(error.to_string().ends_with(" at VStack[1].HStack[0]"));
+    /// ```
+    pub fn path(&self) -> &[String] {
+        match self {
+            Self::UnregisteredType { path, .. }
+            | Self::DowncastFailed { path, .. }
+            | Self::OutputDowncastFailed { path, .. } => path,
+        }
+    }
+
+    /// Record that the error happened inside a container's child.
+    ///
+    /// Containers call this as the error propagates out of them, so the
+    /// segment is added to the front of the path.
+    pub fn in_child(mut self, segment: impl Into<String>) -> Self {
+        match &mut self {
+            Self::UnregisteredType { path, .. }
+            | Self::DowncastFailed { path, .. }
+            | Self::OutputDowncastFailed { path, .. } => path.insert(0, segment.into()),
+        }
+        self
+    }
+}
+
+/// Format a breadcrumb path for an error message, or nothing if it's empty.
+fn display_path(path: &[String]) -> String {
+    if path.is_empty() {
+        String::new()
+    } else {
+        format!(" at {}", path.join("."))
+    }
+}
+
+/// Result type for view extraction operations.
+///
+/// This type alias provides a convenient way to work with extraction results
+/// throughout the codebase, ensuring consistent error handling.
+pub type ExtractionResult<T> = Result<T, ExtractionError>;
+
+/// Errors that can occur while registering view types with a [`ViewRegistry`].
+///
+/// Registration is expected to happen once per view type during backend
+/// setup. Registering the same type twice usually means two pieces of
+/// extension code disagree about how a view should be handled, so the
+/// registry reports it rather than silently replacing the earlier entry.
+#[derive(Debug, thiserror::Error)]
+pub enum RegistryError {
+    /// An extractor is already registered for this view type.
+    #[error("View type '{type_name}' already has a registered extractor")]
+    DuplicateRegistration {
+        /// Human-readable name of the view type
+        type_name: &'static str,
+        /// TypeId of the view type for debugging
+        type_id: TypeId,
+    },
+
+    /// A converter is already registered for this view type.
+    #[error("View type '{type_name}' already has a registered converter")]
+    DuplicateConverter {
+        /// Human-readable name of the view type
+        type_name: &'static str,
+        /// TypeId of the view type for debugging
+        type_id: TypeId,
+    },
+
+    /// A view type has an extractor but no converter.
+    ///
+    /// Such a type extracts statically but its output can't be turned into
+    /// the backend's dynamic representation, so [`ViewRegistryBuilder`]
+    /// rejects it.
+    #[error("View type '{type_name}' has an extractor but no converter")]
+    MissingConverter {
+        /// Human-readable name of the view type
+        type_name: &'static str,
+        /// TypeId of the view type for debugging
+        type_id: TypeId,
+    },
+}
+
+/// Result type for view registration operations.
+pub type RegistryResult<T> = Result<T, RegistryError>;
+
+/// Trait for extracting view data into backend-specific representations.
+///
+/// The ViewExtractor pattern allows different backends to process the same
+/// view types in different ways. For example, a GPU backend might extract
+/// views into vertex buffers and textures, while a testing backend might
+/// extract them into simple data structures for assertions.
+///
+/// Each backend implements ViewExtractor for the view types it supports,
+/// defining how to convert the pure view data into whatever representation
+/// the backend needs for rendering.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::prelude::*;
+///
+/// // A simple backend that extracts text to strings
+/// struct StringBackend;
+///
+/// impl ViewExtractor<Text> for StringBackend {
+///     type Output = String;
+///
+///     fn extract(view: &Text, _ctx: &RenderContext) -> ExtractionResult<Self::Output> {
+///         Ok(view.content.to_string())
+///     }
+/// }
+///
+/// let text = Text::new("Hello, world!");
+/// let ctx = RenderContext::new();
+/// let result = StringBackend::extract(&text, &ctx).unwrap();
+/// assert_eq!(result, "Hello, world!");
+/// ```
+pub trait ViewExtractor<V: View> {
+    /// The backend-specific representation of the extracted view.
+    ///
+    /// This could be anything the backend needs: render commands, widget handles,
+    /// test data structures, etc. The type is determined by what the backend
+    /// needs to efficiently render or process the view.
+    type Output;
+
+    /// Extract a view into the backend's representation.
+    ///
+    /// This method takes a view and the current render context, and produces
+    /// whatever output the backend needs to handle that view. The extraction
+    /// process should be pure - it shouldn't have side effects or modify
+    /// global state.
+    ///
+    /// # Arguments
+    ///
+    /// * `view` - The view to extract
+    /// * `ctx` - The current render context with platform information
+    ///
+    /// # Returns
+    ///
+    /// The backend-specific representation of the view, or an error
+    fn extract(view: &V, ctx: &RenderContext) -> ExtractionResult<Self::Output>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elements::Text;
+
+    #[test]
+    fn basic_extraction() {
+        struct TestBackend;
+
+        impl ViewExtractor<Text> for TestBackend {
+            type Output = String;
+            fn extract(view: &Text, _ctx: &RenderContext) -> ExtractionResult<Self::Output> {
+                Ok(view.content.to_string())
+            }
+        }
+
+        let text = Text::new("Hello");
+        let ctx = RenderContext::new();
+        let result = TestBackend::extract(&text, &ctx).unwrap();
+        assert_eq!(result, "Hello");
+    }
+}
+
+// End of File
@@ -0,0 +1,719 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Type registry for extracting views behind `dyn View`
+
+use std::{
+    any::{Any, TypeId, type_name},
+    collections::HashMap,
+    fmt::{Debug, Formatter, Result as FormatterResult},
+};
+
+use super::{
+    ExtractionError, ExtractionResult, RegistryError, RegistryResult, RenderContext, ViewExtractor,
+};
+use crate::view::View;
+
+/// A registry that maps view types to their extraction and conversion functions.
+///
+/// The `ViewRegistry` enables dynamic view extraction by storing type-erased
+/// extraction functions that can be looked up at runtime based on the concrete
+/// type of a view. This allows backends to extract any registered view type
+/// from a `Box<dyn View>` without knowing the concrete type at compile time.
+///
+/// ## How It Works
+///
+/// 1. **Registration**: View types are registered with their extraction functions
+/// 2. **Lookup**: At runtime, the registry uses `TypeId` to find the right extractor
+/// 3. **Extraction**: The type-erased function is called to extract the view
+/// 4. **Conversion**: Optional conversion functions transform extracted types
+/// 5. **Type Safety**: All operations are type-safe despite the dynamic dispatch
+///
+/// ## Example Usage
+///
+/// ```rust
+/// use ironwood::{prelude::*, backends::mock::MockBackend};
+///
+/// let mut registry = ViewRegistry::new();
+///
+/// // Register view types with their extractors
+/// registry.register::<Text, MockBackend>()?;
+/// registry.register::<ButtonView, MockBackend>()?;
+///
+/// // Now any Text or ButtonView can be extracted dynamically
+/// let view: Box<dyn View> = Box::new(Text::new("Hello"));
+/// let ctx = RenderContext::new();
+/// let extracted = registry.extract_dynamic::<MockBackend>(view.as_ref(), &ctx);
+/// # Ok::<(), ironwood::extraction::RegistryError>(())
+/// ```
+///
+/// ## Thread Safety
+///
+/// The registry is designed to be thread-safe for read operations after initial
+/// setup. Registration should typically happen during application initialization
+/// before any concurrent access.
+#[derive(Default)]
+pub struct ViewRegistry {
+    /// Maps TypeId to type-erased extraction functions
+    ///
+    /// Each function takes (&dyn Any, &RenderContext) and returns ExtractionResult&lt;Box&lt;dyn Any&gt;&gt;
+    /// The Any types represent the view and extracted output respectively
+    #[allow(clippy::type_complexity)]
+    extractors: HashMap<
+        TypeId,
+        Box<dyn Fn(&dyn Any, &RenderContext) -> ExtractionResult<Box<dyn Any>> + Send + Sync>,
+    >,
+
+    /// Maps TypeId to type-erased conversion functions
+    ///
+    /// Each function takes Box&lt;dyn Any&gt; (extracted output) and returns ExtractionResult&lt;Box&lt;dyn Any&gt;&gt; (converted output)
+    /// This enables backends to register custom conversion logic for their extracted types
+    #[allow(clippy::type_complexity)]
+    converters:
+        HashMap<TypeId, Box<dyn Fn(Box<dyn Any>) -> ExtractionResult<Box<dyn Any>> + Send + Sync>>,
+
+    /// Maps TypeId to the human-readable name of each registered view type
+    type_names: HashMap<TypeId, &'static str>,
+}
+
+impl ViewRegistry {
+    /// Create a new empty view registry.
+    ///
+    /// The registry starts empty and view types must be explicitly registered
+    /// before they can be extracted dynamically.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use ironwood::extraction::ViewRegistry;
+    ///
+    /// let registry = ViewRegistry::new();
+    /// assert!(!registry.is_registered::<ironwood::elements::Text>());
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            extractors: HashMap::new(),
+            converters: HashMap::new(),
+            type_names: HashMap::new(),
+        }
+    }
+
+    /// Register a view type with its extraction function for a specific backend.
+    ///
+    /// This method creates a type-erased wrapper around the backend's ViewExtractor
+    /// implementation and stores it in the registry for runtime lookup.
+    ///
+    /// ## Type Parameters
+    ///
+    /// - `V`: The view type to register (must implement View)
+    /// - `B`: The backend type that can extract this view
+    ///
+    /// ## Errors
+    ///
+    /// Returns `RegistryError::DuplicateRegistration` if an extractor is
+    /// already registered for `V`. The existing extractor is left in place.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use ironwood::{prelude::*, backends::mock::MockBackend};
+    ///
+    /// let mut registry = ViewRegistry::new();
+    /// registry.register::<Text, MockBackend>()?;
+    ///
+    /// assert!(registry.is_registered::<Text>());
+    /// # Ok::<(), ironwood::extraction::RegistryError>(())
+    /// ```
+    pub fn register<V, B>(&mut self) -> RegistryResult<()>
+    where
+        V: View + 'static,
+        B: ViewExtractor<V>,
+        B::Output: 'static,
+    {
+        let type_id = TypeId::of::<V>();
+        if self.extractors.contains_key(&type_id) {
+            return Err(RegistryError::DuplicateRegistration {
+                type_name: type_name::<V>(),
+                type_id,
+            });
+        }
+
+        // Create a type-erased extraction function
+        let extractor = Box::new(
+            move |view_any: &dyn Any, ctx: &RenderContext| -> ExtractionResult<Box<dyn Any>> {
+                // Downcast the view from Any to the concrete type
+                let view = view_any.downcast_ref::<V>().ok_or_else(|| {
+                    ExtractionError::DowncastFailed {
+                        expected_type: type_name::<V>(),
+                        actual_type_id: (*view_any).type_id(),
+                        path: Vec::new(),
+                    }
+                })?;
+
+                // Extract using the backend's ViewExtractor implementation
+                let extracted = B::extract(view, ctx)?;
+
+                // Box the result as Any for type erasure
+                Ok(Box::new(extracted))
+            },
+        );
+
+        self.extractors.insert(type_id, extractor);
+        self.type_names.insert(type_id, type_name::<V>());
+        Ok(())
+    }
+
+    /// Register a conversion function for a view type.
+    ///
+    /// This allows backends to register custom logic for converting extracted types
+    /// into backend-specific representations, eliminating the need for hardcoded
+    /// type checking in backend implementations.
+    ///
+    /// ## Type Parameters
+    ///
+    /// - `V`: The original view type
+    /// - `E`: The extracted type (output of ViewExtractor)
+    /// - `C`: The converted type (backend-specific representation)
+    ///
+    /// ## Errors
+    ///
+    /// Returns `RegistryError::DuplicateConverter` if a converter is already
+    /// registered for `V`. The existing converter is left in place.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use ironwood::{prelude::*, backends::{MockBackend, MockText, MockDynamicChild}};
+    ///
+    /// let mut registry = ViewRegistry::new();
+    /// registry.register::<Text, MockBackend>()?;
+    /// registry.register_converter::<Text, MockText, MockDynamicChild, _>(
+    ///     |extracted| MockDynamicChild::Text(extracted)
+    /// )?;
+    /// # Ok::<(), ironwood::extraction::RegistryError>(())
+    /// ```
+    pub fn register_converter<V, E, C, F>(&mut self, converter: F) -> RegistryResult<()>
+    where
+        V: View + 'static,
+        E: 'static,
+        C: 'static,
+        F: Fn(E) -> C + Send + Sync + 'static,
+    {
+        let type_id = TypeId::of::<V>();
+        if self.converters.contains_key(&type_id) {
+            return Err(RegistryError::DuplicateConverter {
+                type_name: type_name::<V>(),
+                type_id,
+            });
+        }
+
+        // Create a type-erased conversion function
+        let type_erased_converter = Box::new(
+            move |extracted_any: Box<dyn Any>| -> ExtractionResult<Box<dyn Any>> {
+                // Downcast the extracted value to the expected type
+                let extracted = extracted_any.downcast::<E>().map_err(|_| {
+                    ExtractionError::OutputDowncastFailed {
+                        expected_type: type_name::<E>(),
+                        path: Vec::new(),
+                    }
+                })?;
+
+                // Apply the conversion function
+                let converted = converter(*extracted);
+
+                // Box the result as Any for type erasure
+                Ok(Box::new(converted))
+            },
+        );
+
+        self.converters.insert(type_id, type_erased_converter);
+        Ok(())
+    }
+
+    /// Register a view type's extractor and converter in one call.
+    ///
+    /// This is equivalent to [`register`](Self::register) followed by
+    /// [`register_converter`](Self::register_converter), with the extracted
+    /// type taken from the backend so it can't be mismatched.
+    ///
+    /// ## Errors
+    ///
+    /// Returns the same errors as the two calls it combines. Both slots are
+    /// checked before either is filled, so on error the registry is left
+    /// unchanged.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use ironwood::{prelude::*, backends::{MockBackend, MockDynamicChild}};
+    ///
+    /// let mut registry = ViewRegistry::new();
+    /// registry.register_full::<Text, MockBackend, MockDynamicChild, _>(MockDynamicChild::Text)?;
+    /// assert!(registry.has_converter::<Text>());
+    /// # Ok::<(), ironwood::extraction::RegistryError>(())
+    /// ```
+    pub fn register_full<V, B, C, F>(&mut self, converter: F) -> RegistryResult<()>
+    where
+        V: View + 'static,
+        B: ViewExtractor<V>,
+        B::Output: 'static,
+        C: 'static,
+        F: Fn(B::Output) -> C + Send + Sync + 'static,
+    {
+        let type_id = TypeId::of::<V>();
+        if !self.extractors.contains_key(&type_id) && self.converters.contains_key(&type_id) {
+            return Err(RegistryError::DuplicateConverter {
+                type_name: type_name::<V>(),
+                type_id,
+            });
+        }
+
+        self.register::<V, B>()?;
+        self.register_converter::<V, B::Output, C, F>(converter)
+    }
+
+    /// Start building a registry that checks every type has a converter.
+    pub fn builder() -> ViewRegistryBuilder {
+        ViewRegistryBuilder::default()
+    }
+
+    /// Check if a converter is registered for a view type.
+    pub fn has_converter<V: View + 'static>(&self) -> bool {
+        self.converters.contains_key(&TypeId::of::<V>())
+    }
+
+    /// Check if a view type is registered in this registry.
+    ///
+    /// This is useful for debugging and validation to ensure all required
+    /// view types have been registered before attempting dynamic extraction.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use ironwood::{prelude::*, backends::MockBackend};
+    ///
+    /// let mut registry = ViewRegistry::new();
+    /// assert!(!registry.is_registered::<Text>());
+    ///
+    /// registry.register::<Text, MockBackend>().unwrap();
+    /// assert!(registry.is_registered::<Text>());
+    /// ```
+    pub fn is_registered<V: View + 'static>(&self) -> bool {
+        self.extractors.contains_key(&TypeId::of::<V>())
+    }
+
+    /// Check that a view's concrete type has a registered extractor.
+    ///
+    /// This performs the same lookup as `extract_dynamic` without running the
+    /// extractor, which makes it suitable for cheap pre-flight validation.
+    ///
+    /// ## Errors
+    ///
+    /// Returns `ExtractionError::UnregisteredType` if the view type is not registered.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use ironwood::{prelude::*, backends::MockBackend};
+    ///
+    /// let mut registry = ViewRegistry::new();
+    /// registry.register::<Text, MockBackend>().unwrap();
+    ///
+    /// assert!(registry.check_registered(&Text::new("Hello")).is_ok());
+    /// assert!(registry.check_registered(&Spacer::new()).is_err());
+    /// ```
+    pub fn check_registered(&self, view: &dyn View) -> ExtractionResult<()> {
+        let type_id = view.as_any().type_id();
+        if self.extractors.contains_key(&type_id) {
+            Ok(())
+        } else {
+            Err(ExtractionError::UnregisteredType {
+                type_name: view.view_type_name(),
+                type_id,
+                path: Vec::new(),
+            })
+        }
+    }
+
+    /// Get the names of every view type with a registered extractor.
+    ///
+    /// Names are the full paths reported by `std::any::type_name`, matching
+    /// the names used in `ExtractionError::UnregisteredType`, and are returned
+    /// in sorted order so the listing is stable across runs.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use ironwood::{prelude::*, backends::MockBackend};
+    ///
+    /// let mut registry = ViewRegistry::new();
+    /// registry.register::<Text, MockBackend>().unwrap();
+    ///
+    /// assert_eq!(registry.registered_types(), vec![std::any::type_name::<Text>()]);
+    /// ```
+    pub fn registered_types(&self) -> Vec<&'static str> {
+        let mut names: Vec<&'static str> = self.type_names.values().copied().collect();
+        names.sort_unstable();
+        names
+    }
+
+    /// Extract a view dynamically using the registered extraction function.
+    ///
+    /// This method looks up the extraction function for the view's concrete type
+    /// and calls it to extract the view. The result is returned as a boxed Any
+    /// that can be downcast to the expected output type.
+    ///
+    /// ## Type Parameters
+    ///
+    /// - `B`: The backend type (used for type inference of the output)
+    ///
+    /// ## Errors
+    ///
+    /// Returns `ExtractionError::UnregisteredType` if the view type is not registered
+    /// in this registry. Returns `ExtractionError::DowncastFailed` if type casting fails.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use ironwood::{prelude::*, backends::MockBackend};
+    ///
+    /// let mut registry = ViewRegistry::new();
+    /// registry.register::<Text, MockBackend>().unwrap();
+    ///
+    /// let view: Box<dyn View> = Box::new(Text::new("Hello"));
+    /// let ctx = RenderContext::new();
+    /// let extracted = registry.extract_dynamic::<MockBackend>(view.as_ref(), &ctx);
+    /// # Ok::<(), ironwood::extraction::ExtractionError>(())
+    /// ```
+    pub fn extract_dynamic<B>(
+        &self,
+        view: &dyn View,
+        ctx: &RenderContext,
+    ) -> ExtractionResult<Box<dyn Any>>
+    where
+        B: 'static,
+    {
+        let type_id = view.type_id();
+
+        let extractor =
+            self.extractors
+                .get(&type_id)
+                .ok_or_else(|| ExtractionError::UnregisteredType {
+                    type_name: view.view_type_name(),
+                    type_id,
+                    path: Vec::new(),
+                })?;
+
+        // Call the type-erased extraction function
+        extractor(view.as_any(), ctx)
+    }
+
+    /// Extract and convert a view dynamically using registered functions.
+    ///
+    /// This method first extracts the view using the registered extraction function,
+    /// then optionally applies a conversion function if one is registered for the view type.
+    ///
+    /// ## Type Parameters
+    ///
+    /// - `B`: The backend type (used for type inference of the output)
+    ///
+    /// ## Errors
+    ///
+    /// Returns the same errors as `extract_dynamic`, plus potential conversion errors.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use ironwood::{prelude::*, backends::MockBackend};
+    ///
+    /// let mut registry = ViewRegistry::new();
+    /// registry.register::<Text, MockBackend>().unwrap();
+    /// // ... register converter if needed
+    ///
+    /// let view: Box<dyn View> = Box::new(Text::new("Hello"));
+    /// let ctx = RenderContext::new();
+    /// let result = registry.extract_and_convert::<MockBackend>(view.as_ref(), &ctx)?;
+    /// # Ok::<(), ironwood::extraction::ExtractionError>(())
+    /// ```
+    pub fn extract_and_convert<B>(
+        &self,
+        view: &dyn View,
+        ctx: &RenderContext,
+    ) -> ExtractionResult<Box<dyn Any>>
+    where
+        B: 'static,
+    {
+        let type_id = view.type_id();
+
+        // First, extract the view
+        let extracted = self.extract_dynamic::<B>(view, ctx)?;
+
+        // Then, apply conversion if available
+        if let Some(converter) = self.converters.get(&type_id) {
+            converter(extracted)
+        } else {
+            Ok(extracted)
+        }
+    }
+
+    /// Get the number of registered view types.
+    ///
+    /// This is primarily useful for debugging and testing to verify that
+    /// the expected number of view types have been registered.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use ironwood::{prelude::*, backends::MockBackend};
+    ///
+    /// let mut registry = ViewRegistry::new();
+    /// assert_eq!(registry.len(), 0);
+    ///
+    /// registry.register::<Text, MockBackend>().unwrap();
+    /// assert_eq!(registry.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.extractors.len()
+    }
+
+    /// Check if the registry is empty (no view types registered).
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use ironwood::extraction::ViewRegistry;
+    ///
+    /// let registry = ViewRegistry::new();
+    /// assert!(registry.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.extractors.is_empty()
+    }
+}
+
+impl Debug for ViewRegistry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FormatterResult {
+        f.debug_struct("ViewRegistry")
+            .field("registered_types", &self.extractors.len())
+            .finish()
+    }
+}
+
+/// Builder for a [`ViewRegistry`] whose types all convert dynamically.
+///
+/// Registration errors are held until [`build`](Self::build), which also
+/// fails if any registered view type is missing its converter. This catches
+/// views that would extract statically but fail inside dynamic containers.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{
+///     prelude::*,
+///     backends::{MockBackend, MockDynamicChild},
+///     extraction::RegistryError,
+/// };
+///
+/// let registry = ViewRegistry::builder()
+///     .register_full::<Text, MockBackend, MockDynamicChild, _>(MockDynamicChild::Text)
+///     .build()
+///     .unwrap();
+/// assert!(registry.is_registered::<Text>());
+///
+/// // Forgetting the converter is caught when building
+/// let incomplete = ViewRegistry::builder()
+///     .register::<Spacer, MockBackend>()
+///     .build();
+/// assert!(matches!(incomplete, Err(RegistryError::MissingConverter { .. })));
+/// ```
+#[derive(Debug)]
+pub struct ViewRegistryBuilder {
+    registry: ViewRegistry,
+    error: Option<RegistryError>,
+}
+
+impl Default for ViewRegistryBuilder {
+    fn default() -> Self {
+        Self {
+            registry: ViewRegistry::new(),
+            error: None,
+        }
+    }
+}
+
+impl ViewRegistryBuilder {
+    /// Register a view type's extractor.
+    pub fn register<V, B>(mut self) -> Self
+    where
+        V: View + 'static,
+        B: ViewExtractor<V>,
+        B::Output: 'static,
+    {
+        let result = self.registry.register::<V, B>();
+        self.record(result)
+    }
+
+    /// Register a conversion function for a view type.
+    pub fn register_converter<V, E, C, F>(mut self, converter: F) -> Self
+    where
+        V: View + 'static,
+        E: 'static,
+        C: 'static,
+        F: Fn(E) -> C + Send + Sync + 'static,
+    {
+        let result = self.registry.register_converter::<V, E, C, F>(converter);
+        self.record(result)
+    }
+
+    /// Register a view type's extractor and converter in one call.
+    pub fn register_full<V, B, C, F>(mut self, converter: F) -> Self
+    where
+        V: View + 'static,
+        B: ViewExtractor<V>,
+        B::Output: 'static,
+        C: 'static,
+        F: Fn(B::Output) -> C + Send + Sync + 'static,
+    {
+        let result = self.registry.register_full::<V, B, C, F>(converter);
+        self.record(result)
+    }
+
+    /// Finish the registry, reporting the first registration error if any.
+    ///
+    /// ## Errors
+    ///
+    /// Returns the first duplicate registration, or
+    /// `RegistryError::MissingConverter` for a type with an extractor but no
+    /// converter. If several types lack a converter, the one whose name
+    /// sorts first is reported, so the error is the same on every run.
+    pub fn build(self) -> RegistryResult<ViewRegistry> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+
+        let missing = self
+            .registry
+            .extractors
+            .keys()
+            .filter(|type_id| !self.registry.converters.contains_key(type_id))
+            .min_by_key(|type_id| self.registry.type_names[type_id]);
+        if let Some(&type_id) = missing {
+            return Err(RegistryError::MissingConverter {
+                type_name: self.registry.type_names[&type_id],
+                type_id,
+            });
+        }
+        Ok(self.registry)
+    }
+
+    /// Keep the first error from a registration step.
+    fn record(mut self, result: RegistryResult<()>) -> Self {
+        if let Err(error) = result
+            && self.error.is_none()
+        {
+            self.error = Some(error);
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elements::Text;
+
+    #[test]
+    fn registry_builder_reports_first_error_at_build() {
+        use crate::backends::mock::{MockBackend, MockDynamicChild};
+
+        let registry = ViewRegistry::builder()
+            .register_full::<Text, MockBackend, _, _>(MockDynamicChild::Text)
+            .build()
+            .unwrap();
+        assert_eq!(registry.len(), 1);
+        assert!(registry.has_converter::<Text>());
+
+        let duplicate = ViewRegistry::builder()
+            .register_full::<Text, MockBackend, _, _>(MockDynamicChild::Text)
+            .register::<Text, MockBackend>()
+            .build();
+        assert!(matches!(
+            duplicate,
+            Err(RegistryError::DuplicateRegistration { type_name: name, .. })
+                if name == type_name::<Text>()
+        ));
+
+        let missing = ViewRegistry::builder()
+            .register_full::<Text, MockBackend, _, _>(MockDynamicChild::Text)
+            .register::<crate::elements::Spacer, MockBackend>()
+            .build();
+        assert!(matches!(
+            missing,
+            Err(RegistryError::MissingConverter { type_name: name, .. })
+                if name == type_name::<crate::elements::Spacer>()
+        ));
+
+        // With several offenders the report doesn't depend on hash order
+        for _ in 0..8 {
+            let missing = ViewRegistry::builder()
+                .register::<Text, MockBackend>()
+                .register::<crate::elements::Spacer, MockBackend>()
+                .register::<crate::elements::RichText, MockBackend>()
+                .build();
+            let Err(RegistryError::MissingConverter {
+                type_name: name, ..
+            }) = missing
+            else {
+                panic!("expected a missing converter, got {missing:?}");
+            };
+            assert_eq!(name, type_name::<crate::elements::Spacer>());
+        }
+    }
+
+    #[test]
+    fn failed_register_full_leaves_the_registry_unchanged() {
+        use crate::backends::mock::{MockBackend, MockDynamicChild, MockText};
+
+        let mut registry = ViewRegistry::new();
+        registry
+            .register_converter::<Text, MockText, MockDynamicChild, _>(MockDynamicChild::Text)
+            .unwrap();
+
+        let result = registry.register_full::<Text, MockBackend, _, _>(MockDynamicChild::Text);
+        assert!(matches!(
+            result,
+            Err(RegistryError::DuplicateConverter { type_name: name, .. })
+                if name == type_name::<Text>()
+        ));
+        assert!(!registry.is_registered::<Text>());
+        assert!(registry.has_converter::<Text>());
+    }
+
+    #[test]
+    fn duplicate_registration_is_reported() {
+        use crate::backends::mock::{MockBackend, MockDynamicChild, MockText};
+
+        let mut registry = ViewRegistry::new();
+        registry.register::<Text, MockBackend>().unwrap();
+
+        let error = registry.register::<Text, MockBackend>().unwrap_err();
+        assert!(matches!(
+            error,
+            RegistryError::DuplicateRegistration { type_name: name, type_id }
+                if name == type_name::<Text>() && type_id == TypeId::of::<Text>()
+        ));
+        assert!(error.to_string().contains("Text"));
+        assert_eq!(registry.len(), 1);
+
+        registry
+            .register_converter::<Text, MockText, MockDynamicChild, _>(MockDynamicChild::Text)
+            .unwrap();
+        let error = registry
+            .register_converter::<Text, MockText, MockDynamicChild, _>(MockDynamicChild::Text)
+            .unwrap_err();
+        assert!(matches!(error, RegistryError::DuplicateConverter { .. }));
+    }
+}
+
+// End of File
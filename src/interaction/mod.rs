@@ -13,120 +13,10 @@
 //! ensuring components remain immutable and updates are explicit.
 
 use crate::{message::Message, model::Model};
-use bitflags::bitflags;
 
-bitflags! {
-    /// Bitflags representing the interactive state of a UI component.
-    ///
-    /// This compact representation uses a single byte to track all common
-    /// interaction states, making it efficient for components that need to
-    /// manage multiple interaction states simultaneously.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use ironwood::prelude::*;
-    ///
-    /// let state = InteractionState::ENABLED | InteractionState::FOCUSED;
-    /// assert!(state.contains(InteractionState::ENABLED));
-    /// assert!(state.contains(InteractionState::FOCUSED));
-    /// assert!(!state.contains(InteractionState::PRESSED));
-    /// ```
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-    pub struct InteractionState: u8 {
-        /// Component is enabled and can receive user interactions
-        const ENABLED = 0b0001;
-        /// Component is currently being pressed (mouse down, touch active)
-        const PRESSED = 0b0010;
-        /// Component currently has keyboard focus
-        const FOCUSED = 0b0100;
-        /// Component is currently being hovered by a pointer
-        const HOVERED = 0b1000;
-    }
-}
+mod state;
 
-impl Default for InteractionState {
-    /// Create a default interaction state with only ENABLED set.
-    ///
-    /// This represents a newly created interactive component that is
-    /// enabled but not currently being interacted with.
-    fn default() -> Self {
-        Self::ENABLED
-    }
-}
-
-impl Enableable for InteractionState {
-    /// Check if this interaction state includes the enabled flag.
-    fn is_enabled(&self) -> bool {
-        self.contains(Self::ENABLED)
-    }
-
-    /// Return a new interaction state with enabled flag set to true.
-    fn enable(self) -> Self {
-        self | Self::ENABLED
-    }
-
-    /// Return a new interaction state with enabled flag set to false.
-    fn disable(self) -> Self {
-        self & !Self::ENABLED
-    }
-}
-
-impl Pressable for InteractionState {
-    /// Check if this interaction state includes the pressed flag.
-    fn is_pressed(&self) -> bool {
-        self.contains(Self::PRESSED)
-    }
-
-    /// Return a new interaction state with pressed flag set to true.
-    fn press(self) -> Self {
-        self | Self::PRESSED
-    }
-
-    /// Return a new interaction state with pressed flag set to false.
-    fn release(self) -> Self {
-        self & !Self::PRESSED
-    }
-}
-
-impl Focusable for InteractionState {
-    /// Check if this interaction state includes the focused flag.
-    fn is_focused(&self) -> bool {
-        self.contains(Self::FOCUSED)
-    }
-
-    /// Check if this interaction state can receive focus (when enabled).
-    fn can_receive_focus(&self) -> bool {
-        self.contains(Self::ENABLED)
-    }
-
-    /// Return a new interaction state with focused flag set to true.
-    fn focus(self) -> Self {
-        self | Self::FOCUSED
-    }
-
-    /// Return a new interaction state with focused flag set to false.
-    fn unfocus(self) -> Self {
-        self & !Self::FOCUSED
-    }
-}
-
-impl Hoverable for InteractionState {
-    /// Check if this interaction state includes the hovered flag.
-    fn is_hovered(&self) -> bool {
-        self.contains(Self::HOVERED)
-    }
-
-    /// Return a new interaction state with hovered flag set to true.
-    fn hover(self) -> Self {
-        self | Self::HOVERED
-    }
-
-    /// Return a new interaction state with hovered flag set to false.
-    fn unhover(self) -> Self {
-        self & !Self::HOVERED
-    }
-}
+pub use state::InteractionState;
 
 /// Messages for controlling the state of interactive components.
 ///
@@ -210,6 +100,20 @@ impl Interactive {
     pub fn with_state(state: InteractionState) -> Self {
         Self { state }
     }
+
+    /// Check whether the component is enabled and pressed, hovered, or focused.
+    ///
+    /// See [`InteractionState::is_active`].
+    pub fn is_active(&self) -> bool {
+        self.state.is_active()
+    }
+
+    /// Check whether the component is enabled and can receive focus.
+    ///
+    /// See [`InteractionState::is_interactive`].
+    pub fn is_interactive(&self) -> bool {
+        self.state.is_interactive()
+    }
 }
 
 impl Default for Interactive {
@@ -576,75 +480,246 @@ impl Hoverable for Interactive {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Implement the interaction traits for a component by delegating to an [`Interactive`] field.
+///
+/// Widgets keep their interaction state in an `Interactive` field and
+/// forward every trait method to it. This macro writes those forwarding
+/// implementations. Name the type and the field; by default the four
+/// interaction traits and [`PreserveInteraction`](crate::model::PreserveInteraction)
+/// are implemented, or list the ones the component supports after a colon.
+/// Generic components are written with a leading `impl<...>`.
+///
+/// ```text
+/// delegate_interaction!(Toggle, interactive);
+/// delegate_interaction!(Checkbox, interactive: Enableable, Focusable, Hoverable, PreserveInteraction);
+/// delegate_interaction!(impl<R> Table<R>, interactive);
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{delegate_interaction, prelude::*};
+///
+/// #[derive(Debug, Clone)]
+/// struct ColorWell {
+///     color: Color,
+///     interactive: Interactive,
+/// }
+///
+/// delegate_interaction!(ColorWell, interactive);
+///
+/// let well = ColorWell { color: Color::RED, interactive: Interactive::new() };
+/// let well = well.hover().focus().press();
+/// assert!(well.is_hovered() && well.is_focused() && well.is_pressed());
+/// assert!(!well.disable().can_receive_focus());
+/// ```
+#[macro_export]
+macro_rules! delegate_interaction {
+    (@impl Enableable [$($generics:tt)*] $ty:ty, $field:ident) => {
+        impl<$($generics)*> $crate::interaction::Enableable for $ty {
+            fn is_enabled(&self) -> bool {
+                $crate::interaction::Enableable::is_enabled(&self.$field)
+            }
 
-    #[test]
-    fn interaction_state_enableable() {
-        let state = InteractionState::default();
-        assert!(state.is_enabled());
+            fn enable(mut self) -> Self {
+                self.$field = $crate::interaction::Enableable::enable(self.$field);
+                self
+            }
 
-        let disabled = state.disable();
-        assert!(!disabled.is_enabled());
+            fn disable(mut self) -> Self {
+                self.$field = $crate::interaction::Enableable::disable(self.$field);
+                self
+            }
+        }
+    };
+    (@impl Pressable [$($generics:tt)*] $ty:ty, $field:ident) => {
+        impl<$($generics)*> $crate::interaction::Pressable for $ty {
+            fn is_pressed(&self) -> bool {
+                $crate::interaction::Pressable::is_pressed(&self.$field)
+            }
 
-        let enabled = disabled.enable();
-        assert!(enabled.is_enabled());
-    }
+            fn press(mut self) -> Self {
+                self.$field = $crate::interaction::Pressable::press(self.$field);
+                self
+            }
 
-    #[test]
-    fn interaction_state_pressable() {
-        let state = InteractionState::default();
-        assert!(!state.is_pressed());
+            fn release(mut self) -> Self {
+                self.$field = $crate::interaction::Pressable::release(self.$field);
+                self
+            }
+        }
+    };
+    (@impl Focusable [$($generics:tt)*] $ty:ty, $field:ident) => {
+        impl<$($generics)*> $crate::interaction::Focusable for $ty {
+            fn is_focused(&self) -> bool {
+                $crate::interaction::Focusable::is_focused(&self.$field)
+            }
 
-        let pressed = state.press();
-        assert!(pressed.is_pressed());
+            fn can_receive_focus(&self) -> bool {
+                $crate::interaction::Focusable::can_receive_focus(&self.$field)
+            }
 
-        let released = pressed.release();
-        assert!(!released.is_pressed());
-    }
+            fn focus(mut self) -> Self {
+                self.$field = $crate::interaction::Focusable::focus(self.$field);
+                self
+            }
 
-    #[test]
-    fn interaction_state_focusable() {
-        let state = InteractionState::default();
-        assert!(!state.is_focused());
-        assert!(state.can_receive_focus());
+            fn unfocus(mut self) -> Self {
+                self.$field = $crate::interaction::Focusable::unfocus(self.$field);
+                self
+            }
+        }
+    };
+    (@impl Hoverable [$($generics:tt)*] $ty:ty, $field:ident) => {
+        impl<$($generics)*> $crate::interaction::Hoverable for $ty {
+            fn is_hovered(&self) -> bool {
+                $crate::interaction::Hoverable::is_hovered(&self.$field)
+            }
 
-        let focused = state.focus();
-        assert!(focused.is_focused());
+            fn hover(mut self) -> Self {
+                self.$field = $crate::interaction::Hoverable::hover(self.$field);
+                self
+            }
 
-        let unfocused = focused.unfocus();
-        assert!(!unfocused.is_focused());
+            fn unhover(mut self) -> Self {
+                self.$field = $crate::interaction::Hoverable::unhover(self.$field);
+                self
+            }
+        }
+    };
+    (@impl PreserveInteraction [$($generics:tt)*] $ty:ty, $field:ident) => {
+        impl<$($generics)*> $crate::model::PreserveInteraction for $ty {
+            fn interaction_snapshot(&self) -> $crate::interaction::InteractionState {
+                self.$field.state
+            }
+
+            fn restore_interaction(
+                mut self,
+                state: $crate::interaction::InteractionState,
+            ) -> Self {
+                self.$field = $crate::interaction::Interactive::with_state(state);
+                self
+            }
+        }
+    };
+    (@each $generics:tt $ty:ty, $field:ident: $($trait:ident),+) => {
+        $($crate::delegate_interaction!(@impl $trait $generics $ty, $field);)+
+    };
+    (impl<$($param:ident),+> $ty:ty, $field:ident) => {
+        $crate::delegate_interaction!(
+            impl<$($param),+> $ty,
+            $field: Enableable, Pressable, Focusable, Hoverable, PreserveInteraction
+        );
+    };
+    (impl<$($param:ident),+> $ty:ty, $field:ident: $($trait:ident),+) => {
+        $crate::delegate_interaction!(@each [$($param),+] $ty, $field: $($trait),+);
+    };
+    ($ty:ty, $field:ident) => {
+        $crate::delegate_interaction!(
+            $ty,
+            $field: Enableable, Pressable, Focusable, Hoverable, PreserveInteraction
+        );
+    };
+    ($ty:ty, $field:ident: $($trait:ident),+) => {
+        $crate::delegate_interaction!(@each [] $ty, $field: $($trait),+);
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A minimal widget whose interaction traits come from the macro.
+    #[derive(Debug, Clone)]
+    struct Swatch {
+        interactive: Interactive,
+    }
+
+    crate::delegate_interaction!(Swatch, interactive);
 
-        // Disabled state cannot receive focus
-        let disabled = state.disable();
-        assert!(!disabled.can_receive_focus());
+    /// A generic widget that only supports some of the traits.
+    #[derive(Debug, Clone)]
+    struct Tagged<T> {
+        tag: T,
+        state: Interactive,
     }
 
+    crate::delegate_interaction!(impl<T> Tagged<T>, state: Enableable, Hoverable);
+
     #[test]
-    fn interaction_state_hoverable() {
-        let state = InteractionState::default();
-        assert!(!state.is_hovered());
+    fn combined_state_queries() {
+        use InteractionState as S;
+
+        assert!(!S::ENABLED.is_active());
+        for flag in [S::PRESSED, S::HOVERED, S::FOCUSED] {
+            assert!((S::ENABLED | flag).is_active());
+            // Disabled components are never active
+            assert!(!flag.is_active());
+        }
+        assert!((S::ENABLED | S::PRESSED | S::HOVERED | S::FOCUSED).is_active());
 
-        let hovered = state.hover();
-        assert!(hovered.is_hovered());
+        assert!(S::ENABLED.is_interactive());
+        assert!(!S::empty().is_interactive());
+        assert!(!(S::HOVERED | S::FOCUSED).is_interactive());
 
-        let unhovered = hovered.unhover();
-        assert!(!unhovered.is_hovered());
+        let interactive = Interactive::new().hover();
+        assert!(interactive.is_active());
+        assert!(interactive.is_interactive());
+        let disabled = interactive.disable();
+        assert!(!disabled.is_active());
+        assert!(!disabled.is_interactive());
     }
 
     #[test]
-    fn interaction_state_trait_chaining() {
-        let state = InteractionState::default()
-            .press()
-            .focus()
-            .hover()
-            .disable();
-
-        assert!(!state.is_enabled());
-        assert!(state.is_pressed());
-        assert!(state.is_focused());
-        assert!(state.is_hovered());
+    fn delegated_traits_match_button() {
+        use crate::{model::PreserveInteraction, widgets::Button};
+
+        let swatch = Swatch {
+            interactive: Interactive::new(),
+        };
+        let button = Button::new("Reference");
+
+        let sequences: [fn(Swatch) -> Swatch; 3] = [
+            |w| w.hover().press().focus(),
+            |w| w.press().release().unhover().unfocus(),
+            |w| w.focus().disable().hover(),
+        ];
+        let button_sequences: [fn(Button) -> Button; 3] = [
+            |b| b.hover().press().focus(),
+            |b| b.press().release().unhover().unfocus(),
+            |b| b.focus().disable().hover(),
+        ];
+        for (apply, apply_button) in sequences.iter().zip(button_sequences) {
+            let swatch = apply(swatch.clone());
+            let button = apply_button(button.clone());
+            assert_eq!(swatch.interactive.state, button.interaction_snapshot());
+            assert_eq!(swatch.is_enabled(), button.is_enabled());
+            assert_eq!(swatch.is_pressed(), button.is_pressed());
+            assert_eq!(swatch.is_focused(), button.is_focused());
+            assert_eq!(swatch.is_hovered(), button.is_hovered());
+            assert_eq!(swatch.can_receive_focus(), button.can_receive_focus());
+
+            // Rebuilt components keep the transient state but not the enabled flag
+            let fresh = Swatch {
+                interactive: Interactive::new(),
+            };
+            let merged = fresh.merge_interaction_from(&swatch);
+            let merged_button = Button::new("Rebuilt").merge_interaction_from(&button);
+            assert_eq!(
+                merged.interaction_snapshot(),
+                merged_button.interaction_snapshot()
+            );
+        }
+
+        let tagged = Tagged {
+            tag: "label",
+            state: Interactive::new(),
+        }
+        .hover()
+        .disable();
+        assert_eq!(tagged.tag, "label");
+        assert!(tagged.is_hovered());
+        assert!(!tagged.is_enabled());
     }
 
     #[test]
@@ -0,0 +1,225 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Interaction state flags shared by interactive components
+
+use bitflags::bitflags;
+
+use super::{Enableable, Focusable, Hoverable, Pressable};
+
+bitflags! {
+    /// Bitflags representing the interactive state of a UI component.
+    ///
+    /// This compact representation uses a single byte to track all common
+    /// interaction states, making it efficient for components that need to
+    /// manage multiple interaction states simultaneously.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// let state = InteractionState::ENABLED | InteractionState::FOCUSED;
+    /// assert!(state.contains(InteractionState::ENABLED));
+    /// assert!(state.contains(InteractionState::FOCUSED));
+    /// assert!(!state.contains(InteractionState::PRESSED));
+    /// ```
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub struct InteractionState: u8 {
+        /// Component is enabled and can receive user interactions
+        const ENABLED = 0b0001;
+        /// Component is currently being pressed (mouse down, touch active)
+        const PRESSED = 0b0010;
+        /// Component currently has keyboard focus
+        const FOCUSED = 0b0100;
+        /// Component is currently being hovered by a pointer
+        const HOVERED = 0b1000;
+    }
+}
+
+impl Default for InteractionState {
+    /// Create a default interaction state with only ENABLED set.
+    ///
+    /// This represents a newly created interactive component that is
+    /// enabled but not currently being interacted with.
+    fn default() -> Self {
+        Self::ENABLED
+    }
+}
+
+impl InteractionState {
+    /// Check whether the user is engaging with an enabled component.
+    ///
+    /// A component is active while it is enabled and pressed, hovered, or
+    /// focused. Backends use this to choose between a resting and a
+    /// highlighted appearance with a single check.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// assert!(!InteractionState::ENABLED.is_active());
+    /// assert!((InteractionState::ENABLED | InteractionState::HOVERED).is_active());
+    /// assert!(!InteractionState::HOVERED.is_active());
+    /// ```
+    pub fn is_active(&self) -> bool {
+        self.is_enabled() && self.intersects(Self::PRESSED | Self::HOVERED | Self::FOCUSED)
+    }
+
+    /// Check whether a component accepts user input at all.
+    ///
+    /// Interactive components are enabled and able to receive focus.
+    pub fn is_interactive(&self) -> bool {
+        self.is_enabled() && self.can_receive_focus()
+    }
+}
+
+impl Enableable for InteractionState {
+    /// Check if this interaction state includes the enabled flag.
+    fn is_enabled(&self) -> bool {
+        self.contains(Self::ENABLED)
+    }
+
+    /// Return a new interaction state with enabled flag set to true.
+    fn enable(self) -> Self {
+        self | Self::ENABLED
+    }
+
+    /// Return a new interaction state with enabled flag set to false.
+    fn disable(self) -> Self {
+        self & !Self::ENABLED
+    }
+}
+
+impl Pressable for InteractionState {
+    /// Check if this interaction state includes the pressed flag.
+    fn is_pressed(&self) -> bool {
+        self.contains(Self::PRESSED)
+    }
+
+    /// Return a new interaction state with pressed flag set to true.
+    fn press(self) -> Self {
+        self | Self::PRESSED
+    }
+
+    /// Return a new interaction state with pressed flag set to false.
+    fn release(self) -> Self {
+        self & !Self::PRESSED
+    }
+}
+
+impl Focusable for InteractionState {
+    /// Check if this interaction state includes the focused flag.
+    fn is_focused(&self) -> bool {
+        self.contains(Self::FOCUSED)
+    }
+
+    /// Check if this interaction state can receive focus (when enabled).
+    fn can_receive_focus(&self) -> bool {
+        self.contains(Self::ENABLED)
+    }
+
+    /// Return a new interaction state with focused flag set to true.
+    fn focus(self) -> Self {
+        self | Self::FOCUSED
+    }
+
+    /// Return a new interaction state with focused flag set to false.
+    fn unfocus(self) -> Self {
+        self & !Self::FOCUSED
+    }
+}
+
+impl Hoverable for InteractionState {
+    /// Check if this interaction state includes the hovered flag.
+    fn is_hovered(&self) -> bool {
+        self.contains(Self::HOVERED)
+    }
+
+    /// Return a new interaction state with hovered flag set to true.
+    fn hover(self) -> Self {
+        self | Self::HOVERED
+    }
+
+    /// Return a new interaction state with hovered flag set to false.
+    fn unhover(self) -> Self {
+        self & !Self::HOVERED
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interaction_state_enableable() {
+        let state = InteractionState::default();
+        assert!(state.is_enabled());
+
+        let disabled = state.disable();
+        assert!(!disabled.is_enabled());
+
+        let enabled = disabled.enable();
+        assert!(enabled.is_enabled());
+    }
+
+    #[test]
+    fn interaction_state_pressable() {
+        let state = InteractionState::default();
+        assert!(!state.is_pressed());
+
+        let pressed = state.press();
+        assert!(pressed.is_pressed());
+
+        let released = pressed.release();
+        assert!(!released.is_pressed());
+    }
+
+    #[test]
+    fn interaction_state_focusable() {
+        let state = InteractionState::default();
+        assert!(!state.is_focused());
+        assert!(state.can_receive_focus());
+
+        let focused = state.focus();
+        assert!(focused.is_focused());
+
+        let unfocused = focused.unfocus();
+        assert!(!unfocused.is_focused());
+
+        // Disabled state cannot receive focus
+        let disabled = state.disable();
+        assert!(!disabled.can_receive_focus());
+    }
+
+    #[test]
+    fn interaction_state_hoverable() {
+        let state = InteractionState::default();
+        assert!(!state.is_hovered());
+
+        let hovered = state.hover();
+        assert!(hovered.is_hovered());
+
+        let unhovered = hovered.unhover();
+        assert!(!unhovered.is_hovered());
+    }
+
+    #[test]
+    fn interaction_state_trait_chaining() {
+        let state = InteractionState::default()
+            .press()
+            .focus()
+            .hover()
+            .disable();
+
+        assert!(!state.is_enabled());
+        assert!(state.is_pressed());
+        assert!(state.is_focused());
+        assert!(state.is_hovered());
+    }
+}
+
+// End of File
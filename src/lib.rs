@@ -51,43 +51,146 @@
 //!
 //! ## Framework Organization
 //!
+//! - **[`animation`]** - Time-based transitions between values
 //! - **[`backends`]** - Concrete backend implementations
+//! - **[`command`]** - Deferred effects and their scheduling
 //! - **[`elements`]** - Basic display building blocks with no state
 //! - **[`extraction`]** - Backend abstraction for rendering views
 //! - **[`interaction`]** - Traits and types for user interaction handling
 //! - **[`message`]** - Message trait and types for state changes
 //! - **[`model`]** - Model trait and types for application state
 //! - **[`style`]** - Styling types for colors, fonts, and layout
+//! - **[`subscription`]** - External event sources that feed messages into models
+//! - **[`testing`]** - Helpers for driving components in tests
 //! - **[`view`]** - View trait and types for rendering views
 //! - **[`widgets`]** - Interactive components with state and behavior
 
+pub mod animation;
 pub mod backends;
+pub mod command;
 pub mod elements;
 pub mod extraction;
 pub mod interaction;
 pub mod message;
 pub mod model;
 pub mod style;
+pub mod subscription;
+pub mod testing;
 pub mod view;
 pub mod widgets;
 
-pub use elements::{Alignment, HStack, Spacer, Text, VStack};
+pub use animation::{Animatable, Animation};
+pub use elements::{
+    Alignment, Alignment2D, Badge, Border, BorderStyle, Card, CardPadding, ContentMode, EdgeInsets,
+    Elevation, FlowLayout, Gauge, GaugeStyle, Grid, HStack, HorizontalEdge, Identified, Image,
+    ImageSource, Label, LayoutDirection, MappedView, Opacity, Padding, RichText, ScrollAxis,
+    ScrollView, Shadow, Spacer, Sparkline, Spinner, SpinnerStyle, Stack, Tagged, Text, TextSpan,
+    Tooltip, TooltipPlacement, VStack, ZStack,
+};
 pub use extraction::{
-    ExtractionError, ExtractionResult, RenderContext, ViewExtractor, ViewRegistry,
+    DirtySet, EnvKey, ExtractionCache, ExtractionError, ExtractionResult, RegistryError,
+    RegistryResult, RenderContext, RenderScheduler, ViewExtractor, ViewRegistry,
+    ViewRegistryBuilder,
 };
 pub use interaction::{
     Enableable, Focusable, Hoverable, InteractionMessage, InteractionState, Interactive, Pressable,
 };
 pub use message::Message;
 pub use model::Model;
-pub use style::{Color, TextStyle};
+pub use style::{
+    Color, ColorParseError, DisabledStyle, FontFamily, FontStyle, FontWeight, PressFeedback,
+    SemanticColor, TextAlignment, TextStyle, Theme, ThemeColors, TruncationMode,
+};
+pub use subscription::Subscription;
 pub use view::View;
-pub use widgets::{Button, ButtonMessage, ButtonView};
+pub use widgets::{
+    ActivityIndicator, ActivityIndicatorMessage, ActivityIndicatorView, Button, ButtonMessage,
+    ButtonView, CheckState, Checkbox, CheckboxMessage, CheckboxView, EmptyState, EmptyStateMessage,
+    LoadingMessage, LoadingView, Picker, PickerMessage, PickerView, Slider, SliderMessage,
+    SliderView, Stepper, StepperMessage, StepperView, Table, TableMessage, TableView, Toggle,
+    ToggleMessage, ToggleView, Toolbar,
+};
+
+// Views are handed to backends on other threads and `Message` requires both
+// bounds, so every view, widget and extracted representation must stay
+// `Send + Sync`. Adding a field that isn't breaks the build here instead of
+// in applications.
+const _: fn() = || {
+    use crate::{
+        backends::{
+            mock::{MockBackend, MockDynamicChild},
+            terminal::TerminalBackend,
+        },
+        elements::{
+            Border, HStack, Identified, Image, ImageSource, MappedView, Opacity, Padding, RichText,
+            ScrollView, Spacer, Spinner, Text, TextInterner, VStack, ZStack,
+        },
+        model::{MappedModel, ModelList, Pair},
+        view::View,
+        widgets::{
+            ActivityIndicator, Button, ButtonMessage, ButtonView, Checkbox, CheckboxView,
+            EmptyState, Picker, PickerView, Slider, SliderView, Stepper, Table, TableView, Toggle,
+            ToggleView,
+        },
+    };
+
+    fn assert_send_sync<T: Send + Sync + ?Sized>() {}
+
+    // Elements, including shared data and boxed dynamic children
+    assert_send_sync::<Text>();
+    assert_send_sync::<RichText>();
+    assert_send_sync::<Image>();
+    assert_send_sync::<ImageSource>();
+    assert_send_sync::<TextInterner>();
+    assert_send_sync::<Spacer>();
+    assert_send_sync::<Spinner>();
+    assert_send_sync::<dyn View>();
+    assert_send_sync::<Box<dyn View>>();
+    assert_send_sync::<VStack<Vec<Box<dyn View>>>>();
+    assert_send_sync::<HStack<Vec<Box<dyn View>>>>();
+    assert_send_sync::<ZStack<Vec<Box<dyn View>>>>();
+    assert_send_sync::<ScrollView<Vec<Box<dyn View>>>>();
+    assert_send_sync::<Padding<Box<dyn View>>>();
+    assert_send_sync::<Border<Box<dyn View>>>();
+    assert_send_sync::<Opacity<Box<dyn View>>>();
+    assert_send_sync::<Identified<Box<dyn View>>>();
+    assert_send_sync::<MappedView<ButtonView, ButtonMessage, ButtonMessage>>();
+
+    // Widgets, their views, and their messages
+    assert_send_sync::<Button>();
+    assert_send_sync::<ButtonView>();
+    assert_send_sync::<ButtonMessage>();
+    assert_send_sync::<Checkbox>();
+    assert_send_sync::<CheckboxView>();
+    assert_send_sync::<Picker>();
+    assert_send_sync::<PickerView>();
+    assert_send_sync::<Slider>();
+    assert_send_sync::<SliderView>();
+    assert_send_sync::<ActivityIndicator>();
+    assert_send_sync::<Stepper>();
+    assert_send_sync::<Table<String>>();
+    assert_send_sync::<TableView>();
+    assert_send_sync::<Toggle>();
+    assert_send_sync::<ToggleView>();
+    assert_send_sync::<EmptyState<ButtonMessage>>();
+
+    // Model combinators
+    assert_send_sync::<Pair<Button, Toggle>>();
+    assert_send_sync::<ModelList<Button>>();
+    assert_send_sync::<MappedModel<Button, ButtonMessage>>();
+
+    // Extracted representations and backends
+    assert_send_sync::<MockDynamicChild>();
+    assert_send_sync::<MockBackend>();
+    assert_send_sync::<TerminalBackend>();
+};
 
 /// Prelude module for Ironwood UI Framework
 ///
 /// This module re-exports the most commonly used types and traits from Ironwood,
 /// allowing users to import everything they need with a single `use` statement.
+/// Less common types stay in their modules, such as [`style`] for themes and
+/// fonts or [`widgets`] for the remaining controls.
 ///
 /// # Examples
 ///
@@ -125,7 +228,7 @@ pub use widgets::{Button, ButtonMessage, ButtonView};
 /// ```
 pub mod prelude {
     // Re-export the core traits that users will need in almost every Ironwood application
-    pub use crate::elements::{Alignment, HStack, Spacer, Text, VStack};
+    pub use crate::elements::{Alignment, HStack, Spacer, Stack, Text, VStack, ZStack};
     pub use crate::extraction::{
         ExtractionError, ExtractionResult, RenderContext, ViewExtractor, ViewRegistry,
     };
@@ -137,7 +240,10 @@ pub mod prelude {
     pub use crate::model::Model;
     pub use crate::style::{Color, TextStyle};
     pub use crate::view::View;
-    pub use crate::widgets::{Button, ButtonMessage, ButtonView};
+    pub use crate::widgets::{
+        Button, ButtonMessage, ButtonView, Checkbox, CheckboxMessage, Slider, SliderMessage,
+        Toggle, ToggleMessage,
+    };
 }
 
 // End of File
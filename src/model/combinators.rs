@@ -0,0 +1,609 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Models that embed and route messages to other models
+
+use std::{collections::BTreeMap, fmt::Debug};
+
+use super::Model;
+use crate::{
+    elements::{MappedView, VStack},
+    message::Message,
+    view::View,
+};
+
+/// Two independent models composed side by side.
+///
+/// `Pair` standardizes the most common composition: a parent that embeds two
+/// unrelated components and routes each message to exactly one of them.
+/// Each sub-model keeps its own state and message type, and the pair's view
+/// stacks both sub-views vertically.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{model::{Pair, PairMessage}, prelude::*};
+///
+/// let toolbar = Pair::new(Button::new("Save"), Button::new("Cancel"));
+///
+/// // Route a message to the second button only
+/// let toolbar = toolbar.update(PairMessage::Second(ButtonMessage::Interaction(
+///     InteractionMessage::HoverChanged(true),
+/// )));
+/// assert!(!toolbar.first.is_hovered());
+/// assert!(toolbar.second.is_hovered());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pair<A, B> {
+    /// The first sub-model, rendered on top
+    pub first: A,
+    /// The second sub-model, rendered below the first
+    pub second: B,
+}
+
+impl<A: Model, B: Model> Pair<A, B> {
+    /// Create a pair from two sub-models.
+    pub fn new(first: A, second: B) -> Self {
+        Self { first, second }
+    }
+}
+
+/// Messages for a [`Pair`], routed to exactly one of its sub-models.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PairMessage<A, B> {
+    /// Message for the first sub-model
+    First(A),
+    /// Message for the second sub-model
+    Second(B),
+}
+
+impl<A: Message, B: Message> Message for PairMessage<A, B> {}
+
+impl<A: Model, B: Model> Model for Pair<A, B> {
+    type Message = PairMessage<A::Message, B::Message>;
+    type View = VStack<(A::View, B::View)>;
+
+    /// Forward the message to the sub-model it targets, leaving the other untouched.
+    fn update(self, message: Self::Message) -> Self {
+        match message {
+            PairMessage::First(message) => Self {
+                first: self.first.update(message),
+                ..self
+            },
+            PairMessage::Second(message) => Self {
+                second: self.second.update(message),
+                ..self
+            },
+        }
+    }
+
+    /// Stack both sub-views vertically, first above second.
+    fn view(&self) -> Self::View {
+        VStack::new((self.first.view(), self.second.view()))
+    }
+}
+
+/// A list of uniform sub-models addressed by index.
+///
+/// `ModelList` manages a dynamic number of components of the same type, such
+/// as editable rows in a todo list. Messages carry the index of the element
+/// they target, and elements can be appended or removed through messages so
+/// the list itself stays an ordinary immutable model. Routes to indices that
+/// don't exist are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{model::{ModelList, ModelListMessage}, prelude::*};
+///
+/// let buttons = ModelList::new()
+///     .with(Button::new("One"))
+///     .with(Button::new("Two"));
+///
+/// let buttons = buttons.update(ModelListMessage::Item(
+///     1,
+///     ButtonMessage::Interaction(InteractionMessage::FocusChanged(true)),
+/// ));
+/// assert!(!buttons.items[0].is_focused());
+/// assert!(buttons.items[1].is_focused());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModelList<M> {
+    /// The sub-models in display order
+    pub items: Vec<M>,
+}
+
+impl<M: Model> ModelList<M> {
+    /// Create an empty list.
+    pub fn new() -> Self {
+        Self { items: Vec::new() }
+    }
+
+    /// Create a list from existing sub-models.
+    pub fn from_items(items: Vec<M>) -> Self {
+        Self { items }
+    }
+
+    /// Append a sub-model to the list.
+    pub fn with(mut self, item: M) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    /// Get the number of sub-models in the list.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Check whether the list contains no sub-models.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
+
+impl<M: Model> Default for ModelList<M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Messages for a [`ModelList`].
+#[derive(Debug, Clone)]
+pub enum ModelListMessage<M: Model> {
+    /// Message for the sub-model at the given index
+    Item(usize, M::Message),
+    /// Append a sub-model to the end of the list
+    Push(M),
+    /// Remove the sub-model at the given index, shifting later items down
+    Remove(usize),
+}
+
+impl<M: Model> Message for ModelListMessage<M> {}
+
+impl<M: Model> Model for ModelList<M> {
+    type Message = ModelListMessage<M>;
+    type View = VStack<Vec<Box<dyn View>>>;
+
+    /// Route item messages by index and apply structural changes.
+    ///
+    /// Item messages and removals that target an index past the end of the
+    /// list leave the list unchanged.
+    fn update(mut self, message: Self::Message) -> Self {
+        match message {
+            ModelListMessage::Item(index, message) => {
+                if index < self.items.len() {
+                    let item = self.items.remove(index);
+                    self.items.insert(index, item.update(message));
+                }
+            }
+            ModelListMessage::Push(item) => self.items.push(item),
+            ModelListMessage::Remove(index) => {
+                if index < self.items.len() {
+                    self.items.remove(index);
+                }
+            }
+        }
+        self
+    }
+
+    /// Stack every sub-view vertically in list order.
+    fn view(&self) -> Self::View {
+        VStack::from_children(
+            self.items
+                .iter()
+                .map(|item| Box::new(item.view()) as Box<dyn View>),
+        )
+    }
+}
+
+/// A sub-model that may or may not be present.
+///
+/// `Optional` models conditionally present components such as a detail panel
+/// that only exists while an item is selected. The inner model is installed and
+/// removed through messages, inner messages are delivered only while a model is
+/// present, and the view renders nothing when empty.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{model::{Optional, OptionalMessage}, prelude::*};
+///
+/// let panel = Optional::<Button>::empty();
+/// assert!(panel.view().is_none());
+///
+/// let panel = panel.update(OptionalMessage::Set(Button::new("Details")));
+/// assert!(panel.view().is_some());
+///
+/// let panel = panel.update(OptionalMessage::Clear);
+/// assert!(panel.is_empty());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Optional<M> {
+    /// The inner model, if present
+    pub inner: Option<M>,
+}
+
+impl<M: Model> Optional<M> {
+    /// Create an optional with no inner model.
+    pub fn empty() -> Self {
+        Self { inner: None }
+    }
+
+    /// Create an optional holding the given inner model.
+    pub fn some(inner: M) -> Self {
+        Self { inner: Some(inner) }
+    }
+
+    /// Check whether the inner model is present.
+    pub fn is_present(&self) -> bool {
+        self.inner.is_some()
+    }
+
+    /// Check whether the inner model is absent.
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_none()
+    }
+}
+
+impl<M: Model> Default for Optional<M> {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+/// Messages for an [`Optional`].
+#[derive(Debug, Clone)]
+pub enum OptionalMessage<M: Model> {
+    /// Install an inner model, replacing any existing one
+    Set(M),
+    /// Remove the inner model
+    Clear,
+    /// Message for the inner model, dropped when no model is present
+    Inner(M::Message),
+}
+
+impl<M: Model> Message for OptionalMessage<M> {}
+
+impl<M: Model> Model for Optional<M> {
+    type Message = OptionalMessage<M>;
+    type View = Option<M::View>;
+
+    /// Install, remove, or forward to the inner model.
+    fn update(self, message: Self::Message) -> Self {
+        match message {
+            OptionalMessage::Set(inner) => Self::some(inner),
+            OptionalMessage::Clear => Self::empty(),
+            OptionalMessage::Inner(message) => Self {
+                inner: self.inner.map(|inner| inner.update(message)),
+            },
+        }
+    }
+
+    /// Render the inner model's view, or nothing when empty.
+    fn view(&self) -> Self::View {
+        self.inner.as_ref().map(Model::view)
+    }
+}
+
+/// A collection of sub-models addressed by key.
+///
+/// `ModelMap` suits dashboards and other views keyed by entity id. Entries are
+/// kept in a `BTreeMap`, so iteration and rendering always follow sorted key
+/// order regardless of insertion order. Messages for keys that aren't present
+/// are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{model::{ModelMap, ModelMapMessage}, prelude::*};
+///
+/// let panels = ModelMap::new()
+///     .with("settings", Button::new("Settings"))
+///     .with("profile", Button::new("Profile"));
+///
+/// let panels = panels.update(ModelMapMessage::Entry(
+///     "profile",
+///     ButtonMessage::Interaction(InteractionMessage::EnabledChanged(false)),
+/// ));
+/// assert!(!panels.entries["profile"].is_enabled());
+/// assert!(panels.entries["settings"].is_enabled());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModelMap<K, M> {
+    /// The sub-models in key order
+    pub entries: BTreeMap<K, M>,
+}
+
+impl<K: Ord, M: Model> ModelMap<K, M> {
+    /// Create an empty map.
+    pub fn new() -> Self {
+        Self {
+            entries: BTreeMap::new(),
+        }
+    }
+
+    /// Insert a sub-model under the given key, replacing any existing entry.
+    pub fn with(mut self, key: K, model: M) -> Self {
+        self.entries.insert(key, model);
+        self
+    }
+
+    /// Get the number of entries in the map.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check whether the map contains no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl<K: Ord, M: Model> Default for ModelMap<K, M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Messages for a [`ModelMap`].
+#[derive(Debug, Clone)]
+pub enum ModelMapMessage<K, M: Model> {
+    /// Message for the sub-model stored under the given key
+    Entry(K, M::Message),
+    /// Insert a sub-model under the given key, replacing any existing entry
+    Insert(K, M),
+    /// Remove the entry stored under the given key
+    Remove(K),
+}
+
+impl<K, M> Message for ModelMapMessage<K, M>
+where
+    K: Debug + Clone + Send + Sync + 'static,
+    M: Model,
+{
+}
+
+impl<K, M> Model for ModelMap<K, M>
+where
+    K: Ord + Debug + Clone + Send + Sync + 'static,
+    M: Model,
+{
+    type Message = ModelMapMessage<K, M>;
+    type View = VStack<Vec<Box<dyn View>>>;
+
+    /// Route entry messages by key and apply insertions and removals.
+    fn update(mut self, message: Self::Message) -> Self {
+        match message {
+            ModelMapMessage::Entry(key, message) => {
+                if let Some(entry) = self.entries.remove(&key) {
+                    self.entries.insert(key, entry.update(message));
+                }
+            }
+            ModelMapMessage::Insert(key, model) => {
+                self.entries.insert(key, model);
+            }
+            ModelMapMessage::Remove(key) => {
+                self.entries.remove(&key);
+            }
+        }
+        self
+    }
+
+    /// Stack every sub-view vertically in sorted key order.
+    fn view(&self) -> Self::View {
+        VStack::from_children(
+            self.entries
+                .values()
+                .map(|entry| Box::new(entry.view()) as Box<dyn View>),
+        )
+    }
+}
+
+/// A sub-model whose view reports events in a parent's message type.
+///
+/// `MappedModel` is created with [`Model::lift`]. It is updated with the
+/// sub-model's own messages, so the parent only has to unwrap its variant
+/// and forward the contents, while the mapped view converts events coming
+/// back from the sub-view into the parent's message type.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::prelude::*;
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// enum AppMessage {
+///     Save(ButtonMessage),
+/// }
+///
+/// impl Message for AppMessage {}
+///
+/// let save = Button::new("Save").lift(AppMessage::Save);
+/// let save = save.update(ButtonMessage::Interaction(InteractionMessage::HoverChanged(true)));
+/// assert!(save.model.is_hovered());
+/// ```
+#[derive(Debug, Clone)]
+pub struct MappedModel<C: Model, P> {
+    /// The embedded sub-model
+    pub model: C,
+    wrap: fn(C::Message) -> P,
+}
+
+impl<C: Model, P: Message> MappedModel<C, P> {
+    /// Embed a sub-model, wrapping its messages with `wrap`.
+    pub fn new(model: C, wrap: fn(C::Message) -> P) -> Self {
+        Self { model, wrap }
+    }
+
+    /// Wrap a message of the sub-model in the parent's message type.
+    pub fn route(&self, message: C::Message) -> P {
+        (self.wrap)(message)
+    }
+
+    /// Remove the mapping, returning the sub-model.
+    pub fn into_inner(self) -> C {
+        self.model
+    }
+}
+
+impl<C: Model, P: Message> Model for MappedModel<C, P> {
+    type Message = C::Message;
+    type View = MappedView<C::View, C::Message, P>;
+
+    /// Forward the message to the sub-model.
+    fn update(self, message: Self::Message) -> Self {
+        Self {
+            model: self.model.update(message),
+            ..self
+        }
+    }
+
+    /// Render the sub-view, reporting its events as parent messages.
+    fn view(&self) -> Self::View {
+        MappedView::new(self.model.view(), self.wrap)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::tests::{Counter, CounterMessage};
+
+    #[test]
+    fn pair_routes_messages_independently() {
+        use crate::{
+            backends::mock::MockBackend,
+            extraction::{RenderContext, ViewExtractor},
+        };
+
+        let pair = Pair::new(Counter { count: 0 }, Counter { count: 10 });
+
+        // Only the targeted sub-model changes
+        let pair = pair.update(PairMessage::First(CounterMessage::Increment));
+        assert_eq!(pair.first.count, 1);
+        assert_eq!(pair.second.count, 10);
+
+        let pair = pair.update(PairMessage::Second(CounterMessage::Decrement));
+        assert_eq!(pair.first.count, 1);
+        assert_eq!(pair.second.count, 9);
+
+        // The combined view stacks first above second
+        let extracted = MockBackend::extract(&pair.view(), &RenderContext::new()).unwrap();
+        assert_eq!(extracted.content.0.content, "Count: 1");
+        assert_eq!(extracted.content.1.content, "Count: 9");
+    }
+
+    #[test]
+    fn model_list_routes_by_index() {
+        use crate::backends::mock::{MockBackend, MockDynamicChild};
+        use crate::extraction::{RenderContext, ViewExtractor};
+
+        let list = ModelList::from_items(vec![
+            Counter { count: 0 },
+            Counter { count: 0 },
+            Counter { count: 0 },
+        ]);
+
+        // Only the addressed counter changes
+        let list = list.update(ModelListMessage::Item(1, CounterMessage::Increment));
+        let counts: Vec<i32> = list.items.iter().map(|c| c.count).collect();
+        assert_eq!(counts, vec![0, 1, 0]);
+
+        // Out-of-range routes are ignored
+        let unchanged = list
+            .clone()
+            .update(ModelListMessage::Item(3, CounterMessage::Increment))
+            .update(ModelListMessage::Remove(7));
+        assert_eq!(unchanged, list);
+
+        // Removing shifts later items so indices stay consistent
+        let list = list
+            .update(ModelListMessage::Remove(0))
+            .update(ModelListMessage::Push(Counter { count: 5 }));
+        let counts: Vec<i32> = list.items.iter().map(|c| c.count).collect();
+        assert_eq!(counts, vec![1, 0, 5]);
+
+        let list = list.update(ModelListMessage::Item(0, CounterMessage::Decrement));
+        assert_eq!(list.items[0].count, 0);
+
+        // Extraction follows list order
+        let extracted = MockBackend::extract(&list.view(), &RenderContext::new()).unwrap();
+        let labels: Vec<String> = extracted
+            .content
+            .iter()
+            .map(|child| match child {
+                MockDynamicChild::Text(text) => text.content.clone(),
+                other => panic!("unexpected child {:?}", other),
+            })
+            .collect();
+        assert_eq!(labels, vec!["Count: 0", "Count: 0", "Count: 5"]);
+    }
+
+    #[test]
+    fn optional_shows_and_hides_inner_model() {
+        use crate::{
+            backends::mock::MockBackend,
+            extraction::{RenderContext, ViewExtractor},
+        };
+
+        let ctx = RenderContext::new();
+        let panel = Optional::<Counter>::empty();
+
+        // Messages to an absent model are dropped without creating one
+        let panel = panel.update(OptionalMessage::Inner(CounterMessage::Increment));
+        assert!(panel.is_empty());
+        assert!(MockBackend::extract(&panel.view(), &ctx).unwrap().is_none());
+
+        // Once populated, inner messages are delivered and the view appears
+        let panel = panel
+            .update(OptionalMessage::Set(Counter { count: 3 }))
+            .update(OptionalMessage::Inner(CounterMessage::Increment));
+        assert!(panel.is_present());
+        let extracted = MockBackend::extract(&panel.view(), &ctx).unwrap();
+        assert!(matches!(extracted, Some(text) if text.content == "Count: 4"));
+
+        // Clearing removes the view again
+        let panel = panel.update(OptionalMessage::Clear);
+        assert!(MockBackend::extract(&panel.view(), &ctx).unwrap().is_none());
+    }
+
+    #[test]
+    fn model_map_routes_by_key_in_sorted_order() {
+        use crate::backends::mock::{MockBackend, MockDynamicChild};
+        use crate::extraction::{RenderContext, ViewExtractor};
+
+        // Insert out of order to verify rendering follows sorted keys
+        let map = ModelMap::new()
+            .with("b", Counter { count: 20 })
+            .with("a", Counter { count: 10 });
+
+        let map = map.update(ModelMapMessage::Entry("b", CounterMessage::Increment));
+        assert_eq!(map.entries["a"].count, 10);
+        assert_eq!(map.entries["b"].count, 21);
+
+        // Messages for missing keys are ignored
+        let unchanged = map
+            .clone()
+            .update(ModelMapMessage::Entry("z", CounterMessage::Increment));
+        assert_eq!(unchanged, map);
+
+        let map = map
+            .update(ModelMapMessage::Insert("c", Counter { count: 30 }))
+            .update(ModelMapMessage::Remove("a"));
+        assert_eq!(map.len(), 2);
+
+        let extracted = MockBackend::extract(&map.view(), &RenderContext::new()).unwrap();
+        let labels: Vec<String> = extracted
+            .content
+            .iter()
+            .map(|child| match child {
+                MockDynamicChild::Text(text) => text.content.clone(),
+                other => panic!("unexpected child {:?}", other),
+            })
+            .collect();
+        assert_eq!(labels, vec!["Count: 21", "Count: 30"]);
+    }
+}
+
+// End of File
@@ -0,0 +1,225 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Models that hold back input while busy
+
+use std::fmt::Debug;
+
+use super::Model;
+use crate::message::Message;
+
+/// What a [`Guarded`] model does with messages that arrive while guarded.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum GuardMode {
+    /// Discard the message
+    #[default]
+    Drop,
+    /// Hold the message and apply it once the guard lifts
+    Queue,
+}
+
+/// A model that ignores input while a condition holds.
+///
+/// The guard is a predicate over the inner model, such as "a request is in
+/// flight". While it holds, [`GuardedMessage::Inner`] messages are dropped
+/// or queued according to the [`GuardMode`], which prevents double submits.
+/// [`GuardedMessage::Bypass`] messages are always applied, so events like a
+/// finished request can lift the guard. Queued messages are applied in order
+/// as soon as it lifts.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{model::{Guarded, GuardedMessage}, prelude::*};
+///
+/// let hover = ButtonMessage::Interaction(InteractionMessage::HoverChanged(true));
+/// let guarded = Guarded::new(Button::new("Submit").disable(), |button| !button.is_enabled());
+///
+/// // Ignored while the button is disabled
+/// let guarded = guarded.update(GuardedMessage::Inner(hover.clone()));
+/// assert!(!guarded.inner.is_hovered());
+///
+/// let enable = ButtonMessage::Interaction(InteractionMessage::EnabledChanged(true));
+/// let guarded = guarded
+///     .update(GuardedMessage::Bypass(enable))
+///     .update(GuardedMessage::Inner(hover));
+/// assert!(guarded.inner.is_hovered());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Guarded<M: Model> {
+    /// The guarded model
+    pub inner: M,
+    guard: fn(&M) -> bool,
+    mode: GuardMode,
+    queued: Vec<M::Message>,
+}
+
+impl<M: Model> Guarded<M> {
+    /// Guard a model, dropping its messages while `guard` returns true.
+    pub fn new(inner: M, guard: fn(&M) -> bool) -> Self {
+        Self {
+            inner,
+            guard,
+            mode: GuardMode::Drop,
+            queued: Vec::new(),
+        }
+    }
+
+    /// Set what happens to messages that arrive while guarded.
+    pub fn mode(mut self, mode: GuardMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Check whether the guard currently holds.
+    pub fn is_guarded(&self) -> bool {
+        (self.guard)(&self.inner)
+    }
+
+    /// Get the messages waiting for the guard to lift, oldest first.
+    pub fn queued(&self) -> &[M::Message] {
+        &self.queued
+    }
+
+    /// Apply queued messages in order until the queue empties or the guard holds again.
+    fn drain_queue(mut self) -> Self {
+        let mut pending = std::mem::take(&mut self.queued).into_iter();
+        while !self.is_guarded() {
+            let Some(message) = pending.next() else {
+                break;
+            };
+            self.inner = self.inner.update(message);
+        }
+        self.queued.extend(pending);
+        self
+    }
+}
+
+/// Messages for a [`Guarded`] model.
+#[derive(Debug, Clone)]
+pub enum GuardedMessage<M: Model> {
+    /// Message subject to the guard
+    Inner(M::Message),
+    /// Message applied even while guarded, such as one that lifts the guard
+    Bypass(M::Message),
+}
+
+impl<M: Model> Message for GuardedMessage<M> {}
+
+impl<M: Model> Model for Guarded<M> {
+    type Message = GuardedMessage<M>;
+    type View = M::View;
+
+    /// Apply, drop, or queue the message depending on the guard.
+    fn update(mut self, message: Self::Message) -> Self {
+        match message {
+            GuardedMessage::Inner(message) if self.is_guarded() => {
+                if self.mode == GuardMode::Queue {
+                    self.queued.push(message);
+                }
+                self
+            }
+            GuardedMessage::Inner(message) | GuardedMessage::Bypass(message) => {
+                self.inner = self.inner.update(message);
+                self.drain_queue()
+            }
+        }
+    }
+
+    /// Render the inner model's view.
+    fn view(&self) -> Self::View {
+        self.inner.view()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elements::Text;
+
+    /// Form that submits once per click
+    #[derive(Debug, Clone, PartialEq)]
+    struct Form {
+        loading: bool,
+        submits: u32,
+    }
+
+    #[derive(Debug, Clone)]
+    enum FormMessage {
+        Click,
+        Loaded,
+    }
+
+    impl Message for FormMessage {}
+
+    impl Model for Form {
+        type Message = FormMessage;
+        type View = Text;
+
+        fn update(self, message: Self::Message) -> Self {
+            match message {
+                FormMessage::Click => Self {
+                    loading: true,
+                    submits: self.submits + 1,
+                },
+                FormMessage::Loaded => Self {
+                    loading: false,
+                    ..self
+                },
+            }
+        }
+
+        fn view(&self) -> Self::View {
+            Text::new(format!("Submitted {} times", self.submits))
+        }
+    }
+
+    #[test]
+    fn guard_drops_input_while_loading() {
+        let form = Form {
+            loading: true,
+            submits: 0,
+        };
+        let guarded = Guarded::new(form.clone(), |form| form.loading);
+
+        let guarded = guarded.update(GuardedMessage::Inner(FormMessage::Click));
+        assert_eq!(guarded.inner, form);
+        assert!(guarded.queued().is_empty());
+
+        // Once loading clears, clicks go through and re-arm the guard
+        let guarded = guarded
+            .update(GuardedMessage::Bypass(FormMessage::Loaded))
+            .update(GuardedMessage::Inner(FormMessage::Click))
+            .update(GuardedMessage::Inner(FormMessage::Click));
+        assert_eq!(guarded.inner.submits, 1);
+        assert!(guarded.is_guarded());
+    }
+
+    #[test]
+    fn guard_queues_input_until_lifted() {
+        let form = Form {
+            loading: true,
+            submits: 0,
+        };
+        let guarded = Guarded::new(form.clone(), |form| form.loading).mode(GuardMode::Queue);
+
+        let guarded = guarded
+            .update(GuardedMessage::Inner(FormMessage::Click))
+            .update(GuardedMessage::Inner(FormMessage::Click));
+        assert_eq!(guarded.inner, form);
+        assert_eq!(guarded.queued().len(), 2);
+
+        // The first queued click applies and guards the second again
+        let guarded = guarded.update(GuardedMessage::Bypass(FormMessage::Loaded));
+        assert_eq!(guarded.inner.submits, 1);
+        assert_eq!(guarded.queued().len(), 1);
+
+        let guarded = guarded.update(GuardedMessage::Bypass(FormMessage::Loaded));
+        assert_eq!(guarded.inner.submits, 2);
+        assert!(guarded.queued().is_empty());
+        assert_eq!(guarded.view().content, "Submitted 2 times");
+    }
+}
+
+// End of File
@@ -0,0 +1,164 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Undo and redo for any model
+
+use std::fmt::Debug;
+
+use super::Model;
+use crate::message::Message;
+
+/// A model that remembers its past states for undo and redo.
+///
+/// Every inner message saves the state it replaces, so undoing is just a
+/// matter of stepping back to an earlier immutable model. Undoing and
+/// redoing move states between the two stacks, and any new inner message
+/// discards the states that could have been redone. Undo and redo with
+/// nothing to step to are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{model::{History, HistoryMessage}, prelude::*};
+///
+/// let hover = ButtonMessage::Interaction(InteractionMessage::HoverChanged(true));
+/// let history = History::new(Button::new("Edit")).update(HistoryMessage::Inner(hover));
+/// assert!(history.present.is_hovered());
+///
+/// let history = history.update(HistoryMessage::Undo);
+/// assert!(!history.present.is_hovered());
+///
+/// let history = history.update(HistoryMessage::Redo);
+/// assert!(history.present.is_hovered());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct History<M> {
+    /// The current state
+    pub present: M,
+    /// Earlier states, most recent last
+    past: Vec<M>,
+    /// Undone states, most recently undone last
+    future: Vec<M>,
+}
+
+impl<M: Model> History<M> {
+    /// Start recording history from an initial state.
+    pub fn new(present: M) -> Self {
+        Self {
+            present,
+            past: Vec::new(),
+            future: Vec::new(),
+        }
+    }
+
+    /// Check whether there is an earlier state to return to.
+    pub fn can_undo(&self) -> bool {
+        !self.past.is_empty()
+    }
+
+    /// Check whether there is an undone state to restore.
+    pub fn can_redo(&self) -> bool {
+        !self.future.is_empty()
+    }
+
+    /// Get the number of states that can be undone.
+    pub fn undo_depth(&self) -> usize {
+        self.past.len()
+    }
+
+    /// Get the number of states that can be redone.
+    pub fn redo_depth(&self) -> usize {
+        self.future.len()
+    }
+}
+
+/// Messages for a [`History`].
+#[derive(Debug, Clone)]
+pub enum HistoryMessage<M: Model> {
+    /// Return to the previous state
+    Undo,
+    /// Restore the most recently undone state
+    Redo,
+    /// Message for the current state, recorded so it can be undone
+    Inner(M::Message),
+}
+
+impl<M: Model> Message for HistoryMessage<M> {}
+
+impl<M: Model> Model for History<M> {
+    type Message = HistoryMessage<M>;
+    type View = M::View;
+
+    /// Record and apply inner messages, or step through the recorded states.
+    fn update(mut self, message: Self::Message) -> Self {
+        match message {
+            HistoryMessage::Undo => {
+                if let Some(previous) = self.past.pop() {
+                    self.future
+                        .push(std::mem::replace(&mut self.present, previous));
+                }
+                self
+            }
+            HistoryMessage::Redo => {
+                if let Some(next) = self.future.pop() {
+                    self.past.push(std::mem::replace(&mut self.present, next));
+                }
+                self
+            }
+            HistoryMessage::Inner(message) => {
+                self.past.push(self.present.clone());
+                self.future.clear();
+                Self {
+                    present: self.present.update(message),
+                    ..self
+                }
+            }
+        }
+    }
+
+    /// Render the current state's view.
+    fn view(&self) -> Self::View {
+        self.present.view()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::tests::{Counter, CounterMessage};
+
+    #[test]
+    fn history_undoes_and_redoes_inner_updates() {
+        let history = History::new(Counter { count: 0 })
+            .update(HistoryMessage::Inner(CounterMessage::Increment))
+            .update(HistoryMessage::Inner(CounterMessage::Increment));
+        assert_eq!(history.present.count, 2);
+        assert_eq!((history.undo_depth(), history.redo_depth()), (2, 0));
+
+        let history = history
+            .update(HistoryMessage::Undo)
+            .update(HistoryMessage::Undo);
+        assert_eq!(history.present.count, 0);
+        assert!(!history.can_undo());
+        assert_eq!(history.view().content, "Count: 0");
+
+        // Undo past the beginning is ignored
+        let history = history.update(HistoryMessage::Undo);
+        assert_eq!(history.present.count, 0);
+
+        let history = history.update(HistoryMessage::Redo);
+        assert_eq!(history.present.count, 1);
+        assert!(history.can_redo());
+
+        // A new inner message discards the redo stack
+        let history = history.update(HistoryMessage::Inner(CounterMessage::Decrement));
+        assert_eq!(history.present.count, 0);
+        assert!(!history.can_redo());
+        let unchanged = history.clone().update(HistoryMessage::Redo);
+        assert_eq!(unchanged, history);
+        assert_eq!(history.undo_depth(), 2);
+    }
+}
+
+// End of File
@@ -0,0 +1,633 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Model system for Ironwood UI Framework
+//!
+//! Models represent application state and define how that state changes
+//! in response to messages. They follow the Elm architecture pattern
+//! of immutable updates through pure functions.
+//!
+//! Models are separate from Views - a Model contains state and behavior,
+//! while a View is a pure data structure describing how that state should
+//! be rendered. Models implement a `view()` method that creates the
+//! appropriate View representation of their current state.
+//!
+//! The `update` method consumes the old model and returns a new one because:
+//!
+//! - **Eliminates data races**: No shared mutable state means no race conditions
+//! - **Enables time-travel debugging**: Immutable updates make state history possible
+//! - **Simplifies reasoning**: State changes are explicit and predictable
+//! - **Supports undo/redo**: Immutable updates enable straightforward undo/redo implementation
+//! - **Enables structural sharing**: Rust's move semantics make this efficient
+//!
+//! While this might seem inefficient, Rust's ownership system and compiler
+//! optimizations make immutable updates as fast as mutation in most cases,
+//! while providing much stronger guarantees about program correctness.
+
+use std::fmt::Debug;
+
+use crate::{
+    interaction::InteractionState, message::Message, subscription::Subscription, view::View,
+};
+
+mod combinators;
+mod guard;
+mod history;
+
+pub use combinators::{
+    MappedModel, ModelList, ModelListMessage, ModelMap, ModelMapMessage, Optional, OptionalMessage,
+    Pair, PairMessage,
+};
+pub use guard::{GuardMode, Guarded, GuardedMessage};
+pub use history::{History, HistoryMessage};
+
+/// Trait for application models in Ironwood.
+///
+/// Models are the single source of truth for application state.
+/// They must be cloneable for efficient updates and debuggable for development.
+/// The `update` method defines how the model changes in response to messages,
+/// and the `view` method creates the visual representation of the current state.
+///
+/// ## Dynamic View Support
+///
+/// Models can optionally implement the `view()` method to provide dynamic view
+/// composition. This enables runtime-determined UI structures, conditional
+/// rendering, and other advanced patterns. Dynamic view composition can deliver
+/// benefits such as:
+///
+/// - **Conditional Rendering**: Show different views based on model state
+/// - **Runtime Lists**: Generate views from collections in the model
+/// - **Complex Layouts**: Build sophisticated UIs with nested containers
+/// - **Type Flexibility**: Mix different view types in the same container
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::prelude::*;
+///
+/// #[derive(Clone, Debug)]
+/// struct AppModel {
+///     count: i32,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum AppMessage {
+///     Increment,
+///     Decrement,
+/// }
+///
+/// impl Message for AppMessage {}
+///
+/// impl Model for AppModel {
+///     type Message = AppMessage;
+///     type View = VStack<(Text, Text)>;
+///
+///     fn update(self, message: Self::Message) -> Self {
+///         match message {
+///             AppMessage::Increment => Self { count: self.count + 1 },
+///             AppMessage::Decrement => Self { count: self.count - 1 },
+///         }
+///     }
+///
+///     fn view(&self) -> Self::View {
+///         VStack::new((
+///             Text::new("Counter"),
+///             Text::new(format!("Count: {}", self.count)),
+///         ))
+///         .spacing(8.0)
+///     }
+/// }
+/// ```
+pub trait Model: Clone + Debug + Send + Sync + 'static {
+    /// The message type that can update this model
+    type Message: Message;
+
+    /// The view type that represents this model's visual state
+    type View: View;
+
+    /// Update the model with a message, consuming the old model and returning a new one.
+    ///
+    /// This follows functional programming principles - the old model is consumed
+    /// and a new model is returned, ensuring immutable updates.
+    fn update(self, message: Self::Message) -> Self;
+
+    /// Generate a view representation of this model's current state.
+    ///
+    /// This method creates a pure data structure that describes how the model
+    /// should be rendered, without containing any rendering logic itself.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// #[derive(Clone, Debug)]
+    /// struct TodoModel {
+    ///     items: Vec<String>,
+    ///     show_completed: bool,
+    /// }
+    ///
+    /// #[derive(Debug, Clone)]
+    /// enum TodoMessage {
+    ///     ToggleCompleted,
+    /// }
+    ///
+    /// impl Message for TodoMessage {}
+    ///
+    /// impl Model for TodoModel {
+    ///     type Message = TodoMessage;
+    ///     type View = VStack<(Text, Vec<Box<dyn View>>)>;
+    ///
+    ///     fn update(self, message: Self::Message) -> Self {
+    ///         match message {
+    ///             TodoMessage::ToggleCompleted => Self {
+    ///                 show_completed: !self.show_completed,
+    ///                 ..self
+    ///             },
+    ///         }
+    ///     }
+    ///
+    ///     fn view(&self) -> Self::View {
+    ///         let mut children: Vec<Box<dyn View>> = vec![
+    ///             Box::new(Text::new("Todo List").font_size(24.0)),
+    ///         ];
+    ///
+    ///         // Add items dynamically
+    ///         for item in &self.items {
+    ///             children.push(Box::new(Text::new(item)));
+    ///         }
+    ///
+    ///         // Conditionally add toggle button
+    ///         if !self.items.is_empty() {
+    ///             children.push(Box::new(Button::new("Toggle Completed").view()));
+    ///         }
+    ///
+    ///         VStack::new((
+    ///             Text::new("Todo List").font_size(24.0),
+    ///             children.into_iter().map(|child| child.into()).collect::<Vec<_>>(),
+    ///         ))
+    ///         .spacing(8.0)
+    ///         .alignment(Alignment::Leading)
+    ///     }
+    /// }
+    /// ```
+    fn view(&self) -> Self::View;
+
+    /// Describe the external event sources this model wants messages from.
+    ///
+    /// The runtime polls the returned subscriptions and feeds the messages
+    /// they produce into [`update`](Model::update). The list is asked for
+    /// again after every update, so a model can start and stop listening as
+    /// its state changes. Models have no subscriptions by default.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use ironwood::{prelude::*, subscription::Subscription};
+    ///
+    /// #[derive(Debug, Clone)]
+    /// struct Stopwatch {
+    ///     running: bool,
+    ///     tenths: u32,
+    /// }
+    ///
+    /// #[derive(Debug, Clone)]
+    /// enum StopwatchMessage {
+    ///     Tick,
+    /// }
+    ///
+    /// impl Message for StopwatchMessage {}
+    ///
+    /// impl Model for Stopwatch {
+    ///     type Message = StopwatchMessage;
+    ///     type View = Text;
+    ///
+    ///     fn update(self, _message: Self::Message) -> Self {
+    ///         Self { tenths: self.tenths + 1, ..self }
+    ///     }
+    ///
+    ///     fn view(&self) -> Self::View {
+    ///         Text::new(format!("{:.1}", self.tenths as f32 / 10.0))
+    ///     }
+    ///
+    ///     fn subscriptions(&self) -> Vec<Subscription<Self::Message>> {
+    ///         if self.running {
+    ///             vec![Subscription::interval(Duration::from_millis(100), || StopwatchMessage::Tick)]
+    ///         } else {
+    ///             Vec::new()
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let stopwatch = Stopwatch { running: false, tenths: 0 };
+    /// assert!(stopwatch.subscriptions().is_empty());
+    /// ```
+    fn subscriptions(&self) -> Vec<Subscription<Self::Message>> {
+        Vec::new()
+    }
+
+    /// Apply a sequence of messages in order, returning only the final model.
+    ///
+    /// The result is the same as calling [`update`](Model::update) once per
+    /// message, but no intermediate model is handed back, so nothing can
+    /// observe or render the states in between. This makes it a natural way
+    /// to apply several changes in one tick or to replay a recorded message
+    /// log.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// let log = vec![
+    ///     ButtonMessage::Interaction(InteractionMessage::HoverChanged(true)),
+    ///     ButtonMessage::Interaction(InteractionMessage::FocusChanged(true)),
+    ///     ButtonMessage::Interaction(InteractionMessage::HoverChanged(false)),
+    /// ];
+    ///
+    /// let button = Button::new("Replay").update_batch(log);
+    /// assert!(button.is_focused());
+    /// assert!(!button.is_hovered());
+    /// ```
+    fn update_batch(self, messages: impl IntoIterator<Item = Self::Message>) -> Self
+    where
+        Self: Sized,
+    {
+        messages
+            .into_iter()
+            .fold(self, |model, message| model.update(message))
+    }
+
+    /// Embed this model in a parent whose messages wrap this model's messages.
+    ///
+    /// The lifted model is updated with its own messages as before, but its
+    /// view reports events as parent messages, so the parent doesn't have to
+    /// wrap them by hand.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// #[derive(Debug, Clone, PartialEq)]
+    /// enum CounterMessage {
+    ///     IncrementButton(ButtonMessage),
+    /// }
+    ///
+    /// impl Message for CounterMessage {}
+    ///
+    /// let increment = Button::new("+").lift(CounterMessage::IncrementButton);
+    /// assert_eq!(
+    ///     increment.view().route(ButtonMessage::Clicked),
+    ///     CounterMessage::IncrementButton(ButtonMessage::Clicked)
+    /// );
+    /// ```
+    fn lift<P: Message>(self, wrap: fn(Self::Message) -> P) -> MappedModel<Self, P>
+    where
+        Self: Sized,
+    {
+        MappedModel::new(self, wrap)
+    }
+}
+
+/// Components whose interaction state can be carried across rebuilds.
+///
+/// Parent models sometimes rebuild an embedded component from scratch in
+/// `update`, for example to change a button's label. A freshly built
+/// component starts with default interaction state, so hover, focus, and
+/// press feedback would be lost. Calling `merge_interaction_from` on the
+/// rebuilt component with the previous instance carries that state forward.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{model::PreserveInteraction, prelude::*};
+///
+/// let old = Button::new("Save").hover();
+/// let rebuilt = Button::new("Saved").merge_interaction_from(&old);
+/// assert!(rebuilt.is_hovered());
+/// ```
+pub trait PreserveInteraction: Sized {
+    /// Capture this component's full interaction state.
+    fn interaction_snapshot(&self) -> InteractionState;
+
+    /// Replace this component's interaction state with a captured snapshot.
+    fn restore_interaction(self, state: InteractionState) -> Self;
+
+    /// Carry transient interaction state over from a previous instance.
+    ///
+    /// Pressed, focused, and hovered flags are taken from `old`, while the
+    /// enabled flag is kept from `self` so a rebuild can still enable or
+    /// disable the component.
+    fn merge_interaction_from(self, old: &Self) -> Self {
+        let transient =
+            InteractionState::PRESSED | InteractionState::FOCUSED | InteractionState::HOVERED;
+        let merged =
+            (self.interaction_snapshot() - transient) | (old.interaction_snapshot() & transient);
+        self.restore_interaction(merged)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elements::{MappedView, Text, VStack};
+
+    /// Minimal counter component shared by the combinator tests
+    #[derive(Debug, Clone, PartialEq)]
+    pub(super) struct Counter {
+        pub(super) count: i32,
+    }
+
+    #[derive(Debug, Clone)]
+    pub(super) enum CounterMessage {
+        Increment,
+        Decrement,
+    }
+
+    impl Message for CounterMessage {}
+
+    impl Model for Counter {
+        type Message = CounterMessage;
+        type View = Text;
+
+        fn update(self, message: Self::Message) -> Self {
+            match message {
+                CounterMessage::Increment => Self {
+                    count: self.count + 1,
+                },
+                CounterMessage::Decrement => Self {
+                    count: self.count - 1,
+                },
+            }
+        }
+
+        fn view(&self) -> Self::View {
+            Text::new(format!("Count: {}", self.count))
+        }
+    }
+
+    #[test]
+    fn model_trait_pattern() {
+        // Verify trait can be implemented with realistic patterns
+        #[derive(Debug, Clone)]
+        struct TestModel {
+            value: i32,
+        }
+
+        #[derive(Debug, Clone)]
+        enum TestMessage {
+            SetValue(i32),
+            Reset,
+        }
+
+        impl Message for TestMessage {}
+
+        impl Model for TestModel {
+            type Message = TestMessage;
+            type View = Text;
+
+            fn update(self, message: Self::Message) -> Self {
+                match message {
+                    TestMessage::SetValue(value) => Self { value },
+                    TestMessage::Reset => Self { value: 0 },
+                }
+            }
+
+            fn view(&self) -> Self::View {
+                Text::new(format!("Value: {}", self.value))
+            }
+        }
+
+        let model = TestModel { value: 5 };
+        let updated = model.update(TestMessage::SetValue(10));
+        assert_eq!(updated.value, 10);
+
+        let reset = updated.update(TestMessage::Reset);
+        assert_eq!(reset.value, 0);
+
+        let _debug_str = format!("{:?}", reset);
+    }
+
+    #[test]
+    fn model_immutability() {
+        // Demonstrate that immutable updates preserve previous states for debugging/undo
+        #[derive(Debug, Clone, PartialEq)]
+        struct TestModel {
+            data: String,
+        }
+
+        #[derive(Debug, Clone)]
+        enum TestMessage {
+            UpdateData(String),
+        }
+
+        impl Message for TestMessage {}
+
+        impl Model for TestModel {
+            type Message = TestMessage;
+            type View = Text;
+
+            fn update(self, message: Self::Message) -> Self {
+                match message {
+                    TestMessage::UpdateData(data) => Self { data },
+                }
+            }
+
+            fn view(&self) -> Self::View {
+                Text::new(format!("Data: {}", self.data))
+            }
+        }
+
+        let original = TestModel {
+            data: "original".to_string(),
+        };
+        let original_data = original.data.clone();
+
+        let updated = original
+            .clone()
+            .update(TestMessage::UpdateData("updated".to_string()));
+
+        // Immutability enables time-travel debugging and undo/redo when history is maintained
+        assert_eq!(original.data, original_data);
+        assert_eq!(updated.data, "updated");
+        assert_ne!(original, updated);
+    }
+
+    #[test]
+    fn increment_then_decrement_is_view_neutral() {
+        use crate::{
+            backends::mock::{MockBackend, assert_same_view},
+            extraction::RenderContext,
+        };
+
+        let original = Counter { count: 3 };
+        let round_trip = original
+            .clone()
+            .update(CounterMessage::Increment)
+            .update(CounterMessage::Decrement);
+
+        assert_same_view::<_, MockBackend>(&original, &round_trip, &RenderContext::new());
+    }
+
+    #[test]
+    #[should_panic(expected = "models produced different views")]
+    fn assert_same_view_detects_differences() {
+        use crate::{
+            backends::mock::{MockBackend, assert_same_view},
+            extraction::RenderContext,
+        };
+
+        let original = Counter { count: 3 };
+        let incremented = original.clone().update(CounterMessage::Increment);
+        assert_same_view::<_, MockBackend>(&original, &incremented, &RenderContext::new());
+    }
+
+    #[test]
+    fn rebuilt_children_keep_interaction_state() {
+        use crate::{
+            interaction::{Enableable, Focusable, Hoverable, InteractionMessage},
+            widgets::{Button, ButtonMessage},
+        };
+
+        #[derive(Debug, Clone)]
+        struct SaveForm {
+            saved: bool,
+            save: Button,
+        }
+
+        #[derive(Debug, Clone)]
+        enum SaveFormMessage {
+            Save(ButtonMessage),
+        }
+
+        impl Message for SaveFormMessage {}
+
+        impl Model for SaveForm {
+            type Message = SaveFormMessage;
+            type View = crate::widgets::ButtonView;
+
+            fn update(self, message: Self::Message) -> Self {
+                match message {
+                    SaveFormMessage::Save(ButtonMessage::Clicked) => Self {
+                        saved: true,
+                        // Rebuilding the button would reset hover without the merge
+                        save: Button::new("Saved").merge_interaction_from(&self.save),
+                    },
+                    SaveFormMessage::Save(message) => Self {
+                        save: self.save.update(message),
+                        ..self
+                    },
+                }
+            }
+
+            fn view(&self) -> Self::View {
+                self.save.view()
+            }
+        }
+
+        let form = SaveForm {
+            saved: false,
+            save: Button::new("Save"),
+        }
+        .update(SaveFormMessage::Save(ButtonMessage::Interaction(
+            InteractionMessage::HoverChanged(true),
+        )))
+        .update(SaveFormMessage::Save(ButtonMessage::Clicked));
+
+        assert!(form.saved);
+        assert_eq!(form.save.text.content, "Saved");
+        assert!(form.save.is_hovered());
+
+        // The rebuilt component's enabled flag wins over the old one
+        let disabled = Button::new("Locked")
+            .disable()
+            .merge_interaction_from(&Button::new("Open").focus());
+        assert!(!disabled.is_enabled());
+        assert!(disabled.is_focused());
+    }
+
+    #[test]
+    fn update_batch_replays_recorded_log() {
+        let log = vec![
+            CounterMessage::Increment,
+            CounterMessage::Increment,
+            CounterMessage::Decrement,
+            CounterMessage::Increment,
+        ];
+
+        let sequential = log
+            .iter()
+            .cloned()
+            .fold(Counter { count: 0 }, |model, message| model.update(message));
+        let batched = Counter { count: 0 }.update_batch(log);
+        assert_eq!(batched, sequential);
+        assert_eq!(batched.count, 2);
+
+        // An empty batch leaves the model unchanged
+        assert_eq!(batched.clone().update_batch(Vec::new()), batched);
+    }
+
+    #[test]
+    fn lifted_models_route_events_as_parent_messages() {
+        #[derive(Debug, Clone)]
+        enum ParentMessage {
+            Left(CounterMessage),
+            Right(CounterMessage),
+        }
+
+        impl Message for ParentMessage {}
+
+        #[derive(Debug, Clone)]
+        struct Parent {
+            left: MappedModel<Counter, ParentMessage>,
+            right: MappedModel<Counter, ParentMessage>,
+        }
+
+        impl Model for Parent {
+            type Message = ParentMessage;
+            type View = VStack<(
+                MappedView<Text, CounterMessage, ParentMessage>,
+                MappedView<Text, CounterMessage, ParentMessage>,
+            )>;
+
+            fn update(self, message: Self::Message) -> Self {
+                match message {
+                    ParentMessage::Left(message) => Self {
+                        left: self.left.update(message),
+                        ..self
+                    },
+                    ParentMessage::Right(message) => Self {
+                        right: self.right.update(message),
+                        ..self
+                    },
+                }
+            }
+
+            fn view(&self) -> Self::View {
+                VStack::new((self.left.view(), self.right.view()))
+            }
+        }
+
+        let parent = Parent {
+            left: Counter { count: 0 }.lift(ParentMessage::Left),
+            right: Counter { count: 0 }.lift(ParentMessage::Right),
+        };
+
+        // An event from the right sub-view comes back as the parent's variant
+        let event = parent.view().content.1.route(CounterMessage::Increment);
+        assert!(matches!(
+            event,
+            ParentMessage::Right(CounterMessage::Increment)
+        ));
+
+        let parent = parent.update(event);
+        assert_eq!(parent.left.model.count, 0);
+        assert_eq!(parent.right.model.count, 1);
+        assert_eq!(parent.right.into_inner(), Counter { count: 1 });
+    }
+}
+
+// End of File
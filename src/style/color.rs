@@ -0,0 +1,836 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Colors and their conversions
+
+use std::fmt::Debug;
+
+use super::css_colors::CSS_NAMED_COLORS;
+
+/// Errors that can occur while parsing a hex color string.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ColorParseError {
+    /// The string doesn't have 3, 6, or 8 hex digits.
+    #[error("Hex color '{input}' must have 3, 6, or 8 digits, found {digits}")]
+    InvalidLength {
+        /// The string that failed to parse
+        input: String,
+        /// Number of characters after the optional `#`
+        digits: usize,
+    },
+
+    /// The string contains a character that isn't a hex digit.
+    #[error("Hex color '{input}' contains invalid digit '{digit}'")]
+    InvalidDigit {
+        /// The string that failed to parse
+        input: String,
+        /// The first character that isn't a hex digit
+        digit: char,
+    },
+}
+
+/// Basic color representation for styling views.
+///
+/// Colors are represented as RGBA values with floating-point components
+/// in the range [0.0, 1.0]. This provides sufficient precision for UI
+/// rendering while being simple to work with.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::prelude::*;
+///
+/// let red = Color::RED;
+/// let custom = Color::rgba(0.5, 0.7, 0.9, 1.0);
+/// let opaque_blue = Color::rgb(0.0, 0.0, 1.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Color {
+    /// Red component (0.0 to 1.0)
+    pub r: f32,
+    /// Green component (0.0 to 1.0)
+    pub g: f32,
+    /// Blue component (0.0 to 1.0)
+    pub b: f32,
+    /// Alpha component (0.0 to 1.0)
+    pub a: f32,
+}
+
+impl Color {
+    /// Create a new color with the specified RGBA components.
+    ///
+    /// All components should be in the range [0.0, 1.0].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// let color = Color::rgba(0.5, 0.7, 0.9, 0.8);
+    /// assert_eq!(color.r, 0.5);
+    /// assert_eq!(color.a, 0.8);
+    /// ```
+    pub const fn rgba(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self { r, g, b, a }
+    }
+
+    /// Create a new opaque color with the specified RGB components.
+    ///
+    /// Alpha is set to 1.0 (fully opaque).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// let blue = Color::rgb(0.0, 0.0, 1.0);
+    /// assert_eq!(blue.b, 1.0);
+    /// assert_eq!(blue.a, 1.0);
+    /// ```
+    pub const fn rgb(r: f32, g: f32, b: f32) -> Self {
+        Self::rgba(r, g, b, 1.0)
+    }
+
+    /// Create an opaque color approximating a blackbody at the given temperature.
+    ///
+    /// Low temperatures produce warm orange tints and high temperatures cool
+    /// blue ones, with roughly neutral white around 6500K. The temperature is
+    /// clamped to the 1000K–40000K range the approximation is fitted for.
+    ///
+    /// # Arguments
+    ///
+    /// * `kelvin` - Color temperature in kelvin
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// let candle = Color::from_temperature(1900.0);
+    /// let daylight = Color::from_temperature(6500.0);
+    /// assert!(candle.r / candle.b > daylight.r / daylight.b);
+    /// ```
+    pub fn from_temperature(kelvin: f32) -> Self {
+        // Curve fit of blackbody colors by Tanner Helland, operating on the
+        // temperature in hundreds of kelvin and producing 0-255 components
+        let t = kelvin.clamp(1000.0, 40000.0) / 100.0;
+
+        let r = if t <= 66.0 {
+            255.0
+        } else {
+            329.698_73 * (t - 60.0).powf(-0.133_204_76)
+        };
+        let g = if t <= 66.0 {
+            99.470_8 * t.ln() - 161.119_57
+        } else {
+            288.122_16 * (t - 60.0).powf(-0.075_514_846)
+        };
+        let b = if t >= 66.0 {
+            255.0
+        } else if t <= 19.0 {
+            0.0
+        } else {
+            138.517_73 * (t - 10.0).ln() - 305.044_8
+        };
+
+        let channel = |value: f32| (value / 255.0).clamp(0.0, 1.0);
+        Self::rgb(channel(r), channel(g), channel(b))
+    }
+
+    /// Generate `count` colors evenly spaced around the hue wheel, starting at `self`.
+    ///
+    /// Saturation, lightness, and alpha are kept from the base color, which
+    /// makes the palette suitable for charts and other categorical coloring.
+    /// Gray base colors have no hue to rotate, so their palettes step through
+    /// lightness instead. A `count` of 1 returns just the base color.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// let series = Color::BLUE.palette(3);
+    /// assert_eq!(series.len(), 3);
+    /// assert_eq!(series[0], Color::BLUE);
+    /// ```
+    pub fn palette(&self, count: usize) -> Vec<Color> {
+        let (hue, saturation, lightness) = self.to_hsl();
+        (0..count)
+            .map(|index| {
+                if index == 0 {
+                    return *self;
+                }
+                let offset = index as f32 / count as f32;
+                if saturation > 0.0 {
+                    Self::from_hsl(
+                        (hue + offset * 360.0) % 360.0,
+                        saturation,
+                        lightness,
+                        self.a,
+                    )
+                } else {
+                    Self::from_hsl(0.0, 0.0, (lightness + offset).fract(), self.a)
+                }
+            })
+            .collect()
+    }
+
+    /// Get the relative luminance of this color as defined by WCAG 2.
+    ///
+    /// Components are treated as sRGB and linearized before weighting. Alpha
+    /// is ignored. The result ranges from 0.0 for black to 1.0 for white.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// assert_eq!(Color::BLACK.relative_luminance(), 0.0);
+    /// assert_eq!(Color::WHITE.relative_luminance(), 1.0);
+    /// ```
+    pub fn relative_luminance(&self) -> f32 {
+        let linear = |channel: f32| {
+            if channel <= 0.04045 {
+                channel / 12.92
+            } else {
+                ((channel + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        0.2126 * linear(self.r) + 0.7152 * linear(self.g) + 0.0722 * linear(self.b)
+    }
+
+    /// Get the WCAG 2 contrast ratio between this color and another.
+    ///
+    /// The ratio is symmetric and ranges from 1.0 (no contrast) to 21.0
+    /// (black on white).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// let ratio = Color::BLACK.contrast_ratio(&Color::WHITE);
+    /// assert!((ratio - 21.0).abs() < 0.001);
+    /// ```
+    pub fn contrast_ratio(&self, other: &Color) -> f32 {
+        let a = self.relative_luminance();
+        let b = other.relative_luminance();
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    /// Pick black or white, whichever is more legible on top of this color.
+    ///
+    /// This is useful for choosing label colors for backgrounds that aren't
+    /// known in advance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// let background = Color::rgb(0.1, 0.2, 0.5);
+    /// let button = Button::new("Continue")
+    ///     .background_color(background)
+    ///     .with_text(|text| text.color(background.readable_text_on()));
+    /// assert_eq!(button.text.style.color, Color::WHITE);
+    /// ```
+    pub fn readable_text_on(&self) -> Color {
+        if self.contrast_ratio(&Color::WHITE) > self.contrast_ratio(&Color::BLACK) {
+            Color::WHITE
+        } else {
+            Color::BLACK
+        }
+    }
+
+    /// Linearly interpolate between this color and another.
+    ///
+    /// `t` is clamped to `[0.0, 1.0]`; 0.0 gives this color and 1.0 gives
+    /// `other`, exactly. Each RGBA component is interpolated independently in
+    /// straight (non-premultiplied) space, and the results are clamped to
+    /// valid component ranges.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// let gray = Color::BLACK.lerp(&Color::WHITE, 0.5);
+    /// assert_eq!(gray, Color::rgb(0.5, 0.5, 0.5));
+    /// ```
+    pub fn lerp(&self, other: &Color, t: f32) -> Color {
+        let t = t.clamp(0.0, 1.0);
+        // Weighting both ends, rather than a + (b - a) * t, keeps the endpoints exact
+        let channel = |from: f32, to: f32| (from * (1.0 - t) + to * t).clamp(0.0, 1.0);
+        Color::rgba(
+            channel(self.r, other.r),
+            channel(self.g, other.g),
+            channel(self.b, other.b),
+            channel(self.a, other.a),
+        )
+    }
+
+    /// Mix this color with another, keeping `weight` of this color.
+    ///
+    /// A weight of 1.0 gives this color and 0.0 gives `other`; it is
+    /// equivalent to `self.lerp(other, 1.0 - weight)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// let tint = Color::BLUE.mix(&Color::WHITE, 0.25);
+    /// assert_eq!(tint, Color::rgb(0.75, 0.75, 1.0));
+    /// ```
+    pub fn mix(&self, other: &Color, weight: f32) -> Color {
+        self.lerp(other, 1.0 - weight.clamp(0.0, 1.0))
+    }
+
+    /// Move this color toward the gray of the same brightness.
+    ///
+    /// An amount of 0.0 leaves the color unchanged and 1.0 removes all of its
+    /// saturation. Brightness is weighted by how light each channel appears,
+    /// so greens give lighter grays than blues. Alpha is kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// let gray = Color::rgb(0.2, 0.4, 0.9).desaturate(1.0);
+    /// assert_eq!(gray.r, gray.g);
+    /// assert_eq!(gray.g, gray.b);
+    /// assert_eq!(Color::RED.desaturate(0.0), Color::RED);
+    /// ```
+    pub fn desaturate(&self, amount: f32) -> Color {
+        let luma = (0.2126 * self.r + 0.7152 * self.g + 0.0722 * self.b).clamp(0.0, 1.0);
+        self.lerp(&Color::rgba(luma, luma, luma, self.a), amount)
+    }
+
+    /// Parse a color from a hex string such as `#3380E6`.
+    ///
+    /// Accepts `RGB`, `RRGGBB`, and `RRGGBBAA` digits in either case, with or
+    /// without a leading `#`. Shorthand digits are doubled, so `#F80` is
+    /// `#FF8800`, and colors without an alpha component are opaque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// assert_eq!(Color::from_hex("#F00").unwrap(), Color::RED);
+    /// assert_eq!(Color::from_hex("0000ff").unwrap(), Color::BLUE);
+    /// assert!(Color::from_hex("#12345").is_err());
+    /// ```
+    pub fn from_hex(input: &str) -> Result<Self, ColorParseError> {
+        let digits = input.strip_prefix('#').unwrap_or(input);
+        if let Some(digit) = digits.chars().find(|c| !c.is_ascii_hexdigit()) {
+            return Err(ColorParseError::InvalidDigit {
+                input: input.to_string(),
+                digit,
+            });
+        }
+
+        // All digits are ASCII from here on, so each is a single byte
+        let values: Vec<u8> = digits
+            .bytes()
+            .map(|digit| (digit as char).to_digit(16).unwrap_or(0) as u8)
+            .collect();
+        let channels: Vec<u8> = match values.len() {
+            3 => values.iter().map(|value| value * 17).collect(),
+            6 | 8 => values
+                .chunks(2)
+                .map(|pair| pair[0] * 16 + pair[1])
+                .collect(),
+            digits => {
+                return Err(ColorParseError::InvalidLength {
+                    input: input.to_string(),
+                    digits,
+                });
+            }
+        };
+
+        let channel = |index: usize| channels.get(index).map_or(1.0, |&c| c as f32 / 255.0);
+        Ok(Self::rgba(channel(0), channel(1), channel(2), channel(3)))
+    }
+
+    /// Format this color as an uppercase `#RRGGBBAA` hex string.
+    ///
+    /// Components are clamped to `[0.0, 1.0]` and rounded to the nearest of
+    /// 256 levels, so parsing the result and formatting it again gives the
+    /// same string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// assert_eq!(Color::rgb(0.2, 0.5, 0.9).to_hex(), "#3380E6FF");
+    /// assert_eq!(Color::from_hex("#3380e6").unwrap().to_hex(), "#3380E6FF");
+    /// ```
+    pub fn to_hex(&self) -> String {
+        let channel = |value: f32| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+        format!(
+            "#{:02X}{:02X}{:02X}{:02X}",
+            channel(self.r),
+            channel(self.g),
+            channel(self.b),
+            channel(self.a)
+        )
+    }
+
+    /// Create an opaque color from hue, saturation, and lightness.
+    ///
+    /// Hue is in degrees and wraps, so 360 and -360 are the same as 0.
+    /// Saturation and lightness are clamped to `[0.0, 1.0]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// assert_eq!(Color::hsl(0.0, 1.0, 0.5), Color::RED);
+    /// assert_eq!(Color::hsl(480.0, 1.0, 0.5), Color::GREEN);
+    /// ```
+    pub fn hsl(hue: f32, saturation: f32, lightness: f32) -> Self {
+        Self::from_hsl(
+            hue,
+            saturation.clamp(0.0, 1.0),
+            lightness.clamp(0.0, 1.0),
+            1.0,
+        )
+    }
+
+    /// Create an opaque color from hue, saturation, and value (brightness).
+    ///
+    /// Hue is in degrees and wraps. Saturation and value are clamped to
+    /// `[0.0, 1.0]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// assert_eq!(Color::hsv(240.0, 1.0, 1.0), Color::BLUE);
+    /// assert_eq!(Color::hsv(0.0, 0.0, 1.0), Color::WHITE);
+    /// ```
+    pub fn hsv(hue: f32, saturation: f32, value: f32) -> Self {
+        let saturation = saturation.clamp(0.0, 1.0);
+        let value = value.clamp(0.0, 1.0);
+        let lightness = value * (1.0 - saturation / 2.0);
+        let hsl_saturation = if lightness == 0.0 || lightness == 1.0 {
+            0.0
+        } else {
+            (value - lightness) / lightness.min(1.0 - lightness)
+        };
+        Self::from_hsl(hue, hsl_saturation, lightness, 1.0)
+    }
+
+    /// Convert to hue (degrees in `[0, 360)`), saturation, and lightness.
+    ///
+    /// Alpha is ignored. Grays have no hue and report a hue and saturation
+    /// of 0.0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// assert_eq!(Color::RED.to_hsl(), (0.0, 1.0, 0.5));
+    /// assert_eq!(Color::WHITE.to_hsl(), (0.0, 0.0, 1.0));
+    /// ```
+    pub fn to_hsl(&self) -> (f32, f32, f32) {
+        let max = self.r.max(self.g).max(self.b);
+        let min = self.r.min(self.g).min(self.b);
+        let lightness = (max + min) / 2.0;
+        let delta = max - min;
+        if delta == 0.0 {
+            return (0.0, 0.0, lightness);
+        }
+
+        let saturation = delta / (1.0 - (2.0 * lightness - 1.0).abs());
+        let hue = if max == self.r {
+            60.0 * ((self.g - self.b) / delta).rem_euclid(6.0)
+        } else if max == self.g {
+            60.0 * ((self.b - self.r) / delta + 2.0)
+        } else {
+            60.0 * ((self.r - self.g) / delta + 4.0)
+        };
+        (hue, saturation, lightness)
+    }
+
+    /// Create a color from hue (degrees), saturation, lightness, and alpha.
+    fn from_hsl(hue: f32, saturation: f32, lightness: f32, alpha: f32) -> Self {
+        let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+        let sector = hue.rem_euclid(360.0) / 60.0;
+        let x = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+        let (r, g, b) = match sector as u32 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+        let m = lightness - chroma / 2.0;
+        Self::rgba(r + m, g + m, b + m, alpha)
+    }
+
+    /// Pure black color
+    pub const BLACK: Color = Color::rgb(0.0, 0.0, 0.0);
+
+    /// Pure white color
+    pub const WHITE: Color = Color::rgb(1.0, 1.0, 1.0);
+
+    /// Pure red color
+    pub const RED: Color = Color::rgb(1.0, 0.0, 0.0);
+
+    /// Pure green color
+    pub const GREEN: Color = Color::rgb(0.0, 1.0, 0.0);
+
+    /// Pure blue color
+    pub const BLUE: Color = Color::rgb(0.0, 0.0, 1.0);
+
+    /// Pure yellow color (CSS `yellow`)
+    pub const YELLOW: Color = Color::rgb(1.0, 1.0, 0.0);
+
+    /// Pure cyan color (CSS `cyan`)
+    pub const CYAN: Color = Color::rgb(0.0, 1.0, 1.0);
+
+    /// Pure magenta color (CSS `magenta`)
+    pub const MAGENTA: Color = Color::rgb(1.0, 0.0, 1.0);
+
+    /// Orange color (CSS `orange`, `#FFA500`)
+    pub const ORANGE: Color = Color::rgb(1.0, 165.0 / 255.0, 0.0);
+
+    /// Purple color (CSS `purple`, `#800080`)
+    pub const PURPLE: Color = Color::rgb(128.0 / 255.0, 0.0, 128.0 / 255.0);
+
+    /// Medium gray color (CSS `gray`, `#808080`)
+    pub const GRAY: Color = Color::rgb(128.0 / 255.0, 128.0 / 255.0, 128.0 / 255.0);
+
+    /// Fully transparent color
+    pub const TRANSPARENT: Color = Color::rgba(0.0, 0.0, 0.0, 0.0);
+
+    /// Look up one of the standard CSS named colors, ignoring case.
+    ///
+    /// All 148 CSS Color Level 4 names are recognized, including both
+    /// spellings of gray, plus `transparent`. The names follow CSS exactly,
+    /// so `"green"` is the darker `#008000`; [`Color::GREEN`] is pure green,
+    /// which CSS calls `lime`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// assert_eq!(Color::named("Tomato").unwrap().to_hex(), "#FF6347FF");
+    /// assert_eq!(Color::named("lime"), Some(Color::GREEN));
+    /// assert_eq!(Color::named("not-a-color"), None);
+    /// ```
+    pub fn named(name: &str) -> Option<Color> {
+        let name = name.to_ascii_lowercase();
+        if name == "transparent" {
+            return Some(Self::TRANSPARENT);
+        }
+        let index = CSS_NAMED_COLORS
+            .binary_search_by_key(&name.as_str(), |&(css_name, _)| css_name)
+            .ok()?;
+        let rgb = CSS_NAMED_COLORS[index].1;
+        let channel = |shift: u32| ((rgb >> shift) & 0xFF) as f32 / 255.0;
+        Some(Self::rgb(channel(16), channel(8), channel(0)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Assert that two colors match within a small tolerance on every component.
+    fn assert_close(actual: Color, expected: Color) {
+        for (a, e) in [
+            (actual.r, expected.r),
+            (actual.g, expected.g),
+            (actual.b, expected.b),
+            (actual.a, expected.a),
+        ] {
+            assert!((a - e).abs() < 1e-5, "{:?} != {:?}", actual, expected);
+        }
+    }
+
+    #[test]
+    fn hsl_and_hsv_known_values() {
+        assert_eq!(Color::hsl(0.0, 1.0, 0.5), Color::RED);
+        assert_eq!(Color::hsl(120.0, 1.0, 0.5), Color::GREEN);
+        assert_eq!(Color::hsl(240.0, 1.0, 0.5), Color::BLUE);
+        assert_eq!(Color::hsl(0.0, 0.0, 0.0), Color::BLACK);
+        assert_eq!(Color::hsl(0.0, 0.0, 1.0), Color::WHITE);
+        assert_close(Color::hsl(60.0, 1.0, 0.25), Color::rgb(0.5, 0.5, 0.0));
+
+        assert_eq!(Color::hsv(0.0, 1.0, 1.0), Color::RED);
+        assert_eq!(Color::hsv(0.0, 0.0, 0.0), Color::BLACK);
+        assert_close(Color::hsv(180.0, 0.5, 0.8), Color::rgb(0.4, 0.8, 0.8));
+
+        // Hue wraps and the other components are clamped
+        assert_close(Color::hsl(360.0 + 240.0, 1.0, 0.5), Color::BLUE);
+        assert_close(Color::hsl(-120.0, 1.0, 0.5), Color::BLUE);
+        assert_eq!(Color::hsl(0.0, 5.0, 0.5), Color::RED);
+        assert_eq!(Color::hsv(0.0, 1.0, 7.0), Color::RED);
+    }
+
+    #[test]
+    fn hsl_round_trips() {
+        assert_eq!(Color::RED.to_hsl(), (0.0, 1.0, 0.5));
+        assert_eq!(Color::rgb(0.5, 0.5, 0.5).to_hsl(), (0.0, 0.0, 0.5));
+
+        for color in [
+            Color::rgb(0.2, 0.5, 0.9),
+            Color::rgb(0.9, 0.1, 0.4),
+            Color::rgb(0.3, 0.3, 0.1),
+            Color::rgb(0.75, 0.75, 0.75),
+        ] {
+            let (hue, saturation, lightness) = color.to_hsl();
+            assert!((0.0..360.0).contains(&hue));
+            assert_close(Color::hsl(hue, saturation, lightness), color);
+        }
+    }
+
+    #[test]
+    fn lerp_hits_endpoints_exactly() {
+        let from = Color::rgba(0.1, 0.7, 0.3, 0.9);
+        let to = Color::rgba(0.9, 0.2, 0.6, 0.4);
+
+        assert_eq!(from.lerp(&to, 0.0), from);
+        assert_eq!(from.lerp(&to, 1.0), to);
+        assert_eq!(from.lerp(&to, -3.0), from);
+        assert_eq!(from.lerp(&to, 3.0), to);
+
+        let mid = from.lerp(&to, 0.5);
+        for (actual, expected) in [(mid.r, 0.5), (mid.g, 0.45), (mid.b, 0.45), (mid.a, 0.65)] {
+            assert!(
+                (actual - expected).abs() < 1e-6,
+                "{} != {}",
+                actual,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn mix_weights_self() {
+        let from = Color::RED;
+        let to = Color::BLUE;
+        assert_eq!(from.mix(&to, 1.0), from);
+        assert_eq!(from.mix(&to, 0.0), to);
+        assert_eq!(from.mix(&to, 0.75), from.lerp(&to, 0.25));
+
+        // Out-of-range components are clamped in the result
+        let bright = Color::rgba(2.0, 0.5, -1.0, 1.0);
+        let mixed = bright.mix(&Color::WHITE, 1.0);
+        assert_eq!(mixed, Color::rgba(1.0, 0.5, 0.0, 1.0));
+    }
+
+    #[test]
+    fn named_colors_match_css() {
+        for (name, hex) in [
+            ("aliceblue", "#F0F8FFFF"),
+            ("cornflowerblue", "#6495EDFF"),
+            ("green", "#008000FF"),
+            ("orange", "#FFA500FF"),
+            ("rebeccapurple", "#663399FF"),
+            ("SlateGrey", "#708090FF"),
+            ("yellowgreen", "#9ACD32FF"),
+            ("transparent", "#00000000"),
+        ] {
+            assert_eq!(Color::named(name).unwrap().to_hex(), hex, "{}", name);
+        }
+        assert_eq!(Color::named("grey"), Color::named("gray"));
+        assert_eq!(Color::named(""), None);
+        assert_eq!(Color::named("bluish"), None);
+    }
+
+    #[test]
+    fn named_constants_match_css() {
+        for (color, name) in [
+            (Color::BLACK, "black"),
+            (Color::WHITE, "white"),
+            (Color::RED, "red"),
+            (Color::GREEN, "lime"),
+            (Color::BLUE, "blue"),
+            (Color::YELLOW, "yellow"),
+            (Color::CYAN, "cyan"),
+            (Color::MAGENTA, "magenta"),
+            (Color::ORANGE, "orange"),
+            (Color::PURPLE, "purple"),
+            (Color::GRAY, "gray"),
+            (Color::TRANSPARENT, "transparent"),
+        ] {
+            assert_eq!(Color::named(name), Some(color), "{}", name);
+        }
+    }
+
+    #[test]
+    fn hex_forms_parse_to_the_same_color() {
+        let expected = Color::rgb(1.0, 136.0 / 255.0, 0.0);
+        for input in ["#F80", "f80", "#FF8800", "ff8800", "#Ff8800fF"] {
+            assert_eq!(
+                Color::from_hex(input).unwrap(),
+                expected,
+                "parsing {}",
+                input
+            );
+        }
+
+        let translucent = Color::from_hex("#00000080").unwrap();
+        assert_eq!(translucent.a, 128.0 / 255.0);
+    }
+
+    #[test]
+    fn hex_round_trip_is_stable() {
+        for hex in ["#000000FF", "#3380E6FF", "#12345678", "#FFFFFF00"] {
+            assert_eq!(Color::from_hex(hex).unwrap().to_hex(), hex);
+        }
+
+        let color = Color::rgb(0.2, 0.5, 0.9);
+        let once = Color::from_hex(&color.to_hex()).unwrap();
+        assert_eq!(Color::from_hex(&once.to_hex()).unwrap(), once);
+
+        // Out-of-range components are clamped rather than wrapping
+        assert_eq!(Color::rgba(1.5, -0.5, 0.0, 2.0).to_hex(), "#FF0000FF");
+    }
+
+    #[test]
+    fn invalid_hex_is_rejected() {
+        assert_eq!(
+            Color::from_hex("#GG0000"),
+            Err(ColorParseError::InvalidDigit {
+                input: "#GG0000".to_string(),
+                digit: 'G'
+            })
+        );
+        assert_eq!(
+            Color::from_hex("#1234"),
+            Err(ColorParseError::InvalidLength {
+                input: "#1234".to_string(),
+                digits: 4
+            })
+        );
+        assert!(Color::from_hex("").is_err());
+        assert!(Color::from_hex("#").is_err());
+        assert!(Color::from_hex("#FFFFFFFFF").is_err());
+        assert!(Color::from_hex("##FFF").is_err());
+        assert!(Color::from_hex("#FFé").is_err());
+
+        let message = Color::from_hex("12").unwrap_err().to_string();
+        assert!(message.contains("3, 6, or 8 digits"));
+    }
+
+    #[test]
+    fn color_edge_cases() {
+        use crate::{
+            backends::mock::MockBackend,
+            elements::Text,
+            extraction::{RenderContext, ViewExtractor},
+        };
+
+        let ctx = RenderContext::new();
+
+        // Colors outside normal 0.0-1.0 range
+        let over_range = Color::rgba(1.2, -0.1, 1.5, 0.5);
+        let text = Text::new("Test").color(over_range);
+        let extracted = MockBackend::extract(&text, &ctx).unwrap();
+        assert_eq!(extracted.color, over_range);
+
+        // Fully transparent color
+        let transparent = Color::rgba(1.0, 0.0, 0.0, 0.0);
+        let text = Text::new("Test").color(transparent);
+        let extracted = MockBackend::extract(&text, &ctx).unwrap();
+        assert_eq!(extracted.color, transparent);
+
+        // Precise color values
+        let precise = Color::rgba(0.123_456_8, 0.987_654_3, 0.555_555_6, 0.333_333_3);
+        let text = Text::new("Test").color(precise);
+        let extracted = MockBackend::extract(&text, &ctx).unwrap();
+        assert_eq!(extracted.color, precise);
+
+        // Large display font with transparency
+        let display_text = Text::new("Large Display")
+            .font_size(72.0)
+            .color(Color::rgba(0.0, 0.0, 0.0, 0.1));
+        let extracted = MockBackend::extract(&display_text, &ctx).unwrap();
+        assert_eq!(extracted.font_size, 72.0);
+        assert_eq!(extracted.color.a, 0.1);
+    }
+
+    #[test]
+    fn readable_text_color_follows_contrast() {
+        let dark_blue = Color::rgb(0.0, 0.0, 0.4);
+        let light_gray = Color::rgb(0.85, 0.85, 0.85);
+
+        assert_eq!(dark_blue.readable_text_on(), Color::WHITE);
+        assert_eq!(light_gray.readable_text_on(), Color::BLACK);
+        assert_eq!(Color::BLACK.readable_text_on(), Color::WHITE);
+        assert_eq!(Color::WHITE.readable_text_on(), Color::BLACK);
+
+        // Contrast is symmetric
+        assert_eq!(
+            dark_blue.contrast_ratio(&light_gray),
+            light_gray.contrast_ratio(&dark_blue)
+        );
+    }
+
+    #[test]
+    fn palette_spans_hue_wheel() {
+        let palette = Color::BLUE.palette(5);
+        assert_eq!(palette.len(), 5);
+        assert_eq!(palette[0], Color::BLUE);
+
+        // Hues are evenly spaced 72 degrees apart starting from blue (240)
+        let hues: Vec<f32> = palette.iter().map(|color| color.to_hsl().0).collect();
+        for (index, hue) in hues.iter().enumerate() {
+            let expected = (240.0 + 72.0 * index as f32) % 360.0;
+            assert!((hue - expected).abs() < 0.01, "{:?}", hues);
+        }
+        for (index, color) in palette.iter().enumerate() {
+            assert!(!palette[index + 1..].contains(color));
+        }
+
+        assert_eq!(Color::RED.palette(1), vec![Color::RED]);
+        assert!(Color::RED.palette(0).is_empty());
+
+        // Gray has no hue, so its palette varies lightness instead
+        let grays = Color::rgb(0.5, 0.5, 0.5).palette(3);
+        assert_ne!(grays[0], grays[1]);
+        assert_ne!(grays[1], grays[2]);
+    }
+
+    #[test]
+    fn color_temperature_progression() {
+        let warm = Color::from_temperature(2700.0);
+        let neutral = Color::from_temperature(6500.0);
+        let cool = Color::from_temperature(12000.0);
+
+        assert!(warm.r / warm.b > neutral.r / neutral.b);
+        assert!(neutral.r / neutral.b > cool.r / cool.b);
+        assert_eq!(warm.a, 1.0);
+
+        // 6500K is close to white
+        for channel in [neutral.r, neutral.g, neutral.b] {
+            assert!(channel > 0.9, "{:?} is not near white", neutral);
+        }
+
+        // Out-of-range temperatures clamp to the fitted range
+        assert_eq!(
+            Color::from_temperature(0.0),
+            Color::from_temperature(1000.0)
+        );
+        assert_eq!(
+            Color::from_temperature(1.0e6),
+            Color::from_temperature(40000.0)
+        );
+    }
+}
+
+// End of File
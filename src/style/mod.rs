@@ -0,0 +1,28 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Styling system for Ironwood UI Framework
+//!
+//! This module contains types and utilities for styling UI components.
+//! Styling in Ironwood is declarative - you describe how things should look
+//! rather than imperatively applying styles.
+//!
+//! The styling system is designed to be:
+//! - **Simple**: Easy to understand and use
+//! - **Consistent**: Uniform behavior across all components
+//! - **Extensible**: Easy to add new styling properties
+//! - **Platform-agnostic**: Works the same across different backends
+
+mod color;
+mod css_colors;
+mod states;
+mod text;
+mod theme;
+
+pub use color::{Color, ColorParseError};
+pub use states::{DisabledStyle, PressFeedback};
+pub use text::{FontFamily, FontStyle, FontWeight, TextAlignment, TextStyle, TruncationMode};
+pub use theme::{SemanticColor, Theme, ThemeColors};
+
+// End of File
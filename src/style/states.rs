@@ -0,0 +1,139 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Styles for disabled and pressed controls
+
+use std::fmt::Debug;
+
+use crate::interaction::{Enableable, InteractionState};
+
+/// Appearance applied to a widget while it is disabled.
+///
+/// Disabled widgets are dimmed by rendering them at a reduced opacity. The
+/// opacity is kept separate from the widget's colors, so the configured
+/// colors are reported unchanged and backends only need to apply a single
+/// effective opacity when drawing.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{prelude::*, style::DisabledStyle};
+///
+/// let style = DisabledStyle::new().opacity(0.3);
+/// let disabled = InteractionState::default().disable();
+///
+/// assert_eq!(style.effective_opacity(disabled), 0.3);
+/// assert_eq!(style.effective_opacity(InteractionState::default()), 1.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DisabledStyle {
+    /// Opacity applied while disabled (0.0 to 1.0)
+    pub opacity: f32,
+}
+
+impl DisabledStyle {
+    /// Opacity used by the default disabled style.
+    pub const DEFAULT_OPACITY: f32 = 0.5;
+
+    /// Create a new disabled style with the default opacity.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the opacity applied while disabled, clamped to 0.0–1.0.
+    ///
+    /// A value that isn't a number leaves disabled widgets fully opaque.
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.opacity = if opacity.is_nan() {
+            1.0
+        } else {
+            opacity.clamp(0.0, 1.0)
+        };
+        self
+    }
+
+    /// Get the opacity a widget in the given interaction state should be drawn at.
+    ///
+    /// Enabled widgets are always fully opaque; the configured opacity only
+    /// applies while disabled.
+    pub fn effective_opacity(&self, state: InteractionState) -> f32 {
+        if state.is_enabled() {
+            1.0
+        } else {
+            self.opacity
+        }
+    }
+}
+
+impl Default for DisabledStyle {
+    /// Create a default disabled style at half opacity.
+    fn default() -> Self {
+        Self {
+            opacity: Self::DEFAULT_OPACITY,
+        }
+    }
+}
+
+/// Visual "push" applied to a widget while it is pressed.
+///
+/// Backends scale the widget about its center and shift it by the offset,
+/// in logical pixels, for as long as the press lasts. The default feedback
+/// leaves the widget unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{prelude::*, style::PressFeedback};
+///
+/// let feedback = PressFeedback::new(0.95).offset(0.0, 1.0);
+/// assert_eq!(feedback.scale, 0.95);
+/// assert_eq!(feedback.offset, (0.0, 1.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PressFeedback {
+    /// Scale factor applied while pressed (1.0 is unchanged)
+    pub scale: f32,
+    /// Horizontal and vertical shift applied while pressed
+    pub offset: (f32, f32),
+}
+
+impl PressFeedback {
+    /// Create press feedback that scales the widget without moving it.
+    pub const fn new(scale: f32) -> Self {
+        Self {
+            scale,
+            offset: (0.0, 0.0),
+        }
+    }
+
+    /// Set the shift applied while pressed.
+    pub const fn offset(mut self, x: f32, y: f32) -> Self {
+        self.offset = (x, y);
+        self
+    }
+}
+
+impl Default for PressFeedback {
+    /// Create feedback that leaves the widget unchanged.
+    fn default() -> Self {
+        Self::new(1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn disabled_opacity_is_clamped() {
+        assert_eq!(DisabledStyle::new().opacity(-0.5).opacity, 0.0);
+        assert_eq!(DisabledStyle::new().opacity(1.5).opacity, 1.0);
+        assert_eq!(DisabledStyle::new().opacity(f32::NAN).opacity, 1.0);
+        assert_eq!(DisabledStyle::new().opacity(0.3).opacity, 0.3);
+    }
+}
+
+// End of File
@@ -0,0 +1,652 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Fonts and text styles
+
+use std::{
+    collections::BTreeSet,
+    fmt::{Debug, Formatter, Result as FormatterResult},
+    sync::{Mutex, PoisonError},
+};
+
+use bitflags::bitflags;
+
+use super::Color;
+
+/// Thickness of a font's strokes, on the CSS scale from 100 to 900.
+///
+/// Backends pick the closest weight the font provides; 400 is regular body
+/// text and 700 is bold.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{prelude::*, style::FontWeight};
+///
+/// assert_eq!(FontWeight::default(), FontWeight::REGULAR);
+/// assert_eq!(FontWeight::new(700), FontWeight::BOLD);
+/// assert_eq!(FontWeight::new(1000).value(), 900);
+/// assert!(FontWeight::SEMIBOLD > FontWeight::MEDIUM);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FontWeight(u16);
+
+impl FontWeight {
+    /// Weight 100, the thinnest
+    pub const THIN: FontWeight = FontWeight(100);
+    /// Weight 200
+    pub const EXTRA_LIGHT: FontWeight = FontWeight(200);
+    /// Weight 300
+    pub const LIGHT: FontWeight = FontWeight(300);
+    /// Weight 400, used for body text
+    pub const REGULAR: FontWeight = FontWeight(400);
+    /// Weight 500
+    pub const MEDIUM: FontWeight = FontWeight(500);
+    /// Weight 600
+    pub const SEMIBOLD: FontWeight = FontWeight(600);
+    /// Weight 700, used for emphasis and headings
+    pub const BOLD: FontWeight = FontWeight(700);
+    /// Weight 800
+    pub const EXTRA_BOLD: FontWeight = FontWeight(800);
+    /// Weight 900, the heaviest
+    pub const BLACK: FontWeight = FontWeight(900);
+
+    /// Create a weight, clamping it to the supported 100–900 range.
+    pub fn new(weight: u16) -> Self {
+        Self(weight.clamp(Self::THIN.0, Self::BLACK.0))
+    }
+
+    /// Get the numeric weight.
+    pub fn value(&self) -> u16 {
+        self.0
+    }
+}
+
+impl Default for FontWeight {
+    /// Regular weight, as used for body text.
+    fn default() -> Self {
+        Self::REGULAR
+    }
+}
+
+/// Whether text is drawn upright or slanted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FontStyle {
+    /// Upright text
+    #[default]
+    Normal,
+    /// Slanted text, for emphasis
+    Italic,
+}
+
+/// Typeface family requested for text.
+///
+/// Families are pure data: backends map the generic families to fonts the
+/// platform provides and fall back to their default font when a named
+/// family isn't installed.
+///
+/// Named families hold a `&'static str` so the type stays `Copy`. Literal
+/// names can be used directly; names only known at runtime go through
+/// [`FontFamily::named`], which interns them.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{prelude::*, style::FontFamily};
+///
+/// let code = Text::new("let x = 1;").font_family(FontFamily::Monospace);
+/// let brand = Text::new("Ironwood").font_family(FontFamily::Named("Inter"));
+/// assert_eq!(brand.style.font_family, Some(FontFamily::named("Inter".to_string())));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum FontFamily {
+    /// The platform's sans-serif font
+    SansSerif,
+    /// The platform's serif font, suited to body copy
+    Serif,
+    /// The platform's fixed-width font, suited to code
+    Monospace,
+    /// A specific installed font family
+    Named(&'static str),
+}
+
+/// Every font name interned by [`FontFamily::named`].
+///
+/// Applications use a handful of font families, so each distinct name is
+/// leaked once and shared for the rest of the program.
+static FONT_NAMES: Mutex<BTreeSet<&'static str>> = Mutex::new(BTreeSet::new());
+
+impl FontFamily {
+    /// Create a family that refers to a specific font by name.
+    ///
+    /// The name is interned, so naming the same family again reuses the
+    /// stored name instead of allocating.
+    pub fn named(name: impl AsRef<str>) -> Self {
+        let name = name.as_ref();
+        let mut names = FONT_NAMES.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(&interned) = names.get(name) {
+            return Self::Named(interned);
+        }
+        let interned: &'static str = Box::leak(name.into());
+        names.insert(interned);
+        Self::Named(interned)
+    }
+}
+
+/// Deserialized form of [`FontFamily`], which owns the names it reads so
+/// they can be interned.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(rename = "FontFamily")]
+enum OwnedFontFamily {
+    SansSerif,
+    Serif,
+    Monospace,
+    Named(String),
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for FontFamily {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(match OwnedFontFamily::deserialize(deserializer)? {
+            OwnedFontFamily::SansSerif => Self::SansSerif,
+            OwnedFontFamily::Serif => Self::Serif,
+            OwnedFontFamily::Monospace => Self::Monospace,
+            OwnedFontFamily::Named(name) => Self::named(name),
+        })
+    }
+}
+
+/// How the lines of a text block are aligned horizontally.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TextAlignment {
+    /// Lines start at the leading edge
+    #[default]
+    Leading,
+    /// Lines are centered
+    Center,
+    /// Lines end at the trailing edge
+    Trailing,
+    /// Lines are stretched to fill the width, except the last line
+    Justified,
+}
+
+/// Where text that exceeds its line limit is cut off.
+///
+/// Backends replace the removed characters with an ellipsis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TruncationMode {
+    /// Clip the text without an ellipsis
+    None,
+    /// Keep the start of the text, as in "Long docu…"
+    #[default]
+    Tail,
+    /// Keep both ends of the text, as in "Long…name"
+    Middle,
+    /// Keep the end of the text, as in "…ent name"
+    Head,
+}
+
+bitflags! {
+    /// The [`TextStyle`] properties that were set explicitly.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    struct TextProperties: u16 {
+        const FONT_SIZE = 1 << 0;
+        const COLOR = 1 << 1;
+        const FONT_WEIGHT = 1 << 2;
+        const FONT_STYLE = 1 << 3;
+        const FONT_FAMILY = 1 << 4;
+        const ALIGNMENT = 1 << 5;
+        const LINE_SPACING = 1 << 6;
+        const LINE_LIMIT = 1 << 7;
+        const TRUNCATION = 1 << 8;
+    }
+}
+
+/// Text styling properties for UI elements
+///
+/// `TextStyle` encapsulates all text-related styling properties including
+/// color, font size, weight, and style. This provides a consistent way to style text across
+/// different UI components.
+///
+/// Styles are created with [`TextStyle::new`] or [`Default`] and customized
+/// with the builder methods, which record which properties were set so that
+/// [`TextStyle::inherit`] never overrides them. Struct literal syntax isn't
+/// available for this reason.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{prelude::*, style::FontWeight};
+///
+/// // Default text style (16px, black)
+/// let default_style = TextStyle::default();
+///
+/// // Custom text style
+/// let heading_style = TextStyle::new()
+///     .font_size(24.0)
+///     .color(Color::BLUE);
+///
+/// // Builder pattern
+/// let warning_style = TextStyle::new()
+///     .font_size(14.0)
+///     .color(Color::RED)
+///     .font_weight(FontWeight::BOLD);
+/// ```
+#[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TextStyle {
+    /// Font size in logical pixels
+    pub font_size: f32,
+    /// Text color
+    pub color: Color,
+    /// Stroke thickness of the font
+    pub font_weight: FontWeight,
+    /// Upright or italic
+    pub font_style: FontStyle,
+    /// Typeface family, or `None` for the backend's default font
+    pub font_family: Option<FontFamily>,
+    /// Horizontal alignment of lines within the text block
+    pub alignment: TextAlignment,
+    /// Line height as a multiple of the font's normal line height
+    pub line_spacing: f32,
+    /// Maximum number of lines to show, or `None` for no limit
+    pub line_limit: Option<usize>,
+    /// Where text is cut off when it exceeds the line limit
+    pub truncation: TruncationMode,
+    /// Properties set through the builder methods, which are never inherited
+    #[cfg_attr(feature = "serde", serde(default))]
+    explicit: TextProperties,
+}
+
+impl TextStyle {
+    /// Create a new text style with default values.
+    ///
+    /// Default values are 16px font size, black color, regular weight,
+    /// normal style, the backend's default font family, leading alignment,
+    /// normal line spacing, and no line limit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// let style = TextStyle::new();
+    /// assert_eq!(style.font_size, 16.0);
+    /// assert_eq!(style.color, Color::BLACK);
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the font size for this text style.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - Font size in logical pixels
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// let style = TextStyle::new().font_size(24.0);
+    /// assert_eq!(style.font_size, 24.0);
+    /// ```
+    pub fn font_size(mut self, size: f32) -> Self {
+        self.font_size = size;
+        self.explicit |= TextProperties::FONT_SIZE;
+        self
+    }
+
+    /// Set the color for this text style.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The text color
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// let style = TextStyle::new().color(Color::RED);
+    /// assert_eq!(style.color, Color::RED);
+    /// ```
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = color;
+        self.explicit |= TextProperties::COLOR;
+        self
+    }
+
+    /// Set the font weight for this text style.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::{prelude::*, style::FontWeight};
+    ///
+    /// let style = TextStyle::new().font_weight(FontWeight::BOLD);
+    /// assert_eq!(style.font_weight, FontWeight::BOLD);
+    /// ```
+    pub fn font_weight(mut self, weight: FontWeight) -> Self {
+        self.font_weight = weight;
+        self.explicit |= TextProperties::FONT_WEIGHT;
+        self
+    }
+
+    /// Set the font style for this text style.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::{prelude::*, style::FontStyle};
+    ///
+    /// let style = TextStyle::new().font_style(FontStyle::Italic);
+    /// assert_eq!(style.font_style, FontStyle::Italic);
+    /// ```
+    pub fn font_style(mut self, style: FontStyle) -> Self {
+        self.font_style = style;
+        self.explicit |= TextProperties::FONT_STYLE;
+        self
+    }
+
+    /// Set the font family for this text style.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::{prelude::*, style::FontFamily};
+    ///
+    /// let style = TextStyle::new().font_family(FontFamily::Serif);
+    /// assert_eq!(style.font_family, Some(FontFamily::Serif));
+    /// ```
+    pub fn font_family(mut self, family: FontFamily) -> Self {
+        self.font_family = Some(family);
+        self.explicit |= TextProperties::FONT_FAMILY;
+        self
+    }
+
+    /// Set how lines are aligned within the text block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::{prelude::*, style::TextAlignment};
+    ///
+    /// let style = TextStyle::new().alignment(TextAlignment::Justified);
+    /// assert_eq!(style.alignment, TextAlignment::Justified);
+    /// ```
+    pub fn alignment(mut self, alignment: TextAlignment) -> Self {
+        self.alignment = alignment;
+        self.explicit |= TextProperties::ALIGNMENT;
+        self
+    }
+
+    /// Set the line height as a multiple of the font's normal line height.
+    ///
+    /// Negative values are treated as zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// let style = TextStyle::new().line_spacing(1.5);
+    /// assert_eq!(style.line_spacing, 1.5);
+    /// ```
+    pub fn line_spacing(mut self, spacing: f32) -> Self {
+        self.line_spacing = spacing.max(0.0);
+        self.explicit |= TextProperties::LINE_SPACING;
+        self
+    }
+
+    /// Limit the text to at most `lines` lines.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// let style = TextStyle::new().line_limit(2);
+    /// assert_eq!(style.line_limit, Some(2));
+    /// ```
+    pub fn line_limit(mut self, lines: usize) -> Self {
+        self.line_limit = Some(lines);
+        self.explicit |= TextProperties::LINE_LIMIT;
+        self
+    }
+
+    /// Set where text is cut off when it exceeds the line limit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::{prelude::*, style::TruncationMode};
+    ///
+    /// let style = TextStyle::new().truncation(TruncationMode::Middle);
+    /// assert_eq!(style.truncation, TruncationMode::Middle);
+    /// ```
+    pub fn truncation(mut self, mode: TruncationMode) -> Self {
+        self.truncation = mode;
+        self.explicit |= TextProperties::TRUNCATION;
+        self
+    }
+
+    /// Fill in this style's unset properties from an inherited style.
+    ///
+    /// A property counts as set once it was passed to one of the builder
+    /// methods, even if the value equals the default. Set properties win
+    /// over `parent`; every other property is taken from it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// let parent = TextStyle::new().font_size(20.0).color(Color::BLUE);
+    /// let merged = TextStyle::new().color(Color::BLACK).inherit(&parent);
+    /// assert_eq!(merged.font_size, 20.0);
+    /// assert_eq!(merged.color, Color::BLACK);
+    /// ```
+    pub fn inherit(&self, parent: &TextStyle) -> TextStyle {
+        let mut merged = *self;
+        macro_rules! inherit {
+            ($($field:ident: $property:ident),+ $(,)?) => {
+                $(if !self.explicit.contains(TextProperties::$property) {
+                    merged.$field = parent.$field;
+                })+
+            };
+        }
+
+        inherit!(
+            font_size: FONT_SIZE,
+            color: COLOR,
+            font_weight: FONT_WEIGHT,
+            font_style: FONT_STYLE,
+            font_family: FONT_FAMILY,
+            alignment: ALIGNMENT,
+            line_spacing: LINE_SPACING,
+            line_limit: LINE_LIMIT,
+            truncation: TRUNCATION,
+        );
+        merged.explicit |= parent.explicit;
+        merged
+    }
+
+    /// Check whether the color was set explicitly rather than left to inherit.
+    pub(crate) fn has_explicit_color(&self) -> bool {
+        self.explicit.contains(TextProperties::COLOR)
+    }
+}
+
+impl Default for TextStyle {
+    /// Create a default text style with 16px regular black text.
+    fn default() -> Self {
+        Self {
+            font_size: 16.0,
+            color: Color::BLACK,
+            font_weight: FontWeight::REGULAR,
+            font_style: FontStyle::Normal,
+            font_family: None,
+            alignment: TextAlignment::Leading,
+            line_spacing: 1.0,
+            line_limit: None,
+            truncation: TruncationMode::Tail,
+            explicit: TextProperties::empty(),
+        }
+    }
+}
+
+impl PartialEq for TextStyle {
+    /// Compare the property values, whether or not they were set explicitly.
+    fn eq(&self, other: &Self) -> bool {
+        self.font_size == other.font_size
+            && self.color == other.color
+            && self.font_weight == other.font_weight
+            && self.font_style == other.font_style
+            && self.font_family == other.font_family
+            && self.alignment == other.alignment
+            && self.line_spacing == other.line_spacing
+            && self.line_limit == other.line_limit
+            && self.truncation == other.truncation
+    }
+}
+
+impl Debug for TextStyle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FormatterResult {
+        f.debug_struct("TextStyle")
+            .field("font_size", &self.font_size)
+            .field("color", &self.color)
+            .field("font_weight", &self.font_weight)
+            .field("font_style", &self.font_style)
+            .field("font_family", &self.font_family)
+            .field("alignment", &self.alignment)
+            .field("line_spacing", &self.line_spacing)
+            .field("line_limit", &self.line_limit)
+            .field("truncation", &self.truncation)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn text_style_functionality() {
+        // Test default text style
+        let default_style = TextStyle::default();
+        assert_eq!(default_style.font_size, 16.0);
+        assert_eq!(default_style.color, Color::BLACK);
+
+        // Test new() method
+        let new_style = TextStyle::new();
+        assert_eq!(new_style, default_style);
+
+        // Test builder pattern and method chaining
+        let custom_style = TextStyle::new().font_size(24.0).color(Color::BLUE);
+        assert_eq!(custom_style.font_size, 24.0);
+        assert_eq!(custom_style.color, Color::BLUE);
+
+        // Test that later calls override earlier ones
+        let override_style = TextStyle::new()
+            .font_size(18.0)
+            .color(Color::RED)
+            .font_size(20.0); // Should override previous font size
+
+        assert_eq!(override_style.font_size, 20.0);
+        assert_eq!(override_style.color, Color::RED);
+    }
+
+    #[test]
+    fn font_weight_and_style() {
+        // Defaults keep existing text regular and upright
+        let style = TextStyle::default();
+        assert_eq!(style.font_weight, FontWeight::REGULAR);
+        assert_eq!(style.font_style, FontStyle::Normal);
+
+        let style = style
+            .font_weight(FontWeight::SEMIBOLD)
+            .font_style(FontStyle::Italic);
+        assert_eq!(style.font_weight.value(), 600);
+        assert_eq!(style.font_style, FontStyle::Italic);
+
+        // Weights outside the CSS scale clamp to its ends
+        assert_eq!(FontWeight::new(0), FontWeight::THIN);
+        assert_eq!(FontWeight::new(950), FontWeight::BLACK);
+        assert!(FontWeight::LIGHT < FontWeight::REGULAR);
+    }
+
+    #[test]
+    fn paragraph_layout_settings() {
+        let style = TextStyle::default();
+        assert_eq!(style.alignment, TextAlignment::Leading);
+        assert_eq!(style.line_spacing, 1.0);
+
+        let style = style.alignment(TextAlignment::Center).line_spacing(1.25);
+        assert_eq!(style.alignment, TextAlignment::Center);
+        assert_eq!(style.line_spacing, 1.25);
+        assert_eq!(style.line_spacing(-2.0).line_spacing, 0.0);
+    }
+
+    #[test]
+    fn line_limit_and_truncation() {
+        let style = TextStyle::default();
+        assert_eq!(style.line_limit, None);
+        assert_eq!(style.truncation, TruncationMode::Tail);
+
+        let style = style.line_limit(1).truncation(TruncationMode::Head);
+        assert_eq!(style.line_limit, Some(1));
+        assert_eq!(style.truncation, TruncationMode::Head);
+    }
+
+    #[test]
+    fn inherit_keeps_explicit_values_equal_to_the_defaults() {
+        let parent = TextStyle::new()
+            .font_size(24.0)
+            .color(Color::BLUE)
+            .font_weight(FontWeight::BOLD)
+            .truncation(TruncationMode::Middle);
+        let child = TextStyle::new()
+            .font_size(16.0)
+            .color(Color::BLACK)
+            .truncation(TruncationMode::Tail);
+        assert_eq!(child, TextStyle::default());
+
+        let merged = child.inherit(&parent);
+        assert_eq!(merged.font_size, 16.0);
+        assert_eq!(merged.color, Color::BLACK);
+        assert_eq!(merged.truncation, TruncationMode::Tail);
+        assert_eq!(merged.font_weight, FontWeight::BOLD);
+
+        // Properties the parent set explicitly stay explicit after merging
+        let merged = TextStyle::new().inherit(&TextStyle::new().color(Color::BLACK));
+        assert!(merged.has_explicit_color());
+    }
+
+    #[test]
+    fn named_font_families_are_interned_and_copyable() {
+        let runtime = FontFamily::named(String::from("Fira Code"));
+        let again = FontFamily::named("Fira Code");
+        let (FontFamily::Named(first), FontFamily::Named(second)) = (runtime, again) else {
+            panic!("expected named families");
+        };
+        assert!(std::ptr::eq(first, second));
+        assert_eq!(runtime, FontFamily::Named("Fira Code"));
+
+        // Styles holding a named family are still plain copies
+        let style = TextStyle::new().font_family(runtime);
+        let copy = style;
+        assert_eq!(copy, style);
+    }
+}
+
+// End of File
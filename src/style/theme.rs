@@ -0,0 +1,172 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Semantic colors and the themes that resolve them
+
+use std::fmt::Debug;
+
+use super::Color;
+
+/// A role a color plays in the interface, resolved through a [`Theme`].
+///
+/// Views that use semantic colors instead of fixed ones follow the theme and
+/// the render context's accessibility settings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SemanticColor {
+    /// Surface behind content
+    Background,
+    /// Text and icons drawn on the background
+    Foreground,
+    /// Fill of prominent controls such as primary buttons
+    Accent,
+    /// Text and icons drawn on the accent color
+    OnAccent,
+    /// Outlines separating controls from their surroundings
+    Border,
+}
+
+/// The concrete color for every [`SemanticColor`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ThemeColors {
+    /// Color for [`SemanticColor::Background`]
+    pub background: Color,
+    /// Color for [`SemanticColor::Foreground`]
+    pub foreground: Color,
+    /// Color for [`SemanticColor::Accent`]
+    pub accent: Color,
+    /// Color for [`SemanticColor::OnAccent`]
+    pub on_accent: Color,
+    /// Color for [`SemanticColor::Border`]
+    pub border: Color,
+}
+
+impl ThemeColors {
+    /// Light colors used by the default theme.
+    pub const LIGHT: ThemeColors = ThemeColors {
+        background: Color::WHITE,
+        foreground: Color::BLACK,
+        accent: Color::rgb(0.2, 0.4, 0.9),
+        on_accent: Color::WHITE,
+        border: Color::rgb(0.8, 0.8, 0.8),
+    };
+
+    /// Pure black and white with solid borders, for maximum legibility.
+    pub const HIGH_CONTRAST: ThemeColors = ThemeColors {
+        background: Color::WHITE,
+        foreground: Color::BLACK,
+        accent: Color::BLACK,
+        on_accent: Color::WHITE,
+        border: Color::BLACK,
+    };
+
+    /// Get the color for a semantic role.
+    pub fn get(&self, color: SemanticColor) -> Color {
+        match color {
+            SemanticColor::Background => self.background,
+            SemanticColor::Foreground => self.foreground,
+            SemanticColor::Accent => self.accent,
+            SemanticColor::OnAccent => self.on_accent,
+            SemanticColor::Border => self.border,
+        }
+    }
+}
+
+/// Colors that semantic colors resolve to, with a high-contrast variant.
+///
+/// The render context holds the theme and decides which variant applies, so
+/// views never need to know whether high contrast is on.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{prelude::*, style::{SemanticColor, Theme}};
+///
+/// let theme = Theme::default();
+/// assert_eq!(theme.resolve(SemanticColor::Accent, true), Color::BLACK);
+///
+/// // Through the render context
+/// let ctx = RenderContext::new().with_high_contrast(true);
+/// assert_eq!(ctx.resolve_color(SemanticColor::Border), Color::BLACK);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Theme {
+    /// Colors used normally
+    pub standard: ThemeColors,
+    /// Colors used when the user asked for high contrast
+    pub high_contrast: ThemeColors,
+}
+
+impl Theme {
+    /// Create a theme from its normal colors, with the default high-contrast variant.
+    pub const fn new(standard: ThemeColors) -> Self {
+        Self {
+            standard,
+            high_contrast: ThemeColors::HIGH_CONTRAST,
+        }
+    }
+
+    /// Replace the colors used in high-contrast mode.
+    pub fn high_contrast(mut self, colors: ThemeColors) -> Self {
+        self.high_contrast = colors;
+        self
+    }
+
+    /// Get the colors that apply with or without high contrast.
+    pub fn colors(&self, high_contrast: bool) -> &ThemeColors {
+        if high_contrast {
+            &self.high_contrast
+        } else {
+            &self.standard
+        }
+    }
+
+    /// Resolve a semantic color with or without high contrast.
+    pub fn resolve(&self, color: SemanticColor, high_contrast: bool) -> Color {
+        self.colors(high_contrast).get(color)
+    }
+}
+
+impl Default for Theme {
+    /// Create the light theme.
+    fn default() -> Self {
+        Self::new(ThemeColors::LIGHT)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn theme_resolves_high_contrast_variant() {
+        let custom = ThemeColors {
+            accent: Color::GREEN,
+            ..ThemeColors::LIGHT
+        };
+        let theme = Theme::new(custom);
+        assert_eq!(theme.resolve(SemanticColor::Accent, false), Color::GREEN);
+        assert_eq!(theme.resolve(SemanticColor::Accent, true), Color::BLACK);
+        assert_eq!(theme.resolve(SemanticColor::OnAccent, true), Color::WHITE);
+
+        // High contrast borders are solid black
+        assert_ne!(
+            theme.resolve(SemanticColor::Border, false),
+            theme.resolve(SemanticColor::Border, true)
+        );
+
+        let theme = theme.high_contrast(ThemeColors {
+            accent: Color::rgb(1.0, 1.0, 0.0),
+            ..ThemeColors::HIGH_CONTRAST
+        });
+        assert_eq!(
+            theme.resolve(SemanticColor::Accent, true),
+            Color::rgb(1.0, 1.0, 0.0)
+        );
+    }
+}
+
+// End of File
@@ -0,0 +1,203 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Subscriptions to events from outside the message flow
+//!
+//! Some messages don't come from the user interface at all: a clock ticks,
+//! a window is resized, a background job reports progress. A model describes
+//! the external sources it wants to hear from by returning subscriptions from
+//! [`Model::subscriptions`](crate::model::Model::subscriptions), and the
+//! runtime polls them and feeds the resulting messages back into `update`.
+//!
+//! Like animations, subscriptions hold no clock. Time is measured from an
+//! origin shared by the caller, such as the time since the app started, which
+//! keeps subscriptions pure and easy to test.
+
+use std::{
+    fmt::{Debug, Formatter, Result as FormatterResult},
+    sync::Arc,
+    time::Duration,
+};
+
+/// Function producing a message for an external event.
+type Produce<M> = Arc<dyn Fn() -> M + Send + Sync>;
+
+/// An external event source paired with the message it produces.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use ironwood::{prelude::*, subscription::Subscription};
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// enum ClockMessage {
+///     Tick,
+/// }
+///
+/// impl Message for ClockMessage {}
+///
+/// let clock = Subscription::interval(Duration::from_secs(1), || ClockMessage::Tick);
+///
+/// // Polling from 0.5s to 2.5s sees the ticks at 1s and 2s
+/// let messages = clock.poll(Duration::from_millis(500), Duration::from_millis(2500));
+/// assert_eq!(messages, vec![ClockMessage::Tick, ClockMessage::Tick]);
+/// ```
+pub struct Subscription<M> {
+    source: Source,
+    produce: Produce<M>,
+}
+
+/// The kinds of external event sources.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Source {
+    /// Fires every `period`, starting one period after the time origin
+    Interval(Duration),
+}
+
+impl<M: 'static> Subscription<M> {
+    /// Produce a message every `period`, starting one period after the time origin.
+    ///
+    /// A zero period never fires.
+    pub fn interval(period: Duration, produce: impl Fn() -> M + Send + Sync + 'static) -> Self {
+        Self {
+            source: Source::Interval(period),
+            produce: Arc::new(produce),
+        }
+    }
+
+    /// Get the period of an interval subscription.
+    pub fn period(&self) -> Option<Duration> {
+        match self.source {
+            Source::Interval(period) => Some(period),
+        }
+    }
+
+    /// Collect the messages for events after `from` and up to and including `to`.
+    ///
+    /// Polling consecutive windows that share their boundaries sees every
+    /// event exactly once.
+    pub fn poll(&self, from: Duration, to: Duration) -> Vec<M> {
+        let count = match self.source {
+            Source::Interval(period) => {
+                if period.is_zero() || to <= from {
+                    0
+                } else {
+                    let ticks = |time: Duration| time.as_nanos() / period.as_nanos();
+                    ticks(to) - ticks(from)
+                }
+            }
+        };
+        (0..count).map(|_| (self.produce)()).collect()
+    }
+
+    /// Convert the produced messages, typically into a parent's message type.
+    pub fn map<P>(self, f: impl Fn(M) -> P + Send + Sync + 'static) -> Subscription<P> {
+        let produce = self.produce;
+        Subscription {
+            source: self.source,
+            produce: Arc::new(move || f(produce())),
+        }
+    }
+}
+
+impl<M> Clone for Subscription<M> {
+    fn clone(&self) -> Self {
+        Self {
+            source: self.source,
+            produce: Arc::clone(&self.produce),
+        }
+    }
+}
+
+impl<M> Debug for Subscription<M> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FormatterResult {
+        f.debug_struct("Subscription")
+            .field("source", &self.source)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        elements::Text,
+        message::Message,
+        model::{Model, PairMessage},
+    };
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Clock {
+        seconds: u32,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum ClockMessage {
+        Tick,
+    }
+
+    impl Message for ClockMessage {}
+
+    impl Model for Clock {
+        type Message = ClockMessage;
+        type View = Text;
+
+        fn update(self, _message: Self::Message) -> Self {
+            Self {
+                seconds: self.seconds + 1,
+            }
+        }
+
+        fn view(&self) -> Self::View {
+            Text::new(format!("{}s", self.seconds))
+        }
+
+        fn subscriptions(&self) -> Vec<Subscription<Self::Message>> {
+            vec![Subscription::interval(Duration::from_secs(1), || {
+                ClockMessage::Tick
+            })]
+        }
+    }
+
+    #[test]
+    fn interval_windows_see_each_tick_once() {
+        let tick = Subscription::interval(Duration::from_millis(100), || ClockMessage::Tick);
+        let ms = Duration::from_millis;
+
+        assert_eq!(tick.poll(ms(0), ms(99)).len(), 0);
+        assert_eq!(tick.poll(ms(99), ms(100)).len(), 1);
+        assert_eq!(tick.poll(ms(100), ms(350)).len(), 2);
+        assert_eq!(tick.poll(ms(0), ms(350)).len(), 3);
+
+        // Backwards windows and zero periods never fire
+        assert!(tick.poll(ms(350), ms(0)).is_empty());
+        let never = Subscription::interval(Duration::ZERO, || ClockMessage::Tick);
+        assert!(never.poll(ms(0), ms(1000)).is_empty());
+    }
+
+    #[test]
+    fn polled_messages_drive_the_model() {
+        let clock = Clock { seconds: 0 };
+        let messages: Vec<ClockMessage> = clock
+            .subscriptions()
+            .iter()
+            .flat_map(|subscription| subscription.poll(Duration::ZERO, Duration::from_secs(3)))
+            .collect();
+
+        let clock = clock.update_batch(messages);
+        assert_eq!(clock.seconds, 3);
+
+        // Child subscriptions can be lifted into a parent's messages
+        let lifted = Clock { seconds: 0 }.subscriptions()[0]
+            .clone()
+            .map(PairMessage::<ClockMessage, ClockMessage>::First);
+        assert_eq!(
+            lifted.poll(Duration::ZERO, Duration::from_secs(1)),
+            vec![PairMessage::First(ClockMessage::Tick)]
+        );
+    }
+}
+
+// End of File
@@ -0,0 +1,325 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Helpers for driving components in tests
+//!
+//! Tests of interactive components usually replay what a pointer does to a
+//! widget: move over it, press, release, move away. [`InteractionSequence`]
+//! builds those message sequences in a realistic order so tests don't have
+//! to spell out every [`InteractionMessage`] by hand, and
+//! [`assert_interaction_clean`] checks that a widget is back at rest once the
+//! sequence is over.
+
+use crate::{
+    interaction::{Enableable, Focusable, Hoverable, InteractionMessage, Pressable},
+    widgets::ButtonMessage,
+};
+
+/// One step of a synthesized interaction.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SyntheticEvent {
+    /// An interaction state change
+    Interaction(InteractionMessage),
+    /// A completed activation: the pointer was pressed and released over the widget
+    Click,
+}
+
+/// Builder for ordered interaction message sequences.
+///
+/// The builder tracks whether the pointer is over the widget and whether it
+/// is pressed, and fills in the steps a real pointer couldn't skip:
+///
+/// - Pressing while the pointer is outside hovers in first.
+/// - Releasing when nothing is pressed does nothing.
+/// - Hovering out while pressed cancels the press without a click.
+///
+/// The finished sequence is converted to a widget's messages with
+/// [`map`](Self::map), or with [`button_messages`](Self::button_messages)
+/// for buttons.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{prelude::*, testing::InteractionSequence};
+///
+/// let clicks = InteractionSequence::new().click().hover_out();
+///
+/// let button = Button::new("Save").update_batch(clicks.button_messages());
+/// assert!(!button.is_hovered());
+/// assert!(!button.is_pressed());
+/// assert_eq!(clicks.clicks(), 1);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct InteractionSequence {
+    events: Vec<SyntheticEvent>,
+    hovered: bool,
+    pressed: bool,
+}
+
+impl InteractionSequence {
+    /// Start an empty sequence with the pointer outside the widget.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Move the pointer over the widget.
+    pub fn hover_in(mut self) -> Self {
+        if !self.hovered {
+            self.hovered = true;
+            self.push(InteractionMessage::HoverChanged(true));
+        }
+        self
+    }
+
+    /// Move the pointer away, cancelling any press in progress.
+    pub fn hover_out(mut self) -> Self {
+        if self.pressed {
+            self.pressed = false;
+            self.push(InteractionMessage::PressStateChanged(false));
+        }
+        if self.hovered {
+            self.hovered = false;
+            self.push(InteractionMessage::HoverChanged(false));
+        }
+        self
+    }
+
+    /// Press the pointer down over the widget.
+    pub fn press(mut self) -> Self {
+        self = self.hover_in();
+        if !self.pressed {
+            self.pressed = true;
+            self.push(InteractionMessage::PressStateChanged(true));
+        }
+        self
+    }
+
+    /// Release the pointer, completing a click if it was pressed over the widget.
+    pub fn release(mut self) -> Self {
+        if self.pressed {
+            self.pressed = false;
+            self.push(InteractionMessage::PressStateChanged(false));
+            self.events.push(SyntheticEvent::Click);
+        }
+        self
+    }
+
+    /// Press and release over the widget.
+    pub fn click(self) -> Self {
+        self.press().release()
+    }
+
+    /// Give the widget keyboard focus.
+    pub fn focus(mut self) -> Self {
+        self.push(InteractionMessage::FocusChanged(true));
+        self
+    }
+
+    /// Take keyboard focus away from the widget.
+    pub fn blur(mut self) -> Self {
+        self.push(InteractionMessage::FocusChanged(false));
+        self
+    }
+
+    /// Get the synthesized events in order.
+    pub fn events(&self) -> &[SyntheticEvent] {
+        &self.events
+    }
+
+    /// Get the number of completed clicks in the sequence.
+    pub fn clicks(&self) -> usize {
+        self.events
+            .iter()
+            .filter(|event| **event == SyntheticEvent::Click)
+            .count()
+    }
+
+    /// Convert every event into a widget's message type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::{
+    ///     prelude::*,
+    ///     testing::{InteractionSequence, SyntheticEvent},
+    ///     widgets::ToggleMessage,
+    /// };
+    ///
+    /// let messages = InteractionSequence::new().click().map(|event| match event {
+    ///     SyntheticEvent::Interaction(message) => ToggleMessage::Interaction(message),
+    ///     SyntheticEvent::Click => ToggleMessage::Switched(true),
+    /// });
+    /// let toggle = Toggle::new(false).update_batch(messages);
+    /// assert!(toggle.is_on);
+    /// ```
+    pub fn map<M>(&self, f: impl FnMut(SyntheticEvent) -> M) -> Vec<M> {
+        self.events.iter().cloned().map(f).collect()
+    }
+
+    /// Get only the interaction state changes, dropping clicks.
+    pub fn interactions(&self) -> Vec<InteractionMessage> {
+        self.events
+            .iter()
+            .filter_map(|event| match event {
+                SyntheticEvent::Interaction(message) => Some(message.clone()),
+                SyntheticEvent::Click => None,
+            })
+            .collect()
+    }
+
+    /// Convert the sequence into button messages.
+    pub fn button_messages(&self) -> Vec<ButtonMessage> {
+        self.map(|event| match event {
+            SyntheticEvent::Interaction(message) => ButtonMessage::Interaction(message),
+            SyntheticEvent::Click => ButtonMessage::Clicked,
+        })
+    }
+
+    fn push(&mut self, message: InteractionMessage) {
+        self.events.push(SyntheticEvent::Interaction(message));
+    }
+}
+
+/// Assert that a widget is enabled and not pressed, focused or hovered.
+///
+/// Use this after replaying an interaction sequence to check that no state
+/// leaked. It accepts anything with the four interaction traits, including
+/// widgets such as [`Button`](crate::widgets::Button) and the
+/// [`InteractionState`](crate::interaction::InteractionState) of an extracted
+/// view.
+///
+/// # Panics
+///
+/// Panics if the widget is disabled or still pressed, focused or hovered.
+/// The message names every leftover flag.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{
+///     prelude::*,
+///     testing::{InteractionSequence, assert_interaction_clean},
+/// };
+///
+/// let sequence = InteractionSequence::new().focus().click().hover_out().blur();
+/// let button = Button::new("Save").update_batch(sequence.button_messages());
+/// assert_interaction_clean(&button);
+/// ```
+#[track_caller]
+pub fn assert_interaction_clean<W>(widget: &W)
+where
+    W: Enableable + Pressable + Focusable + Hoverable + ?Sized,
+{
+    let leftovers: Vec<&str> = [
+        (!widget.is_enabled(), "disabled"),
+        (widget.is_pressed(), "pressed"),
+        (widget.is_focused(), "focused"),
+        (widget.is_hovered(), "hovered"),
+    ]
+    .into_iter()
+    .filter_map(|(leaked, flag)| leaked.then_some(flag))
+    .collect();
+    assert!(
+        leftovers.is_empty(),
+        "interaction state leaked: widget is still {}",
+        leftovers.join(", ")
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{model::Model, widgets::Button};
+
+    #[test]
+    fn click_sequence_is_ordered_and_folds_through_a_button() {
+        use InteractionMessage::*;
+
+        let sequence = InteractionSequence::new().click().hover_out();
+        assert_eq!(
+            sequence.events(),
+            &[
+                SyntheticEvent::Interaction(HoverChanged(true)),
+                SyntheticEvent::Interaction(PressStateChanged(true)),
+                SyntheticEvent::Interaction(PressStateChanged(false)),
+                SyntheticEvent::Click,
+                SyntheticEvent::Interaction(HoverChanged(false)),
+            ]
+        );
+
+        let messages = sequence.button_messages();
+        assert_eq!(messages[3], ButtonMessage::Clicked);
+        let button = Button::new("Save").update_batch(messages);
+        assert!(!button.is_pressed());
+        assert!(!button.is_hovered());
+
+        // Stopping halfway leaves the button hovered and pressed
+        let held =
+            Button::new("Save").update_batch(InteractionSequence::new().press().button_messages());
+        assert!(held.is_hovered());
+        assert!(held.is_pressed());
+    }
+
+    #[test]
+    fn impossible_steps_are_filled_in_or_skipped() {
+        use InteractionMessage::*;
+
+        // Dragging off a pressed widget cancels the click
+        let cancelled = InteractionSequence::new().press().hover_out().release();
+        assert_eq!(cancelled.clicks(), 0);
+        assert_eq!(
+            cancelled.interactions(),
+            vec![
+                HoverChanged(true),
+                PressStateChanged(true),
+                PressStateChanged(false),
+                HoverChanged(false),
+            ]
+        );
+
+        // Repeated clicks hover in only once
+        let double = InteractionSequence::new().focus().click().click().blur();
+        assert_eq!(double.clicks(), 2);
+        assert_eq!(
+            double
+                .interactions()
+                .iter()
+                .filter(|message| **message == HoverChanged(true))
+                .count(),
+            1
+        );
+        assert_eq!(double.interactions().first(), Some(&FocusChanged(true)));
+        assert_eq!(double.interactions().last(), Some(&FocusChanged(false)));
+    }
+
+    #[test]
+    fn clean_widgets_pass_after_a_full_sequence() {
+        let sequence = InteractionSequence::new()
+            .hover_in()
+            .focus()
+            .press()
+            .release()
+            .hover_out()
+            .blur();
+        let button = Button::new("Save").update_batch(sequence.button_messages());
+        assert_interaction_clean(&button);
+        assert_interaction_clean(&button.view().interaction_state);
+    }
+
+    #[test]
+    #[should_panic(expected = "interaction state leaked: widget is still pressed, hovered")]
+    fn leftover_flags_are_named() {
+        let held =
+            Button::new("Save").update_batch(InteractionSequence::new().press().button_messages());
+        assert_interaction_clean(&held);
+    }
+
+    #[test]
+    #[should_panic(expected = "widget is still disabled")]
+    fn disabled_widgets_are_not_clean() {
+        assert_interaction_clean(&Button::new("Save").disable());
+    }
+}
+
+// End of File
@@ -12,7 +12,15 @@
 //! what the UI should look like, while rendering backends determine how
 //! to display that description on specific platforms.
 
-use std::{any::Any, fmt::Debug};
+use std::{
+    any::{Any, type_name},
+    fmt::Debug,
+};
+
+use crate::{
+    elements::{Badge, Border, BorderStyle, Identified, MappedView, Opacity, Tagged, Tooltip},
+    message::Message,
+};
 
 /// Marker trait for all view types in Ironwood.
 ///
@@ -76,6 +84,198 @@ pub trait View: Debug + Send + Sync + Any + 'static {
     /// assert_eq!(downcast_text.content, "Hello");
     /// ```
     fn as_any(&self) -> &dyn Any;
+
+    /// Get the name of this view's concrete type.
+    ///
+    /// Unlike `std::any::type_name_of_val`, this reports the concrete type even
+    /// when called through a `&dyn View`, which makes it suitable for error
+    /// messages about views in dynamic trees. Implementors should not override it.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// let view: Box<dyn View> = Box::new(Text::new("Hello"));
+    /// assert_eq!(view.view_type_name(), std::any::type_name::<Text>());
+    /// ```
+    fn view_type_name(&self) -> &'static str {
+        type_name::<Self>()
+    }
+
+    /// Tag this view with a stable identifier.
+    ///
+    /// Ids let models and backends refer to this part of the view tree across
+    /// updates, for example when marking it dirty for partial re-extraction.
+    /// Inside dynamic containers, follow with
+    /// [`Identified::boxed`](crate::elements::Identified::boxed).
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// let label = Text::new("Count: 0").id("count");
+    /// assert_eq!(label.id, "count");
+    /// assert_eq!(label.content.content, "Count: 0");
+    /// ```
+    fn id(self, id: impl Into<String>) -> Identified<Self>
+    where
+        Self: Sized,
+    {
+        Identified::new(id, self)
+    }
+
+    /// Attach a metadata tag to this view.
+    ///
+    /// Tags are free-form key/value pairs that backends pass through, for
+    /// example to label elements for analytics. They don't affect layout
+    /// and, unlike [`id`](View::id), aren't used for lookup. Further calls
+    /// add to the same tags. Inside dynamic containers, follow with
+    /// [`Tagged::boxed`](crate::elements::Tagged::boxed).
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// let cta = Text::new("Get started").tag("analytics_id", "hero_cta");
+    /// assert_eq!(cta.get("analytics_id"), Some("hero_cta"));
+    /// ```
+    fn tag(self, key: impl Into<String>, value: impl Into<String>) -> Tagged<Self>
+    where
+        Self: Sized,
+    {
+        Tagged::new(self).tag(key, value)
+    }
+
+    /// Draw an outline around this view.
+    ///
+    /// Inside dynamic containers, wrap boxed children with
+    /// [`Border::new`](crate::elements::Border::new) instead.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ironwood::{prelude::*, elements::BorderStyle};
+    ///
+    /// let badge = Text::new("New").border(BorderStyle::new(1.0, Color::RED).corner_radius(4.0));
+    /// assert_eq!(badge.style.color, Color::RED);
+    /// assert_eq!(badge.content.content, "New");
+    /// ```
+    fn border(self, style: BorderStyle) -> Border<Self>
+    where
+        Self: Sized,
+    {
+        Border::new(self, style)
+    }
+
+    /// Fade this view and everything inside it.
+    ///
+    /// The value is clamped to 0.0–1.0 and multiplies the alpha of the
+    /// content's own colors, which are left unchanged. Inside dynamic
+    /// containers, wrap boxed children with
+    /// [`Opacity::new`](crate::elements::Opacity::new) instead.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// let hint = Text::new("Optional").opacity(0.6);
+    /// assert_eq!(hint.opacity, 0.6);
+    /// assert_eq!(hint.content.content, "Optional");
+    /// ```
+    fn opacity(self, opacity: f32) -> Opacity<Self>
+    where
+        Self: Sized,
+    {
+        Opacity::new(self, opacity)
+    }
+
+    /// Draw a badge over the top trailing corner of this view.
+    ///
+    /// The badge starts as a plain red dot; give it a count or text with
+    /// [`Badge::count`](crate::elements::Badge::count) or
+    /// [`Badge::text`](crate::elements::Badge::text). Inside dynamic
+    /// containers, wrap boxed children with
+    /// [`Badge::new`](crate::elements::Badge::new) instead.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// let mail = Text::new("✉").badge().count(5);
+    /// assert_eq!(mail.content.unwrap().content, "5");
+    /// assert_eq!(mail.child.content, "✉");
+    /// ```
+    fn badge(self) -> Badge<Self>
+    where
+        Self: Sized,
+    {
+        Badge::new(self)
+    }
+
+    /// Attach help text shown while the pointer hovers over this view.
+    ///
+    /// The text appears above the view unless another
+    /// [`placement`](crate::elements::Tooltip::placement) is set. Inside
+    /// dynamic containers, wrap boxed children with
+    /// [`Tooltip::new`](crate::elements::Tooltip::new) instead.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ironwood::{prelude::*, elements::TooltipPlacement};
+    ///
+    /// let help = Text::new("ⓘ").tooltip("Applies to new projects only");
+    /// assert_eq!(help.text.content, "Applies to new projects only");
+    /// assert_eq!(help.placement, TooltipPlacement::Top);
+    /// ```
+    fn tooltip(self, text: impl Into<String>) -> Tooltip<Self>
+    where
+        Self: Sized,
+    {
+        Tooltip::new(self, text)
+    }
+
+    /// Convert the messages this view produces into a parent's message type.
+    ///
+    /// This lets a component written against its own message type be
+    /// embedded in any parent model. Backends extract the content
+    /// unchanged, and [`MappedView::route`] applies `f` to messages coming
+    /// back from it.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// #[derive(Debug, Clone, PartialEq)]
+    /// enum FormMessage {
+    ///     Submit(ButtonMessage),
+    /// }
+    ///
+    /// impl Message for FormMessage {}
+    ///
+    /// let submit = Button::new("Submit").view().map_messages(FormMessage::Submit);
+    /// assert_eq!(
+    ///     submit.route(ButtonMessage::Clicked),
+    ///     FormMessage::Submit(ButtonMessage::Clicked)
+    /// );
+    /// ```
+    fn map_messages<M, P>(
+        self,
+        f: impl Fn(M) -> P + Send + Sync + 'static,
+    ) -> MappedView<Self, M, P>
+    where
+        Self: Sized,
+        M: Message,
+        P: Message,
+    {
+        MappedView::new(self, f)
+    }
 }
 
 // Dynamic view collection implementation
@@ -85,6 +285,23 @@ impl View for Vec<Box<dyn View>> {
     }
 }
 
+/// Homogeneous view collection implementation
+///
+/// When every child has the same concrete type, a `Vec<V>` can be extracted
+/// statically without the type erasure of `Vec<Box<dyn View>>`.
+impl<V: View> View for Vec<V> {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Fixed-size array implementation for a known number of identical views
+impl<V: View, const N: usize> View for [V; N] {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
 /// Unit type implementation for utility types that don't have visual representation
 impl View for () {
     fn as_any(&self) -> &dyn Any {
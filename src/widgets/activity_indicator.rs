@@ -0,0 +1,173 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Activity indicator component that steps a spinner through its segments
+//!
+//! An ActivityIndicator wraps a [`Spinner`] and cycles through a fixed number
+//! of segments, one per tick, so that backends can draw the segment at the
+//! current phase highlighted. The application decides how often to tick, so
+//! the indicator never needs a clock of its own.
+
+use std::any::Any;
+
+use crate::{elements::Spinner, message::Message, model::Model, view::View};
+
+/// Number of segments an indicator cycles through unless configured otherwise.
+const DEFAULT_SEGMENTS: usize = 8;
+
+/// View representation of an activity indicator's visual state.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ActivityIndicatorView {
+    /// How the indicator looks
+    pub spinner: Spinner,
+    /// The highlighted segment, always less than `segments`
+    pub phase: usize,
+    /// Number of segments in one full turn
+    pub segments: usize,
+}
+
+impl View for ActivityIndicatorView {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Messages that drive an ActivityIndicator component.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ActivityIndicatorMessage {
+    /// Advance to the next segment, wrapping after a full turn
+    Tick,
+}
+
+impl Message for ActivityIndicatorMessage {}
+
+/// Spinner that cycles through segments on each tick.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{prelude::*, widgets::{ActivityIndicator, ActivityIndicatorMessage}};
+///
+/// let indicator = ActivityIndicator::new().segments(4);
+/// let indicator = (0..5).fold(indicator, |indicator, _| {
+///     indicator.update(ActivityIndicatorMessage::Tick)
+/// });
+/// assert_eq!(indicator.phase, 1);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ActivityIndicator {
+    /// How the indicator looks (set at creation)
+    pub spinner: Spinner,
+    /// The highlighted segment, always less than `segments`
+    pub phase: usize,
+    /// Number of segments in one full turn, at least one
+    pub segments: usize,
+}
+
+impl ActivityIndicator {
+    /// Create an indicator for a default spinner at its first segment.
+    pub fn new() -> Self {
+        Self::with_spinner(Spinner::new())
+    }
+
+    /// Create an indicator that steps the given spinner.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::{prelude::*, elements::{Spinner, SpinnerStyle}, widgets::ActivityIndicator};
+    ///
+    /// let indicator = ActivityIndicator::with_spinner(Spinner::new().style(SpinnerStyle::Dots));
+    /// assert_eq!(indicator.view().spinner.style, SpinnerStyle::Dots);
+    /// ```
+    pub fn with_spinner(spinner: Spinner) -> Self {
+        Self {
+            spinner,
+            phase: 0,
+            segments: DEFAULT_SEGMENTS,
+        }
+    }
+
+    /// Set the number of segments in one full turn.
+    ///
+    /// At least one segment is always kept, and the phase wraps to fit.
+    pub fn segments(mut self, segments: usize) -> Self {
+        self.segments = segments.max(1);
+        self.phase %= self.segments;
+        self
+    }
+}
+
+impl Default for ActivityIndicator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Model for ActivityIndicator {
+    type Message = ActivityIndicatorMessage;
+    type View = ActivityIndicatorView;
+
+    /// Update the indicator's state based on the received message.
+    fn update(self, message: Self::Message) -> Self {
+        match message {
+            ActivityIndicatorMessage::Tick => Self {
+                phase: (self.phase + 1) % self.segments,
+                ..self
+            },
+        }
+    }
+
+    /// Create a view representation of this indicator's current state.
+    fn view(&self) -> Self::View {
+        ActivityIndicatorView {
+            spinner: self.spinner,
+            phase: self.phase,
+            segments: self.segments,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        backends::mock::MockBackend,
+        elements::SpinnerStyle,
+        extraction::{RenderContext, ViewExtractor},
+        style::Color,
+    };
+
+    #[test]
+    fn ticks_wrap_after_a_full_turn() {
+        let indicator = ActivityIndicator::new().segments(3);
+        let phases: Vec<usize> = (0..4)
+            .scan(indicator, |indicator, _| {
+                *indicator = indicator.clone().update(ActivityIndicatorMessage::Tick);
+                Some(indicator.phase)
+            })
+            .collect();
+        assert_eq!(phases, vec![1, 2, 0, 1]);
+
+        let indicator = ActivityIndicator::new()
+            .segments(0)
+            .update(ActivityIndicatorMessage::Tick);
+        assert_eq!(indicator.segments, 1);
+        assert_eq!(indicator.phase, 0);
+    }
+
+    #[test]
+    fn activity_indicator_view_extraction() {
+        let spinner = Spinner::new().style(SpinnerStyle::Bar).color(Color::RED);
+        let indicator =
+            ActivityIndicator::with_spinner(spinner).update(ActivityIndicatorMessage::Tick);
+        let extracted = MockBackend::extract(&indicator.view(), &RenderContext::new()).unwrap();
+        assert_eq!(extracted.phase, 1);
+        assert_eq!(extracted.segments, 8);
+        assert_eq!(extracted.spinner.style, SpinnerStyle::Bar);
+        assert_eq!(extracted.spinner.color, Color::RED);
+    }
+}
+
+// End of File
@@ -12,14 +12,14 @@
 use std::any::Any;
 
 use crate::{
-    elements::Text,
+    elements::{BorderStyle, Text, TextContent},
     interaction::{
         Enableable, Focusable, Hoverable, InteractionMessage, InteractionState, Interactive,
         Pressable,
     },
     message::Message,
     model::Model,
-    style::Color,
+    style::{Color, DisabledStyle, PressFeedback},
     view::View,
 };
 
@@ -32,10 +32,20 @@ use crate::{
 pub struct ButtonView {
     /// The text content of the button
     pub text: Text,
-    /// Background color of the button
-    pub background_color: Color,
+    /// Background color of the button, or `None` to use the environment's accent color
+    pub background_color: Option<Color>,
     /// Current interaction state (enabled, pressed, focused, hovered)
     pub interaction_state: InteractionState,
+    /// Maximum width available to the label before it is truncated
+    pub max_label_width: Option<f32>,
+    /// Appearance applied while the button is disabled
+    pub disabled_style: DisabledStyle,
+    /// Whether the colors come from the render context's theme
+    pub themed: bool,
+    /// Outline indicating keyboard focus, present only while focused and enabled
+    pub focus_ring: Option<BorderStyle>,
+    /// Push effect to draw, present only while pressed and enabled
+    pub press_feedback: Option<PressFeedback>,
 }
 
 impl View for ButtonView {
@@ -85,13 +95,36 @@ impl Message for ButtonMessage {}
 pub struct Button {
     /// The text content of the button
     pub text: Text,
-    /// Background color of the button (set at creation)
-    pub background_color: Color,
+    /// Background color of the button, or `None` to use the environment's
+    /// [`AccentColor`](crate::extraction::AccentColor) (set at creation)
+    pub background_color: Option<Color>,
     /// Base interactive functionality (enabled, pressed, focused, hovered states)
     pub interactive: Interactive,
+    /// Maximum width available to the label before it is truncated (set at creation)
+    pub max_label_width: Option<f32>,
+    /// Appearance applied while the button is disabled (set at creation)
+    pub disabled_style: DisabledStyle,
+    /// Whether the colors come from the render context's theme (set at creation)
+    pub themed: bool,
+    /// Outline drawn around the button while it has keyboard focus (set at creation)
+    pub focus_ring_style: BorderStyle,
+    /// Push effect drawn while the button is pressed, if any (set at creation)
+    pub press_feedback_style: Option<PressFeedback>,
 }
 
 impl Button {
+    /// Minimum width in logical pixels that a truncated label is allowed to occupy.
+    ///
+    /// Truncation never shrinks the label area below this size, so buttons with
+    /// aggressive width limits remain large enough to hit reliably.
+    pub const MIN_HIT_TARGET: f32 = 44.0;
+
+    /// Focus outline used unless another one is configured.
+    pub const DEFAULT_FOCUS_RING: BorderStyle = BorderStyle::new(2.0, Color::BLUE);
+
+    /// Background used when neither the button nor the environment sets one.
+    pub const DEFAULT_BACKGROUND: Color = Color::rgb(0.9, 0.9, 0.9);
+
     /// Create a new button with the specified text.
     ///
     /// The button starts with default styling and is enabled.
@@ -105,16 +138,26 @@ impl Button {
     /// assert_eq!(button.text.content, "Click me!");
     /// assert!(button.is_enabled());
     /// ```
-    pub fn new(text: impl Into<String>) -> Self {
+    pub fn new(text: impl Into<TextContent>) -> Self {
         Self {
             text: Text::new(text),
-            background_color: Color::rgb(0.9, 0.9, 0.9), // Light gray
+            background_color: None,
             interactive: Interactive::new(),
+            max_label_width: None,
+            disabled_style: DisabledStyle::default(),
+            themed: false,
+            focus_ring_style: Self::DEFAULT_FOCUS_RING,
+            press_feedback_style: None,
         }
     }
 
     /// Set the background color for this button.
     ///
+    /// Buttons without a background color are filled with the render
+    /// context's [`AccentColor`](crate::extraction::AccentColor) when extracted, or with
+    /// [`DEFAULT_BACKGROUND`](Self::DEFAULT_BACKGROUND) if that isn't set,
+    /// so a whole app can be re-colored from one context value.
+    ///
     /// # Examples
     ///
     /// ```
@@ -122,13 +165,186 @@ impl Button {
     ///
     /// let button = Button::new("Action")
     ///     .background_color(Color::BLUE);
-    /// assert_eq!(button.background_color, Color::BLUE);
+    /// assert_eq!(button.background_color, Some(Color::BLUE));
+    /// assert_eq!(Button::new("Plain").background_color, None);
     /// ```
     pub fn background_color(mut self, color: Color) -> Self {
-        self.background_color = color;
+        self.background_color = Some(color);
+        self
+    }
+
+    /// Take this button's colors from the theme instead of its own settings.
+    ///
+    /// Themed buttons are filled with [`SemanticColor::Accent`] and label
+    /// their text in [`SemanticColor::OnAccent`], so they follow the render
+    /// context's theme and high-contrast setting. The button's background
+    /// color and label color are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// let button = Button::new("Continue").themed();
+    /// assert!(button.themed);
+    /// ```
+    ///
+    /// [`SemanticColor::Accent`]: crate::style::SemanticColor::Accent
+    /// [`SemanticColor::OnAccent`]: crate::style::SemanticColor::OnAccent
+    pub fn themed(mut self) -> Self {
+        self.themed = true;
+        self
+    }
+
+    /// Set the outline drawn around this button while it has keyboard focus.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::{prelude::*, elements::BorderStyle};
+    ///
+    /// let style = BorderStyle::new(3.0, Color::ORANGE).corner_radius(6.0);
+    /// let button = Button::new("Next").focus_ring_style(style);
+    /// assert_eq!(button.focus().focus_ring(), Some(style));
+    /// ```
+    pub fn focus_ring_style(mut self, style: BorderStyle) -> Self {
+        self.focus_ring_style = style;
+        self
+    }
+
+    /// Get the focus outline to draw, if the button shows one.
+    ///
+    /// Only enabled buttons with keyboard focus show a focus ring, so
+    /// backends can draw focus indicators without checking the state
+    /// themselves.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// let button = Button::new("Save");
+    /// assert_eq!(button.focus_ring(), None);
+    /// assert_eq!(button.clone().focus().focus_ring(), Some(Button::DEFAULT_FOCUS_RING));
+    /// assert_eq!(button.focus().disable().focus_ring(), None);
+    /// ```
+    pub fn focus_ring(&self) -> Option<BorderStyle> {
+        (self.is_enabled() && self.is_focused()).then_some(self.focus_ring_style)
+    }
+
+    /// Set the push effect drawn while this button is pressed.
+    ///
+    /// Buttons have no press feedback unless one is configured.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::{prelude::*, style::PressFeedback};
+    ///
+    /// let button = Button::new("Go").press_feedback_style(PressFeedback::new(0.95));
+    /// assert_eq!(button.press_feedback_style, Some(PressFeedback::new(0.95)));
+    /// ```
+    pub fn press_feedback_style(mut self, feedback: PressFeedback) -> Self {
+        self.press_feedback_style = Some(feedback);
+        self
+    }
+
+    /// Get the push effect to draw, if the button shows one.
+    ///
+    /// Feedback only applies while an enabled button is pressed, so it
+    /// disappears as soon as the press is released.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::{prelude::*, style::PressFeedback};
+    ///
+    /// let button = Button::new("Go").press_feedback_style(PressFeedback::new(0.95));
+    /// assert_eq!(button.press_feedback(), None);
+    /// assert_eq!(button.clone().press().press_feedback(), Some(PressFeedback::new(0.95)));
+    /// assert_eq!(button.press().disable().press_feedback(), None);
+    /// ```
+    pub fn press_feedback(&self) -> Option<PressFeedback> {
+        self.press_feedback_style
+            .filter(|_| self.is_enabled() && self.is_pressed())
+    }
+
+    /// Limit the width available to the button's label.
+    ///
+    /// Labels that would not fit within `width` logical pixels are truncated
+    /// with an ellipsis when the button is laid out. The limit is never allowed
+    /// to drop below [`Button::MIN_HIT_TARGET`]. Buttons without a limit always
+    /// show their full label.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// let button = Button::new("Export Current Selection").max_label_width(80.0);
+    /// assert_eq!(button.max_label_width, Some(80.0));
+    /// ```
+    pub fn max_label_width(mut self, width: f32) -> Self {
+        self.max_label_width = Some(width);
         self
     }
 
+    /// Set how this button appears while disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::{prelude::*, style::DisabledStyle};
+    ///
+    /// let button = Button::new("Delete").disabled_style(DisabledStyle::new().opacity(0.3));
+    /// assert_eq!(button.disabled_style.opacity, 0.3);
+    /// ```
+    pub fn disabled_style(mut self, style: DisabledStyle) -> Self {
+        self.disabled_style = style;
+        self
+    }
+
+    /// Disable this button and dim its colors to match.
+    ///
+    /// The background and label are desaturated to gray, the background is
+    /// lightened halfway to white, and the label is blended halfway into the
+    /// new background so it reads as inactive. An unset background is
+    /// treated as [`DEFAULT_BACKGROUND`](Self::DEFAULT_BACKGROUND). The
+    /// result no longer follows the theme or the accent color, and its
+    /// disabled opacity is reset to fully opaque because the colors already
+    /// carry the dimming.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// let button = Button::new("Submit")
+    ///     .background_color(Color::BLUE)
+    ///     .with_text(|text| text.color(Color::WHITE))
+    ///     .as_disabled_style();
+    ///
+    /// assert!(!button.is_enabled());
+    /// let background = button.background_color.unwrap();
+    /// assert_eq!(background, Color::BLUE.desaturate(1.0).mix(&Color::WHITE, 0.5));
+    /// assert_eq!(button.text.style.color, Color::WHITE.mix(&background, 0.5));
+    /// ```
+    pub fn as_disabled_style(self) -> Self {
+        let background = self
+            .background_color
+            .unwrap_or(Self::DEFAULT_BACKGROUND)
+            .desaturate(1.0)
+            .mix(&Color::WHITE, 0.5);
+        let text = self.text.style.color.desaturate(1.0).mix(&background, 0.5);
+        Self {
+            background_color: Some(background),
+            themed: false,
+            disabled_style: DisabledStyle::new().opacity(1.0),
+            ..self.with_text(|label| label.color(text))
+        }
+        .disable()
+    }
+
     /// Configure the text content of this button.
     ///
     /// This method allows fluent configuration of the button's text styling
@@ -152,6 +368,72 @@ impl Button {
         self.text = f(self.text);
         self
     }
+
+    /// Capture this button's full interaction state.
+    ///
+    /// Together with [`Button::restore_interaction`], this lets a parent that
+    /// rebuilds a button from scratch carry transient state such as hover and
+    /// focus over to the new instance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// let button = Button::new("Save").hover();
+    /// assert!(button.interaction_snapshot().is_hovered());
+    /// ```
+    pub fn interaction_snapshot(&self) -> InteractionState {
+        self.interactive.state
+    }
+
+    /// Replace this button's interaction state with a previously captured snapshot.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// let old = Button::new("Save").hover().focus();
+    /// let rebuilt = Button::new("Save changes").restore_interaction(old.interaction_snapshot());
+    /// assert!(rebuilt.is_hovered());
+    /// assert!(rebuilt.is_focused());
+    /// ```
+    pub fn restore_interaction(self, state: InteractionState) -> Self {
+        Self {
+            interactive: Interactive::with_state(state),
+            ..self
+        }
+    }
+
+    /// Check whether this button is enabled and pressed, hovered, or focused.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// let button = Button::new("Save").hover();
+    /// assert!(button.is_active());
+    /// assert!(!button.disable().is_active());
+    /// ```
+    pub fn is_active(&self) -> bool {
+        self.interactive.is_active()
+    }
+
+    /// Check whether this button is enabled and can receive focus.
+    pub fn is_interactive(&self) -> bool {
+        self.interactive.is_interactive()
+    }
+}
+
+impl Default for Button {
+    /// Create an enabled button with an empty label and default styling.
+    ///
+    /// This allows parent models containing buttons to derive `Default`.
+    fn default() -> Self {
+        Self::new("")
+    }
 }
 
 impl Model for Button {
@@ -205,10 +487,18 @@ impl Model for Button {
             text: self.text.clone(),
             background_color: self.background_color,
             interaction_state: self.interactive.state,
+            max_label_width: self.max_label_width,
+            disabled_style: self.disabled_style,
+            themed: self.themed,
+            focus_ring: self.focus_ring(),
+            press_feedback: self.press_feedback(),
         }
     }
 }
 
+// Matches the inherent methods so `Button` works with generic helpers
+crate::delegate_interaction!(Button, interactive: PreserveInteraction);
+
 impl Enableable for Button {
     /// Check if this button is currently enabled for user interaction.
     ///
@@ -478,7 +768,7 @@ mod tests {
         // Test basic button creation
         let button = Button::new("Click me");
         assert_eq!(button.text.content, "Click me");
-        assert_eq!(button.background_color, Color::rgb(0.9, 0.9, 0.9));
+        assert_eq!(button.background_color, None);
         assert_eq!(button.text.style.color, Color::BLACK);
         assert!(button.is_enabled());
         assert!(!button.is_pressed());
@@ -494,11 +784,38 @@ mod tests {
             .with_text(|text| text.color(Color::WHITE))
             .disable();
 
-        assert_eq!(styled_button.background_color, Color::BLUE);
+        assert_eq!(styled_button.background_color, Some(Color::BLUE));
         assert_eq!(styled_button.text.style.color, Color::WHITE);
         assert!(!styled_button.is_enabled());
     }
 
+    #[test]
+    fn disabled_style_dims_colors_in_one_call() {
+        let dimmed = Button::new("Send")
+            .background_color(Color::BLUE)
+            .with_text(|text| text.color(Color::WHITE))
+            .themed()
+            .as_disabled_style();
+
+        assert!(!dimmed.is_enabled());
+        assert!(!dimmed.themed);
+        assert_eq!(dimmed.disabled_style.opacity, 1.0);
+
+        let background = dimmed.background_color.unwrap();
+        assert_eq!(background.to_hsl().1, 0.0);
+        assert!(background.relative_luminance() > Color::BLUE.relative_luminance());
+        let text = dimmed.text.style.color;
+        assert_eq!(text.to_hsl().1, 0.0);
+        assert!(text.contrast_ratio(&background) < Color::WHITE.contrast_ratio(&Color::BLUE));
+
+        // Buttons without a background dim the default fill
+        let plain = Button::new("Plain").as_disabled_style();
+        assert_eq!(
+            plain.background_color,
+            Some(Button::DEFAULT_BACKGROUND.mix(&Color::WHITE, 0.5))
+        );
+    }
+
     #[test]
     fn button_interaction_handling() {
         let button = Button::new("Test");
@@ -562,7 +879,7 @@ mod tests {
             .enable();
 
         assert_eq!(button.text.content, "Builder Test");
-        assert_eq!(button.background_color, Color::GREEN);
+        assert_eq!(button.background_color, Some(Color::GREEN));
         assert_eq!(button.text.style.color, Color::BLACK);
         assert!(button.is_enabled());
     }
@@ -619,6 +936,51 @@ mod tests {
         assert!(!button.is_focused());
         assert!(!button.is_hovered());
     }
+
+    #[test]
+    fn parent_models_can_derive_default() {
+        #[derive(Debug, Default)]
+        struct Dialog {
+            confirm: Button,
+            cancel: Button,
+        }
+
+        let dialog = Dialog::default();
+        assert_eq!(dialog.confirm.text.content, "");
+        assert!(dialog.confirm.is_enabled());
+        assert_eq!(dialog.cancel, Button::new(""));
+    }
+
+    #[test]
+    fn interaction_state_survives_rebuild() {
+        let original = Button::new("Original").hover().focus();
+        let snapshot = original.interaction_snapshot();
+
+        let rebuilt = Button::new("Rebuilt");
+        assert!(!rebuilt.is_hovered());
+        assert!(!rebuilt.is_focused());
+
+        let restored = rebuilt.restore_interaction(snapshot);
+        assert!(restored.is_hovered());
+        assert!(restored.is_focused());
+        assert!(restored.is_enabled());
+        assert_eq!(restored.text.content, "Rebuilt");
+    }
+
+    #[test]
+    fn active_and_interactive_queries() {
+        let button = Button::new("Query");
+        assert!(!button.is_active());
+        assert!(button.is_interactive());
+
+        assert!(button.clone().hover().is_active());
+        assert!(button.clone().press().is_active());
+        assert!(button.clone().focus().is_active());
+
+        let disabled = button.hover().focus().disable();
+        assert!(!disabled.is_active());
+        assert!(!disabled.is_interactive());
+    }
 }
 
 // End of File
@@ -0,0 +1,308 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Checkbox component for boolean and tri-state toggles
+//!
+//! The Checkbox component represents a labelled on/off control. In addition to
+//! the checked and unchecked states it supports an indeterminate state, which
+//! parent "select all" controls use to show that only some of their children
+//! are selected. Like Button, a Checkbox is a model that produces CheckboxView
+//! instances through its view() method.
+
+use std::any::Any;
+
+use crate::{
+    elements::{Text, TextContent},
+    interaction::{Enableable, InteractionMessage, InteractionState, Interactive},
+    message::Message,
+    model::Model,
+    style::DisabledStyle,
+    view::View,
+};
+
+/// The check state of a checkbox.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{prelude::*, widgets::CheckState};
+///
+/// assert_eq!(CheckState::Unchecked.toggled(), CheckState::Checked);
+/// assert_eq!(CheckState::Checked.toggled(), CheckState::Unchecked);
+/// assert_eq!(CheckState::Indeterminate.toggled(), CheckState::Checked);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CheckState {
+    /// The checkbox is not checked
+    #[default]
+    Unchecked,
+    /// The checkbox is checked
+    Checked,
+    /// The checkbox represents a mix of checked and unchecked items
+    Indeterminate,
+}
+
+impl CheckState {
+    /// Get the state that results from the user toggling the checkbox.
+    ///
+    /// Checked and unchecked swap with each other. An indeterminate checkbox
+    /// becomes checked, matching how "select all" controls behave.
+    pub fn toggled(self) -> Self {
+        match self {
+            CheckState::Unchecked | CheckState::Indeterminate => CheckState::Checked,
+            CheckState::Checked => CheckState::Unchecked,
+        }
+    }
+
+    /// Check whether this state is fully checked.
+    pub fn is_checked(self) -> bool {
+        self == CheckState::Checked
+    }
+}
+
+impl From<bool> for CheckState {
+    fn from(checked: bool) -> Self {
+        if checked {
+            CheckState::Checked
+        } else {
+            CheckState::Unchecked
+        }
+    }
+}
+
+/// View representation of a checkbox's visual state.
+///
+/// This is a pure data structure that describes how a checkbox should appear,
+/// including its check state, label, and current interaction state.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CheckboxView {
+    /// The current check state
+    pub state: CheckState,
+    /// The label displayed next to the checkbox
+    pub label: Text,
+    /// Current interaction state (enabled, pressed, focused, hovered)
+    pub interaction_state: InteractionState,
+    /// Appearance applied while the checkbox is disabled
+    pub disabled_style: DisabledStyle,
+}
+
+impl View for CheckboxView {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Messages that represent user interactions with a Checkbox component.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CheckboxMessage {
+    /// The user toggled the checkbox
+    Toggled,
+    /// Standard interaction (enabled, pressed, focused, hovered state changes)
+    Interaction(InteractionMessage),
+}
+
+impl Message for CheckboxMessage {}
+
+/// Checkbox component that maintains its check state and responds to user interactions.
+///
+/// Toggling a disabled checkbox has no effect.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{prelude::*, widgets::CheckState};
+///
+/// let select_all = Checkbox::new("Select all").state(CheckState::Indeterminate);
+///
+/// let select_all = select_all.update(CheckboxMessage::Toggled);
+/// assert_eq!(select_all.state, CheckState::Checked);
+///
+/// let select_all = select_all.update(CheckboxMessage::Toggled);
+/// assert_eq!(select_all.state, CheckState::Unchecked);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Checkbox {
+    /// The label displayed next to the checkbox
+    pub label: Text,
+    /// The current check state
+    pub state: CheckState,
+    /// Base interactive functionality (enabled, pressed, focused, hovered states)
+    pub interactive: Interactive,
+    /// Appearance applied while the checkbox is disabled (set at creation)
+    pub disabled_style: DisabledStyle,
+}
+
+impl Checkbox {
+    /// Create a new unchecked checkbox with the specified label.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::{prelude::*, widgets::CheckState};
+    ///
+    /// let checkbox = Checkbox::new("Remember me");
+    /// assert_eq!(checkbox.label.content, "Remember me");
+    /// assert_eq!(checkbox.state, CheckState::Unchecked);
+    /// assert!(checkbox.is_enabled());
+    /// ```
+    pub fn new(label: impl Into<TextContent>) -> Self {
+        Self {
+            label: Text::new(label),
+            state: CheckState::Unchecked,
+            interactive: Interactive::new(),
+            disabled_style: DisabledStyle::default(),
+        }
+    }
+
+    /// Set the check state of this checkbox.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// let checkbox = Checkbox::new("Notifications").state(true.into());
+    /// assert!(checkbox.is_checked());
+    /// ```
+    pub fn state(mut self, state: CheckState) -> Self {
+        self.state = state;
+        self
+    }
+
+    /// Set how this checkbox appears while disabled.
+    pub fn disabled_style(mut self, style: DisabledStyle) -> Self {
+        self.disabled_style = style;
+        self
+    }
+
+    /// Check whether this checkbox is fully checked.
+    pub fn is_checked(&self) -> bool {
+        self.state.is_checked()
+    }
+
+    /// Configure the label of this checkbox.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// let checkbox = Checkbox::new("Agree").with_label(|label| label.font_size(14.0));
+    /// assert_eq!(checkbox.label.style.font_size, 14.0);
+    /// ```
+    pub fn with_label<F>(mut self, f: F) -> Self
+    where
+        F: FnOnce(Text) -> Text,
+    {
+        self.label = f(self.label);
+        self
+    }
+}
+
+impl Model for Checkbox {
+    type Message = CheckboxMessage;
+    type View = CheckboxView;
+
+    /// Update the checkbox's state based on the received message.
+    fn update(self, message: Self::Message) -> Self {
+        match message {
+            CheckboxMessage::Toggled if self.is_enabled() => Self {
+                state: self.state.toggled(),
+                ..self
+            },
+            CheckboxMessage::Toggled => self,
+            CheckboxMessage::Interaction(interaction_msg) => Self {
+                interactive: self.interactive.update(interaction_msg),
+                ..self
+            },
+        }
+    }
+
+    /// Create a view representation of this checkbox's current state.
+    fn view(&self) -> Self::View {
+        CheckboxView {
+            state: self.state,
+            label: self.label.clone(),
+            interaction_state: self.interactive.state,
+            disabled_style: self.disabled_style,
+        }
+    }
+}
+
+crate::delegate_interaction!(
+    Checkbox,
+    interactive: Enableable,
+    Focusable,
+    Hoverable,
+    PreserveInteraction
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        backends::mock::MockBackend,
+        extraction::{RenderContext, ViewExtractor},
+        interaction::{Focusable, Hoverable},
+    };
+
+    #[test]
+    fn toggling_cycles_check_state() {
+        let checkbox = Checkbox::new("Option");
+        assert_eq!(checkbox.state, CheckState::Unchecked);
+
+        let checkbox = checkbox.update(CheckboxMessage::Toggled);
+        assert_eq!(checkbox.state, CheckState::Checked);
+        assert!(checkbox.is_checked());
+
+        let checkbox = checkbox.update(CheckboxMessage::Toggled);
+        assert_eq!(checkbox.state, CheckState::Unchecked);
+
+        let mixed = Checkbox::new("Select all").state(CheckState::Indeterminate);
+        assert!(!mixed.is_checked());
+        assert_eq!(
+            mixed.update(CheckboxMessage::Toggled).state,
+            CheckState::Checked
+        );
+    }
+
+    #[test]
+    fn disabled_checkbox_ignores_toggle() {
+        let checkbox = Checkbox::new("Locked").state(CheckState::Checked).update(
+            CheckboxMessage::Interaction(InteractionMessage::EnabledChanged(false)),
+        );
+        assert!(!checkbox.is_enabled());
+
+        let toggled = checkbox.clone().update(CheckboxMessage::Toggled);
+        assert_eq!(toggled, checkbox);
+    }
+
+    #[test]
+    fn checkbox_interaction_traits() {
+        let checkbox = Checkbox::new("Traits").focus().hover();
+        assert!(checkbox.is_focused());
+        assert!(checkbox.is_hovered());
+
+        let checkbox = checkbox.unfocus().unhover().disable();
+        assert!(!checkbox.is_focused());
+        assert!(!checkbox.is_hovered());
+        assert!(!checkbox.can_receive_focus());
+    }
+
+    #[test]
+    fn checkbox_view_extraction() {
+        let ctx = RenderContext::new();
+        let checkbox = Checkbox::new("Subscribe")
+            .state(CheckState::Indeterminate)
+            .hover();
+
+        let extracted = MockBackend::extract(&checkbox.view(), &ctx).unwrap();
+        assert_eq!(extracted.state, CheckState::Indeterminate);
+        assert_eq!(extracted.label, "Subscribe");
+        assert!(extracted.interaction_state.is_hovered());
+    }
+}
+
+// End of File
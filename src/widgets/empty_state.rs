@@ -0,0 +1,197 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Placeholder shown when a list or screen has nothing to display
+//!
+//! EmptyState combines an icon, a title, an explanatory message, and an
+//! optional button that suggests what to do next, such as creating the first
+//! item. The button is configured with the parent's own message, so the
+//! parent can react to a press without knowing how the placeholder is built.
+
+use crate::{
+    elements::{Alignment, Image, Text, VStack},
+    interaction::Enableable,
+    message::Message,
+    model::Model,
+    widgets::{Button, ButtonMessage, ButtonView},
+};
+
+/// Font size of the title, larger than body text so it reads as a heading.
+const TITLE_FONT_SIZE: f32 = 20.0;
+
+/// Messages that represent user interactions with an EmptyState component.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EmptyStateMessage {
+    /// The action button received a message
+    Action(ButtonMessage),
+}
+
+impl Message for EmptyStateMessage {}
+
+/// An empty-state placeholder whose button routes to a parent message of type `A`.
+///
+/// Parents forward [`EmptyStateMessage`]s to [`update`](Model::update) as
+/// usual and ask [`action_for`](EmptyState::action_for) whether the message
+/// pressed the button.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{prelude::*, widgets::{EmptyState, EmptyStateMessage}};
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// enum AppMessage {
+///     CreateProject,
+/// }
+///
+/// impl Message for AppMessage {}
+///
+/// let empty = EmptyState::new("folder", "No projects", "Projects you create appear here.")
+///     .action("Create", AppMessage::CreateProject);
+///
+/// let pressed = EmptyStateMessage::Action(ButtonMessage::Clicked);
+/// assert_eq!(empty.action_for(&pressed), Some(AppMessage::CreateProject));
+/// ```
+#[derive(Debug, Clone)]
+pub struct EmptyState<A> {
+    /// Name of the icon image shown above the title
+    pub icon: String,
+    /// Short headline, such as "No results"
+    pub title: String,
+    /// Longer explanation of why nothing is shown or what to do
+    pub message: String,
+    /// Button and the message it routes to the parent, if any
+    pub action: Option<(Button, A)>,
+}
+
+impl<A> EmptyState<A> {
+    /// Create an empty state without an action button.
+    pub fn new(
+        icon: impl Into<String>,
+        title: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            icon: icon.into(),
+            title: title.into(),
+            message: message.into(),
+            action: None,
+        }
+    }
+
+    /// Add a button that routes `message` to the parent when pressed.
+    pub fn action(mut self, label: impl Into<String>, message: A) -> Self {
+        self.action = Some((Button::new(label.into()), message));
+        self
+    }
+}
+
+impl<A: Clone> EmptyState<A> {
+    /// Get the parent message a child message triggers, if any.
+    ///
+    /// Only a click on an enabled action button triggers the configured
+    /// message; interaction changes such as hovering do not.
+    pub fn action_for(&self, message: &EmptyStateMessage) -> Option<A> {
+        match (message, &self.action) {
+            (EmptyStateMessage::Action(ButtonMessage::Clicked), Some((button, action)))
+                if button.is_enabled() =>
+            {
+                Some(action.clone())
+            }
+            _ => None,
+        }
+    }
+}
+
+impl<A: Message> Model for EmptyState<A> {
+    type Message = EmptyStateMessage;
+    type View = VStack<(Image, Text, Text, Option<ButtonView>)>;
+
+    /// Update the action button's state based on the received message.
+    fn update(self, message: Self::Message) -> Self {
+        match message {
+            EmptyStateMessage::Action(button_msg) => Self {
+                action: self
+                    .action
+                    .map(|(button, action)| (button.update(button_msg), action)),
+                ..self
+            },
+        }
+    }
+
+    /// Create a centered column of icon, title, message, and optional button.
+    fn view(&self) -> Self::View {
+        VStack::new((
+            Image::named(&self.icon),
+            Text::new(&self.title).font_size(TITLE_FONT_SIZE),
+            Text::new(&self.message),
+            self.action.as_ref().map(|(button, _)| button.view()),
+        ))
+        .alignment(Alignment::Center)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        backends::mock::MockBackend,
+        elements::ImageSource,
+        extraction::{RenderContext, ViewExtractor},
+        interaction::{InteractionMessage, InteractionState},
+    };
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum ListMessage {
+        Create,
+    }
+
+    impl Message for ListMessage {}
+
+    #[test]
+    fn empty_state_extracts_every_part() {
+        let empty = EmptyState::new("inbox", "No items", "Create one to get started.")
+            .action("Create", ListMessage::Create);
+
+        let extracted = MockBackend::extract(&empty.view(), &RenderContext::new()).unwrap();
+        let (icon, title, message, button) = extracted.content;
+        assert_eq!(icon.source, ImageSource::Named("inbox".to_string()));
+        assert_eq!(title.content, "No items");
+        assert!(title.font_size > message.font_size);
+        assert_eq!(message.content, "Create one to get started.");
+        assert_eq!(button.unwrap().text, "Create");
+        assert_eq!(extracted.alignment, Alignment::Center);
+    }
+
+    #[test]
+    fn action_button_is_optional() {
+        let empty = EmptyState::<ListMessage>::new("search", "No results", "Try another search.");
+        let extracted = MockBackend::extract(&empty.view(), &RenderContext::new()).unwrap();
+        assert!(extracted.content.3.is_none());
+
+        let pressed = EmptyStateMessage::Action(ButtonMessage::Clicked);
+        assert_eq!(empty.action_for(&pressed), None);
+    }
+
+    #[test]
+    fn pressing_button_routes_configured_message() {
+        let empty = EmptyState::new("inbox", "No items", "Create one to get started.")
+            .action("Create", ListMessage::Create);
+
+        let pressed = EmptyStateMessage::Action(ButtonMessage::Clicked);
+        assert_eq!(empty.action_for(&pressed), Some(ListMessage::Create));
+
+        let hovered = EmptyStateMessage::Action(ButtonMessage::Interaction(
+            InteractionMessage::HoverChanged(true),
+        ));
+        assert_eq!(empty.action_for(&hovered), None);
+
+        // Interaction messages reach the button
+        let empty = empty.update(hovered);
+        let button = empty.view().content.3.unwrap();
+        assert!(button.interaction_state.contains(InteractionState::HOVERED));
+    }
+}
+
+// End of File
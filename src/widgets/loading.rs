@@ -0,0 +1,130 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Loading indicator composed of a spinner and a status label
+//!
+//! LoadingView is a small composite: an ActivityIndicator stacked above an
+//! optional line of status text. It shows how widgets can be built from
+//! existing components rather than needing a view type of their own.
+
+use crate::{
+    elements::{Text, VStack},
+    message::Message,
+    model::Model,
+    widgets::{ActivityIndicator, ActivityIndicatorMessage, ActivityIndicatorView},
+};
+
+/// Messages that drive a LoadingView component.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LoadingMessage {
+    /// Advance the spinner by one segment
+    Tick,
+}
+
+impl Message for LoadingMessage {}
+
+/// A spinner with an optional status label underneath.
+///
+/// Ticks only advance the spinner; the label changes only when the
+/// application sets a new one.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{prelude::*, widgets::{LoadingMessage, LoadingView}};
+///
+/// let loading = LoadingView::new().label("Loading…").update(LoadingMessage::Tick);
+/// let view = loading.view();
+/// assert_eq!(view.content.0.phase, 1);
+/// assert_eq!(view.content.1.unwrap().content, "Loading…");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoadingView {
+    /// The spinning indicator
+    pub indicator: ActivityIndicator,
+    /// Status text shown below the spinner, if any
+    pub label: Option<String>,
+}
+
+impl LoadingView {
+    /// Create a loading indicator without a label.
+    pub fn new() -> Self {
+        Self {
+            indicator: ActivityIndicator::new(),
+            label: None,
+        }
+    }
+
+    /// Set the status text shown below the spinner.
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Use a custom indicator, for example one with a different segment count.
+    pub fn indicator(mut self, indicator: ActivityIndicator) -> Self {
+        self.indicator = indicator;
+        self
+    }
+}
+
+impl Default for LoadingView {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Model for LoadingView {
+    type Message = LoadingMessage;
+    type View = VStack<(ActivityIndicatorView, Option<Text>)>;
+
+    /// Update the indicator's state based on the received message.
+    fn update(self, message: Self::Message) -> Self {
+        match message {
+            LoadingMessage::Tick => Self {
+                indicator: self.indicator.update(ActivityIndicatorMessage::Tick),
+                ..self
+            },
+        }
+    }
+
+    /// Create a view with the spinner above its label.
+    fn view(&self) -> Self::View {
+        VStack::new((self.indicator.view(), self.label.clone().map(Text::new)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        backends::mock::MockBackend,
+        extraction::{RenderContext, ViewExtractor},
+    };
+
+    #[test]
+    fn tick_advances_only_the_spinner() {
+        let ctx = RenderContext::new();
+        let loading = LoadingView::new().label("Loading…");
+        let before = MockBackend::extract(&loading.view(), &ctx).unwrap();
+
+        let loading = loading.update(LoadingMessage::Tick);
+        let after = MockBackend::extract(&loading.view(), &ctx).unwrap();
+
+        assert_eq!(before.content.0.phase, 0);
+        assert_eq!(after.content.0.phase, 1);
+        assert_eq!(before.content.1, after.content.1);
+        assert_eq!(after.content.1.unwrap().content, "Loading…");
+    }
+
+    #[test]
+    fn label_is_optional() {
+        let loading = LoadingView::new().update(LoadingMessage::Tick);
+        let extracted = MockBackend::extract(&loading.view(), &RenderContext::new()).unwrap();
+        assert_eq!(extracted.content.0.phase, 1);
+        assert!(extracted.content.1.is_none());
+    }
+}
+
+// End of File
@@ -12,8 +12,28 @@
 //! These widgets implement both the Model trait (for state management)
 //! and the View trait (for rendering data).
 
+pub mod activity_indicator;
 pub mod button;
+pub mod checkbox;
+pub mod empty_state;
+pub mod loading;
+pub mod picker;
+pub mod slider;
+pub mod stepper;
+pub mod table;
+pub mod toggle;
+pub mod toolbar;
 
+pub use activity_indicator::*;
 pub use button::*;
+pub use checkbox::*;
+pub use empty_state::*;
+pub use loading::*;
+pub use picker::*;
+pub use slider::*;
+pub use stepper::*;
+pub use table::*;
+pub use toggle::*;
+pub use toolbar::*;
 
 // End of File
@@ -0,0 +1,253 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Picker component for choosing one option from a dropdown list
+//!
+//! The Picker component shows the currently selected option and can be opened
+//! to reveal the full list of options. Choosing an option selects it and closes
+//! the dropdown. Like Button, a Picker is a model that produces PickerView
+//! instances through its view() method.
+
+use std::any::Any;
+
+use crate::{
+    interaction::{Enableable, InteractionMessage, InteractionState, Interactive},
+    message::Message,
+    model::Model,
+    style::DisabledStyle,
+    view::View,
+};
+
+/// View representation of a picker's visual state.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PickerView {
+    /// Label of the selected option, or `None` if there are no options
+    pub selected_label: Option<String>,
+    /// Every option, in display order
+    pub options: Vec<String>,
+    /// Whether the dropdown list is open
+    pub is_open: bool,
+    /// Current interaction state (enabled, pressed, focused, hovered)
+    pub interaction_state: InteractionState,
+    /// Appearance applied while the picker is disabled
+    pub disabled_style: DisabledStyle,
+}
+
+impl View for PickerView {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Messages that represent user interactions with a Picker component.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PickerMessage {
+    /// The user opened the dropdown list
+    Open,
+    /// The dropdown list was dismissed without a choice
+    Close,
+    /// The user chose the option at the given index
+    Choose(usize),
+    /// Standard interaction (enabled, pressed, focused, hovered state changes)
+    Interaction(InteractionMessage),
+}
+
+impl Message for PickerMessage {}
+
+/// Dropdown picker component that selects one option from a list.
+///
+/// A disabled picker can't be opened. Choosing an index past the end of the
+/// list selects the last option.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{prelude::*, widgets::{Picker, PickerMessage}};
+///
+/// let units = Picker::new(["Millimeters", "Inches", "Points"]);
+/// assert_eq!(units.selected_label(), Some("Millimeters"));
+///
+/// let units = units.update(PickerMessage::Open);
+/// assert!(units.is_open);
+///
+/// let units = units.update(PickerMessage::Choose(1));
+/// assert_eq!(units.selected_label(), Some("Inches"));
+/// assert!(!units.is_open);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Picker {
+    /// The options to choose from, in display order
+    pub options: Vec<String>,
+    /// Index of the selected option
+    pub selected: usize,
+    /// Whether the dropdown list is open
+    pub is_open: bool,
+    /// Base interactive functionality (enabled, pressed, focused, hovered states)
+    pub interactive: Interactive,
+    /// Appearance applied while the picker is disabled (set at creation)
+    pub disabled_style: DisabledStyle,
+}
+
+impl Picker {
+    /// Create a closed picker with the first option selected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::{prelude::*, widgets::Picker};
+    ///
+    /// let picker = Picker::new(vec!["Small".to_string(), "Large".to_string()]);
+    /// assert_eq!(picker.selected, 0);
+    /// assert!(!picker.is_open);
+    /// ```
+    pub fn new<I, S>(options: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            options: options.into_iter().map(Into::into).collect(),
+            selected: 0,
+            is_open: false,
+            interactive: Interactive::new(),
+            disabled_style: DisabledStyle::default(),
+        }
+    }
+
+    /// Set the initially selected option, clamped to the available options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::{prelude::*, widgets::Picker};
+    ///
+    /// let picker = Picker::new(["Low", "Medium", "High"]).selected(2);
+    /// assert_eq!(picker.selected_label(), Some("High"));
+    /// ```
+    pub fn selected(mut self, index: usize) -> Self {
+        self.selected = self.clamp_index(index);
+        self
+    }
+
+    /// Set how this picker appears while disabled.
+    pub fn disabled_style(mut self, style: DisabledStyle) -> Self {
+        self.disabled_style = style;
+        self
+    }
+
+    /// Get the label of the selected option, or `None` if there are no options.
+    pub fn selected_label(&self) -> Option<&str> {
+        self.options.get(self.selected).map(String::as_str)
+    }
+
+    /// Clamp an index to the last available option.
+    fn clamp_index(&self, index: usize) -> usize {
+        index.min(self.options.len().saturating_sub(1))
+    }
+}
+
+impl Model for Picker {
+    type Message = PickerMessage;
+    type View = PickerView;
+
+    /// Update the picker's state based on the received message.
+    fn update(self, message: Self::Message) -> Self {
+        match message {
+            PickerMessage::Open if self.is_enabled() => Self {
+                is_open: true,
+                ..self
+            },
+            PickerMessage::Open => self,
+            PickerMessage::Close => Self {
+                is_open: false,
+                ..self
+            },
+            PickerMessage::Choose(index) => Self {
+                selected: self.clamp_index(index),
+                is_open: false,
+                ..self
+            },
+            PickerMessage::Interaction(interaction_msg) => Self {
+                interactive: self.interactive.update(interaction_msg),
+                ..self
+            },
+        }
+    }
+
+    /// Create a view representation of this picker's current state.
+    fn view(&self) -> Self::View {
+        PickerView {
+            selected_label: self.selected_label().map(str::to_string),
+            options: self.options.clone(),
+            is_open: self.is_open,
+            interaction_state: self.interactive.state,
+            disabled_style: self.disabled_style,
+        }
+    }
+}
+
+crate::delegate_interaction!(Picker, interactive);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        backends::mock::MockBackend,
+        extraction::{RenderContext, ViewExtractor},
+        interaction::Hoverable,
+    };
+
+    #[test]
+    fn choosing_selects_and_closes() {
+        let picker = Picker::new(["Red", "Green", "Blue"]).update(PickerMessage::Open);
+        assert!(picker.is_open);
+
+        let picker = picker.update(PickerMessage::Choose(2));
+        assert_eq!(picker.selected, 2);
+        assert!(!picker.is_open);
+
+        // Out-of-range choices clamp to the last option
+        let picker = picker
+            .update(PickerMessage::Open)
+            .update(PickerMessage::Choose(10));
+        assert_eq!(picker.selected_label(), Some("Blue"));
+
+        let picker = picker
+            .update(PickerMessage::Open)
+            .update(PickerMessage::Close);
+        assert!(!picker.is_open);
+        assert_eq!(picker.selected, 2);
+    }
+
+    #[test]
+    fn disabled_picker_does_not_open() {
+        let picker = Picker::new(["Only"]).disable();
+        assert!(!picker.update(PickerMessage::Open).is_open);
+    }
+
+    #[test]
+    fn empty_picker_has_no_selection() {
+        let picker = Picker::new(Vec::<String>::new()).update(PickerMessage::Choose(3));
+        assert_eq!(picker.selected, 0);
+        assert_eq!(picker.selected_label(), None);
+    }
+
+    #[test]
+    fn picker_view_extraction() {
+        let ctx = RenderContext::new();
+        let picker = Picker::new(["Draft", "Published"])
+            .selected(1)
+            .update(PickerMessage::Open)
+            .hover();
+
+        let extracted = MockBackend::extract(&picker.view(), &ctx).unwrap();
+        assert_eq!(extracted.selected_label.as_deref(), Some("Published"));
+        assert_eq!(extracted.options, vec!["Draft", "Published"]);
+        assert!(extracted.is_open);
+        assert!(extracted.interaction_state.is_hovered());
+        assert_eq!(extracted.opacity, 1.0);
+    }
+}
+
+// End of File
@@ -0,0 +1,324 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Slider component for continuous numeric input
+//!
+//! The Slider component lets users pick an `f32` value from an inclusive range
+//! by dragging a thumb along a track. Values are always clamped into the range
+//! and, when a step is configured, snapped to the nearest step. Like Button, a
+//! Slider is a model that produces SliderView instances through its view() method.
+
+use std::{any::Any, ops::RangeInclusive};
+
+use crate::{
+    interaction::{Enableable, InteractionMessage, InteractionState, Interactive},
+    message::Message,
+    model::Model,
+    style::DisabledStyle,
+    view::View,
+};
+
+/// View representation of a slider's visual state.
+///
+/// This is a pure data structure that describes how a slider should appear,
+/// including the current value, the range bounds, and the interaction state
+/// used for thumb pressed/hovered feedback.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SliderView {
+    /// The current value, always within `min..=max`
+    pub value: f32,
+    /// The lower bound of the range
+    pub min: f32,
+    /// The upper bound of the range
+    pub max: f32,
+    /// The step values snap to, if any
+    pub step: Option<f32>,
+    /// Current interaction state (enabled, pressed, focused, hovered)
+    pub interaction_state: InteractionState,
+    /// Appearance applied while the slider is disabled
+    pub disabled_style: DisabledStyle,
+}
+
+impl View for SliderView {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Messages that represent user interactions with a Slider component.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SliderMessage {
+    /// The user dragged the thumb to the given value
+    DragTo(f32),
+    /// The value was set programmatically
+    SetValue(f32),
+    /// Standard interaction (enabled, pressed, focused, hovered state changes)
+    Interaction(InteractionMessage),
+}
+
+impl Message for SliderMessage {}
+
+/// Slider component that holds a value within an inclusive `f32` range.
+///
+/// Dragging a disabled slider has no effect, while `SetValue` always applies
+/// so applications can keep a disabled slider in sync with their data. Both
+/// clamp the value into the range and snap it to the step, if one is set.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::prelude::*;
+///
+/// let volume = Slider::new(0.0..=100.0).step(5.0);
+///
+/// let volume = volume.update(SliderMessage::DragTo(42.0));
+/// assert_eq!(volume.value, 40.0);
+///
+/// let volume = volume.update(SliderMessage::DragTo(150.0));
+/// assert_eq!(volume.value, 100.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Slider {
+    /// The current value, always within `range`
+    pub value: f32,
+    /// The inclusive range of allowed values
+    pub range: RangeInclusive<f32>,
+    /// The step values snap to, if any (set at creation)
+    pub step: Option<f32>,
+    /// Base interactive functionality (enabled, pressed, focused, hovered states)
+    pub interactive: Interactive,
+    /// Appearance applied while the slider is disabled (set at creation)
+    pub disabled_style: DisabledStyle,
+}
+
+impl Slider {
+    /// Create a new slider over the given range, starting at its lower bound.
+    ///
+    /// A reversed range such as `10.0..=0.0` is treated as `0.0..=10.0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either bound is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// let slider = Slider::new(0.0..=1.0);
+    /// assert_eq!(slider.value, 0.0);
+    /// assert!(slider.is_enabled());
+    /// ```
+    pub fn new(range: RangeInclusive<f32>) -> Self {
+        let (start, end) = range.into_inner();
+        assert!(
+            !start.is_nan() && !end.is_nan(),
+            "slider range bounds must not be NaN, got {start}..={end}"
+        );
+        let (min, max) = if start <= end {
+            (start, end)
+        } else {
+            (end, start)
+        };
+        Self {
+            value: min,
+            range: min..=max,
+            step: None,
+            interactive: Interactive::new(),
+            disabled_style: DisabledStyle::default(),
+        }
+    }
+
+    /// Set the initial value, clamped into the range and snapped to the step.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// let slider = Slider::new(0.0..=1.0).value(0.25);
+    /// assert_eq!(slider.value, 0.25);
+    /// ```
+    pub fn value(mut self, value: f32) -> Self {
+        self.value = self.constrain(value);
+        self
+    }
+
+    /// Snap values to multiples of `step` measured from the range's lower bound.
+    ///
+    /// Non-positive steps are ignored. The current value is re-snapped
+    /// immediately so the slider never holds an off-step value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// let slider = Slider::new(0.0..=10.0).value(3.4).step(1.0);
+    /// assert_eq!(slider.value, 3.0);
+    /// ```
+    pub fn step(mut self, step: f32) -> Self {
+        self.step = (step > 0.0).then_some(step);
+        self.value = self.constrain(self.value);
+        self
+    }
+
+    /// Set how this slider appears while disabled.
+    pub fn disabled_style(mut self, style: DisabledStyle) -> Self {
+        self.disabled_style = style;
+        self
+    }
+
+    /// Clamp a value into the range and snap it to the nearest step.
+    ///
+    /// NaN becomes the lower bound.
+    fn constrain(&self, value: f32) -> f32 {
+        let (min, max) = (*self.range.start(), *self.range.end());
+        if value.is_nan() {
+            return min;
+        }
+        let value = match self.step {
+            Some(step) => min + ((value - min) / step).round() * step,
+            None => value,
+        };
+        // Snapping can overshoot the upper bound when the range isn't a whole
+        // number of steps, so clamp last. Unlike `clamp`, this doesn't panic
+        // if the public `range` field was reversed after construction
+        value.max(min).min(max)
+    }
+}
+
+impl Model for Slider {
+    type Message = SliderMessage;
+    type View = SliderView;
+
+    /// Update the slider's state based on the received message.
+    fn update(self, message: Self::Message) -> Self {
+        match message {
+            SliderMessage::DragTo(value) if self.is_enabled() => self.value(value),
+            SliderMessage::DragTo(_) => self,
+            SliderMessage::SetValue(value) => self.value(value),
+            SliderMessage::Interaction(interaction_msg) => Self {
+                interactive: self.interactive.update(interaction_msg),
+                ..self
+            },
+        }
+    }
+
+    /// Create a view representation of this slider's current state.
+    fn view(&self) -> Self::View {
+        SliderView {
+            value: self.value,
+            min: *self.range.start(),
+            max: *self.range.end(),
+            step: self.step,
+            interaction_state: self.interactive.state,
+            disabled_style: self.disabled_style,
+        }
+    }
+}
+
+crate::delegate_interaction!(Slider, interactive);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        backends::mock::MockBackend,
+        extraction::{RenderContext, ViewExtractor},
+        interaction::Pressable,
+    };
+
+    #[test]
+    fn values_are_clamped_into_range() {
+        let slider = Slider::new(-1.0..=1.0);
+        assert_eq!(slider.value, -1.0);
+
+        let slider = slider.update(SliderMessage::SetValue(5.0));
+        assert_eq!(slider.value, 1.0);
+
+        let slider = slider.update(SliderMessage::DragTo(-5.0));
+        assert_eq!(slider.value, -1.0);
+
+        let slider = slider.update(SliderMessage::DragTo(0.3));
+        assert_eq!(slider.value, 0.3);
+    }
+
+    #[test]
+    fn values_snap_to_nearest_step() {
+        let slider = Slider::new(10.0..=20.0).step(2.5);
+
+        assert_eq!(
+            slider.clone().update(SliderMessage::DragTo(13.6)).value,
+            12.5
+        );
+        assert_eq!(
+            slider.clone().update(SliderMessage::DragTo(13.8)).value,
+            15.0
+        );
+        assert_eq!(
+            slider.clone().update(SliderMessage::DragTo(11.2)).value,
+            10.0
+        );
+        assert_eq!(
+            slider.clone().update(SliderMessage::SetValue(19.9)).value,
+            20.0
+        );
+
+        // A range that isn't a whole number of steps never snaps past its end
+        let uneven = Slider::new(0.0..=9.0).step(4.0);
+        assert_eq!(uneven.update(SliderMessage::DragTo(8.9)).value, 8.0);
+
+        // Non-positive steps are ignored
+        assert_eq!(Slider::new(0.0..=1.0).step(0.0).step, None);
+    }
+
+    #[test]
+    fn reversed_ranges_and_nan_values_are_normalized() {
+        let slider = Slider::new(10.0..=0.0);
+        assert_eq!(slider.range, 0.0..=10.0);
+        assert_eq!(slider.value, 0.0);
+        assert_eq!(slider.clone().value(12.0).value, 10.0);
+
+        assert_eq!(slider.clone().value(7.0).value(f32::NAN).value, 0.0);
+        let stepped = Slider::new(2.0..=4.0).step(0.5);
+        assert_eq!(stepped.update(SliderMessage::DragTo(f32::NAN)).value, 2.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "must not be NaN")]
+    fn nan_bounds_are_rejected() {
+        Slider::new(0.0..=f32::NAN);
+    }
+
+    #[test]
+    fn disabled_slider_ignores_drags() {
+        let slider = Slider::new(0.0..=1.0).value(0.5).disable();
+
+        let dragged = slider.clone().update(SliderMessage::DragTo(1.0));
+        assert_eq!(dragged.value, 0.5);
+
+        let set = slider.update(SliderMessage::SetValue(1.0));
+        assert_eq!(set.value, 1.0);
+    }
+
+    #[test]
+    fn slider_view_extraction() {
+        let ctx = RenderContext::new();
+        let slider = Slider::new(0.0..=100.0)
+            .value(25.0)
+            .update(SliderMessage::Interaction(
+                InteractionMessage::PressStateChanged(true),
+            ));
+        assert!(slider.is_pressed());
+
+        let extracted = MockBackend::extract(&slider.view(), &ctx).unwrap();
+        assert_eq!(extracted.value, 25.0);
+        assert_eq!(extracted.min, 0.0);
+        assert_eq!(extracted.max, 100.0);
+        assert!(extracted.interaction_state.is_pressed());
+    }
+}
+
+// End of File
@@ -0,0 +1,292 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Stepper component for incrementing a bounded integer
+//!
+//! The Stepper component pairs an `i32` value with increment and decrement
+//! controls. Steps saturate at the range bounds, and the view reports which
+//! controls are usable so backends can gray out the ones at a bound. Like
+//! Button, a Stepper is a model that produces StepperView instances through
+//! its view() method.
+
+use std::{any::Any, ops::RangeInclusive};
+
+use crate::{
+    interaction::{Enableable, InteractionMessage, InteractionState, Interactive},
+    message::Message,
+    model::Model,
+    style::DisabledStyle,
+    view::View,
+};
+
+/// View representation of a stepper's visual state.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StepperView {
+    /// The current value, always within `min..=max`
+    pub value: i32,
+    /// The lower bound of the range
+    pub min: i32,
+    /// The upper bound of the range
+    pub max: i32,
+    /// How far each increment or decrement moves the value
+    pub step: i32,
+    /// Whether the increment control can be used
+    pub increment_enabled: bool,
+    /// Whether the decrement control can be used
+    pub decrement_enabled: bool,
+    /// Current interaction state (enabled, pressed, focused, hovered)
+    pub interaction_state: InteractionState,
+    /// Appearance applied while the stepper is disabled
+    pub disabled_style: DisabledStyle,
+}
+
+impl View for StepperView {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Messages that represent user interactions with a Stepper component.
+#[derive(Debug, Clone, PartialEq)]
+pub enum StepperMessage {
+    /// The user pressed the increment control
+    Increment,
+    /// The user pressed the decrement control
+    Decrement,
+    /// The value was set programmatically
+    SetValue(i32),
+    /// Standard interaction (enabled, pressed, focused, hovered state changes)
+    Interaction(InteractionMessage),
+}
+
+impl Message for StepperMessage {}
+
+/// Stepper component that holds an integer within an inclusive range.
+///
+/// Increments and decrements move the value by `step` and saturate at the
+/// bounds; a disabled stepper ignores them. `SetValue` always applies, clamped
+/// into the range, so applications can keep the stepper in sync with their data.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{prelude::*, widgets::{Stepper, StepperMessage}};
+///
+/// let quantity = Stepper::new(0..=10).step(4);
+///
+/// let quantity = quantity
+///     .update(StepperMessage::Increment)
+///     .update(StepperMessage::Increment)
+///     .update(StepperMessage::Increment);
+/// assert_eq!(quantity.value, 10);
+/// assert!(!quantity.view().increment_enabled);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stepper {
+    /// The current value, always within `range`
+    pub value: i32,
+    /// The inclusive range of allowed values
+    pub range: RangeInclusive<i32>,
+    /// How far each increment or decrement moves the value (set at creation)
+    pub step: i32,
+    /// Base interactive functionality (enabled, pressed, focused, hovered states)
+    pub interactive: Interactive,
+    /// Appearance applied while the stepper is disabled (set at creation)
+    pub disabled_style: DisabledStyle,
+}
+
+impl Stepper {
+    /// Create a new stepper over the given range, starting at its lower bound.
+    ///
+    /// A reversed range such as `5..=1` is treated as `1..=5`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::{prelude::*, widgets::Stepper};
+    ///
+    /// let stepper = Stepper::new(1..=5);
+    /// assert_eq!(stepper.value, 1);
+    /// assert_eq!(stepper.step, 1);
+    /// ```
+    pub fn new(range: RangeInclusive<i32>) -> Self {
+        let (start, end) = range.into_inner();
+        let (min, max) = (start.min(end), start.max(end));
+        Self {
+            value: min,
+            range: min..=max,
+            step: 1,
+            interactive: Interactive::new(),
+            disabled_style: DisabledStyle::default(),
+        }
+    }
+
+    /// Set the initial value, clamped into the range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::{prelude::*, widgets::Stepper};
+    ///
+    /// let stepper = Stepper::new(0..=10).value(25);
+    /// assert_eq!(stepper.value, 10);
+    /// ```
+    pub fn value(mut self, value: i32) -> Self {
+        // Unlike `clamp`, this doesn't panic if the public `range` field was
+        // reversed after construction
+        self.value = value.max(*self.range.start()).min(*self.range.end());
+        self
+    }
+
+    /// Set how far each increment or decrement moves the value.
+    ///
+    /// Non-positive steps are ignored.
+    pub fn step(mut self, step: i32) -> Self {
+        if step > 0 {
+            self.step = step;
+        }
+        self
+    }
+
+    /// Set how this stepper appears while disabled.
+    pub fn disabled_style(mut self, style: DisabledStyle) -> Self {
+        self.disabled_style = style;
+        self
+    }
+
+    /// Check whether incrementing would change the value.
+    pub fn can_increment(&self) -> bool {
+        self.is_enabled() && self.value < *self.range.end()
+    }
+
+    /// Check whether decrementing would change the value.
+    pub fn can_decrement(&self) -> bool {
+        self.is_enabled() && self.value > *self.range.start()
+    }
+}
+
+impl Model for Stepper {
+    type Message = StepperMessage;
+    type View = StepperView;
+
+    /// Update the stepper's state based on the received message.
+    fn update(self, message: Self::Message) -> Self {
+        match message {
+            StepperMessage::Increment if self.is_enabled() => {
+                let value = self.value.saturating_add(self.step);
+                self.value(value)
+            }
+            StepperMessage::Decrement if self.is_enabled() => {
+                let value = self.value.saturating_sub(self.step);
+                self.value(value)
+            }
+            StepperMessage::Increment | StepperMessage::Decrement => self,
+            StepperMessage::SetValue(value) => self.value(value),
+            StepperMessage::Interaction(interaction_msg) => Self {
+                interactive: self.interactive.update(interaction_msg),
+                ..self
+            },
+        }
+    }
+
+    /// Create a view representation of this stepper's current state.
+    fn view(&self) -> Self::View {
+        StepperView {
+            value: self.value,
+            min: *self.range.start(),
+            max: *self.range.end(),
+            step: self.step,
+            increment_enabled: self.can_increment(),
+            decrement_enabled: self.can_decrement(),
+            interaction_state: self.interactive.state,
+            disabled_style: self.disabled_style,
+        }
+    }
+}
+
+crate::delegate_interaction!(Stepper, interactive);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        backends::mock::MockBackend,
+        extraction::{RenderContext, ViewExtractor},
+    };
+
+    #[test]
+    fn steps_saturate_at_bounds() {
+        let stepper = Stepper::new(-5..=5).step(3);
+
+        let stepper = stepper.update(StepperMessage::Decrement);
+        assert_eq!(stepper.value, -5);
+
+        let stepper = stepper
+            .update(StepperMessage::Increment)
+            .update(StepperMessage::Increment);
+        assert_eq!(stepper.value, 1);
+
+        let stepper = stepper
+            .update(StepperMessage::Increment)
+            .update(StepperMessage::Increment);
+        assert_eq!(stepper.value, 5);
+
+        // Saturating arithmetic keeps extreme ranges from overflowing
+        let wide = Stepper::new(i32::MIN..=i32::MAX)
+            .value(i32::MAX - 1)
+            .step(10)
+            .update(StepperMessage::Increment);
+        assert_eq!(wide.value, i32::MAX);
+    }
+
+    #[test]
+    fn reversed_ranges_are_normalized() {
+        let stepper = Stepper::new(RangeInclusive::new(5, 1));
+        assert_eq!(stepper.range, 1..=5);
+        assert_eq!(stepper.value, 1);
+
+        let stepper = stepper
+            .update(StepperMessage::Decrement)
+            .update(StepperMessage::SetValue(9));
+        assert_eq!(stepper.value, 5);
+        assert_eq!(stepper.update(StepperMessage::Increment).value, 5);
+    }
+
+    #[test]
+    fn set_value_clamps_and_ignores_disabled_state() {
+        let stepper = Stepper::new(0..=10).disable();
+
+        let stepper = stepper.update(StepperMessage::Increment);
+        assert_eq!(stepper.value, 0);
+
+        let stepper = stepper.update(StepperMessage::SetValue(42));
+        assert_eq!(stepper.value, 10);
+
+        let stepper = stepper.update(StepperMessage::SetValue(-1));
+        assert_eq!(stepper.value, 0);
+    }
+
+    #[test]
+    fn stepper_view_reports_usable_controls() {
+        let ctx = RenderContext::new();
+
+        let at_min = MockBackend::extract(&Stepper::new(0..=2).view(), &ctx).unwrap();
+        assert!(at_min.increment_enabled);
+        assert!(!at_min.decrement_enabled);
+
+        let middle = Stepper::new(0..=2).value(1);
+        let extracted = MockBackend::extract(&middle.view(), &ctx).unwrap();
+        assert_eq!(extracted.value, 1);
+        assert!(extracted.increment_enabled);
+        assert!(extracted.decrement_enabled);
+        assert_eq!(extracted.opacity, 1.0);
+
+        let disabled = MockBackend::extract(&middle.disable().view(), &ctx).unwrap();
+        assert!(!disabled.increment_enabled);
+        assert!(!disabled.decrement_enabled);
+        assert_eq!(disabled.opacity, DisabledStyle::DEFAULT_OPACITY);
+    }
+}
+
+// End of File
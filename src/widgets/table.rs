@@ -0,0 +1,729 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Table component for displaying rows of data in columns
+//!
+//! A Table holds application rows of any type together with column
+//! definitions that describe how to render each cell. Clicking a header sorts
+//! by that column and clicking a row selects it. Sorting only changes the
+//! display order; the rows themselves stay in the order the application
+//! provided. Like Button, a Table is a model that produces TableView instances
+//! through its view() method.
+
+use std::{
+    any::Any,
+    cmp::Ordering,
+    fmt::{Debug, Formatter, Result as FormatterResult},
+    sync::Arc,
+};
+
+use crate::{
+    elements::Alignment,
+    interaction::{Enableable, InteractionMessage, InteractionState, Interactive},
+    message::Message,
+    model::Model,
+    style::DisabledStyle,
+    view::View,
+};
+
+/// Function that renders a row's cell as text.
+type CellRenderer<R> = Arc<dyn Fn(&R) -> String + Send + Sync>;
+
+/// Function that orders two rows by a column.
+type RowComparator<R> = Arc<dyn Fn(&R, &R) -> Ordering + Send + Sync>;
+
+/// Function that writes edited cell text back into a row.
+type CellSetter<R> = Arc<dyn Fn(&mut R, &str) + Send + Sync>;
+
+/// Definition of a table column over rows of type `R`.
+///
+/// By default a column sorts by the text of its cells. Columns holding
+/// numbers or dates should provide a comparator with [`Column::sort_by`].
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{prelude::*, widgets::Column};
+///
+/// struct File {
+///     name: String,
+///     size: u64,
+/// }
+///
+/// let size = Column::new("Size", |file: &File| format!("{} KB", file.size))
+///     .alignment(Alignment::Trailing)
+///     .sort_by(|a, b| a.size.cmp(&b.size));
+///
+/// let file = File { name: "notes.txt".to_string(), size: 12 };
+/// assert_eq!(size.cell(&file), "12 KB");
+/// ```
+pub struct Column<R> {
+    /// The column's header text
+    pub header: String,
+    /// How cells in this column are aligned horizontally
+    pub alignment: Alignment,
+    /// Renders a row's cell for this column
+    render: CellRenderer<R>,
+    /// Orders rows when sorting by this column, if not by cell text
+    compare: Option<RowComparator<R>>,
+    /// Applies committed edits, if cells in this column are editable
+    set: Option<CellSetter<R>>,
+}
+
+impl<R> Column<R> {
+    /// Create a leading-aligned column that renders cells with `render`.
+    pub fn new(
+        header: impl Into<String>,
+        render: impl Fn(&R) -> String + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            header: header.into(),
+            alignment: Alignment::default(),
+            render: Arc::new(render),
+            compare: None,
+            set: None,
+        }
+    }
+
+    /// Set how cells in this column are aligned.
+    pub fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Sort rows by this column with a custom comparator instead of cell text.
+    pub fn sort_by(mut self, compare: impl Fn(&R, &R) -> Ordering + Send + Sync + 'static) -> Self {
+        self.compare = Some(Arc::new(compare));
+        self
+    }
+
+    /// Make cells in this column editable, applying committed text with `set`.
+    ///
+    /// The setter receives the full edited text and may ignore input it
+    /// can't parse, in which case the row is left unchanged.
+    pub fn editable(mut self, set: impl Fn(&mut R, &str) + Send + Sync + 'static) -> Self {
+        self.set = Some(Arc::new(set));
+        self
+    }
+
+    /// Check whether cells in this column can be edited.
+    pub fn is_editable(&self) -> bool {
+        self.set.is_some()
+    }
+
+    /// Render the cell for a row.
+    pub fn cell(&self, row: &R) -> String {
+        (self.render)(row)
+    }
+
+    /// Order two rows by this column.
+    fn compare(&self, a: &R, b: &R) -> Ordering {
+        match &self.compare {
+            Some(compare) => compare(a, b),
+            None => self.cell(a).cmp(&self.cell(b)),
+        }
+    }
+}
+
+// Manual impl: derive would require `R: Clone` even though only the Arcs are cloned
+impl<R> Clone for Column<R> {
+    fn clone(&self) -> Self {
+        Self {
+            header: self.header.clone(),
+            alignment: self.alignment,
+            render: self.render.clone(),
+            compare: self.compare.clone(),
+            set: self.set.clone(),
+        }
+    }
+}
+
+impl<R> Debug for Column<R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FormatterResult {
+        f.debug_struct("Column")
+            .field("header", &self.header)
+            .field("alignment", &self.alignment)
+            .field("custom_sort", &self.compare.is_some())
+            .field("editable", &self.set.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Direction of a table sort.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SortDirection {
+    /// Smallest values first
+    #[default]
+    Ascending,
+    /// Largest values first
+    Descending,
+}
+
+impl SortDirection {
+    /// Get the opposite direction.
+    pub fn reversed(self) -> Self {
+        match self {
+            Self::Ascending => Self::Descending,
+            Self::Descending => Self::Ascending,
+        }
+    }
+}
+
+/// The column a table is sorted by and in which direction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SortState {
+    /// Index of the sort column
+    pub column: usize,
+    /// Direction of the sort
+    pub direction: SortDirection,
+}
+
+/// A cell being edited and the text typed into it so far.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CellEdit {
+    /// Row of the cell; an index into the rows in a Table and a display
+    /// position in a TableView
+    pub row: usize,
+    /// Column of the cell
+    pub column: usize,
+    /// The edit buffer, shown in place of the cell's text
+    pub text: String,
+}
+
+/// View representation of a table's visual state.
+///
+/// Rows are rendered to text and listed in display order, so backends don't
+/// need to know the application's row type.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableView {
+    /// Header text of each column
+    pub headers: Vec<String>,
+    /// Horizontal alignment of each column
+    pub alignments: Vec<Alignment>,
+    /// Cell text of each row, in display order
+    pub rows: Vec<Vec<String>>,
+    /// Display position of the selected row, if any
+    pub selected: Option<usize>,
+    /// Column of the selected cell within the selected row, if any
+    pub selected_column: Option<usize>,
+    /// Whether cells in each column can be edited
+    pub editable: Vec<bool>,
+    /// The cell being edited, if any, with its row as a display position
+    pub editing: Option<CellEdit>,
+    /// The current sort, if any
+    pub sort: Option<SortState>,
+    /// Current interaction state (enabled, pressed, focused, hovered)
+    pub interaction_state: InteractionState,
+    /// Appearance applied while the table is disabled
+    pub disabled_style: DisabledStyle,
+}
+
+impl View for TableView {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Messages that represent user interactions with a Table component.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TableMessage {
+    /// The user selected the row at the given display position
+    Select(usize),
+    /// The user selected the cell at the given display position and column
+    SelectCell {
+        /// Display position of the cell's row
+        row: usize,
+        /// Column of the cell
+        column: usize,
+    },
+    /// The selection was cleared
+    ClearSelection,
+    /// Start editing the selected cell, with its current text in the buffer
+    BeginEdit,
+    /// The text in the active cell's editor changed
+    EditText(String),
+    /// Write the edited text back into the row and stop editing
+    CommitEdit,
+    /// Discard the edited text and stop editing
+    CancelEdit,
+    /// The user clicked the header of the given column
+    ///
+    /// Sorting by the current sort column reverses the direction; any other
+    /// column sorts ascending.
+    SortBy(usize),
+    /// Standard interaction (enabled, pressed, focused, hovered state changes)
+    Interaction(InteractionMessage),
+}
+
+impl Message for TableMessage {}
+
+/// Table component that displays rows of type `R` in columns.
+///
+/// The selection follows its row when the table is re-sorted. Cells in
+/// editable columns can be edited in place: editing starts from the selected
+/// cell, and the row data only changes when the edit is committed. Selecting
+/// another cell or clearing the selection cancels an edit in progress. A
+/// disabled table ignores selection, sorting, and editing, and out-of-range
+/// rows or columns are ignored as well.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{prelude::*, widgets::{Column, Table, TableMessage}};
+///
+/// let table = Table::new(vec![
+///     Column::new("Name", |row: &(&str, u32)| row.0.to_string()),
+///     Column::new("Age", |row: &(&str, u32)| row.1.to_string())
+///         .sort_by(|a, b| a.1.cmp(&b.1)),
+/// ])
+/// .rows(vec![("Mira", 34), ("Ada", 36), ("Linus", 8)]);
+///
+/// let table = table.update(TableMessage::SortBy(1));
+/// let view = table.view();
+/// assert_eq!(view.rows[0], vec!["Linus", "8"]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Table<R> {
+    /// Column definitions, in display order
+    pub columns: Vec<Column<R>>,
+    /// The rows, in the order the application provided
+    pub rows: Vec<R>,
+    /// Index into `rows` of the selected row, if any
+    pub selected: Option<usize>,
+    /// Column of the selected cell within the selected row, if any
+    pub selected_column: Option<usize>,
+    /// The cell being edited, if any
+    pub editing: Option<CellEdit>,
+    /// The current sort, if any
+    pub sort: Option<SortState>,
+    /// Base interactive functionality (enabled, pressed, focused, hovered states)
+    pub interactive: Interactive,
+    /// Appearance applied while the table is disabled (set at creation)
+    pub disabled_style: DisabledStyle,
+}
+
+impl<R> Table<R> {
+    /// Create an empty, unsorted table with the given columns.
+    pub fn new(columns: Vec<Column<R>>) -> Self {
+        Self {
+            columns,
+            rows: Vec::new(),
+            selected: None,
+            selected_column: None,
+            editing: None,
+            sort: None,
+            interactive: Interactive::new(),
+            disabled_style: DisabledStyle::default(),
+        }
+    }
+
+    /// Set the table's rows, clearing any selection or edit.
+    pub fn rows(mut self, rows: Vec<R>) -> Self {
+        self.rows = rows;
+        self.selected = None;
+        self.selected_column = None;
+        self.editing = None;
+        self
+    }
+
+    /// Set how this table appears while disabled.
+    pub fn disabled_style(mut self, style: DisabledStyle) -> Self {
+        self.disabled_style = style;
+        self
+    }
+
+    /// Get the indices into `rows` in display order.
+    ///
+    /// The sort is stable, so rows that compare equal keep their original
+    /// relative order and the display order is fully deterministic.
+    pub fn display_order(&self) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..self.rows.len()).collect();
+        if let Some(sort) = self.sort
+            && let Some(column) = self.columns.get(sort.column)
+        {
+            order.sort_by(|&a, &b| {
+                let ordering = column.compare(&self.rows[a], &self.rows[b]);
+                match sort.direction {
+                    SortDirection::Ascending => ordering,
+                    SortDirection::Descending => ordering.reverse(),
+                }
+            });
+        }
+        order
+    }
+
+    /// Get the selected row, if any.
+    pub fn selected_row(&self) -> Option<&R> {
+        self.selected.and_then(|index| self.rows.get(index))
+    }
+
+    /// Check whether a cell is being edited.
+    pub fn is_editing(&self) -> bool {
+        self.editing.is_some()
+    }
+}
+
+impl<R> Model for Table<R>
+where
+    R: Clone + Debug + Send + Sync + 'static,
+{
+    type Message = TableMessage;
+    type View = TableView;
+
+    /// Update the table's state based on the received message.
+    fn update(self, message: Self::Message) -> Self {
+        match message {
+            TableMessage::Select(position) if self.is_enabled() => {
+                match self.display_order().get(position) {
+                    Some(&index) => Self {
+                        selected: Some(index),
+                        selected_column: None,
+                        editing: None,
+                        ..self
+                    },
+                    None => self,
+                }
+            }
+            TableMessage::SelectCell { row, column }
+                if self.is_enabled() && column < self.columns.len() =>
+            {
+                match self.display_order().get(row) {
+                    Some(&index) => Self {
+                        selected: Some(index),
+                        selected_column: Some(column),
+                        editing: None,
+                        ..self
+                    },
+                    None => self,
+                }
+            }
+            TableMessage::SortBy(column) if self.is_enabled() && column < self.columns.len() => {
+                let direction = match self.sort {
+                    Some(sort) if sort.column == column => sort.direction.reversed(),
+                    _ => SortDirection::Ascending,
+                };
+                Self {
+                    sort: Some(SortState { column, direction }),
+                    ..self
+                }
+            }
+            TableMessage::BeginEdit if self.is_enabled() => {
+                match (self.selected, self.selected_column) {
+                    (Some(row), Some(column)) if self.columns[column].is_editable() => {
+                        let text = self.columns[column].cell(&self.rows[row]);
+                        Self {
+                            editing: Some(CellEdit { row, column, text }),
+                            ..self
+                        }
+                    }
+                    _ => self,
+                }
+            }
+            TableMessage::EditText(text) => match self.editing {
+                Some(edit) => Self {
+                    editing: Some(CellEdit { text, ..edit }),
+                    ..self
+                },
+                None => self,
+            },
+            TableMessage::CommitEdit => match self.editing {
+                Some(edit) => {
+                    let mut rows = self.rows;
+                    if let Some(set) = &self.columns[edit.column].set {
+                        set(&mut rows[edit.row], &edit.text);
+                    }
+                    Self {
+                        rows,
+                        editing: None,
+                        ..self
+                    }
+                }
+                None => self,
+            },
+            TableMessage::Select(_)
+            | TableMessage::SelectCell { .. }
+            | TableMessage::SortBy(_)
+            | TableMessage::BeginEdit => self,
+            TableMessage::ClearSelection => Self {
+                selected: None,
+                selected_column: None,
+                editing: None,
+                ..self
+            },
+            TableMessage::CancelEdit => Self {
+                editing: None,
+                ..self
+            },
+            TableMessage::Interaction(interaction_msg) => Self {
+                interactive: self.interactive.update(interaction_msg),
+                ..self
+            },
+        }
+    }
+
+    /// Create a view representation of this table's current state.
+    fn view(&self) -> Self::View {
+        let order = self.display_order();
+        let position = |index: usize| order.iter().position(|&row| row == index);
+        TableView {
+            headers: self
+                .columns
+                .iter()
+                .map(|column| column.header.clone())
+                .collect(),
+            alignments: self.columns.iter().map(|column| column.alignment).collect(),
+            rows: order
+                .iter()
+                .map(|&index| {
+                    let row = &self.rows[index];
+                    self.columns.iter().map(|column| column.cell(row)).collect()
+                })
+                .collect(),
+            selected: self.selected.and_then(&position),
+            selected_column: self.selected_column,
+            editable: self.columns.iter().map(Column::is_editable).collect(),
+            editing: self.editing.as_ref().and_then(|edit| {
+                Some(CellEdit {
+                    row: position(edit.row)?,
+                    ..edit.clone()
+                })
+            }),
+            sort: self.sort,
+            interaction_state: self.interactive.state,
+            disabled_style: self.disabled_style,
+        }
+    }
+}
+
+crate::delegate_interaction!(impl<R> Table<R>, interactive);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        backends::mock::MockBackend,
+        extraction::{RenderContext, ViewExtractor},
+    };
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Planet {
+        name: &'static str,
+        moons: u32,
+    }
+
+    fn editable_planets() -> Table<Planet> {
+        let mut table = planets();
+        table.columns[1] = table.columns[1]
+            .clone()
+            .editable(|planet: &mut Planet, text| {
+                if let Ok(moons) = text.trim().parse() {
+                    planet.moons = moons;
+                }
+            });
+        table
+    }
+
+    fn planets() -> Table<Planet> {
+        Table::new(vec![
+            Column::new("Planet", |planet: &Planet| planet.name.to_string()),
+            Column::new("Moons", |planet: &Planet| planet.moons.to_string())
+                .alignment(Alignment::Trailing)
+                .sort_by(|a, b| a.moons.cmp(&b.moons)),
+        ])
+        .rows(vec![
+            Planet {
+                name: "Mars",
+                moons: 2,
+            },
+            Planet {
+                name: "Earth",
+                moons: 1,
+            },
+            Planet {
+                name: "Jupiter",
+                moons: 95,
+            },
+            Planet {
+                name: "Venus",
+                moons: 0,
+            },
+        ])
+    }
+
+    fn names(view: &TableView) -> Vec<&str> {
+        view.rows.iter().map(|row| row[0].as_str()).collect()
+    }
+
+    #[test]
+    fn sorting_reorders_rows() {
+        let table = planets();
+        assert_eq!(
+            names(&table.view()),
+            vec!["Mars", "Earth", "Jupiter", "Venus"]
+        );
+
+        let table = table.update(TableMessage::SortBy(0));
+        assert_eq!(
+            names(&table.view()),
+            vec!["Earth", "Jupiter", "Mars", "Venus"]
+        );
+
+        let table = table.update(TableMessage::SortBy(0));
+        assert_eq!(table.sort.unwrap().direction, SortDirection::Descending);
+        assert_eq!(
+            names(&table.view()),
+            vec!["Venus", "Mars", "Jupiter", "Earth"]
+        );
+
+        // Custom comparators sort numerically rather than by cell text
+        let table = table.update(TableMessage::SortBy(1));
+        assert_eq!(
+            names(&table.view()),
+            vec!["Venus", "Earth", "Mars", "Jupiter"]
+        );
+
+        // The application's row order is never changed
+        assert_eq!(table.rows[0].name, "Mars");
+    }
+
+    #[test]
+    fn selection_follows_row_across_sorts() {
+        let table = planets().update(TableMessage::Select(2));
+        assert_eq!(table.selected_row().unwrap().name, "Jupiter");
+
+        let table = table.update(TableMessage::SortBy(1));
+        assert_eq!(table.view().selected, Some(3));
+        assert_eq!(table.selected_row().unwrap().name, "Jupiter");
+
+        let table = table.update(TableMessage::Select(10));
+        assert_eq!(table.selected_row().unwrap().name, "Jupiter");
+
+        let table = table.update(TableMessage::ClearSelection);
+        assert_eq!(table.view().selected, None);
+    }
+
+    #[test]
+    fn committed_edit_updates_row() {
+        let table = editable_planets()
+            .update(TableMessage::SelectCell { row: 1, column: 1 })
+            .update(TableMessage::BeginEdit);
+        assert_eq!(
+            table.editing,
+            Some(CellEdit {
+                row: 1,
+                column: 1,
+                text: "1".to_string()
+            })
+        );
+
+        let table = table
+            .update(TableMessage::EditText("2".to_string()))
+            .update(TableMessage::CommitEdit);
+        assert!(!table.is_editing());
+        assert_eq!(
+            table.rows[1],
+            Planet {
+                name: "Earth",
+                moons: 2
+            }
+        );
+        assert_eq!(table.view().rows[1], vec!["Earth", "2"]);
+    }
+
+    #[test]
+    fn canceled_edit_leaves_row_unchanged() {
+        let table = editable_planets()
+            .update(TableMessage::SelectCell { row: 1, column: 1 })
+            .update(TableMessage::BeginEdit)
+            .update(TableMessage::EditText("7".to_string()));
+        assert_eq!(table.view().editing.unwrap().text, "7");
+
+        let table = table.update(TableMessage::CancelEdit);
+        assert!(!table.is_editing());
+        assert_eq!(
+            table.rows[1],
+            Planet {
+                name: "Earth",
+                moons: 1
+            }
+        );
+
+        // Moving to another cell also discards the edit
+        let table = table
+            .update(TableMessage::BeginEdit)
+            .update(TableMessage::EditText("7".to_string()))
+            .update(TableMessage::SelectCell { row: 2, column: 1 })
+            .update(TableMessage::CommitEdit);
+        assert_eq!(table.rows[1].moons, 1);
+        assert_eq!(table.rows[2].moons, 95);
+    }
+
+    #[test]
+    fn only_editable_cells_can_be_edited() {
+        let table = editable_planets()
+            .update(TableMessage::SelectCell { row: 0, column: 0 })
+            .update(TableMessage::BeginEdit);
+        assert!(!table.is_editing());
+
+        // Selecting a whole row doesn't select a cell to edit
+        let table = table
+            .update(TableMessage::Select(0))
+            .update(TableMessage::BeginEdit);
+        assert!(!table.is_editing());
+    }
+
+    #[test]
+    fn edited_cell_follows_row_across_sorts() {
+        let table = editable_planets()
+            .update(TableMessage::SelectCell { row: 2, column: 1 })
+            .update(TableMessage::BeginEdit)
+            .update(TableMessage::SortBy(1));
+
+        let view = table.view();
+        assert_eq!(view.editable, vec![false, true]);
+        assert_eq!(view.selected, Some(3));
+        assert_eq!(view.selected_column, Some(1));
+        assert_eq!(view.editing.unwrap().row, 3);
+    }
+
+    #[test]
+    fn disabled_table_ignores_selection_and_sorting() {
+        let table = planets()
+            .disable()
+            .update(TableMessage::Select(0))
+            .update(TableMessage::SelectCell { row: 0, column: 0 })
+            .update(TableMessage::SortBy(0));
+        assert_eq!(table.selected, None);
+        assert_eq!(table.sort, None);
+    }
+
+    #[test]
+    fn table_view_extraction() {
+        let table = planets()
+            .update(TableMessage::SortBy(0))
+            .update(TableMessage::Select(0));
+
+        let extracted = MockBackend::extract(&table.view(), &RenderContext::new()).unwrap();
+        assert_eq!(extracted.headers, vec!["Planet", "Moons"]);
+        assert_eq!(
+            extracted.alignments,
+            vec![Alignment::Leading, Alignment::Trailing]
+        );
+        assert_eq!(extracted.rows[0], vec!["Earth", "1"]);
+        assert_eq!(extracted.selected, Some(0));
+        assert_eq!(
+            extracted.sort,
+            Some(SortState {
+                column: 0,
+                direction: SortDirection::Ascending
+            })
+        );
+        assert_eq!(extracted.opacity, 1.0);
+    }
+}
+
+// End of File
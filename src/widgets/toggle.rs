@@ -0,0 +1,212 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Toggle component for binary on/off switches
+//!
+//! The Toggle component is a switch-style control for settings that take
+//! effect immediately. Unlike Checkbox it is strictly binary and has no
+//! indeterminate state. Its track is drawn with a configurable on-color and
+//! off-color.
+
+use std::any::Any;
+
+use crate::{
+    interaction::{Enableable, InteractionMessage, InteractionState, Interactive},
+    message::Message,
+    model::Model,
+    style::{Color, DisabledStyle},
+    view::View,
+};
+
+/// View representation of a toggle's visual state.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ToggleView {
+    /// Whether the toggle is switched on
+    pub is_on: bool,
+    /// Track color while switched on
+    pub on_color: Color,
+    /// Track color while switched off
+    pub off_color: Color,
+    /// Current interaction state (enabled, pressed, focused, hovered)
+    pub interaction_state: InteractionState,
+    /// Appearance applied while the toggle is disabled
+    pub disabled_style: DisabledStyle,
+}
+
+impl View for ToggleView {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Messages that represent user interactions with a Toggle component.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ToggleMessage {
+    /// The user switched the toggle on or off
+    Switched(bool),
+    /// Standard interaction (enabled, pressed, focused, hovered state changes)
+    Interaction(InteractionMessage),
+}
+
+impl Message for ToggleMessage {}
+
+/// Switch-style toggle component for binary settings.
+///
+/// Switching a disabled toggle has no effect.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::prelude::*;
+///
+/// let wifi = Toggle::new(false).on_color(Color::BLUE);
+///
+/// let wifi = wifi.update(ToggleMessage::Switched(true));
+/// assert!(wifi.is_on);
+/// assert_eq!(wifi.view().on_color, Color::BLUE);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Toggle {
+    /// Whether the toggle is switched on
+    pub is_on: bool,
+    /// Track color while switched on (set at creation)
+    pub on_color: Color,
+    /// Track color while switched off (set at creation)
+    pub off_color: Color,
+    /// Base interactive functionality (enabled, pressed, focused, hovered states)
+    pub interactive: Interactive,
+    /// Appearance applied while the toggle is disabled (set at creation)
+    pub disabled_style: DisabledStyle,
+}
+
+impl Toggle {
+    /// Create a new toggle in the given position with default colors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// let toggle = Toggle::new(true);
+    /// assert!(toggle.is_on);
+    /// assert!(toggle.is_enabled());
+    /// ```
+    pub fn new(is_on: bool) -> Self {
+        Self {
+            is_on,
+            on_color: Color::rgb(0.2, 0.78, 0.35), // Green
+            off_color: Color::rgb(0.9, 0.9, 0.9),  // Light gray
+            interactive: Interactive::new(),
+            disabled_style: DisabledStyle::default(),
+        }
+    }
+
+    /// Set the track color used while the toggle is on.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// let toggle = Toggle::new(true).on_color(Color::BLUE);
+    /// assert_eq!(toggle.on_color, Color::BLUE);
+    /// ```
+    pub fn on_color(mut self, color: Color) -> Self {
+        self.on_color = color;
+        self
+    }
+
+    /// Set the track color used while the toggle is off.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ironwood::prelude::*;
+    ///
+    /// let toggle = Toggle::new(false).off_color(Color::BLACK);
+    /// assert_eq!(toggle.off_color, Color::BLACK);
+    /// ```
+    pub fn off_color(mut self, color: Color) -> Self {
+        self.off_color = color;
+        self
+    }
+
+    /// Set how this toggle appears while disabled.
+    pub fn disabled_style(mut self, style: DisabledStyle) -> Self {
+        self.disabled_style = style;
+        self
+    }
+}
+
+impl Model for Toggle {
+    type Message = ToggleMessage;
+    type View = ToggleView;
+
+    /// Update the toggle's state based on the received message.
+    fn update(self, message: Self::Message) -> Self {
+        match message {
+            ToggleMessage::Switched(is_on) if self.is_enabled() => Self { is_on, ..self },
+            ToggleMessage::Switched(_) => self,
+            ToggleMessage::Interaction(interaction_msg) => Self {
+                interactive: self.interactive.update(interaction_msg),
+                ..self
+            },
+        }
+    }
+
+    /// Create a view representation of this toggle's current state.
+    fn view(&self) -> Self::View {
+        ToggleView {
+            is_on: self.is_on,
+            on_color: self.on_color,
+            off_color: self.off_color,
+            interaction_state: self.interactive.state,
+            disabled_style: self.disabled_style,
+        }
+    }
+}
+
+crate::delegate_interaction!(Toggle, interactive);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        backends::mock::MockBackend,
+        extraction::{RenderContext, ViewExtractor},
+        interaction::Focusable,
+    };
+
+    #[test]
+    fn toggle_switches_on_and_off() {
+        let toggle = Toggle::new(false);
+
+        let toggle = toggle.update(ToggleMessage::Switched(true));
+        assert!(toggle.is_on);
+
+        let toggle = toggle.update(ToggleMessage::Switched(false));
+        assert!(!toggle.is_on);
+
+        let disabled = Toggle::new(false).disable();
+        assert!(!disabled.update(ToggleMessage::Switched(true)).is_on);
+    }
+
+    #[test]
+    fn toggle_view_extraction() {
+        let ctx = RenderContext::new();
+        let toggle = Toggle::new(true)
+            .on_color(Color::GREEN)
+            .off_color(Color::RED)
+            .focus();
+
+        let extracted = MockBackend::extract(&toggle.view(), &ctx).unwrap();
+        assert!(extracted.is_on);
+        assert_eq!(extracted.on_color, Color::GREEN);
+        assert_eq!(extracted.off_color, Color::RED);
+        assert!(extracted.interaction_state.is_focused());
+        assert_eq!(extracted.opacity, 1.0);
+    }
+}
+
+// End of File
@@ -0,0 +1,171 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Toolbar row with leading, center, and trailing regions
+//!
+//! A toolbar places controls at both ends of a row and a title in the
+//! middle. It is composed from existing elements: the side regions sit in
+//! an HStack pushed apart by a Spacer, and the center region is layered on
+//! top with a ZStack. Because the center shares the full frame rather than
+//! the space left between the sides, it stays centered however wide the
+//! side regions are.
+
+use crate::{
+    elements::{Alignment2D, HStack, Spacer, ZStack},
+    view::View,
+};
+
+/// The view a toolbar builds: the side regions in a row, with the center layered on top.
+pub type ToolbarView<L, C, T> = ZStack<(HStack<(L, Spacer, T)>, C)>;
+
+/// A row with leading, center, and trailing regions.
+///
+/// Regions start empty, as the unit view `()`, and take no space. Setting a
+/// region replaces it with any view, such as a button or a stack of buttons.
+/// The toolbar holds no state of its own; parents keep the models behind
+/// its controls and route their messages as usual.
+///
+/// # Examples
+///
+/// ```
+/// use ironwood::{prelude::*, widgets::Toolbar};
+///
+/// let toolbar = Toolbar::new()
+///     .leading(Button::new("Back").view())
+///     .center(Text::new("Settings"))
+///     .trailing(Button::new("Done").view());
+///
+/// let view = toolbar.into_view();
+/// assert_eq!(view.content.1.content, "Settings");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Toolbar<L = (), C = (), T = ()> {
+    /// Content at the leading edge
+    pub leading: L,
+    /// Content centered in the toolbar
+    pub center: C,
+    /// Content at the trailing edge
+    pub trailing: T,
+    /// Minimum spacing between the side regions
+    pub spacing: f32,
+}
+
+impl Toolbar {
+    /// Create a toolbar with every region empty.
+    pub fn new() -> Self {
+        Self {
+            leading: (),
+            center: (),
+            trailing: (),
+            spacing: 0.0,
+        }
+    }
+}
+
+impl Default for Toolbar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<L: View, C: View, T: View> Toolbar<L, C, T> {
+    /// Place `view` at the leading edge.
+    pub fn leading<V: View>(self, view: V) -> Toolbar<V, C, T> {
+        Toolbar {
+            leading: view,
+            center: self.center,
+            trailing: self.trailing,
+            spacing: self.spacing,
+        }
+    }
+
+    /// Place `view` in the center.
+    pub fn center<V: View>(self, view: V) -> Toolbar<L, V, T> {
+        Toolbar {
+            leading: self.leading,
+            center: view,
+            trailing: self.trailing,
+            spacing: self.spacing,
+        }
+    }
+
+    /// Place `view` at the trailing edge.
+    pub fn trailing<V: View>(self, view: V) -> Toolbar<L, C, V> {
+        Toolbar {
+            leading: self.leading,
+            center: self.center,
+            trailing: view,
+            spacing: self.spacing,
+        }
+    }
+
+    /// Set the minimum spacing between the side regions.
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Build the view for this toolbar.
+    ///
+    /// The side regions are separated by a spacer that takes at least the
+    /// toolbar's spacing, and the center is aligned to the middle of the
+    /// whole frame.
+    pub fn into_view(self) -> ToolbarView<L, C, T> {
+        ZStack::new((
+            HStack::new((self.leading, Spacer::min_size(self.spacing), self.trailing)),
+            self.center,
+        ))
+        .alignment(Alignment2D::CENTER)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        backends::mock::MockBackend,
+        elements::Text,
+        extraction::{RenderContext, ViewExtractor},
+        model::Model,
+        widgets::Button,
+    };
+
+    #[test]
+    fn regions_are_placed_in_order() {
+        let toolbar = Toolbar::new()
+            .leading(Button::new("Back").view())
+            .center(Text::new("Inbox"))
+            .trailing(Button::new("Compose").view())
+            .spacing(12.0);
+
+        let extracted = MockBackend::extract(&toolbar.into_view(), &RenderContext::new()).unwrap();
+        let (row, center) = extracted.content;
+        let (leading, spacer, trailing) = row.content;
+        assert_eq!(leading.text, "Back");
+        assert_eq!(spacer.min_size, 12.0);
+        assert_eq!(trailing.text, "Compose");
+
+        // The center is layered over the full row, not placed between the sides
+        assert_eq!(center.content, "Inbox");
+        assert_eq!(extracted.alignment, Alignment2D::CENTER);
+    }
+
+    #[test]
+    fn empty_regions_collapse() {
+        let toolbar = Toolbar::new().center(Text::new("Title"));
+        let extracted = MockBackend::extract(&toolbar.into_view(), &RenderContext::new()).unwrap();
+        let ((leading, _, trailing), center) = (extracted.content.0.content, extracted.content.1);
+        assert_eq!(leading, ());
+        assert_eq!(trailing, ());
+        assert_eq!(center.content, "Title");
+
+        // A toolbar with only a trailing action has no leading or center content
+        let toolbar = Toolbar::new().trailing(Text::new("Edit"));
+        let view = toolbar.into_view();
+        assert_eq!(view.content.1, ());
+        assert_eq!(view.content.0.content.2.content, "Edit");
+    }
+}
+
+// End of File
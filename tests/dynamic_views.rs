@@ -7,9 +7,12 @@
 //! These tests validate the dynamic view features introduced for runtime type checking,
 //! including downcasting, trait object behavior, error handling, and integration patterns.
 
-use std::any::TypeId;
+use std::{any::TypeId, thread};
 
-use ironwood::{backends::mock::MockBackend, prelude::*};
+use ironwood::{
+    backends::mock::{MockBackend, MockDynamicChild},
+    prelude::*,
+};
 
 /// Test dynamic downcasting functionality for core view types.
 ///
@@ -31,7 +34,7 @@ fn test_dynamic_downcasting_core_types() {
     let view: &dyn View = &button_view;
     let downcast_button = view.as_any().downcast_ref::<ButtonView>().unwrap();
     assert_eq!(downcast_button.text.content, "Click");
-    assert_eq!(downcast_button.background_color, Color::BLUE);
+    assert_eq!(downcast_button.background_color, Some(Color::BLUE));
 
     // Test container downcasting
     let vstack = VStack::new((Text::new("A"), Text::new("B"))).spacing(10.0);
@@ -129,7 +132,7 @@ fn test_conditional_view_construction() {
     let button_view = create_view(true);
     let button = button_view.as_any().downcast_ref::<ButtonView>().unwrap();
     assert_eq!(button.text.content, "Dynamic Button");
-    assert_eq!(button.background_color, Color::RED);
+    assert_eq!(button.background_color, Some(Color::RED));
 
     // Test text path
     let text_view = create_view(false);
@@ -175,7 +178,7 @@ fn test_framework_dynamic_integration() {
 /// This validates a real-world pattern of building UIs dynamically based on configuration.
 #[test]
 fn test_dynamic_form_generation() {
-    fn create_form_field(field_type: &str, label: &str) -> Box<dyn View> {
+    fn create_form_field(field_type: &str, label: &'static str) -> Box<dyn View> {
         match field_type {
             "title" => Box::new(Text::new(label).font_size(18.0)),
             "input" => Box::new(Text::new(format!("{}:", label))),
@@ -223,4 +226,52 @@ fn test_dynamic_form_generation() {
     );
 }
 
+/// Dynamic children always extract in insertion order.
+///
+/// Snapshot tests depend on this, so it's checked over many children,
+/// repeated extractions, and extractions running on several threads at once.
+#[test]
+fn test_dynamic_children_extract_in_insertion_order() {
+    fn labels(extracted: &MockDynamicChild) -> Vec<String> {
+        let MockDynamicChild::VStack(stack) = extracted else {
+            panic!("expected a dynamic stack, got {:?}", extracted);
+        };
+        stack
+            .content
+            .iter()
+            .map(|child| match child {
+                MockDynamicChild::Text(text) => text.content.clone(),
+                MockDynamicChild::Button(button) => button.text.clone(),
+                other => panic!("unexpected child {:?}", other),
+            })
+            .collect()
+    }
+
+    // Alternate child types so each one dispatches to a different extractor
+    let stack = VStack::from_children((0..100).map(|index| -> Box<dyn View> {
+        if index % 2 == 0 {
+            Box::new(Text::new(index.to_string()))
+        } else {
+            Box::new(Button::new(index.to_string()).view())
+        }
+    }));
+    let expected: Vec<String> = (0..100).map(|index| index.to_string()).collect();
+
+    let backend = MockBackend::new();
+    let ctx = RenderContext::new();
+    for _ in 0..10 {
+        let extracted = backend.extract_dynamic(&stack, &ctx).unwrap();
+        assert_eq!(labels(&extracted), expected);
+    }
+
+    thread::scope(|scope| {
+        let workers: Vec<_> = (0..4)
+            .map(|_| scope.spawn(|| backend.extract_dynamic(&stack, &ctx).unwrap()))
+            .collect();
+        for worker in workers {
+            assert_eq!(labels(&worker.join().unwrap()), expected);
+        }
+    });
+}
+
 // End of File
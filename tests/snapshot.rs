@@ -0,0 +1,100 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for binary snapshots
+//!
+//! These tests validate that extracted view trees survive a round trip
+//! through the compact binary format unchanged, and that the format is
+//! actually compact compared to JSON for a realistic tree.
+
+#![cfg(feature = "bincode")]
+
+use ironwood::{
+    backends::{
+        mock::{MockBackend, MockDynamicChild},
+        snapshot,
+    },
+    prelude::*,
+    style::FontFamily,
+};
+
+/// Build a dashboard like the one in the composition tests as a dynamic tree and extract it.
+fn extract_dashboard() -> MockDynamicChild {
+    let header = HStack::dynamic()
+        .child(Box::new(
+            Text::new("Dashboard").font_size(24.0).color(Color::BLUE),
+        ))
+        .child(Box::new(
+            Button::new("Settings")
+                .background_color(Color::rgb(0.7, 0.7, 0.7))
+                .view(),
+        ));
+
+    let metrics = VStack::dynamic()
+        .child(Box::new(
+            Text::new("Metrics").font_size(18.0).color(Color::BLACK),
+        ))
+        .child(Box::new(Text::new("Users: 1,234").color(Color::GREEN)))
+        .child(Box::new(Text::new("Revenue: $5,678").color(Color::BLUE)))
+        .child(Box::new(Text::new("Growth: +12%").color(Color::GREEN)))
+        .child(Box::new(
+            Text::new("Updated 09:41").font_family(FontFamily::named(String::from("Fira Code"))),
+        ))
+        .spacing(10.0);
+
+    let actions = HStack::dynamic()
+        .child(Box::new(
+            Button::new("Export Data")
+                .background_color(Color::BLUE)
+                .view(),
+        ))
+        .child(Box::new(Spacer::new()))
+        .child(Box::new(
+            Button::new("Refresh").background_color(Color::GREEN).view(),
+        ))
+        .child(Box::new(
+            Button::new("Help")
+                .background_color(Color::rgb(0.8, 0.8, 0.8))
+                .view(),
+        ))
+        .spacing(20.0);
+
+    let dashboard = VStack::dynamic()
+        .child(Box::new(header))
+        .child(Box::new(metrics))
+        .child(Box::new(actions));
+
+    MockBackend::new()
+        .extract_dynamic(&dashboard, &RenderContext::new())
+        .unwrap()
+}
+
+/// Test that a tree decodes to exactly the tree that was encoded.
+#[test]
+fn dashboard_round_trips_through_bytes() {
+    let extracted = extract_dashboard();
+
+    let bytes = snapshot::to_bytes(&extracted).unwrap();
+    let decoded: MockDynamicChild = snapshot::from_bytes(&bytes).unwrap();
+
+    assert_eq!(extracted, decoded);
+}
+
+/// Test that the binary snapshot is smaller than the same tree as JSON.
+#[test]
+fn binary_snapshot_is_smaller_than_json() {
+    let extracted = extract_dashboard();
+
+    let bytes = snapshot::to_bytes(&extracted).unwrap();
+    let json = serde_json::to_vec(&extracted).unwrap();
+
+    assert!(
+        bytes.len() < json.len(),
+        "binary snapshot ({} bytes) should be smaller than JSON ({} bytes)",
+        bytes.len(),
+        json.len()
+    );
+}
+
+// End of File
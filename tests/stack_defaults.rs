@@ -0,0 +1,55 @@
+// This Source Code Form is subject to the terms of the Mozilla Public License, v. 2.0.
+// If a copy of the MPL was not distributed with this file,
+// You can obtain one at <https://mozilla.org/MPL/2.0/>.
+
+//! Integration tests for process-wide stack defaults
+//!
+//! Stack defaults are global state, so these tests live in their own test
+//! binary to keep them from interfering with tests that rely on the built-in
+//! zero-spacing default.
+
+use ironwood::{backends::mock::MockBackend, elements::StackDefaults, prelude::*};
+
+/// Test that installed defaults apply to new stacks without overriding explicit values.
+///
+/// This validates that a design-system spacing convention can be configured once,
+/// while layouts that set spacing or alignment explicitly keep working unchanged.
+#[test]
+fn installed_defaults_apply_to_new_stacks() {
+    let ctx = RenderContext::new();
+
+    StackDefaults::new()
+        .spacing(8.0)
+        .alignment(Alignment::Center)
+        .install();
+
+    // Fresh static and dynamic stacks pick up the installed defaults
+    let vstack = VStack::new((Text::new("Top"), Text::new("Bottom")));
+    let extracted = MockBackend::extract(&vstack, &ctx).unwrap();
+    assert_eq!(extracted.spacing, 8.0);
+    assert_eq!(extracted.alignment, Alignment::Center);
+
+    let hstack = HStack::dynamic().child(Box::new(Text::new("Item")));
+    let extracted = MockBackend::extract(&hstack, &ctx).unwrap();
+    assert_eq!(extracted.spacing, 8.0);
+    assert_eq!(extracted.alignment, Alignment::Center);
+
+    // Explicit configuration always wins over the defaults
+    let explicit = VStack::new(Text::new("Explicit"))
+        .spacing(0.0)
+        .alignment(Alignment::Trailing);
+    let extracted = MockBackend::extract(&explicit, &ctx).unwrap();
+    assert_eq!(extracted.spacing, 0.0);
+    assert_eq!(extracted.alignment, Alignment::Trailing);
+
+    // Resetting restores the built-in defaults for subsequently created stacks
+    StackDefaults::reset();
+    let after_reset = HStack::new(Text::new("Reset"));
+    assert_eq!(after_reset.spacing, 0.0);
+    assert_eq!(after_reset.alignment, Alignment::Leading);
+
+    // Stacks created before the reset keep their configuration
+    assert_eq!(vstack.spacing, 8.0);
+}
+
+// End of File